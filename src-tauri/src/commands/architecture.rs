@@ -0,0 +1,216 @@
+use crate::providers::registry::resolve_swift_binary_path;
+use serde::Serialize;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::process::Command;
+use tauri::AppHandle;
+
+const MH_MAGIC: u32 = 0xfeed_face;
+const MH_MAGIC_64: u32 = 0xfeed_facf;
+const FAT_MAGIC: u32 = 0xcafe_babe;
+const FAT_MAGIC_64: u32 = 0xcafe_babf;
+const CPU_ARCH_ABI64: u32 = 0x0100_0000;
+const CPU_TYPE_X86: u32 = 7;
+const CPU_TYPE_ARM: u32 = 12;
+// Java class files share the fat magic; real universal binaries carry a handful of slices.
+const MAX_FAT_ARCHS: u32 = 16;
+const HEADER_READ_BYTES: u64 = 4096;
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchitectureCheck {
+    pub host_arch: String,
+    pub worker_path: String,
+    pub worker_archs: Vec<String>,
+    pub mismatch: bool,
+    pub detail: String,
+}
+
+fn arch_name(cpu_type: u32) -> String {
+    match cpu_type {
+        value if value == CPU_TYPE_X86 | CPU_ARCH_ABI64 => "x86_64".to_string(),
+        value if value == CPU_TYPE_ARM | CPU_ARCH_ABI64 => "arm64".to_string(),
+        CPU_TYPE_X86 => "i386".to_string(),
+        CPU_TYPE_ARM => "arm".to_string(),
+        other => format!("cpu-0x{:x}", other),
+    }
+}
+
+fn read_u32(bytes: &[u8], offset: usize, big_endian: bool) -> Option<u32> {
+    let word: [u8; 4] = bytes.get(offset..offset + 4)?.try_into().ok()?;
+    Some(if big_endian {
+        u32::from_be_bytes(word)
+    } else {
+        u32::from_le_bytes(word)
+    })
+}
+
+/// Lists the architectures in a thin or universal Mach-O header.
+fn macho_architectures(header: &[u8]) -> Result<Vec<String>, String> {
+    let magic = read_u32(header, 0, true).ok_or_else(|| "File is too short".to_string())?;
+    match magic {
+        MH_MAGIC | MH_MAGIC_64 => read_u32(header, 4, true)
+            .map(|cpu_type| vec![arch_name(cpu_type)])
+            .ok_or_else(|| "Truncated Mach-O header".to_string()),
+        value if value.swap_bytes() == MH_MAGIC || value.swap_bytes() == MH_MAGIC_64 => {
+            read_u32(header, 4, false)
+                .map(|cpu_type| vec![arch_name(cpu_type)])
+                .ok_or_else(|| "Truncated Mach-O header".to_string())
+        }
+        FAT_MAGIC | FAT_MAGIC_64 => {
+            let count = read_u32(header, 4, true)
+                .filter(|count| (1..=MAX_FAT_ARCHS).contains(count))
+                .ok_or_else(|| "Not a universal Mach-O binary".to_string())?;
+            let entry_size = if magic == FAT_MAGIC { 20 } else { 32 };
+            (0..count as usize)
+                .map(|index| {
+                    read_u32(header, 8 + index * entry_size, true)
+                        .map(arch_name)
+                        .ok_or_else(|| "Truncated universal Mach-O header".to_string())
+                })
+                .collect()
+        }
+        _ => Err("Not a Mach-O binary".to_string()),
+    }
+}
+
+fn read_binary_architectures(path: &Path) -> Result<Vec<String>, String> {
+    let mut header = Vec::new();
+    File::open(path)
+        .and_then(|file| file.take(HEADER_READ_BYTES).read_to_end(&mut header))
+        .map_err(|error| format!("Failed to read {}: {}", path.display(), error))?;
+    macho_architectures(&header)
+}
+
+fn normalize_host_arch(arch: &str) -> String {
+    match arch {
+        "aarch64" => "arm64".to_string(),
+        "x86" => "i386".to_string(),
+        other => other.to_string(),
+    }
+}
+
+// Under Rosetta the app itself reports x86_64, so ask the kernel what the hardware really is.
+fn running_under_rosetta() -> bool {
+    Command::new("sysctl")
+        .args(["-n", "sysctl.proc_translated"])
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim() == "1")
+        .unwrap_or(false)
+}
+
+fn host_arch() -> String {
+    if cfg!(target_os = "macos") && std::env::consts::ARCH == "x86_64" && running_under_rosetta() {
+        return "arm64".to_string();
+    }
+    normalize_host_arch(std::env::consts::ARCH)
+}
+
+fn assess_architecture(
+    host_arch: &str,
+    worker_path: &Path,
+    worker_archs: Result<Vec<String>, String>,
+) -> ArchitectureCheck {
+    let (worker_archs, mismatch, detail) = match worker_archs {
+        Ok(archs) if archs.iter().any(|arch| arch == host_arch) => {
+            let detail = format!("Worker binary includes a native {} build.", host_arch);
+            (archs, false, detail)
+        }
+        Ok(archs) => {
+            let detail = format!(
+                "Worker binary is built for {} but this Mac is {}; it will run under translation or fail.",
+                archs.join(", "),
+                host_arch
+            );
+            (archs, true, detail)
+        }
+        Err(error) => (
+            Vec::new(),
+            false,
+            format!("Could not determine worker architecture: {}", error),
+        ),
+    };
+
+    ArchitectureCheck {
+        host_arch: host_arch.to_string(),
+        worker_path: worker_path.to_string_lossy().to_string(),
+        worker_archs,
+        mismatch,
+        detail,
+    }
+}
+
+pub fn check_worker_architecture(worker_path: &Path) -> ArchitectureCheck {
+    assess_architecture(
+        &host_arch(),
+        worker_path,
+        read_binary_architectures(worker_path),
+    )
+}
+
+#[tauri::command]
+pub fn architecture_check(app: AppHandle) -> ArchitectureCheck {
+    check_worker_architecture(&resolve_swift_binary_path(&app))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thin_header_le(magic: u32, cpu_type: u32) -> Vec<u8> {
+        let mut header = magic.to_le_bytes().to_vec();
+        header.extend(cpu_type.to_le_bytes());
+        header.extend([0u8; 20]);
+        header
+    }
+
+    #[test]
+    fn parses_thin_and_universal_mach_o_headers() {
+        assert_eq!(
+            macho_architectures(&thin_header_le(MH_MAGIC_64, 0x0100_000c)),
+            Ok(vec!["arm64".to_string()])
+        );
+        assert_eq!(
+            macho_architectures(&thin_header_le(MH_MAGIC_64, 0x0100_0007)),
+            Ok(vec!["x86_64".to_string()])
+        );
+
+        let mut universal = FAT_MAGIC.to_be_bytes().to_vec();
+        universal.extend(2u32.to_be_bytes());
+        for cpu_type in [0x0100_0007u32, 0x0100_000c] {
+            universal.extend(cpu_type.to_be_bytes());
+            universal.extend([0u8; 16]);
+        }
+        assert_eq!(
+            macho_architectures(&universal),
+            Ok(vec!["x86_64".to_string(), "arm64".to_string()])
+        );
+
+        assert!(macho_architectures(b"#!/bin/sh\n").is_err());
+        assert!(macho_architectures(&[0xca, 0xfe]).is_err());
+        let mut java_class = FAT_MAGIC.to_be_bytes().to_vec();
+        java_class.extend(52u32.to_be_bytes());
+        assert!(macho_architectures(&java_class).is_err());
+    }
+
+    #[test]
+    fn flags_workers_without_a_native_slice_for_the_host() {
+        let path = Path::new("/Applications/Aura.app/Contents/MacOS/coreml-batch");
+
+        let translated = assess_architecture("arm64", path, Ok(vec!["x86_64".to_string()]));
+        assert!(translated.mismatch);
+        assert!(translated.detail.contains("built for x86_64"));
+
+        let universal = assess_architecture(
+            "arm64",
+            path,
+            Ok(vec!["x86_64".to_string(), "arm64".to_string()]),
+        );
+        assert!(!universal.mismatch);
+
+        let unreadable = assess_architecture("arm64", path, Err("Not a Mach-O binary".to_string()));
+        assert!(!unreadable.mismatch);
+        assert!(unreadable.worker_archs.is_empty());
+    }
+}
@@ -0,0 +1,520 @@
+use crate::commands::transcript::{
+    detect_transcript_format, read_transcript_file, TranscriptFormat,
+};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Cue {
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub text: String,
+}
+
+struct TranscriptDocument {
+    text: String,
+    cues: Option<Vec<Cue>>,
+}
+
+fn format_name(format: TranscriptFormat) -> &'static str {
+    match format {
+        TranscriptFormat::Text => "txt",
+        TranscriptFormat::Json => "json",
+        TranscriptFormat::Srt => "srt",
+        TranscriptFormat::Vtt => "vtt",
+    }
+}
+
+fn parse_target_format(value: &str) -> Result<TranscriptFormat, String> {
+    match value
+        .trim()
+        .trim_start_matches('.')
+        .to_ascii_lowercase()
+        .as_str()
+    {
+        "txt" | "text" => Ok(TranscriptFormat::Text),
+        "json" => Ok(TranscriptFormat::Json),
+        "srt" => Ok(TranscriptFormat::Srt),
+        "vtt" => Ok(TranscriptFormat::Vtt),
+        other => Err(format!("Unsupported transcript format: {}", other)),
+    }
+}
+
+// SRT writes `00:01:02,345`; VTT writes `00:01:02.345` and may drop the hours.
+fn parse_timestamp(value: &str, separator: char) -> Option<u64> {
+    let (clock, millis) = value.trim().split_once(separator)?;
+    let parts = clock
+        .split(':')
+        .map(|part| part.parse::<u64>().ok())
+        .collect::<Option<Vec<u64>>>()?;
+    let (hours, minutes, seconds) = match parts.as_slice() {
+        [hours, minutes, seconds] => (*hours, *minutes, *seconds),
+        [minutes, seconds] if separator == '.' => (0, *minutes, *seconds),
+        _ => return None,
+    };
+    Some(((hours * 60 + minutes) * 60 + seconds) * 1000 + millis.parse::<u64>().ok()?)
+}
+
+fn format_timestamp(millis: u64, separator: char) -> String {
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        millis / 3_600_000,
+        (millis / 60_000) % 60,
+        (millis / 1000) % 60,
+        separator,
+        millis % 1000
+    )
+}
+
+fn parse_cue_blocks(content: &str, separator: char, label: &str) -> Result<Vec<Cue>, String> {
+    let normalized = content.replace("\r\n", "\n");
+    let mut cues = Vec::new();
+
+    for block in normalized.split("\n\n") {
+        let lines = block
+            .lines()
+            .skip_while(|line| line.trim().is_empty())
+            .collect::<Vec<&str>>();
+        // Cue numbers and VTT identifiers sit above the timing line; they are renumbered on output.
+        let Some(timing_index) = lines.iter().position(|line| line.contains("-->")) else {
+            continue;
+        };
+        let timing = lines[timing_index];
+        let (start, end) = timing
+            .split_once("-->")
+            .and_then(|(start, end)| {
+                // VTT timing lines may carry cue settings after the end time.
+                let end = end.split_whitespace().next()?;
+                Some((
+                    parse_timestamp(start, separator)?,
+                    parse_timestamp(end, separator)?,
+                ))
+            })
+            .ok_or_else(|| format!("Invalid {} timing line: {}", label, timing))?;
+        cues.push(Cue {
+            start_ms: start,
+            end_ms: end,
+            text: lines[timing_index + 1..].join("\n"),
+        });
+    }
+
+    Ok(cues)
+}
+
+pub(crate) fn parse_srt_cues(content: &str) -> Result<Vec<Cue>, String> {
+    parse_cue_blocks(content, ',', "SRT")
+}
+
+pub(crate) fn render_srt(cues: &[Cue]) -> String {
+    let blocks = cues
+        .iter()
+        .enumerate()
+        .map(|(index, cue)| {
+            format!(
+                "{}\n{} --> {}\n{}",
+                index + 1,
+                format_timestamp(cue.start_ms, ','),
+                format_timestamp(cue.end_ms, ','),
+                cue.text
+            )
+        })
+        .collect::<Vec<String>>();
+    format!("{}\n", blocks.join("\n\n"))
+}
+
+fn render_vtt(cues: &[Cue]) -> String {
+    let blocks = cues
+        .iter()
+        .map(|cue| {
+            format!(
+                "{} --> {}\n{}",
+                format_timestamp(cue.start_ms, '.'),
+                format_timestamp(cue.end_ms, '.'),
+                cue.text
+            )
+        })
+        .collect::<Vec<String>>();
+    format!("WEBVTT\n\n{}\n", blocks.join("\n\n"))
+}
+
+fn seconds_to_millis(value: Option<&Value>) -> Option<u64> {
+    value
+        .and_then(Value::as_f64)
+        .filter(|seconds| seconds.is_finite() && *seconds >= 0.0)
+        .map(|seconds| (seconds * 1000.0).round() as u64)
+}
+
+// A pause this long between tokens starts a new cue, as does a token that ends a sentence.
+const TOKEN_CUE_GAP_MS: u64 = 1000;
+
+// The CoreML worker writes `tokenTimings` instead of segments; SentencePiece marks word starts with `▁`.
+fn token_timing_cues(tokens: &[Value]) -> Option<Vec<Cue>> {
+    let mut cues = Vec::new();
+    let mut current: Option<Cue> = None;
+    for token in tokens {
+        let start_ms = seconds_to_millis(token.get("startTime"))?;
+        let end_ms = seconds_to_millis(token.get("endTime"))?;
+        let piece = token
+            .get("token")
+            .and_then(Value::as_str)?
+            .replace('\u{2581}', " ");
+
+        let continues = current
+            .as_ref()
+            .is_some_and(|cue| start_ms.saturating_sub(cue.end_ms) < TOKEN_CUE_GAP_MS);
+        if !continues {
+            cues.extend(current.take());
+        }
+        let cue = current.get_or_insert_with(|| Cue {
+            start_ms,
+            end_ms,
+            text: String::new(),
+        });
+        cue.text.push_str(&piece);
+        cue.end_ms = cue.end_ms.max(end_ms);
+        if piece.trim_end().ends_with(['.', '?', '!']) {
+            cues.extend(current.take());
+        }
+    }
+    cues.extend(current);
+
+    for cue in &mut cues {
+        cue.text = cue.text.split_whitespace().collect::<Vec<&str>>().join(" ");
+    }
+    Some(cues).filter(|cues| !cues.is_empty())
+}
+
+fn json_document(content: &str) -> Result<TranscriptDocument, String> {
+    let parsed: Value = serde_json::from_str(content)
+        .map_err(|error| format!("Failed to parse transcript JSON: {}", error))?;
+    let segments = parsed
+        .get("segments")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+
+    // A single segment without timing makes the whole file untimed rather than silently dropping it.
+    let cues = if segments.is_empty() {
+        parsed
+            .get("tokenTimings")
+            .and_then(Value::as_array)
+            .and_then(|tokens| token_timing_cues(tokens))
+    } else {
+        segments
+            .iter()
+            .map(|segment| {
+                Some(Cue {
+                    start_ms: seconds_to_millis(segment.get("start"))?,
+                    end_ms: seconds_to_millis(segment.get("end"))?,
+                    text: segment
+                        .get("text")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .trim()
+                        .to_string(),
+                })
+            })
+            .collect::<Option<Vec<Cue>>>()
+            .filter(|cues| !cues.is_empty())
+    };
+
+    let text = parsed
+        .get("text")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| cue_text(cues.as_deref().unwrap_or_default()));
+    Ok(TranscriptDocument { text, cues })
+}
+
+// Diarized sidecars carry either a numeric index or a pyannote-style `SPEAKER_00` id.
+fn speaker_label(segment: &Value) -> Option<String> {
+    match segment.get("speaker")? {
+        Value::Number(number) => number
+            .as_u64()
+            .map(|index| format!("Speaker {}", index + 1)),
+        Value::String(name) => {
+            let name = name.trim();
+            if name.is_empty() {
+                return None;
+            }
+            match name
+                .strip_prefix("SPEAKER_")
+                .and_then(|digits| digits.parse::<u64>().ok())
+            {
+                Some(index) => Some(format!("Speaker {}", index + 1)),
+                None => Some(name.to_string()),
+            }
+        }
+        _ => None,
+    }
+}
+
+fn render_speaker_vtt(content: &str) -> Result<String, String> {
+    let document = json_document(content)?;
+    let cues = document
+        .cues
+        .ok_or_else(|| "Cannot convert json to vtt: the source has no timestamps".to_string())?;
+    let parsed: Value = serde_json::from_str(content)
+        .map_err(|error| format!("Failed to parse transcript JSON: {}", error))?;
+    let speakers = parsed
+        .get("segments")
+        .and_then(Value::as_array)
+        .map(|segments| segments.iter().map(speaker_label).collect::<Vec<_>>())
+        .unwrap_or_default();
+    if speakers.iter().all(Option::is_none) {
+        return Ok(render_vtt(&cues));
+    }
+
+    let tagged = cues
+        .into_iter()
+        .zip(speakers)
+        .map(|(cue, speaker)| match speaker {
+            Some(speaker) => Cue {
+                text: format!("<v {}>{}", speaker, cue.text),
+                ..cue
+            },
+            None => cue,
+        })
+        .collect::<Vec<Cue>>();
+    Ok(render_vtt(&tagged))
+}
+
+fn cue_text(cues: &[Cue]) -> String {
+    cues.iter()
+        .map(|cue| cue.text.split_whitespace().collect::<Vec<&str>>().join(" "))
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn timed_document(cues: Vec<Cue>) -> TranscriptDocument {
+    TranscriptDocument {
+        text: cue_text(&cues),
+        cues: Some(cues),
+    }
+}
+
+fn read_document(format: TranscriptFormat, content: &str) -> Result<TranscriptDocument, String> {
+    match format {
+        TranscriptFormat::Text => Ok(TranscriptDocument {
+            text: content.trim().to_string(),
+            cues: None,
+        }),
+        TranscriptFormat::Json => json_document(content),
+        TranscriptFormat::Srt => parse_srt_cues(content).map(timed_document),
+        TranscriptFormat::Vtt => parse_cue_blocks(content, '.', "VTT").map(timed_document),
+    }
+}
+
+fn render_document(
+    document: TranscriptDocument,
+    source: TranscriptFormat,
+    target: TranscriptFormat,
+) -> Result<String, String> {
+    if target == TranscriptFormat::Text {
+        return Ok(format!("{}\n", document.text));
+    }
+    // JSON sidecars may be untimed, so plain text still converts to a text-only sidecar.
+    if target == TranscriptFormat::Json && document.cues.is_none() {
+        return serde_json::to_string_pretty(&json!({ "text": document.text, "segments": [] }))
+            .map_err(|error| format!("Failed to serialize transcript JSON: {}", error));
+    }
+
+    let cues = document.cues.ok_or_else(|| {
+        format!(
+            "Cannot convert {} to {}: the source has no timestamps",
+            format_name(source),
+            format_name(target)
+        )
+    })?;
+    match target {
+        TranscriptFormat::Srt => Ok(render_srt(&cues)),
+        TranscriptFormat::Vtt => Ok(render_vtt(&cues)),
+        _ => {
+            let segments = cues
+                .iter()
+                .enumerate()
+                .map(|(index, cue)| {
+                    json!({
+                        "id": index,
+                        "start": cue.start_ms as f64 / 1000.0,
+                        "end": cue.end_ms as f64 / 1000.0,
+                        "text": cue.text,
+                    })
+                })
+                .collect::<Vec<Value>>();
+            serde_json::to_string_pretty(&json!({ "text": document.text, "segments": segments }))
+                .map_err(|error| format!("Failed to serialize transcript JSON: {}", error))
+        }
+    }
+}
+
+fn load_document(source: &Path) -> Result<(TranscriptFormat, TranscriptDocument), String> {
+    let content = read_transcript_file(source)?.content;
+    let source_format = detect_transcript_format(source, &content);
+    let document = read_document(source_format, &content)
+        .map_err(|error| format!("{} ({})", error, source.display()))?;
+    Ok((source_format, document))
+}
+
+/// Returns the timed cues of a JSON sidecar, read from `segments` or the CoreML `tokenTimings`.
+pub(crate) fn sidecar_cues(content: &str) -> Result<Vec<Cue>, String> {
+    json_document(content)?
+        .cues
+        .ok_or_else(|| "Transcript JSON has no timestamps".to_string())
+}
+
+/// Returns the spoken text of a transcript in any supported format, without timing.
+pub(crate) fn transcript_plain_text(source: &Path) -> Result<String, String> {
+    load_document(source).map(|(_, document)| document.text)
+}
+
+fn convert(source: &Path, target: TranscriptFormat) -> Result<String, String> {
+    let (source_format, document) = load_document(source)?;
+    render_document(document, source_format, target)
+}
+
+fn write_converted(destination: &Path, converted: String) -> Result<String, String> {
+    if let Some(parent) = destination
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent).map_err(|error| {
+            format!(
+                "Failed to create destination directory {}: {}",
+                parent.display(),
+                error
+            )
+        })?;
+    }
+    std::fs::write(destination, converted).map_err(|error| {
+        format!(
+            "Failed to write converted transcript {}: {}",
+            destination.display(),
+            error
+        )
+    })?;
+    Ok(destination.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+pub fn convert_transcript(
+    source_path: String,
+    destination_path: String,
+    target_format: String,
+) -> Result<String, String> {
+    let source = PathBuf::from(source_path.trim());
+    if source.as_os_str().is_empty() {
+        return Err("Source transcript path is empty".to_string());
+    }
+    let destination = PathBuf::from(destination_path.trim());
+    if destination.as_os_str().is_empty() {
+        return Err("Destination transcript path is empty".to_string());
+    }
+
+    let converted = convert(&source, parse_target_format(&target_format)?)?;
+    write_converted(&destination, converted)
+}
+
+/// Writes a VTT from a JSON sidecar, tagging each cue with `<v Speaker N>` when the
+/// segments carry diarization; sidecars without speakers produce plain cues.
+#[tauri::command]
+pub fn export_speaker_vtt(source_path: String, destination_path: String) -> Result<String, String> {
+    let source = PathBuf::from(source_path.trim());
+    if source.as_os_str().is_empty() {
+        return Err("Source transcript path is empty".to_string());
+    }
+    let destination = PathBuf::from(destination_path.trim());
+    if destination.as_os_str().is_empty() {
+        return Err("Destination transcript path is empty".to_string());
+    }
+
+    let content = read_transcript_file(&source)?.content;
+    if detect_transcript_format(&source, &content) != TranscriptFormat::Json {
+        return Err(format!(
+            "Speaker labels need a JSON sidecar: {}",
+            source.display()
+        ));
+    }
+    let converted = render_speaker_vtt(&content)
+        .map_err(|error| format!("{} ({})", error, source.display()))?;
+    write_converted(&destination, converted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("fixtures")
+            .join("chunks")
+            .join(name)
+    }
+
+    #[test]
+    fn converts_json_sidecar_to_srt_and_vtt() {
+        let source = fixture("interview_part000.json");
+        assert_eq!(
+            convert(&source, TranscriptFormat::Srt).expect("JSON has timestamps"),
+            "1\n00:00:00,000 --> 00:00:02,500\nWelcome back\n\n2\n00:00:02,500 --> 00:00:04,000\nto the show.\n"
+        );
+
+        let vtt = convert(&source, TranscriptFormat::Vtt).expect("JSON has timestamps");
+        assert!(vtt.starts_with("WEBVTT\n\n00:00:00.000 --> 00:00:02.500\nWelcome back"));
+        let round_trip = parse_cue_blocks(&vtt, '.', "VTT").expect("generated VTT parses");
+        assert_eq!(round_trip.len(), 2);
+        assert_eq!(round_trip[1].end_ms, 4000);
+    }
+
+    #[test]
+    fn speaker_vtt_tags_diarized_cues() {
+        let vtt = render_speaker_vtt(
+            &std::fs::read_to_string(fixture("interview_diarized.json"))
+                .expect("diarized fixture should be readable"),
+        )
+        .expect("diarized JSON has timestamps");
+        assert_eq!(
+            vtt,
+            "WEBVTT\n\n00:00:00.000 --> 00:00:02.500\n<v Speaker 1>Welcome back\n\n00:00:02.500 --> 00:00:04.000\n<v Speaker 2>Thanks for having me.\n\n00:00:04.000 --> 00:00:05.000\n<v Host>Let's begin.\n"
+        );
+    }
+
+    #[test]
+    fn speaker_vtt_falls_back_to_plain_cues() {
+        let content = std::fs::read_to_string(fixture("interview_part000.json"))
+            .expect("fixture should be readable");
+        let vtt = render_speaker_vtt(&content).expect("JSON has timestamps");
+        assert!(!vtt.contains("<v "));
+        assert_eq!(
+            vtt,
+            convert(&fixture("interview_part000.json"), TranscriptFormat::Vtt)
+                .expect("JSON has timestamps")
+        );
+    }
+
+    #[test]
+    fn refuses_to_invent_timestamps_for_plain_text() {
+        let root = std::env::temp_dir().join(format!("convert-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&root).expect("fixture directory should be created");
+        let source = root.join("notes.txt");
+        std::fs::write(&source, "Just words, no timing.\n").expect("fixture should be written");
+
+        let error = convert_transcript(
+            source.to_string_lossy().to_string(),
+            root.join("notes.srt").to_string_lossy().to_string(),
+            "srt".to_string(),
+        )
+        .expect_err("text has no timestamps");
+        assert_eq!(
+            error,
+            "Cannot convert txt to srt: the source has no timestamps"
+        );
+        assert!(!root.join("notes.srt").exists());
+        assert!(parse_target_format("docx").is_err());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::providers::manifest::QueueItem;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+const FAST_HASH_SAMPLE_BYTES: u64 = 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateCluster {
+    pub hash: String,
+    pub size_bytes: u64,
+    pub items: Vec<QueueItem>,
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn hash_audio_file(path: &Path, size: u64, fast: bool) -> Result<String, String> {
+    let mut file = File::open(path)
+        .map_err(|error| format!("Failed to open {}: {}", path.display(), error))?;
+    let mut hasher = Sha256::new();
+
+    // Fast mode samples both ends plus the size, which is enough to tell real recordings apart.
+    if fast && size > FAST_HASH_SAMPLE_BYTES * 2 {
+        hasher.update(size.to_le_bytes());
+        let mut head = (&mut file).take(FAST_HASH_SAMPLE_BYTES);
+        std::io::copy(&mut head, &mut hasher)
+            .map_err(|error| format!("Failed to read {}: {}", path.display(), error))?;
+        file.seek(SeekFrom::End(-(FAST_HASH_SAMPLE_BYTES as i64)))
+            .map_err(|error| format!("Failed to seek {}: {}", path.display(), error))?;
+        std::io::copy(&mut file, &mut hasher)
+            .map_err(|error| format!("Failed to read {}: {}", path.display(), error))?;
+    } else {
+        std::io::copy(&mut file, &mut hasher)
+            .map_err(|error| format!("Failed to read {}: {}", path.display(), error))?;
+    }
+
+    Ok(to_hex(&hasher.finalize()))
+}
+
+fn group_duplicate_audio(items: Vec<QueueItem>, fast: bool) -> Vec<DuplicateCluster> {
+    // Only files sharing a size can be identical, so everything else skips hashing entirely.
+    let mut by_size: HashMap<u64, Vec<QueueItem>> = HashMap::new();
+    for item in items {
+        match std::fs::metadata(&item.path) {
+            Ok(metadata) if metadata.is_file() => {
+                by_size.entry(metadata.len()).or_default().push(item)
+            }
+            Ok(_) => {}
+            Err(error) => eprintln!("[duplicates] skipping {}: {}", item.path.display(), error),
+        }
+    }
+
+    let mut clusters = Vec::new();
+    for (size, candidates) in by_size {
+        if candidates.len() < 2 {
+            continue;
+        }
+
+        let mut by_hash: HashMap<String, Vec<QueueItem>> = HashMap::new();
+        for item in candidates {
+            match hash_audio_file(&item.path, size, fast) {
+                Ok(hash) => by_hash.entry(hash).or_default().push(item),
+                Err(error) => eprintln!("[duplicates] {}", error),
+            }
+        }
+
+        clusters.extend(
+            by_hash
+                .into_iter()
+                .filter(|(_, items)| items.len() > 1)
+                .map(|(hash, items)| DuplicateCluster {
+                    hash,
+                    size_bytes: size,
+                    items,
+                }),
+        );
+    }
+
+    for cluster in &mut clusters {
+        cluster
+            .items
+            .sort_by(|left, right| left.path.cmp(&right.path));
+    }
+    clusters.sort_by(|left, right| left.items[0].path.cmp(&right.items[0].path));
+    clusters
+}
+
+#[tauri::command]
+pub async fn find_duplicate_audio(
+    items: Vec<QueueItem>,
+    fast_mode: Option<bool>,
+) -> Result<Vec<DuplicateCluster>, String> {
+    let fast = fast_mode.unwrap_or(false);
+    tokio::task::spawn_blocking(move || group_duplicate_audio(items, fast))
+        .await
+        .map_err(|error| format!("Failed to find duplicate audio: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use uuid::Uuid;
+
+    fn queue_item(id: &str, path: PathBuf) -> QueueItem {
+        QueueItem {
+            id: id.to_string(),
+            path,
+            status: "queued".to_string(),
+        }
+    }
+
+    #[test]
+    fn groups_byte_identical_files_under_different_names() {
+        let root = std::env::temp_dir().join(format!("duplicate-audio-tests-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&root).expect("fixture directory should be created");
+
+        let sample_len = FAST_HASH_SAMPLE_BYTES as usize * 2 + 16;
+        let original = vec![7u8; sample_len];
+        let mut distinct = original.clone();
+        distinct[sample_len / 2] = 8;
+
+        let first = root.join("interview.wav");
+        let copy = root.join("interview (copy).wav");
+        let other = root.join("other.wav");
+        std::fs::write(&first, &original).expect("fixture should be written");
+        std::fs::write(&copy, &original).expect("fixture should be written");
+        std::fs::write(&other, &distinct).expect("fixture should be written");
+
+        let items = vec![
+            queue_item("a", first.clone()),
+            queue_item("b", other.clone()),
+            queue_item("c", copy.clone()),
+            queue_item("d", root.join("missing.wav")),
+        ];
+
+        let clusters = group_duplicate_audio(items.clone(), false);
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].size_bytes, sample_len as u64);
+        assert_eq!(clusters[0].hash.len(), 64);
+        assert_eq!(
+            clusters[0]
+                .items
+                .iter()
+                .map(|item| item.id.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["c", "a"]
+        );
+
+        // Fast mode skips the middle of the file, so the mid-file edit is not noticed.
+        let fast_clusters = group_duplicate_audio(items, true);
+        assert_eq!(fast_clusters.len(), 1);
+        assert_eq!(fast_clusters[0].items.len(), 3);
+        assert_ne!(fast_clusters[0].hash, clusters[0].hash);
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+}
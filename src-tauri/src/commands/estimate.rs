@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+const WORDS_PER_MINUTE: f64 = 150.0;
+const BYTES_PER_WORD: f64 = 6.0;
+const JSON_SIZE_MULTIPLIER: f64 = 12.0;
+const JSON_OVERHEAD_BYTES: u64 = 512;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EstimateItem {
+    #[serde(default)]
+    pub duration: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct SizeBreakdown {
+    txt_bytes: u64,
+    json_bytes: u64,
+    unknown_duration_items: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputSizeEstimate {
+    pub txt_bytes: u64,
+    pub json_bytes: u64,
+    pub both_bytes: u64,
+    pub estimated_bytes: u64,
+    pub estimated_display: String,
+    pub available_disk_bytes: u64,
+    pub available_display: String,
+    pub sufficient: bool,
+    pub unknown_duration_items: usize,
+}
+
+fn estimate_txt_bytes(duration_seconds: f64) -> u64 {
+    let words = duration_seconds / 60.0 * WORDS_PER_MINUTE;
+    (words * BYTES_PER_WORD).ceil() as u64
+}
+
+fn estimate_json_bytes(txt_bytes: u64) -> u64 {
+    (txt_bytes as f64 * JSON_SIZE_MULTIPLIER).ceil() as u64 + JSON_OVERHEAD_BYTES
+}
+
+fn estimate_breakdown(durations: &[Option<f64>]) -> SizeBreakdown {
+    let mut breakdown = SizeBreakdown::default();
+    for duration in durations {
+        match duration {
+            Some(seconds) if seconds.is_finite() && *seconds >= 0.0 => {
+                let txt = estimate_txt_bytes(*seconds);
+                breakdown.txt_bytes = breakdown.txt_bytes.saturating_add(txt);
+                breakdown.json_bytes = breakdown
+                    .json_bytes
+                    .saturating_add(estimate_json_bytes(txt));
+            }
+            _ => breakdown.unknown_duration_items += 1,
+        }
+    }
+    breakdown
+}
+
+fn bytes_for_format(breakdown: SizeBreakdown, output_format: &str) -> Result<u64, String> {
+    match output_format.trim().to_ascii_lowercase().as_str() {
+        "txt" => Ok(breakdown.txt_bytes),
+        "json" => Ok(breakdown.json_bytes),
+        "both" => Ok(breakdown.txt_bytes.saturating_add(breakdown.json_bytes)),
+        other => Err(format!("Unsupported output format: {}", other)),
+    }
+}
+
+fn build_estimate(
+    breakdown: SizeBreakdown,
+    output_format: &str,
+    available_disk_bytes: u64,
+) -> Result<OutputSizeEstimate, String> {
+    let estimated_bytes = bytes_for_format(breakdown, output_format)?;
+
+    Ok(OutputSizeEstimate {
+        txt_bytes: breakdown.txt_bytes,
+        json_bytes: breakdown.json_bytes,
+        both_bytes: breakdown.txt_bytes.saturating_add(breakdown.json_bytes),
+        estimated_bytes,
+        estimated_display: crate::format_bytes(estimated_bytes),
+        available_disk_bytes,
+        available_display: crate::format_bytes(available_disk_bytes),
+        sufficient: available_disk_bytes >= estimated_bytes,
+        unknown_duration_items: breakdown.unknown_duration_items,
+    })
+}
+
+#[tauri::command]
+pub fn estimate_output_size(
+    items: Vec<EstimateItem>,
+    output_format: String,
+    output_dir: String,
+) -> Result<OutputSizeEstimate, String> {
+    let output_dir = output_dir.trim();
+    if output_dir.is_empty() {
+        return Err("Output directory is empty".to_string());
+    }
+
+    let durations = items
+        .iter()
+        .map(|item| item.duration)
+        .collect::<Vec<Option<f64>>>();
+    let breakdown = estimate_breakdown(&durations);
+    let available = crate::available_disk_bytes_for(&PathBuf::from(output_dir))?;
+
+    build_estimate(breakdown, &output_format, available)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimates_text_and_json_sizes_from_duration() {
+        // 10 minutes at 150 wpm = 1500 words * 6 bytes.
+        assert_eq!(estimate_txt_bytes(600.0), 9_000);
+        assert_eq!(estimate_json_bytes(9_000), 108_512);
+
+        let breakdown = estimate_breakdown(&[Some(600.0), Some(60.0), None, Some(f64::NAN)]);
+        assert_eq!(breakdown.txt_bytes, 9_000 + 900);
+        assert_eq!(breakdown.json_bytes, 108_512 + 11_312);
+        assert_eq!(breakdown.unknown_duration_items, 2);
+    }
+
+    #[test]
+    fn selects_bytes_for_output_format_and_flags_insufficient_space() {
+        let breakdown = estimate_breakdown(&[Some(600.0)]);
+
+        let both = build_estimate(breakdown, "both", 1_000_000).expect("both should estimate");
+        assert_eq!(both.estimated_bytes, 117_512);
+        assert_eq!(both.both_bytes, 117_512);
+        assert!(both.sufficient);
+
+        let txt = build_estimate(breakdown, "TXT", 5_000).expect("txt should estimate");
+        assert_eq!(txt.estimated_bytes, 9_000);
+        assert!(!txt.sufficient);
+
+        let json = build_estimate(breakdown, "json", 108_512).expect("json should estimate");
+        assert!(json.sufficient, "exactly enough space is sufficient");
+
+        assert!(build_estimate(breakdown, "srt", 1_000_000).is_err());
+    }
+}
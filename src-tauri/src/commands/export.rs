@@ -1,12 +1,24 @@
+use crate::commands::paths::expand_path;
+use crate::providers::manifest::QueueItem;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs::{self, File};
-use std::io::Write;
+use std::io::{self, Read, Write};
 use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use tauri::{AppHandle, Emitter};
 use zip::write::SimpleFileOptions;
 use zip::ZipWriter;
 
+const EXPORT_PROGRESS_EVENT: &str = "export-progress";
+// Headers and central-directory records a zip adds for each entry, rounded up generously.
+const ZIP_ENTRY_OVERHEAD_BYTES: u64 = 1024;
+
+static ACTIVE_EXPORTS: LazyLock<Mutex<HashMap<String, Arc<AtomicBool>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
 #[derive(Debug, Clone, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum ExportFormat {
@@ -24,6 +36,16 @@ pub enum ExportNaming {
     Numbered,
 }
 
+// exFAT and NTFS are written through the same Windows naming rules, even when mounted on macOS.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TargetFilesystem {
+    #[default]
+    Native,
+    Exfat,
+    Ntfs,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ExportOptions {
@@ -35,6 +57,18 @@ pub struct ExportOptions {
     pub include_metadata: bool,
     #[serde(default)]
     pub preserve_structure: bool,
+    #[serde(default)]
+    pub move_files: bool,
+    #[serde(default)]
+    pub utf8_bom: bool,
+    #[serde(default)]
+    pub group_by_language: bool,
+    #[serde(default)]
+    pub target_filesystem: TargetFilesystem,
+    #[serde(default)]
+    pub overwrite: bool,
+    #[serde(default)]
+    pub skip_space_check: bool,
 }
 
 impl Default for ExportOptions {
@@ -44,6 +78,12 @@ impl Default for ExportOptions {
             naming: ExportNaming::Preserve,
             include_metadata: true,
             preserve_structure: false,
+            move_files: false,
+            utf8_bom: false,
+            group_by_language: false,
+            target_filesystem: TargetFilesystem::Native,
+            overwrite: false,
+            skip_space_check: false,
         }
     }
 }
@@ -52,10 +92,80 @@ impl Default for ExportOptions {
 #[serde(rename_all = "camelCase")]
 pub struct ExportItem {
     pub id: String,
+    #[serde(default)]
+    pub path: Option<String>,
     pub status: String,
     pub relative_path: Option<String>,
     pub transcript_path: Option<String>,
     pub json_path: Option<String>,
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportResult {
+    pub destination: String,
+    pub cancelled: bool,
+    pub renamed: Vec<RenamedExportFile>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RenamedExportFile {
+    pub source_path: String,
+    pub requested_path: String,
+    pub exported_path: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportProgress {
+    pub export_id: String,
+    pub written: usize,
+    pub total: usize,
+    pub current_path: Option<String>,
+    pub done: bool,
+    pub cancelled: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ExportRun<T> {
+    Finished(T),
+    Cancelled,
+}
+
+struct ExportRegistration {
+    export_id: String,
+    cancel_flag: Arc<AtomicBool>,
+}
+
+impl ExportRegistration {
+    fn register(export_id: &str) -> Result<Self, String> {
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let mut exports = ACTIVE_EXPORTS
+            .lock()
+            .map_err(|_| "Failed to lock active exports".to_string())?;
+        exports.insert(export_id.to_string(), cancel_flag.clone());
+        Ok(Self {
+            export_id: export_id.to_string(),
+            cancel_flag,
+        })
+    }
+}
+
+impl Drop for ExportRegistration {
+    fn drop(&mut self) {
+        if let Ok(mut exports) = ACTIVE_EXPORTS.lock() {
+            // A newer export may have reused the id; only remove our own flag.
+            if exports
+                .get(&self.export_id)
+                .is_some_and(|flag| Arc::ptr_eq(flag, &self.cancel_flag))
+            {
+                exports.remove(&self.export_id);
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -63,6 +173,7 @@ struct PreparedExportFile {
     source_path: PathBuf,
     export_path: String,
     item_id: String,
+    requested_path: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -88,6 +199,46 @@ fn default_true() -> bool {
     true
 }
 
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+const BOM_TEXT_EXTENSIONS: [&str; 4] = ["txt", "srt", "vtt", "md"];
+
+// JSON parsers reject a leading BOM, so only plain-text formats get one.
+fn takes_utf8_bom(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| {
+            BOM_TEXT_EXTENSIONS
+                .iter()
+                .any(|candidate| extension.eq_ignore_ascii_case(candidate))
+        })
+}
+
+fn copy_with_utf8_bom(source: &mut impl Read, sink: &mut impl Write) -> io::Result<()> {
+    let mut prefix = Vec::with_capacity(UTF8_BOM.len());
+    source
+        .by_ref()
+        .take(UTF8_BOM.len() as u64)
+        .read_to_end(&mut prefix)?;
+    if prefix != UTF8_BOM {
+        sink.write_all(UTF8_BOM)?;
+    }
+    sink.write_all(&prefix)?;
+    io::copy(source, sink).map(|_| ())
+}
+
+fn prepend_utf8_bom(path: &Path) -> Result<(), String> {
+    let content = fs::read(path)
+        .map_err(|error| format!("Failed to read transcript {}: {}", path.display(), error))?;
+    if content.starts_with(UTF8_BOM) {
+        return Ok(());
+    }
+    let mut file = File::create(path)
+        .map_err(|error| format!("Failed to rewrite transcript {}: {}", path.display(), error))?;
+    file.write_all(UTF8_BOM)
+        .and_then(|_| file.write_all(&content))
+        .map_err(|error| format!("Failed to rewrite transcript {}: {}", path.display(), error))
+}
+
 fn normalize_export_name(path: &str) -> String {
     path.replace('\\', "/")
 }
@@ -123,7 +274,7 @@ fn split_file_name(path: &str) -> (String, String) {
     (candidate.to_string(), String::new())
 }
 
-fn dedupe_path(candidate: String, used_paths: &mut HashSet<String>) -> String {
+pub(crate) fn dedupe_path(candidate: String, used_paths: &mut HashSet<String>) -> String {
     if used_paths.insert(candidate.clone()) {
         return candidate;
     }
@@ -179,6 +330,83 @@ fn export_name_for(
     Ok(named)
 }
 
+const WINDOWS_RESERVED_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+pub fn sanitize_for_filesystem(name: &str, target: TargetFilesystem) -> String {
+    if target == TargetFilesystem::Native {
+        return name.to_string();
+    }
+
+    let replaced = name
+        .chars()
+        .map(|ch| match ch {
+            '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*' => '_',
+            ch if ch.is_control() => '_',
+            ch => ch,
+        })
+        .collect::<String>();
+    // Windows silently drops trailing dots and spaces, so `notes.` and `notes` would collide.
+    let trimmed = replaced.trim_end_matches(['.', ' ']);
+    if trimmed.is_empty() {
+        return "_".to_string();
+    }
+
+    // `CON.txt` is as reserved as `CON`; only the part before the first dot counts.
+    let device = trimmed.split('.').next().unwrap_or_default().trim_end();
+    if WINDOWS_RESERVED_NAMES
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(device))
+    {
+        return format!("_{}", trimmed);
+    }
+    trimmed.to_string()
+}
+
+fn sanitize_export_path(path: &Path, target: TargetFilesystem) -> PathBuf {
+    path.components()
+        .filter_map(|component| match component {
+            Component::Normal(segment) => {
+                Some(sanitize_for_filesystem(&segment.to_string_lossy(), target))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+const UNKNOWN_LANGUAGE_FOLDER: &str = "unknown";
+
+fn language_folder(language: &str) -> Option<String> {
+    let folder = language
+        .trim()
+        .to_ascii_lowercase()
+        .chars()
+        .filter(|ch| ch.is_ascii_alphanumeric() || *ch == '-' || *ch == '_')
+        .collect::<String>();
+    (!folder.is_empty()).then_some(folder)
+}
+
+// The item's own language wins; otherwise the worker's JSON sidecar may have recorded one.
+fn export_language_for(item: &ExportItem) -> String {
+    let sidecar_language = || {
+        let path = item.json_path.as_deref().map(str::trim)?;
+        let content = fs::read_to_string(path).ok()?;
+        let parsed = serde_json::from_str::<serde_json::Value>(&content).ok()?;
+        parsed
+            .get("language")
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_string)
+    };
+
+    item.language
+        .as_deref()
+        .and_then(language_folder)
+        .or_else(|| sidecar_language().as_deref().and_then(language_folder))
+        .unwrap_or_else(|| UNKNOWN_LANGUAGE_FOLDER.to_string())
+}
+
 fn collect_export_sources(
     items: &[ExportItem],
     options: &ExportOptions,
@@ -188,6 +416,27 @@ fn collect_export_sources(
     let timestamp_prefix = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
     let mut sequence = 1usize;
 
+    // Items the scan gave no relative path fall back to their place under the shared source folder.
+    let derived_paths = if options.preserve_structure {
+        let sourced = items
+            .iter()
+            .filter_map(|item| {
+                Some(QueueItem {
+                    id: item.id.clone(),
+                    path: PathBuf::from(item.path.as_deref()?),
+                    status: item.status.clone(),
+                })
+            })
+            .collect::<Vec<QueueItem>>();
+        derive_relative_path_plan(&sourced)
+            .items
+            .into_iter()
+            .map(|derived| (derived.id, derived.relative_path))
+            .collect::<HashMap<String, String>>()
+    } else {
+        HashMap::new()
+    };
+
     for item in items
         .iter()
         .filter(|candidate| candidate.status.eq_ignore_ascii_case("completed"))
@@ -208,11 +457,18 @@ fn collect_export_sources(
             continue;
         }
 
-        let parent = if options.preserve_structure {
-            sanitize_parent_path(item.relative_path.as_deref())
+        let mut parent = if options.group_by_language {
+            PathBuf::from(export_language_for(item))
         } else {
             PathBuf::new()
         };
+        if options.preserve_structure {
+            let relative_path = item
+                .relative_path
+                .as_deref()
+                .or_else(|| derived_paths.get(&item.id).map(String::as_str));
+            parent.push(sanitize_parent_path(relative_path));
+        }
 
         for source in sources {
             if !source.exists() {
@@ -222,13 +478,19 @@ fn collect_export_sources(
             let file_name = export_name_for(&source, &options.naming, sequence, &timestamp_prefix)?;
             sequence += 1;
 
+            let requested = parent.join(file_name);
+            let safe = sanitize_export_path(&requested, options.target_filesystem);
             let export_path = normalize_export_name(&dedupe_path(
-                parent.join(file_name).to_string_lossy().to_string(),
+                safe.to_string_lossy().to_string(),
                 &mut used_paths,
             ));
+            let requested_path = normalize_export_name(&requested.to_string_lossy());
 
             prepared.push(PreparedExportFile {
                 source_path: source,
+                requested_path: (requested_path != export_path
+                    && options.target_filesystem != TargetFilesystem::Native)
+                    .then_some(requested_path),
                 export_path,
                 item_id: item.id.clone(),
             });
@@ -265,6 +527,47 @@ fn build_metadata(items: &[ExportItem], files: &[PreparedExportFile]) -> ExportM
     }
 }
 
+fn estimated_export_bytes(
+    files: &[PreparedExportFile],
+    metadata: Option<&ExportMetadata>,
+    format: &ExportFormat,
+) -> Result<u64, String> {
+    let mut total = 0_u64;
+    for file in files {
+        let size = fs::metadata(&file.source_path)
+            .map_err(|error| {
+                format!(
+                    "Failed to read transcript size {}: {}",
+                    file.source_path.display(),
+                    error
+                )
+            })?
+            .len();
+        total = total.saturating_add(size);
+    }
+    if let Some(metadata) = metadata {
+        let payload = serde_json::to_vec_pretty(metadata)
+            .map_err(|error| format!("Failed to serialize export metadata: {}", error))?;
+        total = total.saturating_add(payload.len() as u64);
+    }
+    if *format == ExportFormat::Zip {
+        total = total.saturating_add(ZIP_ENTRY_OVERHEAD_BYTES * (files.len() as u64 + 1));
+    }
+    Ok(total)
+}
+
+fn ensure_export_space(destination: &Path, required: u64, available: u64) -> Result<(), String> {
+    if required > available {
+        return Err(format!(
+            "Not enough free space for export to {}: needs {} but only {} is available",
+            destination.display(),
+            crate::format_bytes(required),
+            crate::format_bytes(available)
+        ));
+    }
+    Ok(())
+}
+
 fn ensure_parent_directory(path: &Path) -> Result<(), String> {
     if let Some(parent) = path.parent() {
         if !parent.as_os_str().is_empty() {
@@ -281,11 +584,7 @@ fn ensure_parent_directory(path: &Path) -> Result<(), String> {
     Ok(())
 }
 
-fn export_as_zip(
-    destination: &Path,
-    files: &[PreparedExportFile],
-    metadata: Option<&ExportMetadata>,
-) -> Result<(), String> {
+pub(crate) fn create_zip_archive(destination: &Path) -> Result<ZipWriter<File>, String> {
     ensure_parent_directory(destination)?;
     let file = File::create(destination).map_err(|error| {
         format!(
@@ -294,58 +593,260 @@ fn export_as_zip(
             error
         )
     })?;
-    let mut zip = ZipWriter::new(file);
+    Ok(ZipWriter::new(file))
+}
+
+pub(crate) fn add_zip_entry(
+    zip: &mut ZipWriter<File>,
+    name: &str,
+    payload: &[u8],
+) -> Result<(), String> {
+    zip.start_file(name, SimpleFileOptions::default())
+        .map_err(|error| format!("Failed to add {} to archive: {}", name, error))?;
+    zip.write_all(payload)
+        .map_err(|error| format!("Failed to write {} to archive: {}", name, error))
+}
+
+pub(crate) fn finish_zip_archive(zip: ZipWriter<File>, destination: &Path) -> Result<(), String> {
+    zip.finish()
+        .map_err(|error| {
+            format!(
+                "Failed to finalize archive {}: {}",
+                destination.display(),
+                error
+            )
+        })
+        .map(|_| ())
+}
+
+fn export_as_zip(
+    destination: &Path,
+    files: &[PreparedExportFile],
+    metadata: Option<&ExportMetadata>,
+    utf8_bom: bool,
+    cancel_flag: &AtomicBool,
+    on_file_written: &mut dyn FnMut(usize, &str),
+) -> Result<ExportRun<()>, String> {
+    let mut zip = create_zip_archive(destination)?;
     let options = SimpleFileOptions::default();
 
-    for entry in files {
-        let content = fs::read(&entry.source_path).map_err(|error| {
+    for (index, entry) in files.iter().enumerate() {
+        if cancel_flag.load(Ordering::SeqCst) {
+            drop(zip);
+            discard_partial_archive(destination);
+            return Ok(ExportRun::Cancelled);
+        }
+
+        let mut source = File::open(&entry.source_path).map_err(|error| {
             format!(
                 "Failed to read transcript {}: {}",
                 entry.source_path.display(),
                 error
             )
         })?;
+        // Entries past 4 GiB need zip64 headers, which must be requested before writing.
+        let large_file = source
+            .metadata()
+            .map(|metadata| metadata.len() >= u64::from(u32::MAX))
+            .unwrap_or(false);
 
-        zip.start_file(&entry.export_path, options)
+        zip.start_file(&entry.export_path, options.large_file(large_file))
             .map_err(|error| {
                 format!(
                     "Failed to add archive entry {}: {}",
                     entry.export_path, error
                 )
             })?;
-        zip.write_all(&content).map_err(|error| {
+        let written = if utf8_bom && takes_utf8_bom(&entry.source_path) {
+            copy_with_utf8_bom(&mut source, &mut zip)
+        } else {
+            io::copy(&mut source, &mut zip).map(|_| ())
+        };
+        written.map_err(|error| {
             format!(
                 "Failed to write archive entry {}: {}",
                 entry.export_path, error
             )
         })?;
+        on_file_written(index + 1, &entry.export_path);
     }
 
     if let Some(metadata) = metadata {
         let payload = serde_json::to_vec_pretty(metadata)
             .map_err(|error| format!("Failed to serialize export metadata: {}", error))?;
-        zip.start_file("metadata.json", options)
-            .map_err(|error| format!("Failed to add metadata.json to archive: {}", error))?;
-        zip.write_all(&payload)
-            .map_err(|error| format!("Failed to write metadata.json to archive: {}", error))?;
+        add_zip_entry(&mut zip, "metadata.json", &payload)?;
     }
 
-    zip.finish()
+    finish_zip_archive(zip, destination).map(ExportRun::Finished)
+}
+
+fn discard_partial_archive(destination: &Path) {
+    if let Err(error) = fs::remove_file(destination) {
+        eprintln!(
+            "[export] failed to remove partial archive {}: {}",
+            destination.display(),
+            error
+        );
+    }
+}
+
+// Moved transcripts go back to where they came from; copies and directories we created are removed.
+fn discard_partial_folder_export(
+    destination: &Path,
+    created_destination: bool,
+    copied: &[PathBuf],
+    moved: &[(String, String)],
+) {
+    for (source, exported) in moved.iter().rev() {
+        if let Err(error) = move_file(Path::new(exported), Path::new(source)) {
+            eprintln!("[export] failed to restore moved transcript: {}", error);
+        }
+    }
+    for path in copied {
+        if let Err(error) = fs::remove_file(path) {
+            eprintln!(
+                "[export] failed to remove partial export {}: {}",
+                path.display(),
+                error
+            );
+        }
+    }
+
+    if created_destination {
+        let _ = fs::remove_dir_all(destination);
+        return;
+    }
+    let exported_paths = copied
+        .iter()
+        .cloned()
+        .chain(moved.iter().map(|(_, exported)| PathBuf::from(exported)));
+    for path in exported_paths {
+        // Only directories left empty by the rollback disappear; remove_dir refuses anything else.
+        for parent in path.ancestors().skip(1) {
+            if parent == destination || fs::remove_dir(parent).is_err() {
+                break;
+            }
+        }
+    }
+}
+
+fn move_file_with(
+    source: &Path,
+    destination: &Path,
+    rename: impl Fn(&Path, &Path) -> io::Result<()>,
+) -> Result<(), String> {
+    match rename(source, destination) {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == io::ErrorKind::CrossesDevices => {
+            fs::copy(source, destination).map_err(|error| {
+                format!(
+                    "Failed to copy transcript {} -> {}: {}",
+                    source.display(),
+                    destination.display(),
+                    error
+                )
+            })?;
+            fs::remove_file(source).map_err(|error| {
+                format!(
+                    "Failed to remove moved transcript {}: {}",
+                    source.display(),
+                    error
+                )
+            })
+        }
+        Err(error) => Err(format!(
+            "Failed to move transcript {} -> {}: {}",
+            source.display(),
+            destination.display(),
+            error
+        )),
+    }
+}
+
+fn move_file(source: &Path, destination: &Path) -> Result<(), String> {
+    move_file_with(source, destination, |from, to| fs::rename(from, to))
+}
+
+fn ensure_not_in_active_session(files: &[PreparedExportFile]) -> Result<(), String> {
+    let Some(active_output_dir) = crate::providers::launcher::active_session_output_dir() else {
+        return Ok(());
+    };
+
+    match files
+        .iter()
+        .find(|entry| entry.source_path.starts_with(&active_output_dir))
+    {
+        Some(entry) => Err(format!(
+            "Cannot move {} while a transcription session is writing to {}",
+            entry.source_path.display(),
+            active_output_dir.display()
+        )),
+        None => Ok(()),
+    }
+}
+
+const LISTED_CONFLICTS: usize = 5;
+
+// Lists what already lives in a folder destination; a missing directory has nothing to clobber.
+fn folder_destination_conflicts(destination: &Path) -> Result<Vec<String>, String> {
+    if !destination.exists() {
+        return Ok(Vec::new());
+    }
+    if !destination.is_dir() {
+        return Err(format!(
+            "Export destination {} is a file, not a folder",
+            destination.display()
+        ));
+    }
+
+    let mut entries = fs::read_dir(destination)
         .map_err(|error| {
             format!(
-                "Failed to finalize archive {}: {}",
+                "Failed to read export destination {}: {}",
                 destination.display(),
                 error
             )
-        })
-        .map(|_| ())
+        })?
+        .filter_map(Result::ok)
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        // Finder drops .DS_Store into any folder it opens; hidden files are not the user's work.
+        .filter(|name| !name.starts_with('.'))
+        .collect::<Vec<String>>();
+    entries.sort();
+    Ok(entries)
+}
+
+fn ensure_folder_destination_writable(destination: &Path, overwrite: bool) -> Result<(), String> {
+    let conflicts = folder_destination_conflicts(destination)?;
+    if overwrite || conflicts.is_empty() {
+        return Ok(());
+    }
+
+    let mut listed = conflicts
+        .iter()
+        .take(LISTED_CONFLICTS)
+        .cloned()
+        .collect::<Vec<String>>();
+    if conflicts.len() > LISTED_CONFLICTS {
+        listed.push(format!("and {} more", conflicts.len() - LISTED_CONFLICTS));
+    }
+    Err(format!(
+        "Export destination {} is not empty ({}); enable overwrite to export into it anyway",
+        destination.display(),
+        listed.join(", ")
+    ))
 }
 
 fn export_as_folder(
     destination: &Path,
     files: &[PreparedExportFile],
     metadata: Option<&ExportMetadata>,
-) -> Result<(), String> {
+    move_files: bool,
+    utf8_bom: bool,
+    cancel_flag: &AtomicBool,
+    on_file_written: &mut dyn FnMut(usize, &str),
+) -> Result<ExportRun<Vec<(String, String)>>, String> {
+    let created_destination = !destination.exists();
     fs::create_dir_all(destination).map_err(|error| {
         format!(
             "Failed to create export destination {}: {}",
@@ -354,82 +855,391 @@ fn export_as_folder(
         )
     })?;
 
-    for entry in files {
-        let destination_file = destination.join(&entry.export_path);
-        ensure_parent_directory(&destination_file)?;
-        fs::copy(&entry.source_path, &destination_file).map_err(|error| {
-            format!(
-                "Failed to copy transcript {} -> {}: {}",
-                entry.source_path.display(),
-                destination_file.display(),
-                error
-            )
-        })?;
-    }
+    let mut moved = Vec::new();
+    let mut copied = Vec::new();
+    // Returns false when cancelled before every file was written.
+    let mut write_entries = || -> Result<bool, String> {
+        for (index, entry) in files.iter().enumerate() {
+            if cancel_flag.load(Ordering::SeqCst) {
+                return Ok(false);
+            }
 
-    if let Some(metadata) = metadata {
-        let metadata_path = destination.join("metadata.json");
-        let payload = serde_json::to_vec_pretty(metadata)
-            .map_err(|error| format!("Failed to serialize export metadata: {}", error))?;
-        fs::write(&metadata_path, payload).map_err(|error| {
-            format!(
-                "Failed to write metadata file {}: {}",
-                metadata_path.display(),
-                error
-            )
-        })?;
+            let destination_file = destination.join(&entry.export_path);
+            ensure_parent_directory(&destination_file)?;
+            let add_bom = utf8_bom && takes_utf8_bom(&entry.source_path);
+            if move_files {
+                move_file(&entry.source_path, &destination_file)?;
+                moved.push((
+                    entry.source_path.to_string_lossy().to_string(),
+                    destination_file.to_string_lossy().to_string(),
+                ));
+                if add_bom {
+                    prepend_utf8_bom(&destination_file)?;
+                }
+                on_file_written(index + 1, &entry.export_path);
+                continue;
+            }
+
+            let copied_file = if add_bom {
+                File::open(&entry.source_path).and_then(|mut source| {
+                    let mut target = File::create(&destination_file)?;
+                    copy_with_utf8_bom(&mut source, &mut target)
+                })
+            } else {
+                fs::copy(&entry.source_path, &destination_file).map(|_| ())
+            };
+            if let Err(error) = copied_file {
+                let _ = fs::remove_file(&destination_file);
+                return Err(format!(
+                    "Failed to copy transcript {} -> {}: {}",
+                    entry.source_path.display(),
+                    destination_file.display(),
+                    error
+                ));
+            }
+            copied.push(destination_file);
+            on_file_written(index + 1, &entry.export_path);
+        }
+
+        if let Some(metadata) = metadata {
+            write_metadata_file(&destination.join("metadata.json"), metadata)?;
+        }
+        Ok(true)
+    };
+
+    // Failures roll back like a cancel, so history never points at transcripts that already moved.
+    match write_entries() {
+        Ok(true) => Ok(ExportRun::Finished(moved)),
+        Ok(false) => {
+            discard_partial_folder_export(destination, created_destination, &copied, &moved);
+            Ok(ExportRun::Cancelled)
+        }
+        Err(error) => {
+            discard_partial_folder_export(destination, created_destination, &copied, &moved);
+            Err(error)
+        }
     }
+}
 
-    Ok(())
+fn write_metadata_file(metadata_path: &Path, metadata: &ExportMetadata) -> Result<(), String> {
+    let payload = serde_json::to_vec_pretty(metadata)
+        .map_err(|error| format!("Failed to serialize export metadata: {}", error))?;
+    fs::write(metadata_path, payload).map_err(|error| {
+        format!(
+            "Failed to write metadata file {}: {}",
+            metadata_path.display(),
+            error
+        )
+    })
 }
 
-#[tauri::command]
-pub fn export_transcripts(
-    items: Vec<ExportItem>,
-    destination: String,
-    options: ExportOptions,
+// A destination ending in .json names the file itself; anything else is a folder to write into.
+fn metadata_only_path(destination: &Path) -> PathBuf {
+    let names_file = destination
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+    if names_file {
+        destination.to_path_buf()
+    } else {
+        destination.join("metadata.json")
+    }
+}
+
+fn run_metadata_export(
+    items: &[ExportItem],
+    destination: &str,
+    options: &ExportOptions,
 ) -> Result<String, String> {
     let destination = destination.trim();
     if destination.is_empty() {
         return Err("Export destination is empty".to_string());
     }
 
-    let prepared = collect_export_sources(&items, &options)?;
-    if prepared.is_empty() {
-        return Err("No completed transcript files available for export".to_string());
-    }
+    // Sources are resolved exactly as a real export would, so exported paths match a later copy.
+    let prepared = collect_export_sources(items, options)?;
+    let metadata_path = metadata_only_path(&expand_path(destination));
+    ensure_parent_directory(&metadata_path)?;
+    write_metadata_file(&metadata_path, &build_metadata(items, &prepared))?;
+    Ok(metadata_path.to_string_lossy().to_string())
+}
 
-    let metadata = options
-        .include_metadata
-        .then(|| build_metadata(&items, &prepared));
-    let destination_path = PathBuf::from(destination);
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DerivedRelativePath {
+    pub id: String,
+    pub relative_path: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RelativePathPlan {
+    pub common_root: Option<String>,
+    pub items: Vec<DerivedRelativePath>,
+}
 
-    match options.format {
-        ExportFormat::Zip => export_as_zip(&destination_path, &prepared, metadata.as_ref())?,
-        ExportFormat::Folder => export_as_folder(&destination_path, &prepared, metadata.as_ref())?,
+// A root without any named directory (e.g. "/" or a bare drive) is not a shared folder.
+fn common_root(paths: &[PathBuf]) -> Option<PathBuf> {
+    let mut parents = paths
+        .iter()
+        .map(|path| path.parent().unwrap_or(Path::new("")));
+    let mut shared = parents.next()?.components().collect::<Vec<Component>>();
+    for parent in parents {
+        let matching = shared
+            .iter()
+            .zip(parent.components())
+            .take_while(|(left, right)| *left == right)
+            .count();
+        shared.truncate(matching);
     }
 
-    Ok(destination.to_string())
+    shared
+        .iter()
+        .any(|component| matches!(component, Component::Normal(_)))
+        .then(|| shared.iter().collect::<PathBuf>())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use uuid::Uuid;
-    use zip::ZipArchive;
+fn derive_relative_path_plan(items: &[QueueItem]) -> RelativePathPlan {
+    let paths = items
+        .iter()
+        .map(|item| item.path.clone())
+        .collect::<Vec<PathBuf>>();
+    let root = common_root(&paths);
 
-    fn temp_root(prefix: &str) -> PathBuf {
-        std::env::temp_dir().join(format!("{}-{}", prefix, Uuid::new_v4()))
-    }
+    let items = items
+        .iter()
+        .map(|item| {
+            let relative = root
+                .as_deref()
+                .and_then(|root| item.path.strip_prefix(root).ok())
+                .map(Path::to_path_buf)
+                .or_else(|| item.path.file_name().map(PathBuf::from))
+                .unwrap_or_else(|| item.path.clone());
+            DerivedRelativePath {
+                id: item.id.clone(),
+                relative_path: normalize_export_name(&relative.to_string_lossy()),
+            }
+        })
+        .collect();
 
-    fn write_file(path: &Path, content: &str) {
-        if let Some(parent) = path.parent() {
-            std::fs::create_dir_all(parent).expect("parent dir should be created");
-        }
-        std::fs::write(path, content.as_bytes()).expect("fixture file should be written");
+    RelativePathPlan {
+        common_root: root.map(|root| root.to_string_lossy().to_string()),
+        items,
     }
+}
 
-    #[test]
+#[tauri::command]
+pub fn derive_relative_paths(items: Vec<QueueItem>) -> RelativePathPlan {
+    derive_relative_path_plan(&items)
+}
+
+fn run_export(
+    items: &[ExportItem],
+    destination: &str,
+    options: &ExportOptions,
+    export_id: Option<String>,
+    emit_progress: &mut dyn FnMut(ExportProgress),
+) -> Result<ExportResult, String> {
+    let destination = destination.trim();
+    if destination.is_empty() {
+        return Err("Export destination is empty".to_string());
+    }
+
+    let prepared = collect_export_sources(items, options)?;
+    if prepared.is_empty() {
+        return Err("No completed transcript files available for export".to_string());
+    }
+
+    if options.move_files {
+        if options.format != ExportFormat::Folder {
+            return Err("Moving transcripts is only supported for folder exports".to_string());
+        }
+        ensure_not_in_active_session(&prepared)?;
+    }
+
+    let destination_path = expand_path(destination);
+    if options.format == ExportFormat::Folder {
+        ensure_folder_destination_writable(&destination_path, options.overwrite)?;
+    }
+
+    let metadata = options
+        .include_metadata
+        .then(|| build_metadata(items, &prepared));
+    if !options.skip_space_check {
+        let required = estimated_export_bytes(&prepared, metadata.as_ref(), &options.format)?;
+        // An unknown free-space figure should not block an export that may well fit.
+        match crate::available_disk_bytes_for(&destination_path) {
+            Ok(available) => ensure_export_space(&destination_path, required, available)?,
+            Err(error) => eprintln!("[export] skipping free space check: {}", error),
+        }
+    }
+    let export_id = export_id
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    let registration = ExportRegistration::register(&export_id)?;
+    let total = prepared.len();
+    let mut on_file_written = |written: usize, current_path: &str| {
+        emit_progress(ExportProgress {
+            export_id: export_id.clone(),
+            written,
+            total,
+            current_path: Some(current_path.to_string()),
+            done: false,
+            cancelled: false,
+        })
+    };
+
+    let finished = match options.format {
+        ExportFormat::Zip => {
+            export_as_zip(
+                &destination_path,
+                &prepared,
+                metadata.as_ref(),
+                options.utf8_bom,
+                &registration.cancel_flag,
+                &mut on_file_written,
+            )? == ExportRun::Finished(())
+        }
+        ExportFormat::Folder => match export_as_folder(
+            &destination_path,
+            &prepared,
+            metadata.as_ref(),
+            options.move_files,
+            options.utf8_bom,
+            &registration.cancel_flag,
+            &mut on_file_written,
+        )? {
+            ExportRun::Finished(moved) => {
+                if !moved.is_empty() {
+                    crate::commands::history::relocate_transcript_paths(&moved).map_err(
+                        |error| {
+                            format!("Moved transcripts but failed to update history: {}", error)
+                        },
+                    )?;
+                }
+                true
+            }
+            ExportRun::Cancelled => false,
+        },
+    };
+
+    emit_progress(ExportProgress {
+        export_id,
+        written: if finished { total } else { 0 },
+        total,
+        current_path: None,
+        done: true,
+        cancelled: !finished,
+    });
+    Ok(ExportResult {
+        destination: destination.to_string(),
+        cancelled: !finished,
+        renamed: prepared
+            .iter()
+            .filter_map(|file| {
+                Some(RenamedExportFile {
+                    source_path: file.source_path.to_string_lossy().to_string(),
+                    requested_path: file.requested_path.clone()?,
+                    exported_path: file.export_path.clone(),
+                })
+            })
+            .collect(),
+    })
+}
+
+#[tauri::command]
+pub fn check_export_destination(destination: String) -> Result<Vec<String>, String> {
+    let destination = destination.trim();
+    if destination.is_empty() {
+        return Err("Export destination is empty".to_string());
+    }
+
+    folder_destination_conflicts(Path::new(destination))
+}
+
+#[tauri::command]
+pub fn export_metadata_only(
+    items: Vec<ExportItem>,
+    destination: String,
+    options: Option<ExportOptions>,
+) -> Result<String, String> {
+    run_metadata_export(&items, &destination, &options.unwrap_or_default())
+}
+
+// Async so a sync `cancel_export` can reach the main thread while files are still being written.
+#[tauri::command]
+pub async fn export_transcripts(
+    items: Vec<ExportItem>,
+    destination: String,
+    options: ExportOptions,
+    export_id: Option<String>,
+) -> Result<ExportResult, String> {
+    tokio::task::spawn_blocking(move || {
+        run_export(&items, &destination, &options, export_id, &mut |_| {})
+    })
+    .await
+    .map_err(|error| format!("Failed to run export: {}", error))?
+}
+
+// Runs off the main thread so the webview can render progress while files are written.
+#[tauri::command]
+pub async fn export_transcripts_with_progress(
+    app: AppHandle,
+    items: Vec<ExportItem>,
+    destination: String,
+    options: ExportOptions,
+    export_id: Option<String>,
+) -> Result<ExportResult, String> {
+    tokio::task::spawn_blocking(move || {
+        run_export(&items, &destination, &options, export_id, &mut |progress| {
+            if let Err(error) = app.emit(EXPORT_PROGRESS_EVENT, progress) {
+                eprintln!("[export] failed to emit export progress: {}", error);
+            }
+        })
+    })
+    .await
+    .map_err(|error| format!("Failed to run export: {}", error))?
+}
+
+#[tauri::command]
+pub fn cancel_export(export_id: String) -> Result<bool, String> {
+    let exports = ACTIVE_EXPORTS
+        .lock()
+        .map_err(|_| "Failed to lock active exports".to_string())?;
+    match exports.get(export_id.trim()) {
+        Some(flag) => {
+            flag.store(true, Ordering::SeqCst);
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+    use zip::ZipArchive;
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("runtime should build")
+            .block_on(future)
+    }
+
+    fn temp_root(prefix: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("{}-{}", prefix, Uuid::new_v4()))
+    }
+
+    fn write_file(path: &Path, content: &str) {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).expect("parent dir should be created");
+        }
+        std::fs::write(path, content.as_bytes()).expect("fixture file should be written");
+    }
+
+    #[test]
     fn exports_zip_with_completed_transcripts_and_metadata() {
         let root = temp_root("parakeet-export-zip");
         let transcript = root.join("a.txt");
@@ -440,21 +1250,25 @@ mod tests {
         write_file(&skipped, "should not export");
 
         let destination = root.join("bundle.zip");
-        let result = export_transcripts(
+        let result = block_on(export_transcripts(
             vec![
                 ExportItem {
                     id: "item-a".to_string(),
+                    path: None,
                     status: "completed".to_string(),
                     relative_path: Some("nested/a.wav".to_string()),
                     transcript_path: Some(transcript.to_string_lossy().to_string()),
                     json_path: Some(json.to_string_lossy().to_string()),
+                    language: None,
                 },
                 ExportItem {
                     id: "item-b".to_string(),
+                    path: None,
                     status: "error".to_string(),
                     relative_path: Some("nested/b.wav".to_string()),
                     transcript_path: Some(skipped.to_string_lossy().to_string()),
                     json_path: None,
+                    language: None,
                 },
             ],
             destination.to_string_lossy().to_string(),
@@ -463,11 +1277,25 @@ mod tests {
                 naming: ExportNaming::Preserve,
                 include_metadata: true,
                 preserve_structure: false,
+                move_files: false,
+                utf8_bom: false,
+                group_by_language: false,
+                target_filesystem: TargetFilesystem::Native,
+                overwrite: false,
+                skip_space_check: false,
             },
-        )
+            None,
+        ))
         .expect("zip export should succeed");
 
-        assert_eq!(result, destination.to_string_lossy().to_string());
+        assert_eq!(
+            result,
+            ExportResult {
+                destination: destination.to_string_lossy().to_string(),
+                cancelled: false,
+                renamed: Vec::new(),
+            }
+        );
 
         let file = File::open(&destination).expect("archive should exist");
         let mut archive = ZipArchive::new(file).expect("archive should be readable");
@@ -486,6 +1314,51 @@ mod tests {
         assert!(archive.by_name("b.txt").is_err());
     }
 
+    #[test]
+    fn streams_large_sources_into_zip_byte_for_byte() {
+        let root = temp_root("parakeet-export-large-zip");
+        let json = root.join("large.json");
+        std::fs::create_dir_all(&root).expect("fixture dir should be created");
+        let content = (0..3 * 1024 * 1024)
+            .map(|index| (index % 251) as u8)
+            .collect::<Vec<u8>>();
+        std::fs::write(&json, &content).expect("large fixture should be written");
+
+        let destination = root.join("large.zip");
+        let files = vec![PreparedExportFile {
+            source_path: json.clone(),
+            export_path: "large.json".to_string(),
+            item_id: "item-large".to_string(),
+            requested_path: None,
+        }];
+        let metadata = build_metadata(&[], &files);
+        export_as_zip(
+            &destination,
+            &files,
+            Some(&metadata),
+            false,
+            &AtomicBool::new(false),
+            &mut |_, _| {},
+        )
+        .expect("zip export should succeed");
+
+        let file = File::open(&destination).expect("archive should exist");
+        let mut archive = ZipArchive::new(file).expect("archive should be readable");
+        {
+            let mut entry = archive
+                .by_name("large.json")
+                .expect("json should be included");
+            let mut extracted = Vec::new();
+            io::Read::read_to_end(&mut entry, &mut extracted).expect("entry should extract");
+            assert_eq!(extracted, content);
+        }
+        archive
+            .by_name("metadata.json")
+            .expect("metadata should be included");
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
     #[test]
     fn exports_folder_with_numbered_names_and_structure() {
         let root = temp_root("parakeet-export-folder");
@@ -495,21 +1368,25 @@ mod tests {
         write_file(&transcript_b, "beta");
 
         let destination = root.join("exported");
-        export_transcripts(
+        block_on(export_transcripts(
             vec![
                 ExportItem {
                     id: "item-a".to_string(),
+                    path: None,
                     status: "completed".to_string(),
                     relative_path: Some("batch-one/a.wav".to_string()),
                     transcript_path: Some(transcript_a.to_string_lossy().to_string()),
                     json_path: None,
+                    language: None,
                 },
                 ExportItem {
                     id: "item-b".to_string(),
+                    path: None,
                     status: "completed".to_string(),
                     relative_path: Some("batch-one/deep/b.wav".to_string()),
                     transcript_path: Some(transcript_b.to_string_lossy().to_string()),
                     json_path: None,
+                    language: None,
                 },
             ],
             destination.to_string_lossy().to_string(),
@@ -518,8 +1395,15 @@ mod tests {
                 naming: ExportNaming::Numbered,
                 include_metadata: false,
                 preserve_structure: true,
+                move_files: false,
+                utf8_bom: false,
+                group_by_language: false,
+                target_filesystem: TargetFilesystem::Native,
+                overwrite: false,
+                skip_space_check: false,
             },
-        )
+            None,
+        ))
         .expect("folder export should succeed");
 
         let first = destination.join("batch-one").join("0001_a.txt");
@@ -538,23 +1422,741 @@ mod tests {
         assert!(!destination.join("metadata.json").exists());
     }
 
+    #[test]
+    fn groups_exported_files_into_language_folders() {
+        let root = temp_root("parakeet-export-language");
+        let transcript_a = root.join("out").join("a.txt");
+        let transcript_b = root.join("out").join("b.txt");
+        let json_b = root.join("out").join("b.json");
+        let transcript_c = root.join("out").join("c.txt");
+        write_file(&transcript_a, "hello");
+        write_file(&transcript_b, "hallo");
+        write_file(&json_b, "{\"text\":\"hallo\",\"language\":\"de\"}");
+        write_file(&transcript_c, "...");
+        let item =
+            |id: &str, transcript: &Path, json: Option<&Path>, language: Option<&str>| ExportItem {
+                id: id.to_string(),
+                path: None,
+                status: "completed".to_string(),
+                relative_path: Some(format!("batch/{}.wav", id)),
+                transcript_path: Some(transcript.to_string_lossy().to_string()),
+                json_path: json.map(|path| path.to_string_lossy().to_string()),
+                language: language.map(str::to_string),
+            };
+
+        let destination = root.join("exported");
+        block_on(export_transcripts(
+            vec![
+                item("a", &transcript_a, None, Some(" EN ")),
+                item("b", &transcript_b, Some(&json_b), None),
+                item("c", &transcript_c, None, None),
+            ],
+            destination.to_string_lossy().to_string(),
+            ExportOptions {
+                format: ExportFormat::Folder,
+                include_metadata: false,
+                preserve_structure: true,
+                group_by_language: true,
+                ..ExportOptions::default()
+            },
+            None,
+        ))
+        .expect("folder export should succeed");
+
+        assert!(destination.join("en").join("batch").join("a.txt").exists());
+        assert!(destination.join("de").join("batch").join("b.txt").exists());
+        assert!(destination.join("de").join("batch").join("b.json").exists());
+        assert!(destination
+            .join(UNKNOWN_LANGUAGE_FOLDER)
+            .join("batch")
+            .join("c.txt")
+            .exists());
+        assert_eq!(language_folder("../.."), None);
+    }
+
+    #[test]
+    fn sanitizes_names_for_windows_family_filesystems() {
+        assert_eq!(
+            sanitize_for_filesystem("Meeting 10:30.txt", TargetFilesystem::Exfat),
+            "Meeting 10_30.txt"
+        );
+        assert_eq!(
+            sanitize_for_filesystem("a<b>|c?*.json", TargetFilesystem::Ntfs),
+            "a_b__c__.json"
+        );
+        assert_eq!(
+            sanitize_for_filesystem("Meeting 10:30.txt", TargetFilesystem::Native),
+            "Meeting 10:30.txt"
+        );
+
+        assert_eq!(
+            sanitize_for_filesystem("CON", TargetFilesystem::Exfat),
+            "_CON"
+        );
+        assert_eq!(
+            sanitize_for_filesystem("nul.txt", TargetFilesystem::Exfat),
+            "_nul.txt"
+        );
+        assert_eq!(
+            sanitize_for_filesystem("com1.tar.gz", TargetFilesystem::Ntfs),
+            "_com1.tar.gz"
+        );
+        assert_eq!(
+            sanitize_for_filesystem("CONSOLE.txt", TargetFilesystem::Exfat),
+            "CONSOLE.txt"
+        );
+
+        assert_eq!(
+            sanitize_for_filesystem("draft. . ", TargetFilesystem::Exfat),
+            "draft"
+        );
+        assert_eq!(sanitize_for_filesystem("...", TargetFilesystem::Exfat), "_");
+        assert_eq!(
+            sanitize_for_filesystem("aux .txt.", TargetFilesystem::Exfat),
+            "_aux .txt"
+        );
+    }
+
+    #[test]
+    fn export_reports_names_rewritten_for_the_target_filesystem() {
+        let root = temp_root("parakeet-export-exfat");
+        let transcript = root.join("out").join("Standup 09:15.txt");
+        let plain = root.join("out").join("plain.txt");
+        write_file(&transcript, "hello");
+        write_file(&plain, "world");
+        let item = |id: &str, path: &Path, relative: &str| ExportItem {
+            id: id.to_string(),
+            path: None,
+            status: "completed".to_string(),
+            relative_path: Some(relative.to_string()),
+            transcript_path: Some(path.to_string_lossy().to_string()),
+            json_path: None,
+            language: None,
+        };
+
+        let destination = root.join("exported");
+        let result = block_on(export_transcripts(
+            vec![
+                item("a", &transcript, "prn/a.wav"),
+                item("b", &plain, "b.wav"),
+            ],
+            destination.to_string_lossy().to_string(),
+            ExportOptions {
+                format: ExportFormat::Folder,
+                include_metadata: false,
+                preserve_structure: true,
+                target_filesystem: TargetFilesystem::Exfat,
+                ..ExportOptions::default()
+            },
+            None,
+        ))
+        .expect("folder export should succeed");
+
+        assert!(destination.join("_prn").join("Standup 09_15.txt").exists());
+        assert_eq!(
+            result.renamed,
+            vec![RenamedExportFile {
+                source_path: transcript.to_string_lossy().to_string(),
+                requested_path: "prn/Standup 09:15.txt".to_string(),
+                exported_path: "_prn/Standup 09_15.txt".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn utf8_bom_is_added_to_text_formats_only() {
+        let root = temp_root("parakeet-export-bom");
+        let transcript = root.join("out").join("a.txt");
+        let captions = root.join("out").join("a.srt");
+        let json = root.join("out").join("a.json");
+        write_file(&transcript, "héllo");
+        write_file(&captions, "\u{feff}1\n00:00:00,000 --> 00:00:01,000\nhi\n");
+        write_file(&json, "{\"text\":\"héllo\"}");
+        let items = vec![
+            ExportItem {
+                id: "item-a".to_string(),
+                path: None,
+                status: "completed".to_string(),
+                relative_path: None,
+                transcript_path: Some(transcript.to_string_lossy().to_string()),
+                json_path: Some(json.to_string_lossy().to_string()),
+                language: None,
+            },
+            ExportItem {
+                id: "item-b".to_string(),
+                path: None,
+                status: "completed".to_string(),
+                relative_path: None,
+                transcript_path: Some(captions.to_string_lossy().to_string()),
+                json_path: None,
+                language: None,
+            },
+        ];
+        let options = |format: ExportFormat| ExportOptions {
+            format,
+            include_metadata: false,
+            utf8_bom: true,
+            ..ExportOptions::default()
+        };
+
+        let folder = root.join("folder");
+        block_on(export_transcripts(
+            items.clone(),
+            folder.to_string_lossy().to_string(),
+            options(ExportFormat::Folder),
+            None,
+        ))
+        .expect("folder export should succeed");
+        let exported_txt = std::fs::read(folder.join("a.txt")).expect("txt should be exported");
+        assert!(exported_txt.starts_with(UTF8_BOM));
+        assert_eq!(&exported_txt[UTF8_BOM.len()..], "héllo".as_bytes());
+        let exported_srt = std::fs::read(folder.join("a.srt")).expect("srt should be exported");
+        assert!(exported_srt.starts_with(UTF8_BOM));
+        assert!(!exported_srt[UTF8_BOM.len()..].starts_with(UTF8_BOM));
+        assert_eq!(
+            std::fs::read(folder.join("a.json")).expect("json should be exported"),
+            std::fs::read(&json).expect("json source should exist")
+        );
+        assert!(!std::fs::read(&transcript)
+            .expect("source should be untouched")
+            .starts_with(UTF8_BOM));
+
+        let archive_path = root.join("bundle.zip");
+        block_on(export_transcripts(
+            items,
+            archive_path.to_string_lossy().to_string(),
+            options(ExportFormat::Zip),
+            None,
+        ))
+        .expect("zip export should succeed");
+        let mut archive = ZipArchive::new(File::open(&archive_path).expect("archive should exist"))
+            .expect("archive should be readable");
+        let mut read_entry = |name: &str| {
+            let mut content = Vec::new();
+            archive
+                .by_name(name)
+                .expect("entry should exist")
+                .read_to_end(&mut content)
+                .expect("entry should extract");
+            content
+        };
+        assert!(read_entry("a.txt").starts_with(UTF8_BOM));
+        assert!(!read_entry("a.json").starts_with(UTF8_BOM));
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn metadata_only_export_writes_no_transcripts() {
+        let root = temp_root("parakeet-export-metadata-only");
+        let transcript = root.join("source").join("a.txt");
+        write_file(&transcript, "hello world");
+        let destination = root.join("index");
+
+        let written = export_metadata_only(
+            vec![
+                ExportItem {
+                    id: "item-a".to_string(),
+                    path: None,
+                    status: "completed".to_string(),
+                    relative_path: Some("nested/a.wav".to_string()),
+                    transcript_path: Some(transcript.to_string_lossy().to_string()),
+                    json_path: None,
+                    language: None,
+                },
+                ExportItem {
+                    id: "item-b".to_string(),
+                    path: None,
+                    status: "error".to_string(),
+                    relative_path: Some("nested/b.wav".to_string()),
+                    transcript_path: None,
+                    json_path: None,
+                    language: None,
+                },
+            ],
+            destination.to_string_lossy().to_string(),
+            None,
+        )
+        .expect("metadata export should succeed");
+
+        let metadata_path = destination.join("metadata.json");
+        assert_eq!(written, metadata_path.to_string_lossy());
+        let metadata: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(&metadata_path).expect("metadata should be readable"),
+        )
+        .expect("metadata should be valid json");
+        assert_eq!(metadata["totalItems"], 2);
+        assert_eq!(metadata["completedItems"], 1);
+        assert_eq!(metadata["failedItems"], 1);
+        assert_eq!(metadata["exportedFiles"], 1);
+        assert_eq!(metadata["entries"][0]["itemId"], "item-a");
+        assert_eq!(metadata["entries"][0]["exportedPath"], "a.txt");
+
+        let written_entries = std::fs::read_dir(&destination)
+            .expect("destination should exist")
+            .map(|entry| entry.expect("entry should read").file_name())
+            .collect::<Vec<_>>();
+        assert_eq!(written_entries, vec!["metadata.json"]);
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn space_check_compares_estimated_size_with_free_space() {
+        let root = temp_root("parakeet-export-space");
+        let transcript = root.join("a.txt");
+        write_file(&transcript, &"x".repeat(4000));
+        let files = vec![PreparedExportFile {
+            source_path: transcript,
+            export_path: "a.txt".to_string(),
+            item_id: "item-a".to_string(),
+            requested_path: None,
+        }];
+
+        let folder = estimated_export_bytes(&files, None, &ExportFormat::Folder)
+            .expect("size should be estimated");
+        assert_eq!(folder, 4000);
+        let zip = estimated_export_bytes(&files, None, &ExportFormat::Zip)
+            .expect("size should be estimated");
+        assert!(zip > folder, "zip estimates include entry overhead");
+        let metadata = build_metadata(&[], &files);
+        assert!(
+            estimated_export_bytes(&files, Some(&metadata), &ExportFormat::Folder)
+                .expect("size should be estimated")
+                > folder
+        );
+
+        let destination = Path::new("/Volumes/Stick/export");
+        assert!(ensure_export_space(destination, 4000, 4000).is_ok());
+        assert!(ensure_export_space(destination, 4000, 1 << 30).is_ok());
+        let error = ensure_export_space(destination, 5 * 1024 * 1024, 1024 * 1024)
+            .expect_err("a short volume should be rejected");
+        assert!(error.contains("needs 5.0 MB but only 1.0 MB"), "{error}");
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
     #[test]
     fn rejects_export_when_no_completed_transcripts_exist() {
         let root = temp_root("parakeet-export-empty");
         let destination = root.join("bundle.zip");
 
-        let result = export_transcripts(
+        let result = block_on(export_transcripts(
             vec![ExportItem {
                 id: "item-a".to_string(),
+                path: None,
                 status: "error".to_string(),
                 relative_path: None,
                 transcript_path: None,
                 json_path: None,
+                language: None,
             }],
             destination.to_string_lossy().to_string(),
             ExportOptions::default(),
-        );
+            None,
+        ));
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn move_renames_within_a_filesystem() {
+        let root = temp_root("parakeet-export-move");
+        let source = root.join("out").join("a.txt");
+        write_file(&source, "alpha");
+        let destination = root.join("moved").join("a.txt");
+        ensure_parent_directory(&destination).expect("destination parent should be created");
+
+        move_file(&source, &destination).expect("move should succeed");
+        assert!(!source.exists());
+        assert_eq!(
+            std::fs::read_to_string(&destination).expect("moved file should exist"),
+            "alpha"
+        );
+    }
+
+    #[test]
+    fn move_falls_back_to_copy_and_delete_across_filesystems() {
+        let root = temp_root("parakeet-export-move-xdev");
+        let source = root.join("out").join("a.txt");
+        write_file(&source, "alpha");
+        let destination = root.join("a.txt");
+
+        move_file_with(&source, &destination, |_, _| {
+            Err(io::Error::from(io::ErrorKind::CrossesDevices))
+        })
+        .expect("cross-device move should fall back to copy");
+        assert!(!source.exists());
+        assert_eq!(
+            std::fs::read_to_string(&destination).expect("copied file should exist"),
+            "alpha"
+        );
+
+        write_file(&source, "beta");
+        let error = move_file_with(&source, &root.join("b.txt"), |_, _| {
+            Err(io::Error::from(io::ErrorKind::PermissionDenied))
+        })
+        .expect_err("other rename failures should not fall back");
+        assert!(error.contains("Failed to move transcript"));
+        assert!(source.exists());
+    }
+
+    #[test]
+    fn folder_exports_refuse_non_empty_destinations_unless_overwriting() {
+        let root = temp_root("parakeet-export-overwrite");
+        let transcript = root.join("out").join("a.txt");
+        write_file(&transcript, "fresh");
+        let destination = root.join("exported");
+        write_file(&destination.join("a.txt"), "keep me");
+        write_file(&destination.join("notes.md"), "important");
+        write_file(&destination.join(".DS_Store"), "finder");
+        let export = |overwrite: bool| {
+            block_on(export_transcripts(
+                vec![ExportItem {
+                    id: "item-a".to_string(),
+                    path: None,
+                    status: "completed".to_string(),
+                    relative_path: None,
+                    transcript_path: Some(transcript.to_string_lossy().to_string()),
+                    json_path: None,
+                    language: None,
+                }],
+                destination.to_string_lossy().to_string(),
+                ExportOptions {
+                    format: ExportFormat::Folder,
+                    include_metadata: false,
+                    overwrite,
+                    ..ExportOptions::default()
+                },
+                None,
+            ))
+        };
+
+        assert_eq!(
+            check_export_destination(destination.to_string_lossy().to_string()),
+            Ok(vec!["a.txt".to_string(), "notes.md".to_string()])
+        );
+        let error = export(false).expect_err("non-empty destinations are refused by default");
+        assert!(
+            error.contains("is not empty (a.txt, notes.md)"),
+            "{}",
+            error
+        );
+        assert_eq!(
+            std::fs::read_to_string(destination.join("a.txt")).expect("file should remain"),
+            "keep me"
+        );
+
+        export(true).expect("overwrite should allow the export");
+        assert_eq!(
+            std::fs::read_to_string(destination.join("a.txt")).expect("file should be exported"),
+            "fresh"
+        );
+        assert_eq!(
+            check_export_destination(root.join("missing").to_string_lossy().to_string()),
+            Ok(Vec::new())
+        );
+    }
+
+    #[test]
+    fn move_is_only_supported_for_folder_exports() {
+        let root = temp_root("parakeet-export-move-zip");
+        let transcript = root.join("a.txt");
+        write_file(&transcript, "alpha");
+
+        let error = block_on(export_transcripts(
+            vec![ExportItem {
+                id: "item-a".to_string(),
+                path: None,
+                status: "completed".to_string(),
+                relative_path: None,
+                transcript_path: Some(transcript.to_string_lossy().to_string()),
+                json_path: None,
+                language: None,
+            }],
+            root.join("bundle.zip").to_string_lossy().to_string(),
+            ExportOptions {
+                move_files: true,
+                ..ExportOptions::default()
+            },
+            None,
+        ))
+        .expect_err("zip exports cannot move files");
+        assert!(error.contains("folder exports"));
+        assert!(transcript.exists());
+    }
+
+    #[test]
+    fn cancelled_exports_stop_before_the_next_file_and_remove_partial_output() {
+        let root = temp_root("parakeet-export-cancel");
+        let transcript = root.join("out").join("a.txt");
+        write_file(&transcript, "alpha");
+        let files = vec![PreparedExportFile {
+            source_path: transcript.clone(),
+            export_path: "nested/a.txt".to_string(),
+            item_id: "item-a".to_string(),
+            requested_path: None,
+        }];
+        let cancelled = AtomicBool::new(true);
+
+        let archive = root.join("bundle.zip");
+        assert_eq!(
+            export_as_zip(&archive, &files, None, false, &cancelled, &mut |_, _| {})
+                .expect("cancel is not an error"),
+            ExportRun::Cancelled
+        );
+        assert!(!archive.exists());
+
+        let folder = root.join("exported");
+        assert_eq!(
+            export_as_folder(
+                &folder,
+                &files,
+                None,
+                true,
+                false,
+                &cancelled,
+                &mut |_, _| {}
+            )
+            .expect("cancel is not an error"),
+            ExportRun::Cancelled
+        );
+        assert!(!folder.exists());
+        assert!(transcript.exists());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn failed_folder_move_restores_files_already_moved() {
+        let root = temp_root("parakeet-export-move-failure");
+        let transcript = root.join("out").join("a.txt");
+        write_file(&transcript, "alpha");
+        let files = vec![
+            PreparedExportFile {
+                source_path: transcript.clone(),
+                export_path: "a.txt".to_string(),
+                item_id: "item-a".to_string(),
+                requested_path: None,
+            },
+            PreparedExportFile {
+                source_path: root.join("out").join("missing.txt"),
+                export_path: "missing.txt".to_string(),
+                item_id: "item-b".to_string(),
+                requested_path: None,
+            },
+        ];
+
+        let folder = root.join("exported");
+        let error = export_as_folder(
+            &folder,
+            &files,
+            None,
+            true,
+            false,
+            &AtomicBool::new(false),
+            &mut |_, _| {},
+        )
+        .expect_err("missing source should fail the move");
+        assert!(error.contains("missing.txt"), "{error}");
+        assert_eq!(
+            std::fs::read_to_string(&transcript).expect("moved file should be restored"),
+            "alpha"
+        );
+        assert!(!folder.exists());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn partial_folder_cleanup_restores_moves_and_keeps_existing_destination() {
+        let root = temp_root("parakeet-export-rollback");
+        let destination = root.join("existing");
+        write_file(&destination.join("keep.txt"), "keep");
+
+        let source = root.join("out").join("moved.txt");
+        let moved_to = destination.join("batch").join("moved.txt");
+        write_file(&moved_to, "moved");
+        let copied = destination.join("batch").join("deep").join("copied.txt");
+        write_file(&copied, "copied");
+        std::fs::create_dir_all(source.parent().expect("source has a parent"))
+            .expect("source dir should be created");
+
+        discard_partial_folder_export(
+            &destination,
+            false,
+            std::slice::from_ref(&copied),
+            &[(
+                source.to_string_lossy().to_string(),
+                moved_to.to_string_lossy().to_string(),
+            )],
+        );
+
+        assert_eq!(
+            std::fs::read_to_string(&source).expect("moved file should be restored"),
+            "moved"
+        );
+        assert!(!destination.join("batch").exists());
+        assert!(destination.join("keep.txt").exists());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn cancel_export_flags_only_registered_exports() {
+        let export_id = format!("export-{}", Uuid::new_v4());
+        assert!(!cancel_export(export_id.clone()).expect("cancel should not error"));
+
+        let registration =
+            ExportRegistration::register(&export_id).expect("export should register");
+        assert!(cancel_export(export_id.clone()).expect("cancel should not error"));
+        assert!(registration.cancel_flag.load(Ordering::SeqCst));
+
+        drop(registration);
+        assert!(!cancel_export(export_id).expect("cancel should not error"));
+    }
+
+    #[test]
+    fn reports_progress_per_written_file_and_a_final_completion() {
+        let root = temp_root("parakeet-export-progress");
+        let transcript = root.join("out").join("a.txt");
+        let json = root.join("out").join("a.json");
+        write_file(&transcript, "alpha");
+        write_file(&json, "{}");
+        let items = vec![ExportItem {
+            id: "item-a".to_string(),
+            path: None,
+            status: "completed".to_string(),
+            relative_path: None,
+            transcript_path: Some(transcript.to_string_lossy().to_string()),
+            json_path: Some(json.to_string_lossy().to_string()),
+            language: None,
+        }];
+
+        for format in [ExportFormat::Zip, ExportFormat::Folder] {
+            let destination = root.join(format!("export-{:?}", format));
+            let mut events = Vec::new();
+            run_export(
+                &items,
+                &destination.to_string_lossy(),
+                &ExportOptions {
+                    format,
+                    ..ExportOptions::default()
+                },
+                Some("export-progress".to_string()),
+                &mut |progress| events.push(progress),
+            )
+            .expect("export should succeed");
+
+            assert_eq!(
+                events
+                    .iter()
+                    .map(|event| (event.written, event.current_path.as_deref(), event.done))
+                    .collect::<Vec<_>>(),
+                vec![
+                    (1, Some("a.txt"), false),
+                    (2, Some("a.json"), false),
+                    (2, None, true),
+                ]
+            );
+            assert!(events
+                .iter()
+                .all(|event| event.total == 2 && event.export_id == "export-progress"));
+            assert!(!events[2].cancelled);
+        }
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    fn queue_item(id: &str, path: &str) -> QueueItem {
+        QueueItem {
+            id: id.to_string(),
+            path: PathBuf::from(path),
+            status: "queued".to_string(),
+        }
+    }
+
+    fn relative_paths(plan: &RelativePathPlan) -> Vec<&str> {
+        plan.items
+            .iter()
+            .map(|item| item.relative_path.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn preserve_structure_falls_back_to_derived_relative_paths() {
+        let root = temp_root("parakeet-export-derived");
+        let item = |id: &str, source: &str, relative_path: Option<&str>| {
+            let transcript = root.join("out").join(format!("{}.txt", id));
+            write_file(&transcript, id);
+            ExportItem {
+                id: id.to_string(),
+                path: Some(source.to_string()),
+                status: "completed".to_string(),
+                relative_path: relative_path.map(str::to_string),
+                transcript_path: Some(transcript.to_string_lossy().to_string()),
+                json_path: None,
+                language: None,
+            }
+        };
+        let items = vec![
+            item("a", "/rec/2024/jan/a.wav", None),
+            item("b", "/rec/2024/feb/b.wav", None),
+            item("c", "/rec/2024/c.wav", Some("scanned/c.wav")),
+        ];
+
+        let prepared = collect_export_sources(
+            &items,
+            &ExportOptions {
+                preserve_structure: true,
+                ..ExportOptions::default()
+            },
+        )
+        .expect("sources should be collected");
+        assert_eq!(
+            prepared
+                .iter()
+                .map(|file| file.export_path.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["jan/a.txt", "feb/b.txt", "scanned/c.txt"]
+        );
+
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn derives_relative_paths_from_the_common_root() {
+        let nested = derive_relative_path_plan(&[
+            queue_item("a", "/Users/me/Recordings/2024/jan/a.wav"),
+            queue_item("b", "/Users/me/Recordings/2024/feb/b.wav"),
+            queue_item("c", "/Users/me/Recordings/2024/c.wav"),
+        ]);
+        assert_eq!(
+            nested.common_root.as_deref(),
+            Some("/Users/me/Recordings/2024")
+        );
+        assert_eq!(
+            relative_paths(&nested),
+            vec!["jan/a.wav", "feb/b.wav", "c.wav"]
+        );
+
+        let single = derive_relative_path_plan(&[queue_item("a", "/Users/me/Recordings/a.wav")]);
+        assert_eq!(single.common_root.as_deref(), Some("/Users/me/Recordings"));
+        assert_eq!(relative_paths(&single), vec!["a.wav"]);
+
+        let disjoint = derive_relative_path_plan(&[
+            queue_item("a", "/Volumes/Card/a.wav"),
+            queue_item("b", "/Users/me/b.wav"),
+        ]);
+        assert_eq!(disjoint.common_root, None);
+        assert_eq!(relative_paths(&disjoint), vec!["a.wav", "b.wav"]);
+
+        assert_eq!(
+            derive_relative_path_plan(&[]),
+            RelativePathPlan {
+                common_root: None,
+                items: Vec::new(),
+            }
+        );
+    }
 }
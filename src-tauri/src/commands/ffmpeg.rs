@@ -0,0 +1,620 @@
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tauri::AppHandle;
+
+// Supported extension -> (codecs that can decode it, demuxer ffmpeg needs to open it).
+const EXTENSION_REQUIREMENTS: &[(&str, &[&str], &str)] = &[
+    ("mp3", &["mp3"], "mp3"),
+    ("wav", &["pcm_s16le"], "wav"),
+    ("m4a", &["aac", "alac"], "m4a"),
+    ("flac", &["flac"], "flac"),
+    ("ogg", &["vorbis", "opus"], "ogg"),
+    ("aac", &["aac"], "aac"),
+    ("aiff", &["pcm_s16be"], "aiff"),
+    ("aif", &["pcm_s16be"], "aiff"),
+    ("caf", &["pcm_s16le", "alac", "aac"], "caf"),
+    ("wma", &["wmav2", "wmav1"], "asf"),
+];
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionSupport {
+    pub extension: String,
+    pub decoders: Vec<String>,
+    pub demuxer: bool,
+    pub supported: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FfmpegCapabilities {
+    pub available: bool,
+    pub version: Option<String>,
+    pub audio_decoders: Vec<String>,
+    pub extensions: Vec<ExtensionSupport>,
+}
+
+impl FfmpegCapabilities {
+    pub fn unsupported_extensions(&self) -> Vec<String> {
+        self.extensions
+            .iter()
+            .filter(|entry| !entry.supported)
+            .map(|entry| entry.extension.clone())
+            .collect()
+    }
+}
+
+fn listing_rows(output: &str) -> impl Iterator<Item = (&str, &str)> {
+    // Both listings print a legend, then a dashed separator, then one row per entry.
+    output
+        .lines()
+        .skip_while(|line| !line.trim_start().starts_with("--"))
+        .skip(1)
+        .filter_map(|line| {
+            let mut tokens = line.split_whitespace();
+            Some((tokens.next()?, tokens.next()?))
+        })
+}
+
+fn parse_audio_decoders(codecs_output: &str) -> HashSet<String> {
+    listing_rows(codecs_output)
+        .filter(|(flags, _)| {
+            let flags = flags.as_bytes();
+            flags.len() >= 3 && flags[0] == b'D' && flags[2] == b'A'
+        })
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+fn parse_demuxers(formats_output: &str) -> HashSet<String> {
+    listing_rows(formats_output)
+        .filter(|(flags, _)| flags.starts_with('D'))
+        .flat_map(|(_, names)| names.split(',').map(str::to_string))
+        .collect()
+}
+
+fn parse_version(version_output: &str) -> Option<String> {
+    version_output
+        .lines()
+        .next()?
+        .strip_prefix("ffmpeg version ")?
+        .split_whitespace()
+        .next()
+        .map(str::to_string)
+}
+
+fn build_capabilities(
+    version_output: &str,
+    codecs_output: &str,
+    formats_output: &str,
+) -> FfmpegCapabilities {
+    let decoders = parse_audio_decoders(codecs_output);
+    let demuxers = parse_demuxers(formats_output);
+
+    let extensions = EXTENSION_REQUIREMENTS
+        .iter()
+        .map(|(extension, codecs, demuxer)| {
+            let available = codecs
+                .iter()
+                .filter(|codec| decoders.contains(**codec))
+                .map(|codec| codec.to_string())
+                .collect::<Vec<String>>();
+            let has_demuxer = demuxers.contains(*demuxer);
+            ExtensionSupport {
+                extension: extension.to_string(),
+                supported: has_demuxer && !available.is_empty(),
+                decoders: available,
+                demuxer: has_demuxer,
+            }
+        })
+        .collect::<Vec<ExtensionSupport>>();
+
+    let mut audio_decoders = EXTENSION_REQUIREMENTS
+        .iter()
+        .flat_map(|(_, codecs, _)| codecs.iter())
+        .filter(|codec| decoders.contains(**codec))
+        .map(|codec| codec.to_string())
+        .collect::<Vec<String>>();
+    audio_decoders.sort();
+    audio_decoders.dedup();
+
+    FfmpegCapabilities {
+        available: true,
+        version: parse_version(version_output),
+        audio_decoders,
+        extensions,
+    }
+}
+
+fn ffmpeg_output(args: &[&str]) -> Option<String> {
+    let output = Command::new("ffmpeg").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+pub fn probe_ffmpeg_capabilities() -> FfmpegCapabilities {
+    let Some(version_output) = ffmpeg_output(&["-version"]) else {
+        return FfmpegCapabilities::default();
+    };
+    let codecs_output = ffmpeg_output(&["-hide_banner", "-codecs"]).unwrap_or_default();
+    let formats_output = ffmpeg_output(&["-hide_banner", "-formats"]).unwrap_or_default();
+
+    build_capabilities(&version_output, &codecs_output, &formats_output)
+}
+
+#[tauri::command]
+pub async fn ffmpeg_capabilities() -> Result<FfmpegCapabilities, String> {
+    tokio::task::spawn_blocking(probe_ffmpeg_capabilities)
+        .await
+        .map_err(|error| format!("Failed to probe ffmpeg capabilities: {}", error))
+}
+
+const TRANSCODE_CACHE_DIR: &str = "_archive/coreml-transcode-cache";
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum FallbackAction {
+    Direct,
+    Transcode,
+    Unsupported,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FallbackPlanEntry {
+    pub path: String,
+    pub action: FallbackAction,
+    pub command: Option<Vec<String>>,
+    pub reason: Option<String>,
+}
+
+// Same layout as the Swift worker's `transcodeOutputURL`: `<cache dir>/<relativePath>.wav`.
+fn transcode_cache_path(output_dir: &Path, relative_path: &str) -> PathBuf {
+    output_dir
+        .join(TRANSCODE_CACHE_DIR)
+        .join(format!("{}.wav", relative_path))
+}
+
+fn transcode_args(input: &Path, output_dir: &Path) -> Vec<String> {
+    // Manifests written by the app carry no `relative`, so the worker falls back to the file name.
+    let relative_path = input
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let output = transcode_cache_path(output_dir, &relative_path);
+    ["ffmpeg", "-y", "-v", "error", "-nostdin", "-i"]
+        .iter()
+        .map(|arg| arg.to_string())
+        .chain([input.to_string_lossy().to_string()])
+        .chain(
+            ["-vn", "-ac", "1", "-ar", "16000", "-f", "wav"]
+                .iter()
+                .map(|arg| arg.to_string()),
+        )
+        .chain([output.to_string_lossy().to_string()])
+        .collect()
+}
+
+fn plan_fallback(
+    paths: &[String],
+    supported_formats: &[String],
+    ffmpeg: &FfmpegCapabilities,
+    output_dir: &Path,
+) -> Vec<FallbackPlanEntry> {
+    paths
+        .iter()
+        .map(|path| {
+            let extension = Path::new(path)
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .unwrap_or_default()
+                .to_ascii_lowercase();
+            // A provider that declares no formats gets every file handed to it as-is.
+            let direct = supported_formats.is_empty()
+                || supported_formats.iter().any(|format| {
+                    format
+                        .trim_start_matches('.')
+                        .eq_ignore_ascii_case(&extension)
+                });
+            let decodable = ffmpeg
+                .extensions
+                .iter()
+                .any(|entry| entry.extension == extension && entry.supported);
+
+            let (action, command, reason) = if direct {
+                (FallbackAction::Direct, None, None)
+            } else if !ffmpeg.available {
+                (
+                    FallbackAction::Unsupported,
+                    None,
+                    Some(format!(
+                        "Provider cannot decode .{} and ffmpeg is not installed",
+                        extension
+                    )),
+                )
+            } else if !decodable {
+                (
+                    FallbackAction::Unsupported,
+                    None,
+                    Some(format!("ffmpeg cannot decode .{} files", extension)),
+                )
+            } else {
+                (
+                    FallbackAction::Transcode,
+                    Some(transcode_args(Path::new(path), output_dir)),
+                    None,
+                )
+            };
+            FallbackPlanEntry {
+                path: path.clone(),
+                action,
+                command,
+                reason,
+            }
+        })
+        .collect()
+}
+
+#[tauri::command]
+pub async fn fallback_plan(
+    app: AppHandle,
+    provider_id: String,
+    items: Vec<String>,
+    output_dir: String,
+) -> Result<Vec<FallbackPlanEntry>, String> {
+    let provider = crate::providers::registry::probe_provider(&app, &provider_id)?;
+    let supported_formats = provider
+        .capabilities
+        .map(|capabilities| capabilities.supported_formats)
+        .unwrap_or_default();
+    let ffmpeg = tokio::task::spawn_blocking(probe_ffmpeg_capabilities)
+        .await
+        .map_err(|error| format!("Failed to probe ffmpeg capabilities: {}", error))?;
+
+    Ok(plan_fallback(
+        &items,
+        &supported_formats,
+        &ffmpeg,
+        Path::new(&output_dir),
+    ))
+}
+
+const SEGMENT_SUFFIX: &str = "_part";
+
+// ffmpeg records each segment it writes here, so leftovers from an earlier split are never reported.
+fn segment_list_path(output_dir: &Path, stem: &str) -> PathBuf {
+    output_dir.join(format!(".{}{}.list", stem, SEGMENT_SUFFIX))
+}
+
+fn split_audio_args(
+    input: &Path,
+    output_dir: &Path,
+    segment_seconds: f64,
+) -> Result<Vec<String>, String> {
+    let stem = input
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .ok_or_else(|| format!("Invalid audio file name: {}", input.display()))?;
+    let extension = input
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .ok_or_else(|| format!("Audio file has no extension: {}", input.display()))?;
+    // The segment muxer treats '%' as a pattern directive, so literal ones in the name are doubled.
+    let pattern = output_dir.join(format!(
+        "{}{}%03d.{}",
+        stem.replace('%', "%%"),
+        SEGMENT_SUFFIX,
+        extension.replace('%', "%%")
+    ));
+
+    // Stream copy keeps the original codec, so chunks split at packet boundaries without re-encoding.
+    Ok(vec![
+        "-hide_banner".to_string(),
+        "-nostdin".to_string(),
+        "-n".to_string(),
+        "-i".to_string(),
+        input.to_string_lossy().to_string(),
+        "-map".to_string(),
+        "0:a".to_string(),
+        "-c".to_string(),
+        "copy".to_string(),
+        "-f".to_string(),
+        "segment".to_string(),
+        "-segment_time".to_string(),
+        segment_seconds.to_string(),
+        "-reset_timestamps".to_string(),
+        "1".to_string(),
+        "-segment_list".to_string(),
+        segment_list_path(output_dir, stem)
+            .to_string_lossy()
+            .to_string(),
+        "-segment_list_type".to_string(),
+        "flat".to_string(),
+        pattern.to_string_lossy().to_string(),
+    ])
+}
+
+fn produced_segments(list_path: &Path, output_dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let list = std::fs::read_to_string(list_path).map_err(|error| {
+        format!(
+            "Failed to read segment list {}: {}",
+            list_path.display(),
+            error
+        )
+    })?;
+    let _ = std::fs::remove_file(list_path);
+    Ok(list
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| output_dir.join(line))
+        .collect())
+}
+
+fn run_split_audio(
+    input: &Path,
+    output_dir: &Path,
+    segment_seconds: f64,
+) -> Result<Vec<String>, String> {
+    if !segment_seconds.is_finite() || segment_seconds <= 0.0 {
+        return Err("Segment length must be greater than zero seconds".to_string());
+    }
+    if !input.is_file() {
+        return Err(format!("Audio file not found: {}", input.display()));
+    }
+    if ffmpeg_output(&["-version"]).is_none() {
+        return Err(
+            "ffmpeg is required to split audio; install it with `brew install ffmpeg`".to_string(),
+        );
+    }
+
+    let args = split_audio_args(input, output_dir, segment_seconds)?;
+    std::fs::create_dir_all(output_dir).map_err(|error| {
+        format!(
+            "Failed to create output directory {}: {}",
+            output_dir.display(),
+            error
+        )
+    })?;
+    let output = Command::new("ffmpeg")
+        .args(&args)
+        .output()
+        .map_err(|error| format!("Failed to launch ffmpeg: {}", error))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!(
+            "Failed to split {}: {}",
+            input.display(),
+            stderr
+                .lines()
+                .last()
+                .unwrap_or("ffmpeg exited with an error")
+        ));
+    }
+
+    let stem = input
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default();
+    Ok(
+        produced_segments(&segment_list_path(output_dir, stem), output_dir)?
+            .into_iter()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect(),
+    )
+}
+
+#[tauri::command]
+pub async fn split_audio(
+    input: String,
+    output_dir: String,
+    segment_seconds: f64,
+) -> Result<Vec<String>, String> {
+    tokio::task::spawn_blocking(move || {
+        run_split_audio(Path::new(&input), Path::new(&output_dir), segment_seconds)
+    })
+    .await
+    .map_err(|error| format!("Failed to split audio: {}", error))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CODECS_FIXTURE: &str = "Codecs:
+ D..... = Decoding supported
+ .E.... = Encoding supported
+ ..V... = Video codec
+ ..A... = Audio codec
+ -------
+ DEV.LS h264                 H.264 / AVC / MPEG-4 AVC / MPEG-4 part 10
+ DEA.L. aac                  AAC (Advanced Audio Coding) (decoders: aac aac_fixed)
+ DEAI.S alac                 ALAC (Apple Lossless Audio Codec)
+ DEAI.S flac                 FLAC (Free Lossless Audio Codec)
+ DEA.L. mp3                  MP3 (MPEG audio layer 3) (decoders: mp3float mp3)
+ DEAI.S pcm_s16be            PCM signed 16-bit big-endian
+ DEAI.S pcm_s16le            PCM signed 16-bit little-endian
+ DEA.L. vorbis               Vorbis
+ .EA.L. opus                 Opus (Opus Interactive Audio Codec) (encoders: libopus)
+ DEA.L. wmav2                Windows Media Audio 2
+";
+
+    const FORMATS_FIXTURE: &str = "File formats:
+ D. = Demuxing supported
+ .E = Muxing supported
+ --
+ D  aac             raw ADTS AAC (Advanced Audio Coding)
+ DE aiff            Audio IFF
+ D  asf             ASF (Advanced / Active Streaming Format)
+ DE flac            raw FLAC
+ D  mov,mp4,m4a,3gp,3g2,mj2 QuickTime / MOV
+ DE mp3             MP3 (MPEG audio layer 3)
+ DE ogg             Ogg
+ DE wav             WAV / WAVE (Waveform Audio)
+  E caf             Apple CAF (Core Audio Format)
+";
+
+    #[test]
+    fn parses_audio_decoders_from_codecs_listing() {
+        let decoders = parse_audio_decoders(CODECS_FIXTURE);
+        assert!(decoders.contains("aac"));
+        assert!(decoders.contains("pcm_s16le"));
+        assert!(!decoders.contains("h264"), "video codecs are ignored");
+        assert!(!decoders.contains("opus"), "encode-only codecs are ignored");
+        assert!(!decoders.contains("Decoding"), "legend rows are skipped");
+    }
+
+    #[test]
+    fn maps_decoders_and_demuxers_to_supported_extensions() {
+        let capabilities = build_capabilities(
+            "ffmpeg version 6.1.1 Copyright (c) 2000-2023 the FFmpeg developers\n",
+            CODECS_FIXTURE,
+            FORMATS_FIXTURE,
+        );
+
+        assert!(capabilities.available);
+        assert_eq!(capabilities.version.as_deref(), Some("6.1.1"));
+        assert!(capabilities.audio_decoders.contains(&"vorbis".to_string()));
+        assert!(!capabilities.audio_decoders.contains(&"opus".to_string()));
+
+        let ogg = capabilities
+            .extensions
+            .iter()
+            .find(|entry| entry.extension == "ogg")
+            .expect("ogg should be listed");
+        assert_eq!(ogg.decoders, vec!["vorbis".to_string()]);
+        assert!(ogg.supported);
+
+        let m4a = capabilities
+            .extensions
+            .iter()
+            .find(|entry| entry.extension == "m4a")
+            .expect("m4a should be listed");
+        assert!(m4a.demuxer, "comma-separated demuxer names are split");
+
+        assert_eq!(
+            capabilities.unsupported_extensions(),
+            vec!["caf".to_string()]
+        );
+    }
+
+    #[test]
+    fn absent_ffmpeg_reports_empty_capabilities() {
+        let capabilities = FfmpegCapabilities::default();
+        assert!(!capabilities.available);
+        assert!(capabilities.version.is_none());
+        assert!(capabilities.extensions.is_empty());
+        assert!(capabilities.unsupported_extensions().is_empty());
+    }
+
+    #[test]
+    fn plans_direct_decode_or_ffmpeg_transcode_per_item() {
+        let items = vec![
+            "/audio/song.flac".to_string(),
+            "/audio/memo.WAV".to_string(),
+        ];
+        let formats = vec!["wav".to_string(), "mp3".to_string()];
+        let output_dir = Path::new("/out");
+        let with_ffmpeg =
+            build_capabilities("ffmpeg version 6.1.1\n", CODECS_FIXTURE, FORMATS_FIXTURE);
+
+        let plan = plan_fallback(&items, &formats, &with_ffmpeg, output_dir);
+        assert_eq!(plan[0].action, FallbackAction::Transcode);
+        assert_eq!(
+            plan[0].command.as_ref().map(|command| command.join(" ")),
+            Some(
+                "ffmpeg -y -v error -nostdin -i /audio/song.flac -vn -ac 1 -ar 16000 -f wav \
+                 /out/_archive/coreml-transcode-cache/song.flac.wav"
+                    .to_string()
+            )
+        );
+        assert_eq!(plan[1].action, FallbackAction::Direct);
+        assert_eq!(plan[1].command, None);
+
+        let plan = plan_fallback(&items, &formats, &FfmpegCapabilities::default(), output_dir);
+        assert_eq!(plan[0].action, FallbackAction::Unsupported);
+        assert!(plan[0]
+            .reason
+            .as_deref()
+            .is_some_and(|reason| reason.contains("ffmpeg is not installed")));
+        assert_eq!(plan[1].action, FallbackAction::Direct);
+
+        // Undeclared formats mean the provider is handed every file directly.
+        let plan = plan_fallback(&items, &[], &FfmpegCapabilities::default(), output_dir);
+        assert!(plan
+            .iter()
+            .all(|entry| entry.action == FallbackAction::Direct));
+    }
+
+    #[test]
+    fn builds_segment_muxer_arguments_and_collects_numbered_chunks() {
+        let args = split_audio_args(
+            Path::new("/audio/long interview.m4a"),
+            Path::new("/tmp/chunks"),
+            1800.0,
+        )
+        .expect("arguments should build");
+        assert_eq!(
+            args,
+            vec![
+                "-hide_banner",
+                "-nostdin",
+                "-n",
+                "-i",
+                "/audio/long interview.m4a",
+                "-map",
+                "0:a",
+                "-c",
+                "copy",
+                "-f",
+                "segment",
+                "-segment_time",
+                "1800",
+                "-reset_timestamps",
+                "1",
+                "-segment_list",
+                "/tmp/chunks/.long interview_part.list",
+                "-segment_list_type",
+                "flat",
+                "/tmp/chunks/long interview_part%03d.m4a",
+            ]
+        );
+        assert_eq!(
+            split_audio_args(Path::new("/audio/100% live.wav"), Path::new("/tmp"), 60.0)
+                .expect("arguments should build")
+                .last()
+                .map(String::as_str),
+            Some("/tmp/100%% live_part%03d.wav")
+        );
+        assert!(split_audio_args(Path::new("/audio/noext"), Path::new("/tmp"), 60.0).is_err());
+        assert!(
+            run_split_audio(Path::new("/audio/a.wav"), Path::new("/tmp"), 0.0)
+                .expect_err("zero-length segments are rejected")
+                .contains("greater than zero")
+        );
+
+        let output_dir =
+            std::env::temp_dir().join(format!("ffmpeg-segments-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&output_dir).expect("output dir should be created");
+        // A stale segment from an earlier, longer split must not be reported.
+        std::fs::write(output_dir.join("talk_part002.wav"), b"")
+            .expect("stale segment should be written");
+        let list_path = segment_list_path(&output_dir, "talk");
+        std::fs::write(&list_path, "talk_part000.wav\ntalk_part001.wav\n")
+            .expect("segment list should be written");
+        assert_eq!(
+            produced_segments(&list_path, &output_dir).expect("segment list should be read"),
+            vec![
+                output_dir.join("talk_part000.wav"),
+                output_dir.join("talk_part001.wav"),
+            ]
+        );
+        assert!(!list_path.exists());
+
+        let _ = std::fs::remove_dir_all(&output_dir);
+    }
+}
@@ -1,9 +1,43 @@
-use crate::providers::manifest::SessionManifest;
+use crate::commands::export::dedupe_path;
+use crate::providers::manifest::{
+    cleanup_manifest, get_sessions_dir, validate_session_id, QueueItem, SessionManifest,
+};
+use crate::providers::registry::{normalize_provider_id, LEGACY_COREML_PROVIDER_ID};
 use chrono::{DateTime, Utc};
-use rusqlite::{params, Connection};
+use rusqlite::{
+    params, Connection, ErrorCode, OptionalExtension, Transaction, TransactionBehavior,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const SESSION_INDEX_FILE_NAME: &str = "INDEX.txt";
+const LEGACY_PROVIDER_IDS: &[&str] = &[LEGACY_COREML_PROVIDER_ID];
+pub const EMPTY_TRANSCRIPT_STATUS: &str = "empty";
+const DEFAULT_HISTORY_PAGE_SIZE: i64 = 50;
+const INTERRUPTED_STOP_REASON: &str = "interrupted";
+const DEFAULT_SLOWEST_FILES_LIMIT: i64 = 10;
+const DEFAULT_RECENT_OUTPUT_DIRS_LIMIT: usize = 8;
+const SESSION_TAG_FILTER: &str =
+    "?1 IS NULL OR EXISTS (SELECT 1 FROM json_each(sessions.tags) WHERE json_each.value = ?1)";
+const HISTORY_BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+const HISTORY_WRITE_ATTEMPTS: u32 = 3;
+const HISTORY_WRITE_RETRY_DELAY: Duration = Duration::from_millis(250);
+const HISTORY_IN_USE_ERROR: &str =
+    "History database is in use by another instance; try again in a moment";
+
+// Criteria are combined with AND; `created_before` is a unix timestamp in seconds.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionDeleteFilter {
+    #[serde(default)]
+    pub provider: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub created_before: Option<i64>,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -15,6 +49,10 @@ pub struct SessionFileRecord {
     pub transcript_path: Option<String>,
     pub json_path: Option<String>,
     pub error: Option<String>,
+    #[serde(default)]
+    pub retries: u32,
+    #[serde(default)]
+    pub processing_seconds: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -31,17 +69,153 @@ pub struct SessionRecord {
     pub skipped: i32,
     pub failed: i32,
     pub duration_seconds: f64,
+    #[serde(default)]
+    pub audio_seconds: f64,
     pub exit_code: i32,
     pub status: String,
+    #[serde(default)]
+    pub stop_reason: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
     pub files: Vec<SessionFileRecord>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionHistoryPage {
+    pub sessions: Vec<SessionRecord>,
+    pub total: i64,
+    pub offset: i64,
+    pub limit: i64,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EmptyTranscript {
+    pub file_id: String,
+    pub path: String,
+    pub transcript_path: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EmptyTranscriptReport {
+    pub session_id: String,
+    pub checked: usize,
+    pub empty: Vec<EmptyTranscript>,
+    pub marked: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct FileOutcome {
     pub status: String,
     pub transcript_path: Option<String>,
     pub json_path: Option<String>,
     pub error: Option<String>,
+    pub audio_seconds: Option<f64>,
+    pub processing_seconds: Option<f64>,
+    pub retries: u32,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryCheckpoint {
+    pub busy: bool,
+    pub log_frames: i64,
+    pub checkpointed_frames: i64,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderStats {
+    pub provider: String,
+    pub sessions: i64,
+    pub files_processed: i64,
+    pub audio_seconds: f64,
+    pub failures: i64,
+    pub average_rtf: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderPerformance {
+    pub provider: String,
+    pub samples: i64,
+    pub average_rtf: Option<f64>,
+    pub insufficient_data: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionCounts {
+    pub total: i64,
+    pub processed: i64,
+    pub skipped: i64,
+    pub failed: i64,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionCountMismatch {
+    pub session_id: String,
+    pub stored: SessionCounts,
+    pub recomputed: SessionCounts,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryIntegrityReport {
+    pub checked: usize,
+    pub mismatches: Vec<SessionCountMismatch>,
+    pub repaired: bool,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SlowFile {
+    pub session_id: String,
+    pub file_id: String,
+    pub name: String,
+    pub path: String,
+    pub processing_seconds: f64,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StaleSession {
+    pub session_id: String,
+    pub manifest_path: String,
+    pub created_at: String,
+    pub provider: String,
+    pub model: String,
+    pub output_dir: String,
+    pub total: usize,
+    // True when progress checkpoints reached history before the app went away.
+    pub checkpointed: bool,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LastSessionSummary {
+    pub session_id: String,
+    pub created_at: i64,
+    pub total: i32,
+    pub processed: i32,
+    pub skipped: i32,
+    pub failed: i32,
+    pub duration_seconds: f64,
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryStats {
+    pub sessions: i64,
+    pub files_processed: i64,
+    pub audio_seconds: f64,
+    pub failures: i64,
+    pub average_rtf: Option<f64>,
+    pub providers: Vec<ProviderStats>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -67,6 +241,10 @@ fn history_db_path() -> Result<PathBuf, String> {
 }
 
 pub fn init_database(path: &Path) -> Result<Connection, String> {
+    init_database_with_timeout(path, HISTORY_BUSY_TIMEOUT)
+}
+
+fn init_database_with_timeout(path: &Path, busy_timeout: Duration) -> Result<Connection, String> {
     if let Some(parent) = path.parent() {
         if !parent.as_os_str().is_empty() {
             std::fs::create_dir_all(parent).map_err(|error| {
@@ -87,6 +265,26 @@ pub fn init_database(path: &Path) -> Result<Connection, String> {
         )
     })?;
 
+    connection.busy_timeout(busy_timeout).map_err(|error| {
+        format!(
+            "Failed to configure history database busy timeout: {}",
+            error
+        )
+    })?;
+
+    // Existing rollback-journal databases are converted in place on first open.
+    connection
+        .query_row("PRAGMA journal_mode = WAL", [], |row| {
+            row.get::<_, String>(0)
+        })
+        .map_err(|error| {
+            if is_busy_error(&error) {
+                HISTORY_IN_USE_ERROR.to_string()
+            } else {
+                format!("Failed to enable history database WAL mode: {}", error)
+            }
+        })?;
+
     connection
         .execute_batch(
             "
@@ -125,11 +323,187 @@ pub fn init_database(path: &Path) -> Result<Connection, String> {
             CREATE INDEX IF NOT EXISTS idx_session_files_name ON session_files(name);
             ",
         )
-        .map_err(|error| format!("Failed to initialize history database schema: {}", error))?;
+        .map_err(|error| {
+            if is_busy_error(&error) {
+                HISTORY_IN_USE_ERROR.to_string()
+            } else {
+                format!("Failed to initialize history database schema: {}", error)
+            }
+        })?;
+
+    ensure_column(
+        &connection,
+        "sessions",
+        "tags",
+        "TEXT NOT NULL DEFAULT '[]'",
+    )?;
+    ensure_column(
+        &connection,
+        "sessions",
+        "audio_seconds",
+        "REAL NOT NULL DEFAULT 0",
+    )?;
+    ensure_column(&connection, "sessions", "stop_reason", "TEXT")?;
+    ensure_column(
+        &connection,
+        "session_files",
+        "retries",
+        "INTEGER NOT NULL DEFAULT 0",
+    )?;
+    ensure_column(&connection, "session_files", "processing_seconds", "REAL")?;
+    migrate_provider_ids_once(&connection)?;
 
     Ok(connection)
 }
 
+// `user_version` records that legacy provider ids were rewritten, so later opens skip the scan.
+const PROVIDER_IDS_MIGRATED_VERSION: i64 = 1;
+
+fn migrate_provider_ids_once(connection: &Connection) -> Result<(), String> {
+    let version = connection
+        .query_row("PRAGMA user_version", [], |row| row.get::<_, i64>(0))
+        .map_err(|error| format!("Failed to read history database version: {}", error))?;
+    if version >= PROVIDER_IDS_MIGRATED_VERSION {
+        return Ok(());
+    }
+
+    let migrated = migrate_provider_ids_in(connection).and_then(|_| {
+        connection
+            .execute_batch(&format!(
+                "PRAGMA user_version = {}",
+                PROVIDER_IDS_MIGRATED_VERSION
+            ))
+            .map_err(|error| {
+                if is_busy_error(&error) {
+                    HISTORY_IN_USE_ERROR.to_string()
+                } else {
+                    format!("Failed to record history database version: {}", error)
+                }
+            })
+    });
+    match migrated {
+        // Another instance holds the write lock; the next open retries instead of failing this one.
+        Err(error) if error == HISTORY_IN_USE_ERROR => {
+            eprintln!("[history] deferred provider id migration: {}", error);
+            Ok(())
+        }
+        result => result,
+    }
+}
+
+// Rows written before provider ids were renamed keep the old id until rewritten here.
+fn migrate_provider_ids_in(connection: &Connection) -> Result<usize, String> {
+    let mut migrated = 0;
+    for legacy in LEGACY_PROVIDER_IDS {
+        // Checking first keeps every open from taking the write lock once the rows are migrated.
+        let present = connection
+            .query_row(
+                "SELECT EXISTS(SELECT 1 FROM sessions WHERE provider = ?)",
+                params![legacy],
+                |row| row.get::<_, bool>(0),
+            )
+            .map_err(|error| format!("Failed to check for legacy provider ids: {}", error))?;
+        if !present {
+            continue;
+        }
+
+        migrated += connection
+            .execute(
+                "UPDATE sessions SET provider = ? WHERE provider = ?",
+                params![normalize_provider_id(legacy), legacy],
+            )
+            .map_err(|error| {
+                if is_busy_error(&error) {
+                    HISTORY_IN_USE_ERROR.to_string()
+                } else {
+                    format!("Failed to migrate legacy provider ids: {}", error)
+                }
+            })?;
+    }
+    Ok(migrated)
+}
+
+fn migrate_provider_ids_with_path(path: Option<&Path>) -> Result<usize, String> {
+    migrate_provider_ids_in(&open_database(path)?)
+}
+
+fn has_column(connection: &Connection, table: &str, column: &str) -> Result<bool, String> {
+    let mut statement = connection
+        .prepare(&format!("PRAGMA table_info({})", table))
+        .map_err(|error| format!("Failed to inspect {} columns: {}", table, error))?;
+    let columns = statement
+        .query_map([], |row| row.get::<_, String>(1))
+        .map_err(|error| format!("Failed to inspect {} columns: {}", table, error))?
+        .collect::<Result<Vec<String>, _>>()
+        .map_err(|error| format!("Failed to decode {} columns: {}", table, error))?;
+    Ok(columns.iter().any(|existing| existing == column))
+}
+
+// Adds a column to databases created before it existed; a no-op once present.
+fn ensure_column(
+    connection: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<(), String> {
+    if has_column(connection, table, column)? {
+        return Ok(());
+    }
+
+    connection
+        .execute_batch(&format!(
+            "ALTER TABLE {} ADD COLUMN {} {}",
+            table, column, definition
+        ))
+        .map_err(|error| {
+            if is_busy_error(&error) {
+                HISTORY_IN_USE_ERROR.to_string()
+            } else {
+                format!("Failed to add {}.{} column: {}", table, column, error)
+            }
+        })
+}
+
+fn is_busy_error(error: &rusqlite::Error) -> bool {
+    matches!(
+        error.sqlite_error_code(),
+        Some(ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked)
+    )
+}
+
+fn begin_write_with_retry<'a>(
+    connection: &'a Connection,
+    context: &str,
+    attempts: u32,
+    retry_delay: Duration,
+) -> Result<Transaction<'a>, String> {
+    let mut attempt = 1;
+    loop {
+        // IMMEDIATE takes the write lock up front so later statements in the
+        // transaction cannot fail with SQLITE_BUSY halfway through.
+        match Transaction::new_unchecked(connection, TransactionBehavior::Immediate) {
+            Ok(transaction) => return Ok(transaction),
+            Err(error) if is_busy_error(&error) => {
+                if attempt >= attempts {
+                    return Err(HISTORY_IN_USE_ERROR.to_string());
+                }
+                attempt += 1;
+                std::thread::sleep(retry_delay);
+            }
+            Err(error) => return Err(format!("Failed to open {}: {}", context, error)),
+        }
+    }
+}
+
+fn begin_write<'a>(connection: &'a Connection, context: &str) -> Result<Transaction<'a>, String> {
+    begin_write_with_retry(
+        connection,
+        context,
+        HISTORY_WRITE_ATTEMPTS,
+        HISTORY_WRITE_RETRY_DELAY,
+    )
+}
+
 fn open_database(path: Option<&Path>) -> Result<Connection, String> {
     match path {
         Some(path) => init_database(path),
@@ -140,7 +514,7 @@ fn open_database(path: Option<&Path>) -> Result<Connection, String> {
     }
 }
 
-fn parse_manifest(path: &Path) -> Result<SessionManifest, String> {
+pub(crate) fn parse_manifest(path: &Path) -> Result<SessionManifest, String> {
     let payload = std::fs::read_to_string(path).map_err(|error| {
         format!(
             "Failed to read session manifest {}: {}",
@@ -170,12 +544,32 @@ fn normalize_file_name(path: &Path) -> String {
         .to_string()
 }
 
+fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    tags.into_iter()
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty() && seen.insert(tag.clone()))
+        .collect()
+}
+
+fn parse_tags(raw: &str) -> Vec<String> {
+    serde_json::from_str::<Vec<String>>(raw).unwrap_or_default()
+}
+
+fn serialize_tags(tags: &[String]) -> Result<String, String> {
+    serde_json::to_string(tags).map_err(|error| format!("Failed to serialize tags: {}", error))
+}
+
 fn to_i32(value: u64) -> i32 {
     i32::try_from(value).unwrap_or(i32::MAX)
 }
 
 fn summarize_from_files(files: &[SessionFileRecord]) -> SessionSummarySnapshot {
-    let processed = files.iter().filter(|file| file.status == "success").count() as u64;
+    // Empty transcripts still went through the worker; they are only flagged for review.
+    let processed = files
+        .iter()
+        .filter(|file| file.status == "success" || file.status == EMPTY_TRANSCRIPT_STATUS)
+        .count() as u64;
     let skipped = files.iter().filter(|file| file.status == "skipped").count() as u64;
     let failed = files.iter().filter(|file| file.status == "failed").count() as u64;
 
@@ -223,11 +617,20 @@ fn build_session_record(
                 transcript_path: outcome.and_then(|value| value.transcript_path.clone()),
                 json_path: outcome.and_then(|value| value.json_path.clone()),
                 error: outcome.and_then(|value| value.error.clone()),
+                retries: outcome.map(|value| value.retries).unwrap_or(0),
+                processing_seconds: outcome.and_then(|value| value.processing_seconds),
             }
         })
         .collect::<Vec<SessionFileRecord>>();
 
     let summary = summary.unwrap_or_else(|| summarize_from_files(&files));
+    let audio_seconds = manifest
+        .files
+        .iter()
+        .filter_map(|entry| outcomes.get(entry.path.to_string_lossy().as_ref()))
+        .filter_map(|outcome| outcome.audio_seconds)
+        .filter(|seconds| seconds.is_finite() && *seconds > 0.0)
+        .sum::<f64>();
 
     SessionRecord {
         id: session_id.to_string(),
@@ -241,24 +644,43 @@ fn build_session_record(
         skipped: to_i32(summary.skipped),
         failed: to_i32(summary.failed),
         duration_seconds: summary.duration_seconds,
+        audio_seconds,
         exit_code,
         status: status.to_string(),
+        stop_reason: None,
+        tags: Vec::new(),
         files,
     }
 }
 
-fn save_session_record(connection: &mut Connection, session: &SessionRecord) -> Result<(), String> {
-    let transaction = connection
-        .transaction()
-        .map_err(|error| format!("Failed to open history transaction: {}", error))?;
+fn save_session_record(connection: &Connection, session: &SessionRecord) -> Result<(), String> {
+    let tags = serialize_tags(&session.tags)?;
+    let transaction = begin_write(connection, "history transaction")?;
 
+    // Checkpoints and the final archive rebuild the row without tags; keep what the user set.
     transaction
         .execute(
             "
-            INSERT OR REPLACE INTO sessions (
+            INSERT INTO sessions (
                 id, created_at, provider, model, output_dir, manifest_path,
-                total, processed, skipped, failed, duration_seconds, exit_code, status
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                total, processed, skipped, failed, duration_seconds, audio_seconds, exit_code,
+                status, stop_reason, tags
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(id) DO UPDATE SET
+                created_at = excluded.created_at,
+                provider = excluded.provider,
+                model = excluded.model,
+                output_dir = excluded.output_dir,
+                manifest_path = excluded.manifest_path,
+                total = excluded.total,
+                processed = excluded.processed,
+                skipped = excluded.skipped,
+                failed = excluded.failed,
+                duration_seconds = excluded.duration_seconds,
+                audio_seconds = excluded.audio_seconds,
+                exit_code = excluded.exit_code,
+                status = excluded.status,
+                stop_reason = excluded.stop_reason
             ",
             params![
                 session.id,
@@ -272,8 +694,11 @@ fn save_session_record(connection: &mut Connection, session: &SessionRecord) ->
                 session.skipped,
                 session.failed,
                 session.duration_seconds,
+                session.audio_seconds,
                 session.exit_code,
-                session.status
+                session.status,
+                session.stop_reason,
+                tags
             ],
         )
         .map_err(|error| format!("Failed to persist session {}: {}", session.id, error))?;
@@ -295,8 +720,9 @@ fn save_session_record(connection: &mut Connection, session: &SessionRecord) ->
             .execute(
                 "
                 INSERT INTO session_files (
-                    session_id, file_id, path, name, status, transcript_path, json_path, error
-                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                    session_id, file_id, path, name, status, transcript_path, json_path, error,
+                    retries, processing_seconds
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                 ",
                 params![
                     session.id,
@@ -306,7 +732,9 @@ fn save_session_record(connection: &mut Connection, session: &SessionRecord) ->
                     file.status,
                     file.transcript_path,
                     file.json_path,
-                    file.error
+                    file.error,
+                    file.retries,
+                    file.processing_seconds
                 ],
             )
             .map_err(|error| {
@@ -329,7 +757,8 @@ fn load_session_files(
     let mut statement = connection
         .prepare(
             "
-            SELECT file_id, path, name, status, transcript_path, json_path, error
+            SELECT file_id, path, name, status, transcript_path, json_path, error, retries,
+                processing_seconds
             FROM session_files
             WHERE session_id = ?
             ORDER BY name ASC
@@ -347,6 +776,8 @@ fn load_session_files(
                 transcript_path: row.get(4)?,
                 json_path: row.get(5)?,
                 error: row.get(6)?,
+                retries: row.get(7)?,
+                processing_seconds: row.get(8)?,
             })
         })
         .map_err(|error| format!("Failed to execute session file query: {}", error))?;
@@ -359,229 +790,2514 @@ fn load_session_files(
     Ok(files)
 }
 
-fn get_sessions_with_path(path: Option<&Path>) -> Result<Vec<SessionRecord>, String> {
-    let connection = open_database(path)?;
+const SESSION_COLUMNS: &str = "
+    id,
+    created_at,
+    provider,
+    model,
+    output_dir,
+    manifest_path,
+    total,
+    processed,
+    skipped,
+    failed,
+    duration_seconds,
+    audio_seconds,
+    exit_code,
+    status,
+    tags,
+    stop_reason
+";
+
+fn session_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<SessionRecord> {
+    Ok(SessionRecord {
+        id: row.get(0)?,
+        created_at: row.get(1)?,
+        provider: row.get(2)?,
+        model: row.get(3)?,
+        output_dir: row.get(4)?,
+        manifest_path: row.get(5)?,
+        total: row.get(6)?,
+        processed: row.get(7)?,
+        skipped: row.get(8)?,
+        failed: row.get(9)?,
+        duration_seconds: row.get(10)?,
+        audio_seconds: row.get(11)?,
+        exit_code: row.get(12)?,
+        status: row.get(13)?,
+        tags: parse_tags(&row.get::<_, String>(14)?),
+        stop_reason: row.get(15)?,
+        files: Vec::new(),
+    })
+}
+
+fn query_sessions(
+    connection: &Connection,
+    tag: Option<&str>,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<SessionRecord>, String> {
     let mut statement = connection
-        .prepare(
+        .prepare(&format!(
             "
-            SELECT
-                id,
-                created_at,
-                provider,
-                model,
-                output_dir,
-                manifest_path,
-                total,
-                processed,
-                skipped,
-                failed,
-                duration_seconds,
-                exit_code,
-                status
+            SELECT {}
             FROM sessions
+            WHERE {}
             ORDER BY created_at DESC
+            LIMIT ?2 OFFSET ?3
             ",
-        )
+            SESSION_COLUMNS, SESSION_TAG_FILTER
+        ))
         .map_err(|error| format!("Failed to prepare history query: {}", error))?;
 
     let rows = statement
-        .query_map([], |row| {
-            Ok((
-                row.get::<_, String>(0)?,
-                row.get::<_, i64>(1)?,
-                row.get::<_, String>(2)?,
-                row.get::<_, String>(3)?,
-                row.get::<_, String>(4)?,
-                row.get::<_, String>(5)?,
-                row.get::<_, i32>(6)?,
-                row.get::<_, i32>(7)?,
-                row.get::<_, i32>(8)?,
-                row.get::<_, i32>(9)?,
-                row.get::<_, f64>(10)?,
-                row.get::<_, i32>(11)?,
-                row.get::<_, String>(12)?,
-            ))
-        })
+        .query_map(params![tag, limit, offset], session_from_row)
         .map_err(|error| format!("Failed to execute history query: {}", error))?;
 
     let mut sessions = Vec::new();
     for row in rows {
-        let (
-            id,
-            created_at,
-            provider,
-            model,
-            output_dir,
-            manifest_path,
-            total,
-            processed,
-            skipped,
-            failed,
-            duration_seconds,
-            exit_code,
-            status,
-        ) = row.map_err(|error| format!("Failed to decode session row: {}", error))?;
-
-        let files = load_session_files(&connection, &id)?;
-        sessions.push(SessionRecord {
-            id,
-            created_at,
-            provider,
-            model,
-            output_dir,
-            manifest_path,
-            total,
-            processed,
-            skipped,
-            failed,
-            duration_seconds,
-            exit_code,
-            status,
-            files,
-        });
+        let mut session =
+            row.map_err(|error| format!("Failed to decode session row: {}", error))?;
+        session.files = load_session_files(connection, &session.id)?;
+        sessions.push(session);
     }
 
     Ok(sessions)
 }
 
-fn delete_session_with_path(path: Option<&Path>, session_id: &str) -> Result<(), String> {
-    let mut connection = open_database(path)?;
-    let transaction = connection
-        .transaction()
-        .map_err(|error| format!("Failed to open delete transaction: {}", error))?;
+fn load_session_record(connection: &Connection, session_id: &str) -> Result<SessionRecord, String> {
+    let mut session = connection
+        .query_row(
+            &format!("SELECT {} FROM sessions WHERE id = ?", SESSION_COLUMNS),
+            params![session_id],
+            session_from_row,
+        )
+        .optional()
+        .map_err(|error| format!("Failed to look up session {}: {}", session_id, error))?
+        .ok_or_else(|| format!("Session not found: {}", session_id))?;
+    session.files = load_session_files(connection, session_id)?;
+    Ok(session)
+}
+
+fn load_session_with_path(path: Option<&Path>, session_id: &str) -> Result<SessionRecord, String> {
+    let connection = open_database(path)?;
+    load_session_record(&connection, session_id)
+}
+
+pub fn load_session(session_id: &str) -> Result<SessionRecord, String> {
+    load_session_with_path(None, session_id)
+}
+
+fn update_session_file_with_path(
+    path: Option<&Path>,
+    session_id: &str,
+    file_id: &str,
+    outcome: &FileOutcome,
+) -> Result<SessionRecord, String> {
+    let connection = open_database(path)?;
+    let mut session = load_session_record(&connection, session_id)?;
+    let file = session
+        .files
+        .iter_mut()
+        .find(|file| file.id == file_id)
+        .ok_or_else(|| format!("File {} not found in session {}", file_id, session_id))?;
+
+    // A failed redo leaves the earlier transcript on disk, so keep pointing at it.
+    file.status = outcome.status.clone();
+    if outcome.transcript_path.is_some() {
+        file.transcript_path = outcome.transcript_path.clone();
+    }
+    if outcome.json_path.is_some() {
+        file.json_path = outcome.json_path.clone();
+    }
+    file.error = outcome.error.clone();
+    file.retries = outcome.retries;
+    if outcome.processing_seconds.is_some() {
+        file.processing_seconds = outcome.processing_seconds;
+    }
+    let file = file.clone();
+
+    let summary = summarize_from_files(&session.files);
+    session.processed = to_i32(summary.processed);
+    session.skipped = to_i32(summary.skipped);
+    session.failed = to_i32(summary.failed);
+
+    let transaction = begin_write(&connection, "session file update")?;
     transaction
         .execute(
-            "DELETE FROM session_files WHERE session_id = ?",
-            params![session_id],
+            "
+            UPDATE session_files
+            SET status = ?, transcript_path = ?, json_path = ?, error = ?, retries = ?,
+                processing_seconds = ?
+            WHERE session_id = ? AND file_id = ?
+            ",
+            params![
+                file.status,
+                file.transcript_path,
+                file.json_path,
+                file.error,
+                file.retries,
+                file.processing_seconds,
+                session_id,
+                file_id
+            ],
         )
-        .map_err(|error| format!("Failed to delete session file rows: {}", error))?;
+        .map_err(|error| {
+            format!(
+                "Failed to update file {} for session {}: {}",
+                file_id, session_id, error
+            )
+        })?;
     transaction
-        .execute("DELETE FROM sessions WHERE id = ?", params![session_id])
-        .map_err(|error| format!("Failed to delete session row: {}", error))?;
+        .execute(
+            "UPDATE sessions SET processed = ?, skipped = ?, failed = ? WHERE id = ?",
+            params![
+                session.processed,
+                session.skipped,
+                session.failed,
+                session_id
+            ],
+        )
+        .map_err(|error| format!("Failed to update session {}: {}", session_id, error))?;
     transaction
         .commit()
-        .map_err(|error| format!("Failed to commit delete transaction: {}", error))
+        .map_err(|error| format!("Failed to commit session file update: {}", error))?;
+
+    Ok(session)
 }
 
-fn archive_session_with_path(
-    history_path: Option<&Path>,
-    manifest_path: &Path,
+pub fn update_session_file(
     session_id: &str,
-    summary: Option<SessionSummarySnapshot>,
-    exit_code: i32,
-    status: &str,
-    outcomes: &HashMap<String, FileOutcome>,
-) -> Result<(), String> {
-    let manifest = parse_manifest(manifest_path)?;
-    let record = build_session_record(
-        manifest_path,
-        manifest,
-        session_id,
-        summary,
-        exit_code,
-        status,
-        outcomes,
-    );
+    file_id: &str,
+    outcome: &FileOutcome,
+) -> Result<SessionRecord, String> {
+    update_session_file_with_path(None, session_id, file_id, outcome)
+}
 
-    let mut connection = open_database(history_path)?;
-    save_session_record(&mut connection, &record)
+// Bracketed spans such as [BLANK_AUDIO], (silence), [Music] or <|nospeech|> are model filler, not speech.
+fn is_empty_transcript(text: &str) -> bool {
+    // Open groups with their closer and whether words appeared inside; only closed groups are filler.
+    let mut open: Vec<(char, bool)> = Vec::new();
+    for ch in text.chars() {
+        match ch {
+            '[' => open.push((']', false)),
+            '(' => open.push((')', false)),
+            '<' => open.push(('>', false)),
+            ']' | ')' | '>' => {
+                if let Some(index) = open.iter().rposition(|(closer, _)| *closer == ch) {
+                    open.truncate(index);
+                }
+            }
+            ch if ch.is_alphanumeric() => match open.last_mut() {
+                Some((_, spoken)) => *spoken = true,
+                None => return false,
+            },
+            _ => {}
+        }
+    }
+    !open.iter().any(|(_, spoken)| *spoken)
 }
 
-pub fn archive_session_from_manifest(
-    manifest_path: &Path,
+fn flag_empty_transcripts_with_path(
+    path: Option<&Path>,
     session_id: &str,
-    summary: Option<SessionSummarySnapshot>,
-    exit_code: i32,
-    status: &str,
-    outcomes: &HashMap<String, FileOutcome>,
-) -> Result<(), String> {
-    archive_session_with_path(
-        None,
-        manifest_path,
-        session_id,
-        summary,
-        exit_code,
-        status,
-        outcomes,
-    )
-}
+    mark: bool,
+) -> Result<EmptyTranscriptReport, String> {
+    let connection = open_database(path)?;
+    let session = load_session_record(&connection, session_id)?;
 
-#[tauri::command]
-pub fn get_session_history() -> Result<Vec<SessionRecord>, String> {
-    get_sessions_with_path(None)
-}
+    let mut checked = 0;
+    let mut empty = Vec::new();
+    for file in &session.files {
+        if file.status != "success" && file.status != EMPTY_TRANSCRIPT_STATUS {
+            continue;
+        }
+        let Some(transcript_path) = file.transcript_path.as_ref().or(file.json_path.as_ref())
+        else {
+            continue;
+        };
+        let text = match crate::commands::convert::transcript_plain_text(Path::new(transcript_path))
+        {
+            Ok(text) => text,
+            Err(error) => {
+                eprintln!("[history] skipping unreadable transcript: {}", error);
+                continue;
+            }
+        };
 
-#[tauri::command]
-pub fn delete_session(session_id: String) -> Result<(), String> {
-    let session_id = session_id.trim();
-    if session_id.is_empty() {
-        return Err("Session id is empty".to_string());
+        checked += 1;
+        if is_empty_transcript(&text) {
+            empty.push(EmptyTranscript {
+                file_id: file.id.clone(),
+                path: file.path.clone(),
+                transcript_path: transcript_path.clone(),
+            });
+        }
     }
 
-    delete_session_with_path(None, session_id)
-}
+    if mark && !empty.is_empty() {
+        let transaction = begin_write(&connection, "empty transcript flags")?;
+        for file in &empty {
+            transaction
+                .execute(
+                    "UPDATE session_files SET status = ? WHERE session_id = ? AND file_id = ?",
+                    params![EMPTY_TRANSCRIPT_STATUS, session_id, file.file_id],
+                )
+                .map_err(|error| {
+                    format!(
+                        "Failed to flag empty transcript {}: {}",
+                        file.file_id, error
+                    )
+                })?;
+        }
+        transaction
+            .commit()
+            .map_err(|error| format!("Failed to commit empty transcript flags: {}", error))?;
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::providers::manifest::{FileEntry, SessionManifest, TranscriptionSettings};
-    use uuid::Uuid;
+    Ok(EmptyTranscriptReport {
+        session_id: session_id.to_string(),
+        checked,
+        empty,
+        marked: mark,
+    })
+}
+
+fn get_sessions_with_path(path: Option<&Path>) -> Result<Vec<SessionRecord>, String> {
+    let connection = open_database(path)?;
+    // A negative LIMIT means no limit in SQLite.
+    query_sessions(&connection, None, -1, 0)
+}
+
+fn get_session_history_page_with_path(
+    path: Option<&Path>,
+    offset: i64,
+    limit: i64,
+    tag: Option<&str>,
+) -> Result<SessionHistoryPage, String> {
+    let connection = open_database(path)?;
+    let total = connection
+        .query_row(
+            &format!("SELECT COUNT(*) FROM sessions WHERE {}", SESSION_TAG_FILTER),
+            params![tag],
+            |row| row.get::<_, i64>(0),
+        )
+        .map_err(|error| format!("Failed to count history sessions: {}", error))?;
+    let sessions = query_sessions(&connection, tag, limit, offset)?;
+
+    Ok(SessionHistoryPage {
+        sessions,
+        total,
+        offset,
+        limit,
+    })
+}
+
+// RTF is processing time over audio time; sessions without recorded audio are excluded.
+const HISTORY_STATS_COLUMNS: &str = "
+    COUNT(*),
+    COALESCE(SUM(processed), 0),
+    COALESCE(SUM(audio_seconds), 0.0),
+    COALESCE(SUM(failed), 0),
+    AVG(CASE WHEN audio_seconds > 0 THEN duration_seconds / audio_seconds END)
+";
+
+// Sessions without file rows are skipped: there is nothing to recompute their counts from.
+fn verify_history_integrity_with_path(
+    path: Option<&Path>,
+    repair: bool,
+) -> Result<HistoryIntegrityReport, String> {
+    let connection = open_database(path)?;
+    let mut statement = connection
+        .prepare(
+            "SELECT s.id, s.total, s.processed, s.skipped, s.failed,
+                COUNT(*),
+                COALESCE(SUM(f.status IN ('success', ?1)), 0),
+                COALESCE(SUM(f.status = 'skipped'), 0),
+                COALESCE(SUM(f.status = 'failed'), 0)
+             FROM sessions s JOIN session_files f ON f.session_id = s.id
+             GROUP BY s.id ORDER BY s.created_at DESC, s.id ASC",
+        )
+        .map_err(|error| format!("Failed to prepare history integrity query: {}", error))?;
+    let rows = statement
+        .query_map(params![EMPTY_TRANSCRIPT_STATUS], |row| {
+            Ok(SessionCountMismatch {
+                session_id: row.get(0)?,
+                stored: SessionCounts {
+                    total: row.get(1)?,
+                    processed: row.get(2)?,
+                    skipped: row.get(3)?,
+                    failed: row.get(4)?,
+                },
+                recomputed: SessionCounts {
+                    total: row.get(5)?,
+                    processed: row.get(6)?,
+                    skipped: row.get(7)?,
+                    failed: row.get(8)?,
+                },
+            })
+        })
+        .map_err(|error| format!("Failed to execute history integrity query: {}", error))?;
+
+    let mut checked = 0;
+    let mut mismatches = Vec::new();
+    for row in rows {
+        let row =
+            row.map_err(|error| format!("Failed to decode history integrity row: {}", error))?;
+        checked += 1;
+        if row.stored != row.recomputed {
+            mismatches.push(row);
+        }
+    }
+    drop(statement);
+
+    if repair && !mismatches.is_empty() {
+        let transaction = begin_write(&connection, "history count repair")?;
+        for mismatch in &mismatches {
+            let counts = mismatch.recomputed;
+            transaction
+                .execute(
+                    "UPDATE sessions SET total = ?, processed = ?, skipped = ?, failed = ? WHERE id = ?",
+                    params![
+                        counts.total,
+                        counts.processed,
+                        counts.skipped,
+                        counts.failed,
+                        mismatch.session_id
+                    ],
+                )
+                .map_err(|error| {
+                    format!(
+                        "Failed to repair counts for session {}: {}",
+                        mismatch.session_id, error
+                    )
+                })?;
+        }
+        transaction
+            .commit()
+            .map_err(|error| format!("Failed to commit history count repair: {}", error))?;
+    }
+
+    Ok(HistoryIntegrityReport {
+        checked,
+        repaired: repair && !mismatches.is_empty(),
+        mismatches,
+    })
+}
+
+// A single session's RTF is dominated by model load time; averages settle after a few runs.
+const MIN_RTF_SAMPLES: i64 = 3;
+
+fn provider_performance_with_path(path: Option<&Path>) -> Result<Vec<ProviderPerformance>, String> {
+    let connection = open_database(path)?;
+    let mut statement = connection
+        .prepare(
+            "SELECT provider,
+                COUNT(CASE WHEN audio_seconds > 0 AND duration_seconds > 0 THEN 1 END),
+                AVG(CASE WHEN audio_seconds > 0 AND duration_seconds > 0
+                    THEN duration_seconds / audio_seconds END)
+             FROM sessions GROUP BY provider",
+        )
+        .map_err(|error| format!("Failed to prepare provider performance query: {}", error))?;
+    let rows = statement
+        .query_map([], |row| {
+            let samples: i64 = row.get(1)?;
+            let average_rtf: Option<f64> = row.get(2)?;
+            let insufficient_data = samples < MIN_RTF_SAMPLES;
+            Ok(ProviderPerformance {
+                provider: row.get(0)?,
+                samples,
+                average_rtf: average_rtf.filter(|_| !insufficient_data),
+                insufficient_data,
+            })
+        })
+        .map_err(|error| format!("Failed to execute provider performance query: {}", error))?;
+
+    let mut performance = rows
+        .collect::<Result<Vec<ProviderPerformance>, _>>()
+        .map_err(|error| format!("Failed to decode provider performance row: {}", error))?;
+    // Fastest first; providers without a figure go last, by name.
+    performance.sort_by(|left, right| match (left.average_rtf, right.average_rtf) {
+        (Some(left_rtf), Some(right_rtf)) => left_rtf.total_cmp(&right_rtf),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => left.provider.cmp(&right.provider),
+    });
+    Ok(performance)
+}
+
+fn history_stats_with_path(path: Option<&Path>) -> Result<HistoryStats, String> {
+    let connection = open_database(path)?;
+
+    let mut stats = connection
+        .query_row(
+            &format!("SELECT {} FROM sessions", HISTORY_STATS_COLUMNS),
+            [],
+            |row| {
+                Ok(HistoryStats {
+                    sessions: row.get(0)?,
+                    files_processed: row.get(1)?,
+                    audio_seconds: row.get(2)?,
+                    failures: row.get(3)?,
+                    average_rtf: row.get(4)?,
+                    providers: Vec::new(),
+                })
+            },
+        )
+        .map_err(|error| format!("Failed to aggregate history stats: {}", error))?;
+
+    let mut statement = connection
+        .prepare(&format!(
+            "SELECT provider, {} FROM sessions GROUP BY provider ORDER BY provider ASC",
+            HISTORY_STATS_COLUMNS
+        ))
+        .map_err(|error| format!("Failed to prepare provider stats query: {}", error))?;
+    let rows = statement
+        .query_map([], |row| {
+            Ok(ProviderStats {
+                provider: row.get(0)?,
+                sessions: row.get(1)?,
+                files_processed: row.get(2)?,
+                audio_seconds: row.get(3)?,
+                failures: row.get(4)?,
+                average_rtf: row.get(5)?,
+            })
+        })
+        .map_err(|error| format!("Failed to execute provider stats query: {}", error))?;
+    for row in rows {
+        stats
+            .providers
+            .push(row.map_err(|error| format!("Failed to decode provider stats row: {}", error))?);
+    }
+
+    Ok(stats)
+}
+
+fn session_output_dir_with_path(path: Option<&Path>, session_id: &str) -> Result<PathBuf, String> {
+    let connection = open_database(path)?;
+    let output_dir = connection
+        .query_row(
+            "SELECT output_dir FROM sessions WHERE id = ?",
+            params![session_id],
+            |row| row.get::<_, String>(0),
+        )
+        .optional()
+        .map_err(|error| format!("Failed to look up session {}: {}", session_id, error))?
+        .map(PathBuf::from)
+        .ok_or_else(|| format!("Session not found: {}", session_id))?;
+
+    if !output_dir.is_dir() {
+        return Err(format!(
+            "Output directory for session {} no longer exists: {}",
+            session_id,
+            output_dir.display()
+        ));
+    }
+
+    Ok(output_dir)
+}
+
+pub fn session_output_dir(session_id: &str) -> Result<PathBuf, String> {
+    session_output_dir_with_path(None, session_id)
+}
+
+fn comparable_path(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+// History stores whatever path the session was started with, so match both the typed and resolved forms.
+fn sessions_for_output_dir_with_path(
+    path: Option<&Path>,
+    output_dir: &Path,
+) -> Result<Vec<SessionRecord>, String> {
+    let typed = output_dir.components().collect::<PathBuf>();
+    let resolved = comparable_path(output_dir);
+    let connection = open_database(path)?;
+    let mut statement = connection
+        .prepare(&format!(
+            "
+            SELECT {}
+            FROM sessions
+            WHERE output_dir IN (?1, ?2)
+            ORDER BY created_at DESC
+            ",
+            SESSION_COLUMNS
+        ))
+        .map_err(|error| format!("Failed to prepare output directory query: {}", error))?;
+    let rows = statement
+        .query_map(
+            params![typed.to_string_lossy(), resolved.to_string_lossy()],
+            session_from_row,
+        )
+        .map_err(|error| format!("Failed to execute output directory query: {}", error))?;
+
+    let mut sessions = Vec::new();
+    for row in rows {
+        let mut session =
+            row.map_err(|error| format!("Failed to decode session row: {}", error))?;
+        session.files = load_session_files(&connection, &session.id)?;
+        sessions.push(session);
+    }
+
+    Ok(sessions)
+}
+
+fn split_previously_transcribed_with_path(
+    path: Option<&Path>,
+    items: Vec<QueueItem>,
+) -> Result<(Vec<QueueItem>, Vec<QueueItem>), String> {
+    let connection = open_database(path)?;
+    let mut statement = connection
+        .prepare("SELECT DISTINCT path FROM session_files WHERE status IN ('success', ?)")
+        .map_err(|error| format!("Failed to prepare transcribed files query: {}", error))?;
+    let transcribed = statement
+        .query_map([EMPTY_TRANSCRIPT_STATUS], |row| row.get::<_, String>(0))
+        .map_err(|error| format!("Failed to execute transcribed files query: {}", error))?
+        .map(|row| row.map(|value| comparable_path(Path::new(&value))))
+        .collect::<Result<HashSet<PathBuf>, _>>()
+        .map_err(|error| format!("Failed to decode transcribed file row: {}", error))?;
+
+    Ok(items
+        .into_iter()
+        .partition(|item| !transcribed.contains(&comparable_path(&item.path))))
+}
+
+pub fn split_previously_transcribed(
+    items: Vec<QueueItem>,
+) -> Result<(Vec<QueueItem>, Vec<QueueItem>), String> {
+    split_previously_transcribed_with_path(None, items)
+}
+
+fn set_session_tags_with_path(
+    path: Option<&Path>,
+    session_id: &str,
+    tags: Vec<String>,
+) -> Result<Vec<String>, String> {
+    let tags = normalize_tags(tags);
+    let serialized = serialize_tags(&tags)?;
+
+    let connection = open_database(path)?;
+    let transaction = begin_write(&connection, "tags transaction")?;
+    let updated = transaction
+        .execute(
+            "UPDATE sessions SET tags = ? WHERE id = ?",
+            params![serialized, session_id],
+        )
+        .map_err(|error| {
+            format!(
+                "Failed to update tags for session {}: {}",
+                session_id, error
+            )
+        })?;
+    if updated == 0 {
+        return Err(format!("Session not found: {}", session_id));
+    }
+    transaction
+        .commit()
+        .map_err(|error| format!("Failed to commit tags transaction: {}", error))?;
+
+    Ok(tags)
+}
+
+fn delete_session_with_path(path: Option<&Path>, session_id: &str) -> Result<(), String> {
+    let connection = open_database(path)?;
+    let transaction = begin_write(&connection, "delete transaction")?;
+    transaction
+        .execute(
+            "DELETE FROM session_files WHERE session_id = ?",
+            params![session_id],
+        )
+        .map_err(|error| format!("Failed to delete session file rows: {}", error))?;
+    transaction
+        .execute("DELETE FROM sessions WHERE id = ?", params![session_id])
+        .map_err(|error| format!("Failed to delete session row: {}", error))?;
+    transaction
+        .commit()
+        .map_err(|error| format!("Failed to commit delete transaction: {}", error))
+}
+
+fn delete_sessions_with_path(
+    path: Option<&Path>,
+    filter: &SessionDeleteFilter,
+) -> Result<usize, String> {
+    let criterion = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+    let provider = criterion(&filter.provider);
+    let status = criterion(&filter.status);
+    if provider.is_none() && status.is_none() && filter.created_before.is_none() {
+        return Err(
+            "Refusing to delete sessions without a provider, status or created-before filter"
+                .to_string(),
+        );
+    }
+
+    const MATCHES_FILTER: &str = "(?1 IS NULL OR provider = ?1)
+        AND (?2 IS NULL OR status = ?2)
+        AND (?3 IS NULL OR created_at < ?3)";
+    let connection = open_database(path)?;
+    let transaction = begin_write(&connection, "delete transaction")?;
+    transaction
+        .execute(
+            &format!(
+                "DELETE FROM session_files WHERE session_id IN (SELECT id FROM sessions WHERE {})",
+                MATCHES_FILTER
+            ),
+            params![provider, status, filter.created_before],
+        )
+        .map_err(|error| format!("Failed to delete session file rows: {}", error))?;
+    let deleted = transaction
+        .execute(
+            &format!("DELETE FROM sessions WHERE {}", MATCHES_FILTER),
+            params![provider, status, filter.created_before],
+        )
+        .map_err(|error| format!("Failed to delete session rows: {}", error))?;
+    transaction
+        .commit()
+        .map_err(|error| format!("Failed to commit delete transaction: {}", error))?;
+    Ok(deleted)
+}
+
+pub(crate) fn archive_session_with_path(
+    history_path: Option<&Path>,
+    manifest_path: &Path,
+    session_id: &str,
+    summary: Option<SessionSummarySnapshot>,
+    exit_code: i32,
+    status: &str,
+    outcomes: &HashMap<String, FileOutcome>,
+) -> Result<SessionRecord, String> {
+    let manifest = parse_manifest(manifest_path)?;
+    let record = build_session_record(
+        manifest_path,
+        manifest,
+        session_id,
+        summary,
+        exit_code,
+        status,
+        outcomes,
+    );
+
+    let connection = open_database(history_path)?;
+    save_session_record(&connection, &record)?;
+    Ok(record)
+}
+
+fn archive_cancelled_session_with_path(
+    history_path: Option<&Path>,
+    manifest_path: &Path,
+    session_id: &str,
+    exit_code: i32,
+    stop_reason: &str,
+    outcomes: &HashMap<String, FileOutcome>,
+) -> Result<SessionRecord, String> {
+    // Files that finished before the stop keep their outcome; only the rest become cancelled.
+    let manifest = parse_manifest(manifest_path)?;
+    let mut record = build_session_record(
+        manifest_path,
+        manifest,
+        session_id,
+        None,
+        exit_code,
+        "cancelled",
+        outcomes,
+    );
+    record.stop_reason = Some(stop_reason.to_string());
+
+    let connection = open_database(history_path)?;
+    save_session_record(&connection, &record)?;
+    Ok(record)
+}
+
+pub fn archive_cancelled_session(
+    manifest_path: &Path,
+    session_id: &str,
+    exit_code: i32,
+    stop_reason: &str,
+    outcomes: &HashMap<String, FileOutcome>,
+) -> Result<SessionRecord, String> {
+    archive_cancelled_session_with_path(
+        None,
+        manifest_path,
+        session_id,
+        exit_code,
+        stop_reason,
+        outcomes,
+    )
+}
+
+pub fn archive_session_from_manifest(
+    manifest_path: &Path,
+    session_id: &str,
+    summary: Option<SessionSummarySnapshot>,
+    exit_code: i32,
+    status: &str,
+    outcomes: &HashMap<String, FileOutcome>,
+) -> Result<SessionRecord, String> {
+    archive_session_with_path(
+        None,
+        manifest_path,
+        session_id,
+        summary,
+        exit_code,
+        status,
+        outcomes,
+    )
+}
+
+fn render_session_index(session: &SessionRecord) -> String {
+    let mut lines = vec![
+        format!("Session: {}", session.id),
+        format!("Provider: {} ({})", session.provider, session.model),
+        format!("Status: {}", session.status),
+        format!(
+            "Files: {} total, {} processed, {} skipped, {} failed",
+            session.total, session.processed, session.skipped, session.failed
+        ),
+        String::new(),
+        "status\tsource\ttranscript\tjson".to_string(),
+    ];
+
+    for file in &session.files {
+        lines.push(format!(
+            "{}\t{}\t{}\t{}",
+            file.status,
+            file.path,
+            file.transcript_path.as_deref().unwrap_or("-"),
+            file.json_path.as_deref().unwrap_or("-")
+        ));
+    }
+
+    let mut rendered = lines.join("\n");
+    rendered.push('\n');
+    rendered
+}
+
+pub fn write_session_index(
+    output_dir: &Path,
+    session: &SessionRecord,
+    append: bool,
+) -> Result<PathBuf, String> {
+    let index_path = output_dir.join(SESSION_INDEX_FILE_NAME);
+    let mut content = render_session_index(session);
+    if append {
+        let existing = std::fs::read_to_string(&index_path).unwrap_or_default();
+        if !existing.is_empty() {
+            content = format!("{}\n{}", existing, content);
+        }
+    }
+    std::fs::write(&index_path, content).map_err(|error| {
+        format!(
+            "Failed to write session index {}: {}",
+            index_path.display(),
+            error
+        )
+    })?;
+    Ok(index_path)
+}
+
+fn relocate_transcript_paths_with_path(
+    path: Option<&Path>,
+    moves: &[(String, String)],
+) -> Result<usize, String> {
+    let connection = open_database(path)?;
+    let transaction = begin_write(&connection, "relocate transaction")?;
+
+    let mut updated = 0;
+    for (from, to) in moves {
+        for column in ["transcript_path", "json_path"] {
+            updated += transaction
+                .execute(
+                    &format!(
+                        "UPDATE session_files SET {column} = ? WHERE {column} = ?",
+                        column = column
+                    ),
+                    params![to, from],
+                )
+                .map_err(|error| {
+                    format!("Failed to update moved transcript path {}: {}", from, error)
+                })?;
+        }
+    }
+
+    transaction
+        .commit()
+        .map_err(|error| format!("Failed to commit relocate transaction: {}", error))?;
+    Ok(updated)
+}
+
+pub fn relocate_transcript_paths(moves: &[(String, String)]) -> Result<usize, String> {
+    relocate_transcript_paths_with_path(None, moves)
+}
+
+fn slowest_files_in(connection: &Connection, limit: i64) -> Result<Vec<SlowFile>, String> {
+    let mut statement = connection
+        .prepare(
+            "SELECT session_id, file_id, name, path, processing_seconds
+             FROM session_files
+             WHERE processing_seconds IS NOT NULL
+             ORDER BY processing_seconds DESC, session_id ASC, name ASC
+             LIMIT ?",
+        )
+        .map_err(|error| format!("Failed to prepare slowest files query: {}", error))?;
+    let rows = statement
+        .query_map(params![limit], |row| {
+            Ok(SlowFile {
+                session_id: row.get(0)?,
+                file_id: row.get(1)?,
+                name: row.get(2)?,
+                path: row.get(3)?,
+                processing_seconds: row.get(4)?,
+            })
+        })
+        .map_err(|error| format!("Failed to execute slowest files query: {}", error))?;
+    rows.collect::<Result<Vec<SlowFile>, _>>()
+        .map_err(|error| format!("Failed to decode slowest file row: {}", error))
+}
+
+fn slowest_files_with_path(path: Option<&Path>, limit: i64) -> Result<Vec<SlowFile>, String> {
+    slowest_files_in(&open_database(path)?, limit)
+}
+
+// Checkpoint rows of a session still running have no final numbers yet, so they are skipped.
+fn last_session_summary_with_path(
+    path: Option<&Path>,
+) -> Result<Option<LastSessionSummary>, String> {
+    let connection = open_database(path)?;
+    connection
+        .query_row(
+            "SELECT id, created_at, total, processed, skipped, failed, duration_seconds, status
+             FROM sessions
+             WHERE status != 'running'
+             ORDER BY created_at DESC, id DESC
+             LIMIT 1",
+            [],
+            |row| {
+                Ok(LastSessionSummary {
+                    session_id: row.get(0)?,
+                    created_at: row.get(1)?,
+                    total: row.get(2)?,
+                    processed: row.get(3)?,
+                    skipped: row.get(4)?,
+                    failed: row.get(5)?,
+                    duration_seconds: row.get(6)?,
+                    status: row.get(7)?,
+                })
+            },
+        )
+        .optional()
+        .map_err(|error| format!("Failed to load last session summary: {}", error))
+}
+
+// Folders deleted or unmounted since their last session are dropped rather than offered again.
+fn recent_output_dirs_with_path(path: Option<&Path>, limit: usize) -> Result<Vec<String>, String> {
+    let connection = open_database(path)?;
+    let mut statement = connection
+        .prepare(
+            "SELECT output_dir, MAX(created_at) AS last_used
+             FROM sessions
+             GROUP BY output_dir
+             ORDER BY last_used DESC, output_dir ASC",
+        )
+        .map_err(|error| format!("Failed to prepare recent output dirs query: {}", error))?;
+    let rows = statement
+        .query_map([], |row| row.get::<_, String>(0))
+        .map_err(|error| format!("Failed to execute recent output dirs query: {}", error))?;
+
+    let mut recent = Vec::new();
+    for output_dir in rows {
+        let output_dir =
+            output_dir.map_err(|error| format!("Failed to decode output dir row: {}", error))?;
+        if Path::new(&output_dir).is_dir() {
+            recent.push(output_dir);
+        }
+        if recent.len() == limit {
+            break;
+        }
+    }
+    Ok(recent)
+}
+
+// A manifest whose session never reached a final history row was left behind by a crash or force quit.
+fn find_stale_sessions_in(
+    history_path: Option<&Path>,
+    sessions_dir: &Path,
+    active_session_id: Option<&str>,
+) -> Result<Vec<StaleSession>, String> {
+    let entries = match std::fs::read_dir(sessions_dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => {
+            return Err(format!(
+                "Failed to read sessions directory {}: {}",
+                sessions_dir.display(),
+                error
+            ))
+        }
+    };
+
+    let connection = open_database(history_path)?;
+    let mut stale = Vec::new();
+    for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+        let Some(session_id) = path
+            .file_stem()
+            .filter(|_| {
+                path.extension()
+                    .is_some_and(|extension| extension == "json")
+            })
+            .and_then(|stem| stem.to_str())
+            .filter(|id| validate_session_id(id).is_ok())
+        else {
+            continue;
+        };
+        if active_session_id == Some(session_id) {
+            continue;
+        }
+
+        let status = connection
+            .query_row(
+                "SELECT status FROM sessions WHERE id = ?",
+                params![session_id],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|error| format!("Failed to look up session {}: {}", session_id, error))?;
+        let checkpointed = match status.as_deref() {
+            None => false,
+            Some("running") => true,
+            Some(_) => continue,
+        };
+
+        let manifest = match parse_manifest(&path) {
+            Ok(manifest) => manifest,
+            Err(error) => {
+                eprintln!("[history] skipping stale session check: {}", error);
+                continue;
+            }
+        };
+        stale.push(StaleSession {
+            session_id: session_id.to_string(),
+            manifest_path: path.to_string_lossy().to_string(),
+            created_at: manifest.created_at,
+            provider: manifest.provider,
+            model: manifest.model,
+            output_dir: manifest.output_dir.to_string_lossy().to_string(),
+            total: manifest.files.len(),
+            checkpointed,
+        });
+    }
+
+    stale.sort_by(|left, right| {
+        left.created_at
+            .cmp(&right.created_at)
+            .then_with(|| left.session_id.cmp(&right.session_id))
+    });
+    Ok(stale)
+}
+
+fn resolve_stale_session_in(
+    history_path: Option<&Path>,
+    sessions_dir: &Path,
+    session_id: &str,
+    discard: bool,
+    active_session_id: Option<&str>,
+) -> Result<(), String> {
+    validate_session_id(session_id)?;
+    if active_session_id == Some(session_id) {
+        return Err(format!("Session is still running: {}", session_id));
+    }
+    let manifest_path = sessions_dir.join(format!("{}.json", session_id));
+    if !manifest_path.is_file() {
+        return Err(format!("Session manifest not found: {}", session_id));
+    }
+
+    // Same rule as find_stale_sessions_in: a finished row means the session is not stale.
+    let connection = open_database(history_path)?;
+    let status = connection
+        .query_row(
+            "SELECT status FROM sessions WHERE id = ?",
+            params![session_id],
+            |row| row.get::<_, String>(0),
+        )
+        .optional()
+        .map_err(|error| format!("Failed to look up session {}: {}", session_id, error))?;
+    if status.as_deref().is_some_and(|status| status != "running") {
+        return Err(format!("Session is not stale: {}", session_id));
+    }
+
+    if discard {
+        delete_session_with_path(history_path, session_id)?;
+        return cleanup_manifest(&manifest_path);
+    }
+
+    // Checkpointed outcomes are kept; only the never-finished status is closed out.
+    let updated = connection
+        .execute(
+            "UPDATE sessions SET status = 'cancelled', stop_reason = ?1
+             WHERE id = ?2 AND status = 'running'",
+            params![INTERRUPTED_STOP_REASON, session_id],
+        )
+        .map_err(|error| format!("Failed to reindex session {}: {}", session_id, error))?;
+    if updated == 0 {
+        archive_cancelled_session_with_path(
+            history_path,
+            &manifest_path,
+            session_id,
+            -1,
+            INTERRUPTED_STOP_REASON,
+            &HashMap::new(),
+        )?;
+    }
+    Ok(())
+}
+
+fn checkpoint_history_with_path(path: Option<&Path>) -> Result<HistoryCheckpoint, String> {
+    let connection = open_database(path)?;
+    connection
+        .query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |row| {
+            Ok(HistoryCheckpoint {
+                busy: row.get::<_, i64>(0)? != 0,
+                log_frames: row.get(1)?,
+                checkpointed_frames: row.get(2)?,
+            })
+        })
+        .map_err(|error| format!("Failed to checkpoint history database: {}", error))
+}
+
+fn render_transcript_name(
+    template: &str,
+    file: &SessionFileRecord,
+    index: usize,
+    date: &str,
+) -> Result<String, String> {
+    let name = Path::new(&file.name)
+        .file_stem()
+        .and_then(|value| value.to_str())
+        .unwrap_or(&file.name);
+
+    let mut rendered = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let end = after
+            .find('}')
+            .ok_or_else(|| format!("Unclosed token in rename template: {}", template))?;
+        match &after[..end] {
+            "name" => rendered.push_str(name),
+            "index" => rendered.push_str(&format!("{:04}", index)),
+            "date" => rendered.push_str(date),
+            "status" => rendered.push_str(&file.status),
+            other => return Err(format!("Unknown rename template token: {{{}}}", other)),
+        }
+        rest = &after[end + 1..];
+    }
+    rendered.push_str(rest);
+
+    let rendered = rendered.replace(['/', '\\'], "_").trim().to_string();
+    if rendered.is_empty() || rendered == "." || rendered == ".." {
+        return Err(format!(
+            "Rename template produced an empty file name for {}",
+            file.path
+        ));
+    }
+
+    Ok(rendered)
+}
+
+fn renamed_output_path(source: &str, stem: &str) -> PathBuf {
+    let source = Path::new(source);
+    let file_name = match source.extension().and_then(|value| value.to_str()) {
+        Some(extension) => format!("{}.{}", stem, extension),
+        None => stem.to_string(),
+    };
+    source.with_file_name(file_name)
+}
+
+fn rename_output(source: &str, target: &Path) -> Result<String, String> {
+    if Path::new(source) != target {
+        std::fs::rename(source, target).map_err(|error| {
+            format!(
+                "Failed to rename transcript {} -> {}: {}",
+                source,
+                target.display(),
+                error
+            )
+        })?;
+    }
+    Ok(target.to_string_lossy().to_string())
+}
+
+fn rename_file_outputs(
+    file: &mut SessionFileRecord,
+    base_name: &str,
+    used_paths: &mut HashSet<String>,
+) -> Result<(), String> {
+    let sources = [file.transcript_path.clone(), file.json_path.clone()];
+    let existing = sources
+        .iter()
+        .flatten()
+        .filter(|source| Path::new(source.as_str()).exists())
+        .cloned()
+        .collect::<Vec<String>>();
+    let Some(primary) = existing.first() else {
+        return Ok(());
+    };
+
+    // Both sidecars share the deduplicated stem so txt and json stay paired.
+    let primary_target = renamed_output_path(primary, base_name)
+        .to_string_lossy()
+        .to_string();
+    let stem = loop {
+        let candidate = PathBuf::from(dedupe_path(primary_target.clone(), used_paths));
+        let stem = candidate
+            .file_stem()
+            .and_then(|value| value.to_str())
+            .unwrap_or(base_name)
+            .to_string();
+        let available = existing.iter().all(|source| {
+            let target = renamed_output_path(source, &stem);
+            target == Path::new(source) || !target.exists()
+        });
+        if available {
+            break stem;
+        }
+    };
+
+    for output_path in [&mut file.transcript_path, &mut file.json_path] {
+        if let Some(source) = output_path.clone().filter(|path| existing.contains(path)) {
+            let target = renamed_output_path(&source, &stem);
+            *output_path = Some(rename_output(&source, &target)?);
+        }
+    }
+
+    Ok(())
+}
+
+fn rename_transcripts_with_path(
+    history_path: Option<&Path>,
+    session_id: &str,
+    template: &str,
+) -> Result<Vec<SessionFileRecord>, String> {
+    let connection = open_database(history_path)?;
+    let created_at = connection
+        .query_row(
+            "SELECT created_at FROM sessions WHERE id = ?",
+            params![session_id],
+            |row| row.get::<_, i64>(0),
+        )
+        .map_err(|error| match error {
+            rusqlite::Error::QueryReturnedNoRows => format!("Session not found: {}", session_id),
+            other => format!("Failed to load session {}: {}", session_id, other),
+        })?;
+    let date = DateTime::<Utc>::from_timestamp(created_at, 0)
+        .unwrap_or_else(Utc::now)
+        .format("%Y%m%d")
+        .to_string();
+
+    let mut files = load_session_files(&connection, session_id)?;
+    let transaction = begin_write(&connection, "rename transaction")?;
+
+    // Renames already applied on disk are still recorded when a later one fails.
+    let mut failure = None;
+    let mut used_paths = HashSet::new();
+    for (position, file) in files.iter_mut().enumerate() {
+        let result = render_transcript_name(template, file, position + 1, &date)
+            .and_then(|base_name| rename_file_outputs(file, &base_name, &mut used_paths));
+
+        transaction
+            .execute(
+                "
+                UPDATE session_files SET transcript_path = ?, json_path = ?
+                WHERE session_id = ? AND file_id = ? AND path = ?
+                ",
+                params![
+                    file.transcript_path,
+                    file.json_path,
+                    session_id,
+                    file.id,
+                    file.path
+                ],
+            )
+            .map_err(|error| {
+                format!(
+                    "Failed to update transcript paths for {}: {}",
+                    file.path, error
+                )
+            })?;
+
+        if let Err(error) = result {
+            failure = Some(error);
+            break;
+        }
+    }
+
+    transaction
+        .commit()
+        .map_err(|error| format!("Failed to commit rename transaction: {}", error))?;
+
+    match failure {
+        Some(error) => Err(error),
+        None => Ok(files),
+    }
+}
+
+#[tauri::command]
+pub fn get_session_history() -> Result<Vec<SessionRecord>, String> {
+    get_sessions_with_path(None)
+}
+
+#[tauri::command]
+pub fn get_session_history_page(
+    offset: Option<i64>,
+    limit: Option<i64>,
+    tag: Option<String>,
+) -> Result<SessionHistoryPage, String> {
+    let offset = offset.unwrap_or(0).max(0);
+    let limit = limit.unwrap_or(DEFAULT_HISTORY_PAGE_SIZE);
+    if limit <= 0 {
+        return Err("History page size must be positive".to_string());
+    }
+    let tag = tag
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty());
+
+    get_session_history_page_with_path(None, offset, limit, tag.as_deref())
+}
+
+#[tauri::command]
+pub fn sessions_for_output_dir(path: String) -> Result<Vec<SessionRecord>, String> {
+    let path = path.trim();
+    if path.is_empty() {
+        return Err("Output directory is empty".to_string());
+    }
+
+    sessions_for_output_dir_with_path(None, Path::new(path))
+}
+
+#[tauri::command]
+pub fn flag_empty_transcripts(
+    session_id: String,
+    mark: Option<bool>,
+) -> Result<EmptyTranscriptReport, String> {
+    let session_id = session_id.trim();
+    if session_id.is_empty() {
+        return Err("Session id is empty".to_string());
+    }
+
+    flag_empty_transcripts_with_path(None, session_id, mark.unwrap_or(false))
+}
+
+#[tauri::command]
+pub fn verify_history_integrity(repair: bool) -> Result<HistoryIntegrityReport, String> {
+    verify_history_integrity_with_path(None, repair)
+}
+
+#[tauri::command]
+pub fn find_stale_sessions() -> Result<Vec<StaleSession>, String> {
+    let active = crate::providers::launcher::active_session_id();
+    find_stale_sessions_in(None, &get_sessions_dir()?, active.as_deref())
+}
+
+#[tauri::command]
+pub fn resolve_stale_session(session_id: String, discard: bool) -> Result<(), String> {
+    let active = crate::providers::launcher::active_session_id();
+    resolve_stale_session_in(
+        None,
+        &get_sessions_dir()?,
+        session_id.trim(),
+        discard,
+        active.as_deref(),
+    )
+}
+
+#[tauri::command]
+pub fn slowest_files(limit: Option<i64>) -> Result<Vec<SlowFile>, String> {
+    let limit = limit.unwrap_or(DEFAULT_SLOWEST_FILES_LIMIT);
+    if limit <= 0 {
+        return Err("Slowest files limit must be positive".to_string());
+    }
+
+    slowest_files_with_path(None, limit)
+}
+
+#[tauri::command]
+pub fn last_session_summary() -> Result<Option<LastSessionSummary>, String> {
+    last_session_summary_with_path(None)
+}
+
+#[tauri::command]
+pub fn recent_output_dirs(limit: Option<usize>) -> Result<Vec<String>, String> {
+    let limit = limit.unwrap_or(DEFAULT_RECENT_OUTPUT_DIRS_LIMIT);
+    if limit == 0 {
+        return Err("Recent output dirs limit must be positive".to_string());
+    }
+
+    recent_output_dirs_with_path(None, limit)
+}
+
+#[tauri::command]
+pub fn provider_performance() -> Result<Vec<ProviderPerformance>, String> {
+    provider_performance_with_path(None)
+}
+
+#[tauri::command]
+pub fn history_stats() -> Result<HistoryStats, String> {
+    history_stats_with_path(None)
+}
+
+#[tauri::command]
+pub fn set_session_tags(session_id: String, tags: Vec<String>) -> Result<Vec<String>, String> {
+    let session_id = session_id.trim();
+    if session_id.is_empty() {
+        return Err("Session id is empty".to_string());
+    }
+
+    set_session_tags_with_path(None, session_id, tags)
+}
+
+#[tauri::command]
+pub fn delete_session(session_id: String) -> Result<(), String> {
+    let session_id = session_id.trim();
+    if session_id.is_empty() {
+        return Err("Session id is empty".to_string());
+    }
+
+    delete_session_with_path(None, session_id)
+}
+
+#[tauri::command]
+pub fn delete_sessions(filter: SessionDeleteFilter) -> Result<usize, String> {
+    delete_sessions_with_path(None, &filter)
+}
+
+#[tauri::command]
+pub fn migrate_provider_ids() -> Result<usize, String> {
+    migrate_provider_ids_with_path(None)
+}
+
+#[tauri::command]
+pub fn checkpoint_history() -> Result<HistoryCheckpoint, String> {
+    checkpoint_history_with_path(None)
+}
+
+#[tauri::command]
+pub fn rename_transcripts(
+    session_id: String,
+    template: String,
+) -> Result<Vec<SessionFileRecord>, String> {
+    let session_id = session_id.trim();
+    if session_id.is_empty() {
+        return Err("Session id is empty".to_string());
+    }
+    if template.trim().is_empty() {
+        return Err("Rename template is empty".to_string());
+    }
+
+    rename_transcripts_with_path(None, session_id, template.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::manifest::{FileEntry, SessionManifest, TranscriptionSettings};
+    use uuid::Uuid;
 
     fn temp_root(prefix: &str) -> PathBuf {
         std::env::temp_dir().join(format!("{}-{}", prefix, Uuid::new_v4()))
     }
 
-    fn fixture_settings() -> TranscriptionSettings {
-        TranscriptionSettings {
-            output_format: "both".to_string(),
-            recursive: true,
-            overwrite: false,
-            max_retries: 1,
-            extensions: vec!["wav".to_string()],
-            ffmpeg_fallback: true,
-            dry_run: false,
-            notifications_enabled: true,
-            notify_on_complete: true,
-            notify_on_error: true,
+    fn fixture_settings() -> TranscriptionSettings {
+        TranscriptionSettings {
+            output_format: "both".to_string(),
+            recursive: true,
+            overwrite: false,
+            max_retries: 1,
+            extensions: vec!["wav".to_string()],
+            ffmpeg_fallback: true,
+            dry_run: false,
+            notifications_enabled: true,
+            notify_on_complete: true,
+            notify_on_error: true,
+            write_index: false,
+            chunk_size: None,
+            skip_previously_transcribed: false,
+            event_log_path: None,
+            extra_args: Vec::new(),
+            warm_pool: false,
+        }
+    }
+
+    fn write_manifest(path: &Path, session_id: &str) {
+        write_manifest_with_files(
+            path,
+            session_id,
+            &[PathBuf::from("/audio/a.wav"), PathBuf::from("/audio/b.wav")],
+        );
+    }
+
+    // Archives a two-file session next to `db_path`, then backdates it (and optionally moves it).
+    fn archive_fixture_session(
+        db_path: &Path,
+        session_id: &str,
+        created_at: i64,
+        status: &str,
+        output_dir: Option<&Path>,
+        summary: Option<SessionSummarySnapshot>,
+    ) {
+        let manifest_path = db_path
+            .with_file_name("sessions")
+            .join(format!("{}.json", session_id));
+        write_manifest(&manifest_path, session_id);
+        archive_session_with_path(
+            Some(db_path),
+            &manifest_path,
+            session_id,
+            summary,
+            if status == "completed" { 0 } else { 1 },
+            status,
+            &HashMap::new(),
+        )
+        .expect("session should archive");
+        open_database(Some(db_path))
+            .expect("history should open")
+            .execute(
+                "UPDATE sessions SET output_dir = COALESCE(?, output_dir), created_at = ? WHERE id = ?",
+                params![
+                    output_dir.map(|dir| dir.to_string_lossy().to_string()),
+                    created_at,
+                    session_id
+                ],
+            )
+            .expect("fixture session should update");
+    }
+
+    fn write_manifest_with_files(path: &Path, session_id: &str, files: &[PathBuf]) {
+        let manifest = SessionManifest {
+            session_id: session_id.to_string(),
+            created_at: "2026-02-12T00:00:00.000Z".to_string(),
+            provider: "coreml-local".to_string(),
+            model: "v3".to_string(),
+            output_dir: PathBuf::from("/tmp/batch-transcripts"),
+            settings: fixture_settings(),
+            files: files
+                .iter()
+                .map(|file| FileEntry {
+                    id: format!(
+                        "file-{}",
+                        normalize_file_name(file).trim_end_matches(".wav")
+                    ),
+                    path: file.clone(),
+                    status: "queued".to_string(),
+                })
+                .collect(),
+            parent_session_id: None,
+        };
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).expect("manifest parent directory should exist");
+        }
+
+        let payload = serde_json::to_vec_pretty(&manifest).expect("manifest should serialize");
+        std::fs::write(path, payload).expect("manifest should be written");
+    }
+
+    #[test]
+    fn archives_sessions_and_loads_history_records() {
+        let root = temp_root("parakeet-history-db");
+        let db_path = root.join("history.db");
+        let manifest_path = root.join("sessions").join("session-a.json");
+        write_manifest(&manifest_path, "session-a");
+
+        let mut outcomes = HashMap::new();
+        outcomes.insert(
+            "/audio/a.wav".to_string(),
+            FileOutcome {
+                status: "success".to_string(),
+                transcript_path: Some("/tmp/batch-transcripts/a.txt".to_string()),
+                json_path: Some("/tmp/batch-transcripts/a.json".to_string()),
+                error: None,
+                audio_seconds: None,
+                processing_seconds: None,
+                retries: 0,
+            },
+        );
+        outcomes.insert(
+            "/audio/b.wav".to_string(),
+            FileOutcome {
+                status: "failed".to_string(),
+                transcript_path: None,
+                json_path: None,
+                error: Some("decode failed".to_string()),
+                audio_seconds: None,
+                processing_seconds: None,
+                retries: 0,
+            },
+        );
+
+        archive_session_with_path(
+            Some(&db_path),
+            &manifest_path,
+            "session-a",
+            Some(SessionSummarySnapshot {
+                total: 2,
+                processed: 1,
+                skipped: 0,
+                failed: 1,
+                duration_seconds: 12.4,
+            }),
+            1,
+            "failed",
+            &outcomes,
+        )
+        .expect("session should be archived");
+
+        let sessions = get_sessions_with_path(Some(&db_path)).expect("history should load");
+        assert_eq!(sessions.len(), 1);
+        let session = &sessions[0];
+        assert_eq!(session.id, "session-a");
+        assert_eq!(session.provider, "coreml-local");
+        assert_eq!(session.model, "v3");
+        assert_eq!(session.total, 2);
+        assert_eq!(session.processed, 1);
+        assert_eq!(session.failed, 1);
+        assert_eq!(session.status, "failed");
+        assert_eq!(session.files.len(), 2);
+        assert_eq!(
+            session.files[0].status, "success",
+            "files are ordered by filename"
+        );
+        assert_eq!(
+            session.files[1].error.as_deref(),
+            Some("decode failed"),
+            "failed item keeps error details"
+        );
+
+        delete_session_with_path(Some(&db_path), "session-a")
+            .expect("session delete should succeed");
+        let remaining = get_sessions_with_path(Some(&db_path)).expect("history should reload");
+        assert!(remaining.is_empty());
+    }
+
+    #[test]
+    fn archives_and_reads_back_per_file_retry_counts() {
+        let root = temp_root("parakeet-history-retries");
+        let db_path = root.join("history.db");
+        let manifest_path = root.join("sessions").join("session-retries.json");
+        write_manifest(&manifest_path, "session-retries");
+
+        let mut outcomes = HashMap::new();
+        outcomes.insert(
+            "/audio/a.wav".to_string(),
+            FileOutcome {
+                status: "success".to_string(),
+                transcript_path: Some("/tmp/batch-transcripts/a.txt".to_string()),
+                json_path: None,
+                error: None,
+                audio_seconds: None,
+                processing_seconds: None,
+                retries: 1,
+            },
+        );
+        outcomes.insert(
+            "/audio/b.wav".to_string(),
+            FileOutcome {
+                status: "success".to_string(),
+                transcript_path: Some("/tmp/batch-transcripts/b.txt".to_string()),
+                json_path: None,
+                error: None,
+                audio_seconds: None,
+                processing_seconds: None,
+                retries: 0,
+            },
+        );
+        archive_session_with_path(
+            Some(&db_path),
+            &manifest_path,
+            "session-retries",
+            None,
+            0,
+            "completed",
+            &outcomes,
+        )
+        .expect("session should be archived");
+
+        let loaded = load_session_with_path(Some(&db_path), "session-retries")
+            .expect("archived session should load");
+        assert_eq!(
+            loaded
+                .files
+                .iter()
+                .map(|file| (file.name.as_str(), file.retries))
+                .collect::<Vec<_>>(),
+            vec![("a.wav", 1), ("b.wav", 0)]
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn mid_run_checkpoint_reflects_partial_outcomes_until_finalized() {
+        let root = temp_root("parakeet-history-checkpoint");
+        let db_path = root.join("history.db");
+        let manifest_path = root.join("sessions").join("session-run.json");
+        write_manifest(&manifest_path, "session-run");
+
+        let mut outcomes = HashMap::new();
+        outcomes.insert(
+            "/audio/a.wav".to_string(),
+            FileOutcome {
+                status: "success".to_string(),
+                transcript_path: Some("/tmp/batch-transcripts/a.txt".to_string()),
+                json_path: None,
+                error: None,
+                audio_seconds: None,
+                processing_seconds: None,
+                retries: 0,
+            },
+        );
+        archive_session_with_path(
+            Some(&db_path),
+            &manifest_path,
+            "session-run",
+            None,
+            -1,
+            "running",
+            &outcomes,
+        )
+        .expect("checkpoint should be archived");
+
+        let running = load_session_with_path(Some(&db_path), "session-run")
+            .expect("checkpointed session should load");
+        assert_eq!(running.status, "running");
+        assert_eq!(
+            (running.total, running.processed, running.failed),
+            (2, 1, 0)
+        );
+        assert_eq!(running.files[0].status, "success");
+        assert_eq!(
+            running.files[1].status, "queued",
+            "unfinished files stay queued"
+        );
+
+        outcomes.insert(
+            "/audio/b.wav".to_string(),
+            FileOutcome {
+                status: "failed".to_string(),
+                transcript_path: None,
+                json_path: None,
+                error: Some("decode failed".to_string()),
+                audio_seconds: None,
+                processing_seconds: None,
+                retries: 0,
+            },
+        );
+        archive_session_with_path(
+            Some(&db_path),
+            &manifest_path,
+            "session-run",
+            None,
+            1,
+            "failed",
+            &outcomes,
+        )
+        .expect("final archive should replace the checkpoint");
+
+        let sessions = get_sessions_with_path(Some(&db_path)).expect("history should load");
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].status, "failed");
+        assert_eq!((sessions[0].processed, sessions[0].failed), (1, 1));
+    }
+
+    #[test]
+    fn session_index_maps_sources_to_transcripts_and_status() {
+        let root = temp_root("parakeet-history-index");
+        let db_path = root.join("history.db");
+        let manifest_path = root.join("sessions").join("session-index.json");
+        write_manifest(&manifest_path, "session-index");
+
+        let mut outcomes = HashMap::new();
+        outcomes.insert(
+            "/audio/a.wav".to_string(),
+            FileOutcome {
+                status: "success".to_string(),
+                transcript_path: Some("/tmp/batch-transcripts/a.txt".to_string()),
+                json_path: Some("/tmp/batch-transcripts/a.json".to_string()),
+                error: None,
+                audio_seconds: None,
+                processing_seconds: None,
+                retries: 0,
+            },
+        );
+        outcomes.insert(
+            "/audio/b.wav".to_string(),
+            FileOutcome {
+                status: "failed".to_string(),
+                transcript_path: None,
+                json_path: None,
+                error: Some("decode failed".to_string()),
+                audio_seconds: None,
+                processing_seconds: None,
+                retries: 0,
+            },
+        );
+
+        let record = archive_session_with_path(
+            Some(&db_path),
+            &manifest_path,
+            "session-index",
+            None,
+            2,
+            "completed",
+            &outcomes,
+        )
+        .expect("session should be archived");
+
+        let output_dir = root.join("out");
+        std::fs::create_dir_all(&output_dir).expect("output directory should be created");
+        let index_path =
+            write_session_index(&output_dir, &record, false).expect("index should be written");
+        assert_eq!(index_path, output_dir.join("INDEX.txt"));
+
+        let content = std::fs::read_to_string(&index_path).expect("index should be readable");
+        let lines = content.lines().collect::<Vec<&str>>();
+        assert_eq!(lines[0], "Session: session-index");
+        assert_eq!(lines[1], "Provider: coreml-local (v3)");
+        assert_eq!(lines[2], "Status: completed");
+        assert_eq!(lines[3], "Files: 2 total, 1 processed, 0 skipped, 1 failed");
+        assert_eq!(lines[5], "status\tsource\ttranscript\tjson");
+        assert_eq!(
+            lines[6],
+            "success\t/audio/a.wav\t/tmp/batch-transcripts/a.txt\t/tmp/batch-transcripts/a.json"
+        );
+        assert_eq!(lines[7], "failed\t/audio/b.wav\t-\t-");
+        assert_eq!(lines.len(), 8);
+
+        // A later chunk of the same run appends its block instead of replacing the first.
+        write_session_index(&output_dir, &record, true).expect("index should be appended");
+        let content = std::fs::read_to_string(&index_path).expect("index should be readable");
+        let lines = content.lines().collect::<Vec<&str>>();
+        assert_eq!(lines.len(), 17);
+        assert_eq!(lines[8], "");
+        assert_eq!(lines[9], "Session: session-index");
+    }
+
+    #[test]
+    fn rename_template_substitutes_tokens() {
+        let file = SessionFileRecord {
+            id: "file-a".to_string(),
+            path: "/audio/interview.wav".to_string(),
+            name: "interview.wav".to_string(),
+            status: "success".to_string(),
+            transcript_path: None,
+            json_path: None,
+            error: None,
+            retries: 0,
+            processing_seconds: None,
+        };
+
+        assert_eq!(
+            render_transcript_name("{date}_{index}_{name}_{status}", &file, 3, "20260212")
+                .expect("template should render"),
+            "20260212_0003_interview_success"
+        );
+        assert_eq!(
+            render_transcript_name("a/b {name}", &file, 1, "20260212")
+                .expect("separators should be replaced"),
+            "a_b interview"
+        );
+        assert!(render_transcript_name("{speaker}", &file, 1, "20260212").is_err());
+        assert!(render_transcript_name("{name", &file, 1, "20260212").is_err());
+    }
+
+    #[test]
+    fn rename_transcripts_dedupes_collisions_and_updates_history() {
+        let root = temp_root("parakeet-history-rename");
+        let db_path = root.join("history.db");
+        let manifest_path = root.join("sessions").join("session-rename.json");
+        write_manifest(&manifest_path, "session-rename");
+
+        let output_dir = root.join("out");
+        std::fs::create_dir_all(&output_dir).expect("output directory should be created");
+        let path_in_out = |name: &str| output_dir.join(name).to_string_lossy().to_string();
+        for name in ["a.txt", "a.json", "b.txt", "b.json", "20260212.txt"] {
+            std::fs::write(output_dir.join(name), name).expect("fixture output should exist");
+        }
+
+        let mut outcomes = HashMap::new();
+        for stem in ["a", "b"] {
+            outcomes.insert(
+                format!("/audio/{}.wav", stem),
+                FileOutcome {
+                    status: "success".to_string(),
+                    transcript_path: Some(path_in_out(&format!("{}.txt", stem))),
+                    json_path: Some(path_in_out(&format!("{}.json", stem))),
+                    error: None,
+                    audio_seconds: None,
+                    processing_seconds: None,
+                    retries: 0,
+                },
+            );
         }
+        archive_session_with_path(
+            Some(&db_path),
+            &manifest_path,
+            "session-rename",
+            None,
+            0,
+            "completed",
+            &outcomes,
+        )
+        .expect("session should be archived");
+
+        let renamed = rename_transcripts_with_path(Some(&db_path), "session-rename", "{date}")
+            .expect("rename should succeed");
+        assert_eq!(
+            renamed[0].transcript_path.as_deref(),
+            Some(path_in_out("20260212-2.txt").as_str()),
+            "an unrelated file already owns the plain name"
+        );
+        assert_eq!(
+            renamed[0].json_path.as_deref(),
+            Some(path_in_out("20260212-2.json").as_str())
+        );
+        assert_eq!(
+            renamed[1].transcript_path.as_deref(),
+            Some(path_in_out("20260212-3.txt").as_str())
+        );
+
+        assert_eq!(
+            std::fs::read_to_string(output_dir.join("20260212.txt")).expect("untouched"),
+            "20260212.txt"
+        );
+        assert_eq!(
+            std::fs::read_to_string(output_dir.join("20260212-3.json")).expect("renamed"),
+            "b.json"
+        );
+        assert!(!output_dir.join("a.txt").exists());
+
+        let sessions = get_sessions_with_path(Some(&db_path)).expect("history should load");
+        assert_eq!(sessions[0].files, renamed);
+
+        assert!(rename_transcripts_with_path(Some(&db_path), "missing", "{name}").is_err());
     }
 
-    fn write_manifest(path: &Path, session_id: &str) {
-        let manifest = SessionManifest {
-            session_id: session_id.to_string(),
-            created_at: "2026-02-12T00:00:00.000Z".to_string(),
-            provider: "coreml-local".to_string(),
-            model: "v3".to_string(),
-            output_dir: PathBuf::from("/tmp/batch-transcripts"),
-            settings: fixture_settings(),
-            files: vec![
-                FileEntry {
-                    id: "file-a".to_string(),
-                    path: PathBuf::from("/audio/a.wav"),
-                    status: "queued".to_string(),
+    #[test]
+    fn relocating_transcripts_rewrites_matching_history_paths() {
+        let root = temp_root("parakeet-history-relocate");
+        let db_path = root.join("history.db");
+        let manifest_path = root.join("sessions").join("session-move.json");
+        write_manifest(&manifest_path, "session-move");
+
+        let mut outcomes = HashMap::new();
+        outcomes.insert(
+            "/audio/a.wav".to_string(),
+            FileOutcome {
+                status: "success".to_string(),
+                transcript_path: Some("/out/a.txt".to_string()),
+                json_path: Some("/out/a.json".to_string()),
+                error: None,
+                audio_seconds: None,
+                processing_seconds: None,
+                retries: 0,
+            },
+        );
+        archive_session_with_path(
+            Some(&db_path),
+            &manifest_path,
+            "session-move",
+            None,
+            0,
+            "completed",
+            &outcomes,
+        )
+        .expect("session should be archived");
+
+        let updated = relocate_transcript_paths_with_path(
+            Some(&db_path),
+            &[
+                ("/out/a.txt".to_string(), "/archive/a.txt".to_string()),
+                ("/out/a.json".to_string(), "/archive/a.json".to_string()),
+                (
+                    "/out/missing.txt".to_string(),
+                    "/archive/missing.txt".to_string(),
+                ),
+            ],
+        )
+        .expect("relocation should succeed");
+        assert_eq!(updated, 2);
+
+        let sessions = get_sessions_with_path(Some(&db_path)).expect("history should load");
+        let file = &sessions[0].files[0];
+        assert_eq!(file.transcript_path.as_deref(), Some("/archive/a.txt"));
+        assert_eq!(file.json_path.as_deref(), Some("/archive/a.json"));
+    }
+
+    #[test]
+    fn session_tags_are_normalized_and_filter_history_pages() {
+        let root = temp_root("parakeet-history-tags");
+        let db_path = root.join("history.db");
+        for session_id in ["session-acme", "session-globex", "session-untagged"] {
+            let manifest_path = root.join("sessions").join(format!("{}.json", session_id));
+            write_manifest(&manifest_path, session_id);
+            archive_session_with_path(
+                Some(&db_path),
+                &manifest_path,
+                session_id,
+                None,
+                0,
+                "completed",
+                &HashMap::new(),
+            )
+            .expect("session should be archived");
+        }
+
+        let tags = set_session_tags_with_path(
+            Some(&db_path),
+            "session-acme",
+            vec![
+                " acme ".to_string(),
+                "interviews".to_string(),
+                "acme".to_string(),
+                "   ".to_string(),
+            ],
+        )
+        .expect("tags should be saved");
+        assert_eq!(tags, vec!["acme".to_string(), "interviews".to_string()]);
+        set_session_tags_with_path(Some(&db_path), "session-globex", vec!["globex".to_string()])
+            .expect("tags should be saved");
+
+        let sessions = get_sessions_with_path(Some(&db_path)).expect("history should load");
+        let acme = sessions
+            .iter()
+            .find(|session| session.id == "session-acme")
+            .expect("tagged session should load");
+        assert_eq!(
+            acme.tags,
+            vec!["acme".to_string(), "interviews".to_string()]
+        );
+        assert_eq!(acme.files.len(), 2);
+
+        let page = get_session_history_page_with_path(Some(&db_path), 0, 10, Some("acme"))
+            .expect("filtered page should load");
+        assert_eq!(page.total, 1);
+        assert_eq!(page.sessions.len(), 1);
+        assert_eq!(page.sessions[0].id, "session-acme");
+
+        let page = get_session_history_page_with_path(Some(&db_path), 0, 2, None)
+            .expect("unfiltered page should load");
+        assert_eq!(page.total, 3);
+        assert_eq!(page.sessions.len(), 2);
+        let rest = get_session_history_page_with_path(Some(&db_path), 2, 2, None)
+            .expect("second page should load");
+        assert_eq!(rest.sessions.len(), 1);
+
+        let empty = get_session_history_page_with_path(Some(&db_path), 0, 10, Some("initech"))
+            .expect("unknown tag should load an empty page");
+        assert_eq!(empty.total, 0);
+        assert!(empty.sessions.is_empty());
+
+        assert!(set_session_tags_with_path(Some(&db_path), "missing", vec![]).is_err());
+
+        let manifest_path = root.join("sessions").join("session-acme.json");
+        archive_session_with_path(
+            Some(&db_path),
+            &manifest_path,
+            "session-acme",
+            None,
+            0,
+            "completed",
+            &HashMap::new(),
+        )
+        .expect("session should be re-archived");
+        let sessions = get_sessions_with_path(Some(&db_path)).expect("history should load");
+        let acme = sessions
+            .iter()
+            .find(|session| session.id == "session-acme")
+            .expect("re-archived session should load");
+        assert_eq!(
+            acme.tags,
+            vec!["acme".to_string(), "interviews".to_string()]
+        );
+    }
+
+    #[test]
+    fn history_stats_aggregate_totals_and_per_provider_breakdown() {
+        let root = temp_root("parakeet-history-stats");
+        let db_path = root.join("history.db");
+
+        let empty = history_stats_with_path(Some(&db_path)).expect("empty stats should load");
+        assert_eq!(empty.sessions, 0);
+        assert_eq!(empty.audio_seconds, 0.0);
+        assert!(empty.average_rtf.is_none());
+        assert!(empty.providers.is_empty());
+
+        let outcome = |status: &str, audio_seconds: Option<f64>| FileOutcome {
+            status: status.to_string(),
+            transcript_path: None,
+            json_path: None,
+            error: None,
+            audio_seconds,
+            processing_seconds: None,
+            retries: 0,
+        };
+        let sessions = [
+            (
+                "session-one",
+                10.0,
+                outcome("success", Some(60.0)),
+                outcome("success", Some(40.0)),
+            ),
+            (
+                "session-two",
+                30.0,
+                outcome("success", Some(100.0)),
+                outcome("failed", None),
+            ),
+            (
+                "session-three",
+                5.0,
+                outcome("failed", None),
+                outcome("failed", None),
+            ),
+        ];
+        for (session_id, duration_seconds, first, second) in sessions {
+            let manifest_path = root.join("sessions").join(format!("{}.json", session_id));
+            write_manifest(&manifest_path, session_id);
+            let processed = [&first, &second]
+                .iter()
+                .filter(|outcome| outcome.status == "success")
+                .count() as u64;
+            let mut outcomes = HashMap::new();
+            outcomes.insert("/audio/a.wav".to_string(), first);
+            outcomes.insert("/audio/b.wav".to_string(), second);
+            archive_session_with_path(
+                Some(&db_path),
+                &manifest_path,
+                session_id,
+                Some(SessionSummarySnapshot {
+                    total: 2,
+                    processed,
+                    skipped: 0,
+                    failed: 2 - processed,
+                    duration_seconds,
+                }),
+                0,
+                "completed",
+                &outcomes,
+            )
+            .expect("session should be archived");
+        }
+        let connection = open_database(Some(&db_path)).expect("history should open");
+        connection
+            .execute(
+                "UPDATE sessions SET provider = 'faster-whisper' WHERE id = 'session-two'",
+                [],
+            )
+            .expect("provider should be updated");
+
+        let stats = history_stats_with_path(Some(&db_path)).expect("stats should load");
+        assert_eq!(stats.sessions, 3);
+        assert_eq!(stats.files_processed, 3);
+        assert_eq!(stats.failures, 3);
+        assert_eq!(stats.audio_seconds, 200.0);
+        // (10/100 + 30/100) / 2; session-three has no audio and is excluded.
+        assert!((stats.average_rtf.expect("rtf should be reported") - 0.2).abs() < 1e-9);
+
+        assert_eq!(stats.providers.len(), 2);
+        let coreml = &stats.providers[0];
+        assert_eq!(coreml.provider, "coreml-local");
+        assert_eq!(coreml.sessions, 2);
+        assert_eq!(coreml.files_processed, 2);
+        assert_eq!(coreml.failures, 2);
+        assert_eq!(coreml.audio_seconds, 100.0);
+        assert!((coreml.average_rtf.expect("rtf should be reported") - 0.1).abs() < 1e-9);
+        let faster = &stats.providers[1];
+        assert_eq!(faster.provider, "faster-whisper");
+        assert_eq!(faster.sessions, 1);
+        assert_eq!(faster.audio_seconds, 100.0);
+        assert!((faster.average_rtf.expect("rtf should be reported") - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn detects_and_repairs_session_counts_that_disagree_with_file_rows() {
+        let root = temp_root("parakeet-history-integrity");
+        let db_path = root.join("history.db");
+        let outcome = |status: &str| FileOutcome {
+            status: status.to_string(),
+            transcript_path: None,
+            json_path: None,
+            error: None,
+            audio_seconds: None,
+            processing_seconds: None,
+            retries: 0,
+        };
+        for session_id in ["intact", "interrupted"] {
+            let manifest_path = root.join("sessions").join(format!("{}.json", session_id));
+            write_manifest(&manifest_path, session_id);
+            let outcomes = HashMap::from([
+                ("/audio/a.wav".to_string(), outcome("success")),
+                ("/audio/b.wav".to_string(), outcome("failed")),
+            ]);
+            archive_session_with_path(
+                Some(&db_path),
+                &manifest_path,
+                session_id,
+                None,
+                0,
+                "completed",
+                &outcomes,
+            )
+            .expect("session should be archived");
+        }
+        open_database(Some(&db_path))
+            .expect("history should open")
+            .execute(
+                "UPDATE sessions SET processed = 0, failed = 0 WHERE id = 'interrupted'",
+                [],
+            )
+            .expect("counts should be corrupted");
+
+        let report = verify_history_integrity_with_path(Some(&db_path), false)
+            .expect("integrity check should run");
+        assert_eq!(report.checked, 2);
+        assert!(!report.repaired);
+        assert_eq!(
+            report.mismatches,
+            vec![SessionCountMismatch {
+                session_id: "interrupted".to_string(),
+                stored: SessionCounts {
+                    total: 2,
+                    processed: 0,
+                    skipped: 0,
+                    failed: 0,
                 },
-                FileEntry {
-                    id: "file-b".to_string(),
-                    path: PathBuf::from("/audio/b.wav"),
-                    status: "queued".to_string(),
+                recomputed: SessionCounts {
+                    total: 2,
+                    processed: 1,
+                    skipped: 0,
+                    failed: 1,
                 },
-            ],
+            }]
+        );
+
+        let repaired =
+            verify_history_integrity_with_path(Some(&db_path), true).expect("repair should run");
+        assert!(repaired.repaired);
+        let session =
+            load_session_with_path(Some(&db_path), "interrupted").expect("session should load");
+        assert_eq!((session.processed, session.failed), (1, 1));
+        let again = verify_history_integrity_with_path(Some(&db_path), false)
+            .expect("integrity check should run");
+        assert!(again.mismatches.is_empty());
+    }
+
+    #[test]
+    fn provider_performance_requires_enough_timed_sessions() {
+        let root = temp_root("parakeet-history-performance");
+        let db_path = root.join("history.db");
+        assert!(provider_performance_with_path(Some(&db_path))
+            .expect("empty history should load")
+            .is_empty());
+
+        let timed = |audio_seconds: f64| FileOutcome {
+            status: "success".to_string(),
+            transcript_path: None,
+            json_path: None,
+            error: None,
+            audio_seconds: Some(audio_seconds),
+            processing_seconds: None,
+            retries: 0,
         };
+        let sessions = [
+            ("coreml-local", "perf-1", 10.0, 100.0),
+            ("coreml-local", "perf-2", 20.0, 100.0),
+            ("coreml-local", "perf-3", 30.0, 100.0),
+            ("coreml-local", "perf-4", 0.0, 100.0),
+            ("whisper-openai", "perf-5", 300.0, 100.0),
+            ("whisper-openai", "perf-6", 200.0, 100.0),
+        ];
+        for (provider, session_id, duration_seconds, audio_seconds) in sessions {
+            let manifest_path = root.join("sessions").join(format!("{}.json", session_id));
+            write_manifest(&manifest_path, session_id);
+            let outcomes = HashMap::from([("/audio/a.wav".to_string(), timed(audio_seconds))]);
+            archive_session_with_path(
+                Some(&db_path),
+                &manifest_path,
+                session_id,
+                Some(SessionSummarySnapshot {
+                    total: 2,
+                    processed: 1,
+                    skipped: 0,
+                    failed: 0,
+                    duration_seconds,
+                }),
+                0,
+                "completed",
+                &outcomes,
+            )
+            .expect("session should be archived");
+            open_database(Some(&db_path))
+                .expect("history should open")
+                .execute(
+                    "UPDATE sessions SET provider = ? WHERE id = ?",
+                    params![provider, session_id],
+                )
+                .expect("provider should be updated");
+        }
 
-        if let Some(parent) = path.parent() {
-            std::fs::create_dir_all(parent).expect("manifest parent directory should exist");
+        let performance =
+            provider_performance_with_path(Some(&db_path)).expect("performance should load");
+        assert_eq!(performance.len(), 2);
+        let coreml = &performance[0];
+        assert_eq!(coreml.provider, "coreml-local");
+        // perf-4 recorded no processing time, so it is not a sample.
+        assert_eq!(coreml.samples, 3);
+        assert!(!coreml.insufficient_data);
+        assert!((coreml.average_rtf.expect("rtf should be reported") - 0.2).abs() < 1e-9);
+        let whisper = &performance[1];
+        assert_eq!(whisper.provider, "whisper-openai");
+        assert_eq!(whisper.samples, 2);
+        assert!(whisper.insufficient_data);
+        assert_eq!(whisper.average_rtf, None);
+    }
+
+    #[test]
+    fn splits_queue_items_with_prior_successful_transcripts() {
+        let root = temp_root("parakeet-history-prior");
+        let db_path = root.join("history.db");
+        let audio_dir = root.join("audio");
+        std::fs::create_dir_all(&audio_dir).expect("audio directory should exist");
+        for name in ["done.wav", "silent.wav", "failed.wav", "new.wav"] {
+            std::fs::write(audio_dir.join(name), b"RIFF").expect("audio fixture should be written");
         }
 
-        let payload = serde_json::to_vec_pretty(&manifest).expect("manifest should serialize");
-        std::fs::write(path, payload).expect("manifest should be written");
+        // History stores a non-canonical spelling of the same file.
+        let done_alias = audio_dir.join("..").join("audio").join("done.wav");
+        let silent = audio_dir.join("silent.wav");
+        let failed = audio_dir.join("failed.wav");
+        let manifest_path = root.join("sessions").join("session-prior.json");
+        write_manifest_with_files(
+            &manifest_path,
+            "session-prior",
+            &[done_alias.clone(), silent.clone(), failed.clone()],
+        );
+        let mut outcomes = HashMap::new();
+        for (path, status) in [
+            (&done_alias, "success"),
+            (&silent, EMPTY_TRANSCRIPT_STATUS),
+            (&failed, "failed"),
+        ] {
+            outcomes.insert(
+                path.to_string_lossy().to_string(),
+                FileOutcome {
+                    status: status.to_string(),
+                    transcript_path: None,
+                    json_path: None,
+                    error: None,
+                    audio_seconds: None,
+                    processing_seconds: None,
+                    retries: 0,
+                },
+            );
+        }
+        archive_session_with_path(
+            Some(&db_path),
+            &manifest_path,
+            "session-prior",
+            None,
+            0,
+            "completed",
+            &outcomes,
+        )
+        .expect("session should be archived");
+
+        let items = ["done.wav", "silent.wav", "failed.wav", "new.wav"]
+            .iter()
+            .map(|name| QueueItem {
+                id: name.to_string(),
+                path: audio_dir.join(name),
+                status: "queued".to_string(),
+            })
+            .collect::<Vec<QueueItem>>();
+        let (remaining, skipped) = split_previously_transcribed_with_path(Some(&db_path), items)
+            .expect("history lookup should succeed");
+
+        let ids = |items: &[QueueItem]| {
+            items
+                .iter()
+                .map(|item| item.id.clone())
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(
+            ids(&skipped),
+            vec!["done.wav".to_string(), "silent.wav".to_string()]
+        );
+        assert_eq!(
+            ids(&remaining),
+            vec!["failed.wav".to_string(), "new.wav".to_string()]
+        );
+    }
+
+    #[test]
+    fn session_output_dir_requires_known_session_and_existing_directory() {
+        let root = temp_root("parakeet-history-output-dir");
+        let db_path = root.join("history.db");
+        let manifest_path = root.join("sessions").join("session-open.json");
+        write_manifest(&manifest_path, "session-open");
+        archive_session_with_path(
+            Some(&db_path),
+            &manifest_path,
+            "session-open",
+            None,
+            0,
+            "completed",
+            &HashMap::new(),
+        )
+        .expect("session should be archived");
+
+        let missing = session_output_dir_with_path(Some(&db_path), "session-missing")
+            .expect_err("unknown sessions should error");
+        assert_eq!(missing, "Session not found: session-missing");
+
+        let output_dir = root.join("transcripts");
+        let connection = open_database(Some(&db_path)).expect("history should open");
+        connection
+            .execute(
+                "UPDATE sessions SET output_dir = ? WHERE id = 'session-open'",
+                params![output_dir.to_string_lossy()],
+            )
+            .expect("output dir should be updated");
+
+        let gone = session_output_dir_with_path(Some(&db_path), "session-open")
+            .expect_err("deleted output directories should error");
+        assert!(gone.contains("no longer exists"), "{gone}");
+
+        std::fs::create_dir_all(&output_dir).expect("output directory should be created");
+        assert_eq!(
+            session_output_dir_with_path(Some(&db_path), "session-open")
+                .expect("existing output directory should resolve"),
+            output_dir
+        );
+    }
+
+    #[test]
+    fn lists_only_sessions_that_wrote_to_the_output_dir() {
+        let root = temp_root("parakeet-history-by-output-dir");
+        let db_path = root.join("history.db");
+        let shared = root.join("shared");
+        let other = root.join("other");
+        std::fs::create_dir_all(&shared).expect("shared dir should be created");
+
+        for (session_id, output_dir, created_at) in [
+            ("session-old", &shared, 1_770_681_600_i64),
+            ("session-elsewhere", &other, 1_770_768_000_i64),
+            ("session-new", &shared, 1_770_854_400_i64),
+        ] {
+            archive_fixture_session(
+                &db_path,
+                session_id,
+                created_at,
+                "completed",
+                Some(output_dir),
+                None,
+            );
+        }
+
+        let sessions = sessions_for_output_dir_with_path(Some(&db_path), &shared.join("."))
+            .expect("output dir query should succeed");
+        assert_eq!(
+            sessions
+                .iter()
+                .map(|session| session.id.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["session-new", "session-old"]
+        );
+        assert!(sessions.iter().all(|session| session.files.len() == 2));
+        assert!(
+            sessions_for_output_dir_with_path(Some(&db_path), &root.join("unused"))
+                .expect("output dir query should succeed")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn flags_and_marks_transcripts_without_speech() {
+        let root = temp_root("parakeet-history-empty");
+        let db_path = root.join("history.db");
+        let manifest_path = root.join("sessions").join("session-empty.json");
+        write_manifest(&manifest_path, "session-empty");
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("fixtures")
+            .join("transcripts");
+        let outcome = |transcript: &str| FileOutcome {
+            status: "success".to_string(),
+            transcript_path: Some(fixtures.join(transcript).to_string_lossy().to_string()),
+            json_path: None,
+            error: None,
+            audio_seconds: None,
+            processing_seconds: None,
+            retries: 0,
+        };
+        let outcomes = HashMap::from([
+            ("/audio/a.wav".to_string(), outcome("blank_audio.txt")),
+            ("/audio/b.wav".to_string(), outcome("speech.txt")),
+        ]);
+        archive_session_with_path(
+            Some(&db_path),
+            &manifest_path,
+            "session-empty",
+            None,
+            0,
+            "completed",
+            &outcomes,
+        )
+        .expect("session should be archived");
+
+        let report = flag_empty_transcripts_with_path(Some(&db_path), "session-empty", false)
+            .expect("transcripts should be checked");
+        assert_eq!(report.checked, 2);
+        assert_eq!(
+            report
+                .empty
+                .iter()
+                .map(|file| file.path.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["/audio/a.wav"]
+        );
+        let unmarked =
+            load_session_with_path(Some(&db_path), "session-empty").expect("session should load");
+        assert!(unmarked.files.iter().all(|file| file.status == "success"));
+
+        flag_empty_transcripts_with_path(Some(&db_path), "session-empty", true)
+            .expect("transcripts should be flagged");
+        let marked =
+            load_session_with_path(Some(&db_path), "session-empty").expect("session should load");
+        let statuses = marked
+            .files
+            .iter()
+            .map(|file| (file.path.as_str(), file.status.as_str()))
+            .collect::<Vec<_>>();
+        assert!(statuses.contains(&("/audio/a.wav", EMPTY_TRANSCRIPT_STATUS)));
+        assert!(statuses.contains(&("/audio/b.wav", "success")));
+        assert_eq!(summarize_from_files(&marked.files).processed, 2);
+
+        assert!(is_empty_transcript("  <|nospeech|> ... ♪ "));
+        assert!(!is_empty_transcript("(laughs) okay"));
+        assert!(is_empty_transcript("[music (applause] ♪"));
+        assert!(!is_empty_transcript("[music] (okay so the meeting starts"));
     }
 
     #[test]
-    fn archives_sessions_and_loads_history_records() {
-        let root = temp_root("parakeet-history-db");
+    fn retranscribed_file_updates_its_record_in_place() {
+        let root = temp_root("parakeet-history-retranscribe");
         let db_path = root.join("history.db");
-        let manifest_path = root.join("sessions").join("session-a.json");
-        write_manifest(&manifest_path, "session-a");
+        let manifest_path = root.join("sessions").join("session-redo.json");
+        write_manifest(&manifest_path, "session-redo");
 
         let mut outcomes = HashMap::new();
         outcomes.insert(
@@ -589,8 +3305,11 @@ mod tests {
             FileOutcome {
                 status: "success".to_string(),
                 transcript_path: Some("/tmp/batch-transcripts/a.txt".to_string()),
-                json_path: Some("/tmp/batch-transcripts/a.json".to_string()),
+                json_path: None,
                 error: None,
+                audio_seconds: None,
+                processing_seconds: None,
+                retries: 0,
             },
         );
         outcomes.insert(
@@ -600,51 +3319,224 @@ mod tests {
                 transcript_path: None,
                 json_path: None,
                 error: Some("decode failed".to_string()),
+                audio_seconds: None,
+                processing_seconds: None,
+                retries: 0,
             },
         );
-
         archive_session_with_path(
             Some(&db_path),
             &manifest_path,
-            "session-a",
-            Some(SessionSummarySnapshot {
-                total: 2,
-                processed: 1,
-                skipped: 0,
-                failed: 1,
-                duration_seconds: 12.4,
-            }),
+            "session-redo",
+            None,
             1,
             "failed",
             &outcomes,
         )
         .expect("session should be archived");
 
+        let updated = update_session_file_with_path(
+            Some(&db_path),
+            "session-redo",
+            "file-b",
+            &FileOutcome {
+                status: "success".to_string(),
+                transcript_path: Some("/tmp/batch-transcripts/b.txt".to_string()),
+                json_path: Some("/tmp/batch-transcripts/b.json".to_string()),
+                error: None,
+                audio_seconds: Some(30.0),
+                processing_seconds: None,
+                retries: 0,
+            },
+        )
+        .expect("file record should update");
+        assert_eq!(updated.processed, 2);
+        assert_eq!(updated.failed, 0);
+
         let sessions = get_sessions_with_path(Some(&db_path)).expect("history should load");
-        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions.len(), 1, "no new session is created");
         let session = &sessions[0];
-        assert_eq!(session.id, "session-a");
-        assert_eq!(session.provider, "coreml-local");
-        assert_eq!(session.model, "v3");
         assert_eq!(session.total, 2);
-        assert_eq!(session.processed, 1);
-        assert_eq!(session.failed, 1);
-        assert_eq!(session.status, "failed");
-        assert_eq!(session.files.len(), 2);
+        assert_eq!(session.processed, 2);
+        assert_eq!(session.failed, 0);
+        let redone = &session.files[1];
+        assert_eq!(redone.id, "file-b");
+        assert_eq!(redone.status, "success");
         assert_eq!(
-            session.files[0].status, "success",
-            "files are ordered by filename"
+            redone.transcript_path.as_deref(),
+            Some("/tmp/batch-transcripts/b.txt")
         );
+        assert!(redone.error.is_none());
+
+        let failed_redo = update_session_file_with_path(
+            Some(&db_path),
+            "session-redo",
+            "file-a",
+            &FileOutcome {
+                status: "failed".to_string(),
+                transcript_path: None,
+                json_path: None,
+                error: Some("worker crashed".to_string()),
+                audio_seconds: None,
+                processing_seconds: None,
+                retries: 0,
+            },
+        )
+        .expect("file record should update");
+        assert_eq!(failed_redo.failed, 1);
         assert_eq!(
-            session.files[1].error.as_deref(),
-            Some("decode failed"),
-            "failed item keeps error details"
+            failed_redo.files[0].transcript_path.as_deref(),
+            Some("/tmp/batch-transcripts/a.txt"),
+            "earlier transcript is still referenced"
         );
 
-        delete_session_with_path(Some(&db_path), "session-a")
-            .expect("session delete should succeed");
-        let remaining = get_sessions_with_path(Some(&db_path)).expect("history should reload");
-        assert!(remaining.is_empty());
+        let missing = update_session_file_with_path(
+            Some(&db_path),
+            "session-redo",
+            "file-missing",
+            &FileOutcome {
+                status: "success".to_string(),
+                transcript_path: None,
+                json_path: None,
+                error: None,
+                audio_seconds: None,
+                processing_seconds: None,
+                retries: 0,
+            },
+        )
+        .expect_err("unknown files should error");
+        assert_eq!(
+            missing,
+            "File file-missing not found in session session-redo"
+        );
+        assert_eq!(
+            load_session_with_path(Some(&db_path), "session-gone")
+                .expect_err("unknown sessions should error"),
+            "Session not found: session-gone"
+        );
+    }
+
+    #[test]
+    fn init_adds_tags_column_to_existing_sessions_table() {
+        let root = temp_root("parakeet-history-tags-migration");
+        let db_path = root.join("history.db");
+        std::fs::create_dir_all(&root).expect("root directory should exist");
+        {
+            let connection = Connection::open(&db_path).expect("legacy database should open");
+            connection
+                .execute_batch(
+                    "
+                    CREATE TABLE sessions (
+                        id TEXT PRIMARY KEY,
+                        created_at INTEGER NOT NULL,
+                        provider TEXT NOT NULL,
+                        model TEXT NOT NULL,
+                        output_dir TEXT NOT NULL,
+                        manifest_path TEXT NOT NULL,
+                        total INTEGER NOT NULL,
+                        processed INTEGER NOT NULL,
+                        skipped INTEGER NOT NULL,
+                        failed INTEGER NOT NULL,
+                        duration_seconds REAL NOT NULL,
+                        exit_code INTEGER NOT NULL,
+                        status TEXT NOT NULL
+                    );
+                    INSERT INTO sessions VALUES
+                        ('legacy', 0, 'coreml-local', 'v3', '/out', '/m.json', 1, 1, 0, 0, 1.0, 0, 'completed');
+                    ",
+                )
+                .expect("legacy schema should be created");
+        }
+
+        init_database(&db_path).expect("first init should migrate");
+        init_database(&db_path).expect("second init should be a no-op");
+
+        let sessions = get_sessions_with_path(Some(&db_path)).expect("history should load");
+        assert_eq!(sessions.len(), 1);
+        assert!(sessions[0].tags.is_empty());
+    }
+
+    #[test]
+    fn writes_retry_while_another_connection_holds_the_lock() {
+        let root = temp_root("parakeet-history-busy");
+        let db_path = root.join("history.db");
+        let timeout = Duration::from_millis(20);
+        let writer = init_database_with_timeout(&db_path, timeout).expect("writer should open");
+
+        let (locked_tx, locked_rx) = std::sync::mpsc::channel();
+        let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+        let holder_path = db_path.clone();
+        let holder = std::thread::spawn(move || {
+            let connection =
+                init_database_with_timeout(&holder_path, timeout).expect("holder should open");
+            let lock = begin_write(&connection, "lock transaction").expect("holder should lock");
+            locked_tx.send(()).expect("lock signal should send");
+            release_rx.recv().expect("release signal should arrive");
+            std::thread::sleep(Duration::from_millis(100));
+            lock.commit().expect("holder should release the lock");
+        });
+        locked_rx.recv().expect("holder should report the lock");
+
+        let error = begin_write_with_retry(&writer, "write", 2, Duration::from_millis(10))
+            .expect_err("write should give up while the lock is held");
+        assert_eq!(error, HISTORY_IN_USE_ERROR);
+
+        release_tx.send(()).expect("release signal should send");
+        // A single attempt would time out after 20ms; retries outlast the holder.
+        let transaction = begin_write_with_retry(&writer, "write", 50, Duration::from_millis(10))
+            .expect("write should succeed once the lock is released");
+        transaction.commit().expect("write should commit");
+        holder.join().expect("holder thread should finish");
+    }
+
+    #[test]
+    fn init_enables_wal_and_upgrades_rollback_journal_databases() {
+        let root = temp_root("parakeet-history-wal");
+        std::fs::create_dir_all(&root).expect("root should be created");
+        let db_path = root.join("history.db");
+        {
+            let legacy = Connection::open(&db_path).expect("legacy database should open");
+            let mode = legacy
+                .query_row("PRAGMA journal_mode = DELETE", [], |row| {
+                    row.get::<_, String>(0)
+                })
+                .expect("legacy journal mode should be set");
+            assert_eq!(mode, "delete");
+            legacy
+                .execute_batch("CREATE TABLE legacy (id INTEGER);")
+                .expect("legacy table should be created");
+        }
+
+        let connection = init_database(&db_path).expect("database should initialize");
+        let mode = connection
+            .query_row("PRAGMA journal_mode", [], |row| row.get::<_, String>(0))
+            .expect("journal mode should be readable");
+        assert_eq!(mode, "wal");
+        drop(connection);
+
+        let manifest_path = root.join("sessions").join("session-wal.json");
+        write_manifest(&manifest_path, "session-wal");
+        archive_session_with_path(
+            Some(&db_path),
+            &manifest_path,
+            "session-wal",
+            None,
+            0,
+            "completed",
+            &HashMap::new(),
+        )
+        .expect("session should be archived");
+
+        let checkpoint =
+            checkpoint_history_with_path(Some(&db_path)).expect("checkpoint should run");
+        assert!(!checkpoint.busy);
+        assert_eq!(checkpoint.log_frames, checkpoint.checkpointed_frames);
+        assert_eq!(
+            get_sessions_with_path(Some(&db_path))
+                .expect("history should load")
+                .len(),
+            1
+        );
     }
 
     #[test]
@@ -674,6 +3566,108 @@ mod tests {
             .all(|file| file.status == "cancelled"));
     }
 
+    #[test]
+    fn cancelled_sessions_keep_outcomes_of_files_finished_before_the_stop() {
+        let root = temp_root("parakeet-history-stop-partial");
+        let db_path = root.join("history.db");
+        let manifest_path = root.join("sessions").join("session-partial.json");
+        write_manifest_with_files(
+            &manifest_path,
+            "session-partial",
+            &[
+                PathBuf::from("/audio/a.wav"),
+                PathBuf::from("/audio/b.wav"),
+                PathBuf::from("/audio/c.wav"),
+            ],
+        );
+        let done = |name: &str| FileOutcome {
+            status: "success".to_string(),
+            transcript_path: Some(format!("/tmp/batch-transcripts/{}.txt", name)),
+            json_path: None,
+            error: None,
+            audio_seconds: Some(10.0),
+            processing_seconds: Some(2.0),
+            retries: 0,
+        };
+        let outcomes = HashMap::from([
+            ("/audio/a.wav".to_string(), done("a")),
+            ("/audio/b.wav".to_string(), done("b")),
+        ]);
+
+        let record = archive_cancelled_session_with_path(
+            Some(&db_path),
+            &manifest_path,
+            "session-partial",
+            -1,
+            "user",
+            &outcomes,
+        )
+        .expect("cancelled session should archive");
+        assert_eq!(record.status, "cancelled");
+        assert_eq!(record.processed, 2);
+
+        let loaded =
+            load_session_with_path(Some(&db_path), "session-partial").expect("session should load");
+        assert_eq!(
+            loaded
+                .files
+                .iter()
+                .map(|file| (file.name.as_str(), file.status.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("a.wav", "success"),
+                ("b.wav", "success"),
+                ("c.wav", "cancelled")
+            ]
+        );
+        assert_eq!(
+            loaded.files[0].transcript_path.as_deref(),
+            Some("/tmp/batch-transcripts/a.txt")
+        );
+    }
+
+    #[test]
+    fn cancelled_sessions_record_their_stop_reason() {
+        let root = temp_root("parakeet-history-stop-reason");
+        let db_path = root.join("history.db");
+
+        for (index, reason) in ["user", "timeout", "forced", "shutdown"].iter().enumerate() {
+            let session_id = format!("session-stop-{}", index);
+            let manifest_path = root.join("sessions").join(format!("{}.json", session_id));
+            write_manifest(&manifest_path, &session_id);
+            let record = archive_cancelled_session_with_path(
+                Some(&db_path),
+                &manifest_path,
+                &session_id,
+                -1,
+                reason,
+                &HashMap::new(),
+            )
+            .expect("cancelled session should archive");
+            assert_eq!(record.status, "cancelled");
+
+            let loaded =
+                load_session_with_path(Some(&db_path), &session_id).expect("session should load");
+            assert_eq!(loaded.stop_reason.as_deref(), Some(*reason));
+        }
+
+        let manifest_path = root.join("sessions").join("session-done.json");
+        write_manifest(&manifest_path, "session-done");
+        archive_session_with_path(
+            Some(&db_path),
+            &manifest_path,
+            "session-done",
+            None,
+            0,
+            "completed",
+            &HashMap::new(),
+        )
+        .expect("completed session should archive");
+        let completed =
+            load_session_with_path(Some(&db_path), "session-done").expect("session should load");
+        assert!(completed.stop_reason.is_none());
+    }
+
     #[test]
     fn failed_sessions_default_file_status_to_failed() {
         let root = temp_root("parakeet-history-failed");
@@ -698,4 +3692,388 @@ mod tests {
         assert_eq!(sessions[0].failed, 2);
         assert!(sessions[0].files.iter().all(|file| file.status == "failed"));
     }
+
+    #[test]
+    fn finds_manifests_without_a_final_history_row() {
+        let root = temp_root("parakeet-history-stale");
+        let db_path = root.join("history.db");
+        let sessions_dir = root.join("sessions");
+        for id in [
+            "session-done",
+            "session-crashed",
+            "session-checkpointed",
+            "session-live",
+        ] {
+            write_manifest(&sessions_dir.join(format!("{}.json", id)), id);
+        }
+        std::fs::write(sessions_dir.join("notes.txt"), "not a manifest")
+            .expect("stray file should be written");
+        archive_session_with_path(
+            Some(&db_path),
+            &sessions_dir.join("session-done.json"),
+            "session-done",
+            None,
+            0,
+            "completed",
+            &HashMap::new(),
+        )
+        .expect("finished session should archive");
+        let mut outcomes = HashMap::new();
+        outcomes.insert(
+            "/audio/a.wav".to_string(),
+            FileOutcome {
+                status: "success".to_string(),
+                transcript_path: Some("/tmp/batch-transcripts/a.txt".to_string()),
+                json_path: None,
+                error: None,
+                audio_seconds: Some(4.0),
+                processing_seconds: None,
+                retries: 0,
+            },
+        );
+        archive_session_with_path(
+            Some(&db_path),
+            &sessions_dir.join("session-checkpointed.json"),
+            "session-checkpointed",
+            None,
+            -1,
+            "running",
+            &outcomes,
+        )
+        .expect("checkpoint should archive");
+
+        let stale = find_stale_sessions_in(Some(&db_path), &sessions_dir, Some("session-live"))
+            .expect("stale sessions should load");
+        assert_eq!(
+            stale
+                .iter()
+                .map(|session| (session.session_id.as_str(), session.checkpointed))
+                .collect::<Vec<_>>(),
+            vec![("session-checkpointed", true), ("session-crashed", false)]
+        );
+        assert_eq!(stale[1].total, 2);
+
+        assert!(resolve_stale_session_in(
+            Some(&db_path),
+            &sessions_dir,
+            "session-done",
+            true,
+            None
+        )
+        .is_err_and(|error| error.contains("not stale")));
+        assert!(sessions_dir.join("session-done.json").exists());
+        assert!(resolve_stale_session_in(
+            Some(&db_path),
+            &sessions_dir,
+            "session-live",
+            true,
+            Some("session-live")
+        )
+        .is_err_and(|error| error.contains("still running")));
+        assert!(sessions_dir.join("session-live.json").exists());
+
+        resolve_stale_session_in(
+            Some(&db_path),
+            &sessions_dir,
+            "session-crashed",
+            false,
+            None,
+        )
+        .expect("crashed session should reindex");
+        resolve_stale_session_in(
+            Some(&db_path),
+            &sessions_dir,
+            "session-checkpointed",
+            false,
+            None,
+        )
+        .expect("checkpointed session should reindex");
+        let reindexed = load_session_with_path(Some(&db_path), "session-checkpointed")
+            .expect("reindexed session should load");
+        assert_eq!(reindexed.status, "cancelled");
+        assert_eq!(reindexed.stop_reason.as_deref(), Some("interrupted"));
+        assert_eq!(reindexed.processed, 1);
+        assert!(
+            find_stale_sessions_in(Some(&db_path), &sessions_dir, Some("session-live"))
+                .expect("stale sessions should load")
+                .is_empty()
+        );
+
+        resolve_stale_session_in(Some(&db_path), &sessions_dir, "session-live", true, None)
+            .expect("stale session should discard");
+        assert!(!sessions_dir.join("session-live.json").exists());
+        assert!(find_stale_sessions_in(Some(&db_path), &sessions_dir, None)
+            .expect("stale sessions should load")
+            .is_empty());
+        assert!(
+            resolve_stale_session_in(Some(&db_path), &sessions_dir, "../x", true, None).is_err()
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn last_session_summary_reports_the_newest_finished_session() {
+        let root = temp_root("parakeet-history-last-summary");
+        let db_path = root.join("history.db");
+        assert_eq!(last_session_summary_with_path(Some(&db_path)), Ok(None));
+
+        for (session_id, status, created_at) in [
+            ("session-older", "completed", 100),
+            ("session-latest", "failed", 300),
+            ("session-middle", "completed", 200),
+            ("session-running", "running", 400),
+        ] {
+            archive_fixture_session(
+                &db_path,
+                session_id,
+                created_at,
+                status,
+                None,
+                Some(SessionSummarySnapshot {
+                    total: 2,
+                    processed: 1,
+                    skipped: 0,
+                    failed: 1,
+                    duration_seconds: 12.5,
+                }),
+            );
+        }
+
+        let summary = last_session_summary_with_path(Some(&db_path))
+            .expect("summary should load")
+            .expect("history has finished sessions");
+        assert_eq!(
+            summary,
+            LastSessionSummary {
+                session_id: "session-latest".to_string(),
+                created_at: 300,
+                total: 2,
+                processed: 1,
+                skipped: 0,
+                failed: 1,
+                duration_seconds: 12.5,
+                status: "failed".to_string(),
+            }
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn recent_output_dirs_are_distinct_newest_first_and_still_on_disk() {
+        let root = temp_root("parakeet-history-recent-dirs");
+        let db_path = root.join("history.db");
+        let kept_old = root.join("out-old");
+        let kept_new = root.join("out-new");
+        let removed = root.join("out-removed");
+        for dir in [&kept_old, &kept_new] {
+            std::fs::create_dir_all(dir).expect("output dir should be created");
+        }
+
+        for (session_id, output_dir, created_at) in [
+            ("session-1", &kept_old, 100),
+            ("session-2", &kept_new, 200),
+            ("session-3", &removed, 300),
+            ("session-4", &kept_old, 400),
+            ("session-5", &kept_new, 150),
+        ] {
+            archive_fixture_session(
+                &db_path,
+                session_id,
+                created_at,
+                "completed",
+                Some(output_dir),
+                None,
+            );
+        }
+
+        let recent =
+            recent_output_dirs_with_path(Some(&db_path), 10).expect("recent dirs should load");
+        assert_eq!(
+            recent,
+            vec![
+                kept_old.to_string_lossy().to_string(),
+                kept_new.to_string_lossy().to_string(),
+            ]
+        );
+        assert_eq!(
+            recent_output_dirs_with_path(Some(&db_path), 1)
+                .expect("recent dirs should load")
+                .len(),
+            1
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn legacy_provider_ids_are_migrated_and_match_the_canonical_filter() {
+        let root = temp_root("parakeet-history-provider-ids");
+        let db_path = root.join("history.db");
+        let manifest_path = root.join("sessions").join("session-legacy.json");
+        write_manifest(&manifest_path, "session-legacy");
+        archive_session_with_path(
+            Some(&db_path),
+            &manifest_path,
+            "session-legacy",
+            None,
+            0,
+            "completed",
+            &HashMap::new(),
+        )
+        .expect("session should archive");
+
+        let connection = Connection::open(&db_path).expect("history should open");
+        connection
+            .execute(
+                "UPDATE sessions SET provider = ? WHERE id = 'session-legacy'",
+                params![LEGACY_COREML_PROVIDER_ID],
+            )
+            .expect("legacy provider should be written");
+        // Opening again does not rescan: the migration already ran when the database was created.
+        init_database(&db_path).expect("history should reopen");
+        assert_eq!(
+            connection
+                .query_row(
+                    "SELECT provider FROM sessions WHERE id = 'session-legacy'",
+                    [],
+                    |row| row.get::<_, String>(0),
+                )
+                .expect("provider should load"),
+            LEGACY_COREML_PROVIDER_ID
+        );
+        assert_eq!(migrate_provider_ids_in(&connection), Ok(1));
+        assert_eq!(migrate_provider_ids_in(&connection), Ok(0));
+        drop(connection);
+
+        let session =
+            load_session_with_path(Some(&db_path), "session-legacy").expect("session should load");
+        assert_eq!(session.provider, "coreml-local");
+        let by_provider = SessionDeleteFilter {
+            provider: Some("coreml-local".to_string()),
+            ..SessionDeleteFilter::default()
+        };
+        assert_eq!(
+            delete_sessions_with_path(Some(&db_path), &by_provider),
+            Ok(1)
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn delete_sessions_removes_only_matching_sessions_and_their_files() {
+        let root = temp_root("parakeet-history-delete-filter");
+        let db_path = root.join("history.db");
+        for (session_id, status, created_at) in [
+            ("session-old-failed", "failed", 100),
+            ("session-old-done", "completed", 200),
+            ("session-new-failed", "failed", 900),
+            ("session-new-done", "completed", 1000),
+        ] {
+            archive_fixture_session(&db_path, session_id, created_at, status, None, None);
+        }
+        let remaining = || {
+            let mut ids = get_sessions_with_path(Some(&db_path))
+                .expect("sessions should list")
+                .into_iter()
+                .map(|session| session.id)
+                .collect::<Vec<_>>();
+            ids.sort();
+            ids
+        };
+
+        assert!(
+            delete_sessions_with_path(Some(&db_path), &SessionDeleteFilter::default())
+                .expect_err("an empty filter must not delete everything")
+                .contains("Refusing")
+        );
+        assert_eq!(remaining().len(), 4);
+
+        let by_status = SessionDeleteFilter {
+            status: Some("failed".to_string()),
+            ..SessionDeleteFilter::default()
+        };
+        assert_eq!(delete_sessions_with_path(Some(&db_path), &by_status), Ok(2));
+        assert_eq!(remaining(), vec!["session-new-done", "session-old-done"]);
+
+        let by_date = SessionDeleteFilter {
+            created_before: Some(500),
+            ..SessionDeleteFilter::default()
+        };
+        assert_eq!(delete_sessions_with_path(Some(&db_path), &by_date), Ok(1));
+        assert_eq!(remaining(), vec!["session-new-done"]);
+
+        let orphaned_files: i64 = open_database(Some(&db_path))
+            .expect("history should open")
+            .query_row(
+                "SELECT COUNT(*) FROM session_files WHERE session_id != 'session-new-done'",
+                [],
+                |row| row.get(0),
+            )
+            .expect("file rows should count");
+        assert_eq!(orphaned_files, 0);
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn slowest_files_orders_by_processing_time() {
+        let root = temp_root("parakeet-history-slowest");
+        let db_path = root.join("history.db");
+        let outcome = |processing_seconds: Option<f64>| FileOutcome {
+            status: "success".to_string(),
+            transcript_path: None,
+            json_path: None,
+            error: None,
+            audio_seconds: None,
+            processing_seconds,
+            retries: 0,
+        };
+        for (session_id, first, second) in [
+            ("session-a", Some(12.5), Some(3.0)),
+            ("session-b", Some(40.0), None),
+        ] {
+            let manifest_path = root.join("sessions").join(format!("{}.json", session_id));
+            write_manifest(&manifest_path, session_id);
+            let outcomes = HashMap::from([
+                ("/audio/a.wav".to_string(), outcome(first)),
+                ("/audio/b.wav".to_string(), outcome(second)),
+            ]);
+            archive_session_with_path(
+                Some(&db_path),
+                &manifest_path,
+                session_id,
+                None,
+                0,
+                "completed",
+                &outcomes,
+            )
+            .expect("session should archive");
+        }
+
+        let slowest =
+            slowest_files_with_path(Some(&db_path), 2).expect("slowest files should load");
+        assert_eq!(
+            slowest
+                .iter()
+                .map(|file| (
+                    file.session_id.as_str(),
+                    file.name.as_str(),
+                    file.processing_seconds
+                ))
+                .collect::<Vec<_>>(),
+            vec![("session-b", "a.wav", 40.0), ("session-a", "a.wav", 12.5)]
+        );
+        assert_eq!(
+            slowest_files_with_path(Some(&db_path), 10)
+                .expect("slowest files should load")
+                .len(),
+            3,
+            "files without a recorded time are left out"
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
 }
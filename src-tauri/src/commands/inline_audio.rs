@@ -0,0 +1,414 @@
+use crate::commands::history::FileOutcome;
+use crate::commands::scan::SUPPORTED_EXTENSIONS;
+use crate::providers::launcher::{
+    advertised_worker_protocol, capped_lines, command_args_for_runtime, parse_fatal_error,
+    parse_file_outcome, running_session_count,
+};
+use crate::providers::manifest::{
+    write_manifest_atomic, FileEntry, SessionManifest, TranscriptionSettings,
+};
+use crate::providers::registry::{self, ProviderRuntime};
+use crate::providers::resolver::{self, ProviderSettings};
+use base64::Engine;
+use chrono::{SecondsFormat, Utc};
+use std::collections::VecDeque;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+use uuid::Uuid;
+
+const MAX_INLINE_AUDIO_BYTES: usize = 100 * 1024 * 1024;
+const INLINE_AUDIO_FILE_ID: &str = "inline-audio";
+// Generous for a 100 MB clip on the slowest provider; a worker past this is treated as hung.
+const INLINE_TRANSCRIPTION_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+const INLINE_EXIT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const STDERR_TAIL_LINES: usize = 20;
+
+static INLINE_RUNNING: AtomicBool = AtomicBool::new(false);
+
+// Holds the single inline slot for one call and frees it however the call ends.
+struct InlineSlot;
+
+impl InlineSlot {
+    fn claim() -> Result<Self, String> {
+        INLINE_RUNNING
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .map_err(|_| "Another inline transcription is already running".to_string())?;
+        let slot = Self;
+        // The count includes this call's own slot.
+        if running_session_count()? > 1 {
+            return Err(
+                "A transcription session is running; try again when it finishes".to_string(),
+            );
+        }
+        Ok(slot)
+    }
+}
+
+impl Drop for InlineSlot {
+    fn drop(&mut self) {
+        INLINE_RUNNING.store(false, Ordering::SeqCst);
+    }
+}
+
+pub(crate) fn inline_transcription_running() -> bool {
+    INLINE_RUNNING.load(Ordering::SeqCst)
+}
+
+#[derive(Debug, Default)]
+struct InlineWorkerReport {
+    outcome: Option<FileOutcome>,
+    fatal_error: Option<String>,
+}
+
+// Owns the scratch folder for one call; dropping it removes the audio, manifest and outputs.
+struct InlineAudio {
+    root: PathBuf,
+    audio_path: PathBuf,
+}
+
+impl Drop for InlineAudio {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.root);
+    }
+}
+
+fn inline_audio_extension(format: &str) -> Result<String, String> {
+    let extension = format.trim().trim_start_matches('.').to_ascii_lowercase();
+    if !SUPPORTED_EXTENSIONS.contains(&extension.as_str()) {
+        return Err(format!("Unsupported audio format: {}", format.trim()));
+    }
+    Ok(extension)
+}
+
+fn decode_inline_audio(audio_base64: &str, max_bytes: usize) -> Result<Vec<u8>, String> {
+    let encoded = audio_base64.trim();
+    if encoded.is_empty() {
+        return Err("No audio bytes provided".to_string());
+    }
+    let too_large = || {
+        format!(
+            "Audio payload exceeds the {} byte limit for inline transcription",
+            max_bytes
+        )
+    };
+    // Reject from the encoded length so an oversized payload is never decoded into memory.
+    if encoded.len() / 4 * 3 > max_bytes.saturating_add(2) {
+        return Err(too_large());
+    }
+
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|error| format!("Failed to decode audio bytes: {}", error))?;
+    if bytes.len() > max_bytes {
+        return Err(too_large());
+    }
+    Ok(bytes)
+}
+
+fn write_inline_audio(
+    bytes: &[u8],
+    extension: &str,
+    scratch_root: &Path,
+) -> Result<InlineAudio, String> {
+    let root = scratch_root.join(format!("inline-audio-{}", Uuid::new_v4()));
+    std::fs::create_dir_all(root.join("out")).map_err(|error| {
+        format!(
+            "Failed to create scratch directory {}: {}",
+            root.display(),
+            error
+        )
+    })?;
+    let audio = InlineAudio {
+        audio_path: root.join(format!("audio.{}", extension)),
+        root,
+    };
+    std::fs::write(&audio.audio_path, bytes).map_err(|error| {
+        format!(
+            "Failed to write audio file {}: {}",
+            audio.audio_path.display(),
+            error
+        )
+    })?;
+    Ok(audio)
+}
+
+// Keeps only what the result needs, so a chatty worker never grows memory past one line.
+fn read_worker_report(
+    lines: impl Iterator<Item = String>,
+    audio_path: &Path,
+) -> InlineWorkerReport {
+    let audio_path = audio_path.to_string_lossy();
+    let mut report = InlineWorkerReport::default();
+    for value in lines.filter_map(|line| serde_json::from_str(line.trim()).ok()) {
+        if let Some(error) = parse_fatal_error(&value) {
+            report.fatal_error = Some(error);
+        }
+        if let Some((file, outcome)) = parse_file_outcome(&value) {
+            if file == audio_path {
+                report.outcome = Some(outcome);
+            }
+        }
+    }
+    report
+}
+
+fn transcript_from_report(
+    report: InlineWorkerReport,
+    exit_status: &str,
+    stderr_tail: &[String],
+) -> Result<String, String> {
+    let Some(outcome) = report.outcome else {
+        if let Some(error) = report.fatal_error {
+            return Err(format!("Worker failed ({}): {}", exit_status, error));
+        }
+        let mut message = format!(
+            "Worker {} without reporting a result for the audio",
+            exit_status
+        );
+        if !stderr_tail.is_empty() {
+            message.push_str(&format!(":\n{}", stderr_tail.join("\n")));
+        }
+        return Err(message);
+    };
+
+    if outcome.status != "success" {
+        return Err(outcome
+            .error
+            .unwrap_or_else(|| format!("Transcription {}", outcome.status)));
+    }
+    let transcript_path = outcome
+        .transcript_path
+        .ok_or_else(|| "Worker reported no transcript file for the audio".to_string())?;
+    std::fs::read_to_string(&transcript_path)
+        .map_err(|error| format!("Failed to read transcript {}: {}", transcript_path, error))
+}
+
+// The manifest lives in the scratch folder, so the run never shows up as a session or in history.
+fn run_inline_transcription(
+    runtime: &ProviderRuntime,
+    audio: &InlineAudio,
+    provider: &str,
+    model: &str,
+    extension: &str,
+) -> Result<String, String> {
+    let output_dir = audio.root.join("out");
+    let manifest = SessionManifest {
+        session_id: Uuid::new_v4().to_string(),
+        created_at: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+        provider: provider.to_string(),
+        model: model.to_string(),
+        output_dir: output_dir.clone(),
+        settings: TranscriptionSettings {
+            extensions: vec![extension.to_string()],
+            ..TranscriptionSettings::default()
+        },
+        files: vec![FileEntry {
+            id: INLINE_AUDIO_FILE_ID.to_string(),
+            path: audio.audio_path.clone(),
+            status: "queued".to_string(),
+        }],
+        parent_session_id: None,
+    };
+    let manifest_path = write_manifest_atomic(&manifest, &audio.root)?;
+    let launch = command_args_for_runtime(
+        runtime,
+        &manifest_path,
+        &output_dir,
+        &[],
+        advertised_worker_protocol(runtime),
+    )?;
+
+    let mut child = Command::new(&launch.program)
+        .args(&launch.args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| format!("Failed to launch worker: {}", error))?;
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| "Failed to capture worker stdout".to_string())?;
+    let stderr = child
+        .stderr
+        .take()
+        .ok_or_else(|| "Failed to capture worker stderr".to_string())?;
+    let audio_path = audio.audio_path.clone();
+    let stdout_reader = std::thread::spawn(move || {
+        read_worker_report(capped_lines(BufReader::new(stdout)), &audio_path)
+    });
+    let stderr_reader = std::thread::spawn(move || {
+        let mut tail = VecDeque::with_capacity(STDERR_TAIL_LINES);
+        for line in capped_lines(BufReader::new(stderr)) {
+            if tail.len() == STDERR_TAIL_LINES {
+                tail.pop_front();
+            }
+            tail.push_back(line);
+        }
+        Vec::from(tail)
+    });
+
+    let status = wait_with_timeout(&mut child, INLINE_TRANSCRIPTION_TIMEOUT)?;
+    let report = stdout_reader.join().unwrap_or_default();
+    let stderr_tail = stderr_reader.join().unwrap_or_default();
+    let exit_status = match status.and_then(|status| status.code()) {
+        Some(code) => format!("exited with status {}", code),
+        None if status.is_some() => "was killed by a signal".to_string(),
+        None => {
+            return Err(format!(
+                "Inline transcription timed out after {} seconds",
+                INLINE_TRANSCRIPTION_TIMEOUT.as_secs()
+            ))
+        }
+    };
+    transcript_from_report(report, &exit_status, &stderr_tail)
+}
+
+// Returns `None` after killing a worker that outlived `timeout`.
+fn wait_with_timeout(
+    child: &mut std::process::Child,
+    timeout: Duration,
+) -> Result<Option<ExitStatus>, String> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child
+            .try_wait()
+            .map_err(|error| format!("Failed to wait for worker: {}", error))?
+        {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Ok(None);
+        }
+        std::thread::sleep(INLINE_EXIT_POLL_INTERVAL);
+    }
+}
+
+#[tauri::command]
+pub async fn transcribe_bytes(
+    app: AppHandle,
+    audio_base64: String,
+    format: String,
+    provider: String,
+    model: String,
+) -> Result<String, String> {
+    let extension = inline_audio_extension(&format)?;
+    let bytes = decode_inline_audio(&audio_base64, MAX_INLINE_AUDIO_BYTES)?;
+
+    let provider = if provider == registry::AUTO_PROVIDER_ID {
+        registry::resolve_best_provider(&app, &model)?.provider_id
+    } else {
+        provider
+    };
+    let settings = ProviderSettings {
+        swift_binary_override: Some(registry::resolve_swift_binary_path(&app)),
+        models_root_override: Some(registry::default_models_root()),
+        check_availability: true,
+    };
+    let runtime = resolver::resolve_provider(&provider, &model, &settings)
+        .map_err(|error| error.to_string())?;
+
+    let slot = InlineSlot::claim()?;
+    tokio::task::spawn_blocking(move || {
+        let _slot = slot;
+        let audio = write_inline_audio(&bytes, &extension, &std::env::temp_dir())?;
+        run_inline_transcription(&runtime, &audio, &provider, &model, &extension)
+    })
+    .await
+    .map_err(|error| format!("Failed to run inline transcription: {}", error))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::engine::general_purpose::STANDARD;
+
+    #[test]
+    fn inline_audio_is_written_with_its_extension_and_removed_on_drop() {
+        let scratch = std::env::temp_dir().join(format!("inline-audio-test-{}", Uuid::new_v4()));
+        let bytes = decode_inline_audio(&STANDARD.encode(b"RIFF....WAVE"), 1024)
+            .expect("payload should decode");
+        let extension = inline_audio_extension(".WAV").expect("wav is supported");
+
+        let audio = write_inline_audio(&bytes, &extension, &scratch).expect("audio should write");
+        assert_eq!(
+            audio
+                .audio_path
+                .extension()
+                .and_then(|value| value.to_str()),
+            Some("wav")
+        );
+        assert_eq!(
+            std::fs::read(&audio.audio_path).expect("audio should read"),
+            b"RIFF....WAVE"
+        );
+
+        let transcript = audio.root.join("out").join("audio.txt");
+        std::fs::write(&transcript, "hello there").expect("transcript should write");
+        let stdout = format!(
+            "{{\"event\":\"file_done\",\"file\":\"{}\",\"output\":{{\"txt\":\"{}\"}}}}\n",
+            audio.audio_path.display(),
+            transcript.display()
+        );
+        let report = read_worker_report(stdout.lines().map(str::to_string), &audio.audio_path);
+        assert_eq!(
+            transcript_from_report(report, "exited with status 0", &[]),
+            Ok("hello there".to_string())
+        );
+
+        let root = audio.root.clone();
+        drop(audio);
+        assert!(!root.exists());
+        let _ = std::fs::remove_dir_all(scratch);
+    }
+
+    #[test]
+    fn reports_fatal_errors_exit_status_and_hung_workers() {
+        let audio_path = Path::new("/tmp/inline/audio.wav");
+        let fatal = read_worker_report(
+            ["{\"event\":\"fatal_error\",\"error\":\"model missing\"}".to_string()].into_iter(),
+            audio_path,
+        );
+        assert_eq!(
+            transcript_from_report(fatal, "exited with status 2", &[]),
+            Err("Worker failed (exited with status 2): model missing".to_string())
+        );
+
+        let silent = read_worker_report(std::iter::empty(), audio_path);
+        assert_eq!(
+            transcript_from_report(silent, "exited with status 139", &["Segfault".to_string()]),
+            Err(
+                "Worker exited with status 139 without reporting a result for the audio:\nSegfault"
+                    .to_string()
+            )
+        );
+
+        let mut sleeper = Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .expect("sleep should spawn");
+        let started = Instant::now();
+        assert_eq!(
+            wait_with_timeout(&mut sleeper, Duration::from_millis(200)).expect("wait should work"),
+            None
+        );
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn rejects_oversized_or_unsupported_inline_audio() {
+        let error = decode_inline_audio(&STANDARD.encode(vec![0_u8; 4096]), 1024)
+            .expect_err("oversized payloads are rejected");
+        assert!(error.contains("1024 byte limit"), "{error}");
+        assert!(decode_inline_audio(&STANDARD.encode(vec![0_u8; 1024]), 1024).is_ok());
+        assert!(decode_inline_audio("not base64!", 1024).is_err());
+        assert!(decode_inline_audio("  ", 1024).is_err());
+        assert!(inline_audio_extension("exe").is_err());
+    }
+}
@@ -0,0 +1,268 @@
+use crate::providers::launcher::{
+    launch_command_for_runtime, model_args_for_runtime, parse_fatal_error,
+};
+use crate::providers::manifest::QueueItem;
+use crate::providers::registry::{self, Provider, ProviderRuntime};
+use crate::providers::resolver::{self, ProviderSettings};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::Command;
+use tauri::AppHandle;
+
+// The Python workers answer this flag with one `language_detected` line per file and write no
+// transcripts; the CoreML worker does not advertise `language_detection`, so it never gets here.
+const DETECT_LANGUAGE_FLAG: &str = "--detect-language";
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LanguageDetection {
+    pub id: String,
+    pub path: String,
+    pub language: Option<String>,
+    pub confidence: Option<f64>,
+    pub error: Option<String>,
+}
+
+fn ensure_language_detection(provider: &Provider) -> Result<(), String> {
+    if !provider.available {
+        return Err(format!("Provider is unavailable: {}", provider.id));
+    }
+    let supported = provider
+        .capabilities
+        .as_ref()
+        .and_then(|capabilities| capabilities.language_detection)
+        .unwrap_or(false);
+    if !supported {
+        return Err(format!(
+            "Provider {} does not support language detection",
+            provider.id
+        ));
+    }
+    Ok(())
+}
+
+fn detection_for(item: &QueueItem, event: Option<&Value>) -> LanguageDetection {
+    let text = |key: &str| {
+        event
+            .and_then(|event| event.get(key))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+    let language = text("language");
+    let error = match event
+        .and_then(|event| event.get("event"))
+        .and_then(Value::as_str)
+    {
+        Some("file_failed") => {
+            Some(text("error").unwrap_or_else(|| "Detection failed".to_string()))
+        }
+        _ if language.is_none() => Some("Worker reported no language for this file".to_string()),
+        _ => None,
+    };
+
+    LanguageDetection {
+        id: item.id.clone(),
+        path: item.path.to_string_lossy().to_string(),
+        language,
+        confidence: event
+            .and_then(|event| event.get("language_probability"))
+            .and_then(Value::as_f64)
+            .map(|probability| probability.clamp(0.0, 1.0)),
+        error,
+    }
+}
+
+fn assemble_detections(
+    items: &[QueueItem],
+    detect: impl FnOnce(&[PathBuf]) -> Result<Vec<Value>, String>,
+) -> Result<Vec<LanguageDetection>, String> {
+    let paths = items
+        .iter()
+        .map(|item| item.path.clone())
+        .collect::<Vec<PathBuf>>();
+    let events = detect(&paths)?;
+    // A worker that dies before detecting anything would otherwise read as "no language" per file.
+    if let Some(error) = events.iter().find_map(parse_fatal_error) {
+        return Err(format!("Language detection failed: {}", error));
+    }
+
+    let mut by_file = HashMap::new();
+    for event in &events {
+        let relevant = matches!(
+            event.get("event").and_then(Value::as_str),
+            Some("language_detected" | "file_failed")
+        );
+        if let (true, Some(file)) = (relevant, event.get("file").and_then(Value::as_str)) {
+            by_file.insert(file.to_string(), event);
+        }
+    }
+
+    Ok(items
+        .iter()
+        .map(|item| {
+            let event = by_file.get(item.path.to_string_lossy().as_ref()).copied();
+            detection_for(item, event)
+        })
+        .collect())
+}
+
+fn run_detector(program: &str, args: &[String], paths: &[PathBuf]) -> Result<Vec<Value>, String> {
+    let output = Command::new(program)
+        .args(args)
+        .arg(DETECT_LANGUAGE_FLAG)
+        .args(paths)
+        .output()
+        .map_err(|error| format!("Failed to launch language detection: {}", error))?;
+
+    let events = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line.trim()).ok())
+        .collect::<Vec<Value>>();
+    if !output.status.success() && events.is_empty() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!(
+            "Language detection failed: {}",
+            stderr
+                .lines()
+                .last()
+                .unwrap_or("worker exited with an error")
+        ));
+    }
+    Ok(events)
+}
+
+#[tauri::command]
+pub async fn detect_languages(
+    app: AppHandle,
+    items: Vec<QueueItem>,
+    provider: String,
+    model: String,
+) -> Result<Vec<LanguageDetection>, String> {
+    if items.is_empty() {
+        return Err("No queue items provided".to_string());
+    }
+
+    let provider = if provider == registry::AUTO_PROVIDER_ID {
+        registry::resolve_best_provider(&app, &model)?.provider_id
+    } else {
+        provider
+    };
+    ensure_language_detection(&registry::probe_provider(&app, &provider)?)?;
+
+    let settings = ProviderSettings {
+        swift_binary_override: Some(registry::resolve_swift_binary_path(&app)),
+        models_root_override: Some(registry::default_models_root()),
+        check_availability: true,
+    };
+    let runtime = resolver::resolve_provider(&provider, &model, &settings)
+        .map_err(|error| error.to_string())?;
+    let mut launch = launch_command_for_runtime(&runtime)
+        .ok_or_else(|| "Cloud API providers do not support local language detection".to_string())?;
+    launch.args.extend(model_args_for_runtime(&runtime));
+    // Python workers normally read the model from the manifest, which detect-only mode skips.
+    if matches!(runtime, ProviderRuntime::PythonUv { .. }) {
+        launch.args.extend(["--model".to_string(), model]);
+    }
+
+    tokio::task::spawn_blocking(move || {
+        assemble_detections(&items, |paths| {
+            run_detector(&launch.program, &launch.args, paths)
+        })
+    })
+    .await
+    .map_err(|error| format!("Failed to run language detection: {}", error))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::registry::Capabilities;
+    use serde_json::json;
+
+    fn item(id: &str, path: &str) -> QueueItem {
+        QueueItem {
+            id: id.to_string(),
+            path: PathBuf::from(path),
+            status: "queued".to_string(),
+        }
+    }
+
+    #[test]
+    fn assembles_per_item_languages_from_detector_events() {
+        let items = vec![
+            item("a", "/audio/a.wav"),
+            item("b", "/audio/b.wav"),
+            item("c", "/audio/c.wav"),
+        ];
+        let detections = assemble_detections(&items, |paths| {
+            assert_eq!(paths.len(), 3);
+            Ok(vec![
+                json!({"event": "started"}),
+                json!({"event": "language_detected", "file": "/audio/b.wav", "language": "de", "language_probability": 0.82}),
+                json!({"event": "language_detected", "file": "/audio/a.wav", "language": "en", "language_probability": 1.4}),
+                json!({"event": "file_failed", "file": "/audio/c.wav", "error": "decode failed"}),
+            ])
+        })
+        .expect("detections should assemble");
+
+        assert_eq!(
+            detections
+                .iter()
+                .map(|detection| (
+                    detection.id.as_str(),
+                    detection.language.as_deref(),
+                    detection.confidence,
+                    detection.error.as_deref()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("a", Some("en"), Some(1.0), None),
+                ("b", Some("de"), Some(0.82), None),
+                ("c", None, None, Some("decode failed")),
+            ]
+        );
+
+        let missing = assemble_detections(&items[..1], |_| Ok(Vec::new()))
+            .expect("detections should assemble");
+        assert!(missing[0].error.is_some());
+        assert!(assemble_detections(&items, |_| Err("boom".to_string())).is_err());
+        assert_eq!(
+            assemble_detections(&items, |_| Ok(vec![
+                json!({"event": "fatal_error", "error": "model not found"})
+            ])),
+            Err("Language detection failed: model not found".to_string())
+        );
+    }
+
+    #[test]
+    fn requires_the_language_detection_capability() {
+        let mut provider = Provider {
+            id: "whisper-openai".to_string(),
+            name: "Whisper".to_string(),
+            runtime: ProviderRuntime::CloudAPI {
+                base_url: String::new(),
+                requires_key: false,
+            },
+            available: true,
+            capabilities: Some(Capabilities {
+                language_detection: Some(false),
+                ..Capabilities::default()
+            }),
+            install_instructions: None,
+        };
+        assert!(ensure_language_detection(&provider)
+            .expect_err("unsupported providers are rejected")
+            .contains("does not support language detection"));
+
+        provider.capabilities = Some(Capabilities {
+            language_detection: Some(true),
+            ..Capabilities::default()
+        });
+        assert!(ensure_language_detection(&provider).is_ok());
+
+        provider.available = false;
+        assert!(ensure_language_detection(&provider).is_err());
+    }
+}
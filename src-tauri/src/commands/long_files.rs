@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+
+// Workers hold a whole file's features in memory; past a few hours that gets risky.
+pub const DEFAULT_LONG_FILE_THRESHOLD_SECONDS: f64 = 3.0 * 60.0 * 60.0;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DurationItem {
+    pub id: String,
+    #[serde(default)]
+    pub duration: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LongFile {
+    pub id: String,
+    pub duration_seconds: f64,
+    pub recommendation: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LongFileReport {
+    pub threshold_seconds: f64,
+    pub flagged: Vec<LongFile>,
+    pub unknown_item_ids: Vec<String>,
+}
+
+fn format_hours_minutes(seconds: f64) -> String {
+    let minutes = (seconds / 60.0).round() as u64;
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("{} min", minutes),
+        (hours, 0) => format!("{} h", hours),
+        (hours, minutes) => format!("{} h {} min", hours, minutes),
+    }
+}
+
+fn split_recommendation(duration_seconds: f64, threshold_seconds: f64) -> String {
+    let parts = (duration_seconds / threshold_seconds).ceil().max(2.0) as u64;
+    format!(
+        "Runs {}, longer than the {} limit; split it into {} parts of about {} before transcribing.",
+        format_hours_minutes(duration_seconds),
+        format_hours_minutes(threshold_seconds),
+        parts,
+        format_hours_minutes(duration_seconds / parts as f64)
+    )
+}
+
+fn flag(items: &[DurationItem], threshold_seconds: f64) -> LongFileReport {
+    let mut flagged = Vec::new();
+    let mut unknown_item_ids = Vec::new();
+    for item in items {
+        match item
+            .duration
+            .filter(|duration| duration.is_finite() && *duration > 0.0)
+        {
+            Some(duration) if duration > threshold_seconds => flagged.push(LongFile {
+                id: item.id.clone(),
+                duration_seconds: duration,
+                recommendation: split_recommendation(duration, threshold_seconds),
+            }),
+            Some(_) => {}
+            None => unknown_item_ids.push(item.id.clone()),
+        }
+    }
+
+    LongFileReport {
+        threshold_seconds,
+        flagged,
+        unknown_item_ids,
+    }
+}
+
+#[tauri::command]
+pub fn flag_long_files(
+    items: Vec<DurationItem>,
+    threshold_seconds: Option<f64>,
+) -> Result<LongFileReport, String> {
+    let threshold_seconds = threshold_seconds.unwrap_or(DEFAULT_LONG_FILE_THRESHOLD_SECONDS);
+    if !threshold_seconds.is_finite() || threshold_seconds <= 0.0 {
+        return Err("Long file threshold must be a positive number of seconds".to_string());
+    }
+
+    Ok(flag(&items, threshold_seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: &str, duration: Option<f64>) -> DurationItem {
+        DurationItem {
+            id: id.to_string(),
+            duration,
+        }
+    }
+
+    #[test]
+    fn flags_files_over_the_threshold_and_reports_unknown_durations() {
+        let items = vec![
+            item("short", Some(45.0 * 60.0)),
+            item("six-hours", Some(6.0 * 3600.0)),
+            item("at-limit", Some(DEFAULT_LONG_FILE_THRESHOLD_SECONDS)),
+            item("unprobed", None),
+            item("broken", Some(f64::NAN)),
+            item(
+                "slightly-over",
+                Some(DEFAULT_LONG_FILE_THRESHOLD_SECONDS + 600.0),
+            ),
+        ];
+
+        let report = flag_long_files(items.clone(), None).expect("default threshold is valid");
+        assert_eq!(
+            report.threshold_seconds,
+            DEFAULT_LONG_FILE_THRESHOLD_SECONDS
+        );
+        assert_eq!(
+            report
+                .flagged
+                .iter()
+                .map(|file| file.id.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["six-hours", "slightly-over"]
+        );
+        assert_eq!(
+            report.flagged[0].recommendation,
+            "Runs 6 h, longer than the 3 h limit; split it into 2 parts of about 3 h before transcribing."
+        );
+        assert_eq!(report.unknown_item_ids, vec!["unprobed", "broken"]);
+
+        let strict = flag_long_files(items, Some(30.0 * 60.0)).expect("threshold is valid");
+        assert_eq!(strict.flagged.len(), 4);
+        assert!(strict.flagged[0]
+            .recommendation
+            .contains("split it into 2 parts of about 23 min"));
+
+        assert!(flag_long_files(Vec::new(), Some(0.0)).is_err());
+    }
+}
@@ -0,0 +1,273 @@
+use crate::commands::convert::{parse_srt_cues, render_srt, Cue};
+use crate::commands::transcript::{
+    detect_transcript_format, read_transcript_file, TranscriptFormat,
+};
+use serde_json::{Map, Value};
+use std::path::{Path, PathBuf};
+
+struct Chunk {
+    path: PathBuf,
+    index: Option<u64>,
+    content: String,
+}
+
+// split_audio names chunks `<stem>_part000.<ext>`; the trailing digits give the play order.
+fn chunk_index(path: &Path) -> Option<u64> {
+    let stem = path.file_stem()?.to_str()?;
+    let digits = stem
+        .rsplit(|ch: char| !ch.is_ascii_digit())
+        .next()
+        .filter(|digits| !digits.is_empty())?;
+    digits.parse().ok()
+}
+
+fn shift_seconds(value: &mut Value, key: &str, offset: f64) {
+    if let Some(seconds) = value.get(key).and_then(Value::as_f64) {
+        value[key] = Value::from(seconds + offset);
+    }
+}
+
+fn merge_json(chunks: &[Chunk], offset_seconds_per_chunk: f64) -> Result<String, String> {
+    let mut merged: Option<Map<String, Value>> = None;
+    let mut texts = Vec::new();
+    let mut segments = Vec::new();
+
+    for (position, chunk) in chunks.iter().enumerate() {
+        let parsed: Value = serde_json::from_str(&chunk.content).map_err(|error| {
+            format!(
+                "Failed to parse transcript JSON {}: {}",
+                chunk.path.display(),
+                error
+            )
+        })?;
+        let Value::Object(mut object) = parsed else {
+            return Err(format!(
+                "Transcript JSON is not an object: {}",
+                chunk.path.display()
+            ));
+        };
+
+        let offset = position as f64 * offset_seconds_per_chunk;
+        if let Some(Value::Array(chunk_segments)) = object.remove("segments") {
+            for mut segment in chunk_segments {
+                shift_seconds(&mut segment, "start", offset);
+                shift_seconds(&mut segment, "end", offset);
+                if let Some(Value::Array(words)) = segment.get_mut("words") {
+                    for word in words {
+                        shift_seconds(word, "start", offset);
+                        shift_seconds(word, "end", offset);
+                    }
+                }
+                if segment.get("id").is_some_and(Value::is_number) {
+                    segment["id"] = Value::from(segments.len());
+                }
+                segments.push(segment);
+            }
+        }
+        if let Some(text) = object
+            .remove("text")
+            .and_then(|text| text.as_str().map(|text| text.trim().to_string()))
+            .filter(|text| !text.is_empty())
+        {
+            texts.push(text);
+        }
+
+        // Language, model and other top-level fields come from the first chunk.
+        merged.get_or_insert(object);
+    }
+
+    let mut merged = merged.unwrap_or_default();
+    merged.insert("text".to_string(), Value::from(texts.join(" ")));
+    merged.insert("segments".to_string(), Value::Array(segments));
+    serde_json::to_string_pretty(&Value::Object(merged))
+        .map_err(|error| format!("Failed to serialize merged transcript: {}", error))
+}
+
+fn merge_srt(chunks: &[Chunk], offset_seconds_per_chunk: f64) -> Result<String, String> {
+    let mut cues = Vec::new();
+
+    for (position, chunk) in chunks.iter().enumerate() {
+        let offset = (position as f64 * offset_seconds_per_chunk * 1000.0).round() as u64;
+        let chunk_cues = parse_srt_cues(&chunk.content)
+            .map_err(|error| format!("{} in {}", error, chunk.path.display()))?;
+        cues.extend(chunk_cues.into_iter().map(|cue| Cue {
+            start_ms: cue.start_ms + offset,
+            end_ms: cue.end_ms + offset,
+            text: cue.text,
+        }));
+    }
+
+    Ok(render_srt(&cues))
+}
+
+fn merge_text(chunks: &[Chunk]) -> String {
+    let parts = chunks
+        .iter()
+        .map(|chunk| chunk.content.trim())
+        .filter(|content| !content.is_empty())
+        .collect::<Vec<&str>>();
+    format!("{}\n", parts.join("\n"))
+}
+
+fn merge_chunks(
+    chunk_paths: &[PathBuf],
+    destination: &Path,
+    offset_seconds_per_chunk: f64,
+) -> Result<String, String> {
+    if chunk_paths.is_empty() {
+        return Err("No chunk transcripts to merge".to_string());
+    }
+    if !offset_seconds_per_chunk.is_finite() || offset_seconds_per_chunk < 0.0 {
+        return Err("Chunk offset must be zero or a positive number of seconds".to_string());
+    }
+
+    let format = match destination
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase)
+        .as_deref()
+    {
+        Some("txt") => TranscriptFormat::Text,
+        Some("json") => TranscriptFormat::Json,
+        Some("srt") => TranscriptFormat::Srt,
+        _ => {
+            return Err(format!(
+                "Merging is supported for .txt, .json and .srt destinations: {}",
+                destination.display()
+            ))
+        }
+    };
+
+    let mut chunks = chunk_paths
+        .iter()
+        .map(|path| {
+            let content = read_transcript_file(path)?.content;
+            if format != TranscriptFormat::Text
+                && detect_transcript_format(path, &content) != format
+            {
+                return Err(format!(
+                    "Chunk {} does not match the destination format",
+                    path.display()
+                ));
+            }
+            Ok(Chunk {
+                path: path.clone(),
+                index: chunk_index(path),
+                content,
+            })
+        })
+        .collect::<Result<Vec<Chunk>, String>>()?;
+    // Stable sort: chunks without an index keep the order they were passed in, after indexed ones.
+    chunks.sort_by_key(|chunk| chunk.index.unwrap_or(u64::MAX));
+
+    match format {
+        TranscriptFormat::Json => merge_json(&chunks, offset_seconds_per_chunk),
+        TranscriptFormat::Srt => merge_srt(&chunks, offset_seconds_per_chunk),
+        _ => Ok(merge_text(&chunks)),
+    }
+}
+
+#[tauri::command]
+pub fn merge_chunk_transcripts(
+    chunk_paths: Vec<String>,
+    destination: String,
+    offset_seconds_per_chunk: f64,
+) -> Result<String, String> {
+    let destination = PathBuf::from(destination.trim());
+    let chunk_paths = chunk_paths
+        .iter()
+        .map(|path| PathBuf::from(path.trim()))
+        .collect::<Vec<PathBuf>>();
+    let merged = merge_chunks(&chunk_paths, &destination, offset_seconds_per_chunk)?;
+
+    if let Some(parent) = destination.parent() {
+        std::fs::create_dir_all(parent).map_err(|error| {
+            format!(
+                "Failed to create destination directory {}: {}",
+                parent.display(),
+                error
+            )
+        })?;
+    }
+    std::fs::write(&destination, merged).map_err(|error| {
+        format!(
+            "Failed to write merged transcript {}: {}",
+            destination.display(),
+            error
+        )
+    })?;
+    Ok(destination.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("fixtures")
+            .join("chunks")
+            .join(name)
+    }
+
+    #[test]
+    fn shifts_json_chunk_timestamps_by_cumulative_offset() {
+        // Passed out of order on purpose; the part index decides placement.
+        let chunks = vec![
+            fixture("interview_part001.json"),
+            fixture("interview_part000.json"),
+        ];
+        let merged: Value = serde_json::from_str(
+            &merge_chunks(&chunks, Path::new("/tmp/interview.json"), 600.0)
+                .expect("chunks should merge"),
+        )
+        .expect("merged transcript should be JSON");
+
+        assert_eq!(
+            merged["text"],
+            "Welcome back to the show. Let's get started."
+        );
+        assert_eq!(merged["language"], "en");
+        let segments = merged["segments"].as_array().expect("segments");
+        assert_eq!(
+            segments
+                .iter()
+                .map(|segment| (segment["id"].as_u64(), segment["start"].as_f64()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Some(0), Some(0.0)),
+                (Some(1), Some(2.5)),
+                (Some(2), Some(600.5))
+            ]
+        );
+        assert_eq!(segments[2]["end"], 603.0);
+        assert_eq!(segments[2]["words"][0]["start"], 602.0);
+        assert_eq!(segments[0]["words"][1]["end"], 2.5);
+
+        assert!(merge_chunks(&chunks, Path::new("/tmp/interview.vtt"), 600.0).is_err());
+        assert!(merge_chunks(&chunks, Path::new("/tmp/interview.json"), -1.0).is_err());
+    }
+
+    #[test]
+    fn renumbers_and_shifts_srt_cues() {
+        let root = std::env::temp_dir().join(format!("merge-srt-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&root).expect("fixture directory should be created");
+        let first = root.join("talk_part000.srt");
+        let second = root.join("talk_part001.srt");
+        std::fs::write(&first, "1\n00:00:00,000 --> 00:00:02,000\nHello\n\n").expect("write");
+        std::fs::write(
+            &second,
+            "1\r\n00:00:01,250 --> 00:00:03,000\r\nAgain\r\nsecond line\r\n",
+        )
+        .expect("write");
+
+        let merged = merge_chunks(&[first, second], &root.join("talk.srt"), 3599.0)
+            .expect("chunks should merge");
+        assert_eq!(
+            merged,
+            "1\n00:00:00,000 --> 00:00:02,000\nHello\n\n2\n01:00:00,250 --> 01:00:02,000\nAgain\nsecond line\n"
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -1,3 +1,19 @@
+pub mod architecture;
+pub mod convert;
+pub mod duplicates;
+pub mod estimate;
 pub mod export;
+pub mod ffmpeg;
 pub mod history;
+pub mod inline_audio;
+pub mod languages;
+pub mod long_files;
+pub mod merge;
+pub mod paths;
+pub mod resources;
+pub mod sample_rate;
 pub mod scan;
+pub mod support;
+pub mod transcript;
+pub mod volumes;
+pub mod wer;
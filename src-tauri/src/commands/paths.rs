@@ -0,0 +1,73 @@
+use std::path::{Path, PathBuf};
+
+// Whitespace is only trimmed around a `~` path; elsewhere it may be part of a real file name.
+fn expand_path_with(path: &str, home: Option<&Path>) -> PathBuf {
+    let Some(rest) = path.trim().strip_prefix('~') else {
+        return PathBuf::from(path);
+    };
+    let Some(home) = home else {
+        return PathBuf::from(path);
+    };
+
+    let tail = rest.trim_start_matches(['/', '\\']);
+    // `~user` is left as typed: another user's home cannot be derived from ours.
+    if tail.len() == rest.len() && !rest.is_empty() {
+        return PathBuf::from(path);
+    }
+    if tail.is_empty() {
+        home.to_path_buf()
+    } else {
+        home.join(tail)
+    }
+}
+
+/// Expands a leading `~` to the home directory; every other path is returned as given.
+pub fn expand_path(path: &str) -> PathBuf {
+    expand_path_with(path, dirs::home_dir().as_deref())
+}
+
+#[tauri::command]
+pub fn resolve_path(path: String) -> String {
+    expand_path(&path).to_string_lossy().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expands_tilde_prefixes_against_the_home_directory() {
+        let home = Path::new("/Users/alex");
+        assert_eq!(
+            expand_path_with("~", Some(home)),
+            PathBuf::from("/Users/alex")
+        );
+        assert_eq!(
+            expand_path_with("~/Recordings/May", Some(home)),
+            PathBuf::from("/Users/alex/Recordings/May")
+        );
+        assert_eq!(
+            expand_path_with("  ~/Recordings ", Some(home)),
+            PathBuf::from("/Users/alex/Recordings")
+        );
+        assert_eq!(
+            expand_path_with("~sam/Recordings", Some(home)),
+            PathBuf::from("~sam/Recordings")
+        );
+        assert_eq!(expand_path_with("~", None), PathBuf::from("~"));
+    }
+
+    #[test]
+    fn leaves_paths_without_a_leading_tilde_unchanged() {
+        let home = Path::new("/Users/alex");
+        for path in [
+            "/Volumes/Share/audio",
+            "relative/out",
+            "notes~/draft",
+            "/Volumes/Share/trailing ",
+            "",
+        ] {
+            assert_eq!(expand_path_with(path, Some(home)), PathBuf::from(path));
+        }
+    }
+}
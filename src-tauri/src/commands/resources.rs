@@ -0,0 +1,178 @@
+use crate::providers::resolver::normalize_model_version;
+use serde::Serialize;
+use std::process::Command;
+
+// Rough resident size of a loaded CoreML TDT model plus the worker around it; v3 carries
+// the larger multilingual vocabulary.
+const V3_RESIDENT_BYTES: u64 = 1_700 * 1024 * 1024;
+const V2_RESIDENT_BYTES: u64 = 1_400 * 1024 * 1024;
+// Left free for the UI, ffmpeg and whatever else the user has open.
+const WARM_POOL_RESERVE_BYTES: u64 = 2 * 1024 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemResources {
+    pub total_memory_bytes: u64,
+    pub available_memory_bytes: u64,
+    pub cpu_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WarmPoolAdvice {
+    pub advisable: bool,
+    pub available_memory_bytes: Option<u64>,
+    pub required_bytes: u64,
+    pub detail: String,
+}
+
+fn sysctl_u64(name: &str) -> Result<u64, String> {
+    let output = Command::new("sysctl")
+        .args(["-n", name])
+        .output()
+        .map_err(|error| format!("Failed to run sysctl {}: {}", name, error))?;
+    String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse::<u64>()
+        .map_err(|_| format!("Unexpected sysctl output for {}", name))
+}
+
+// Free, inactive and speculative pages can all be handed to a new process without swapping.
+fn parse_vm_stat_available(output: &str) -> Option<u64> {
+    let page_size = output
+        .lines()
+        .next()?
+        .split("page size of ")
+        .nth(1)?
+        .split_whitespace()
+        .next()?
+        .parse::<u64>()
+        .ok()?;
+    let pages = |label: &str| {
+        output
+            .lines()
+            .find_map(|line| line.strip_prefix(label))
+            .and_then(|rest| rest.trim().trim_end_matches('.').parse::<u64>().ok())
+    };
+    let free = pages("Pages free:")?;
+    let reclaimable =
+        pages("Pages inactive:").unwrap_or(0) + pages("Pages speculative:").unwrap_or(0);
+    Some((free + reclaimable) * page_size)
+}
+
+fn available_memory_bytes() -> Result<u64, String> {
+    let output = Command::new("vm_stat")
+        .output()
+        .map_err(|error| format!("Failed to run vm_stat: {}", error))?;
+    parse_vm_stat_available(&String::from_utf8_lossy(&output.stdout))
+        .ok_or_else(|| "Unexpected vm_stat output".to_string())
+}
+
+fn read_system_resources() -> Result<SystemResources, String> {
+    Ok(SystemResources {
+        total_memory_bytes: sysctl_u64("hw.memsize")?,
+        available_memory_bytes: available_memory_bytes()?,
+        cpu_count: std::thread::available_parallelism()
+            .map(|count| count.get())
+            .unwrap_or(1),
+    })
+}
+
+fn model_resident_bytes(model_version: &str) -> Result<u64, String> {
+    match normalize_model_version(model_version) {
+        Some("v3") => Ok(V3_RESIDENT_BYTES),
+        Some("v2") => Ok(V2_RESIDENT_BYTES),
+        _ => Err(format!("Unsupported model version: {}", model_version)),
+    }
+}
+
+pub(crate) fn assess_warm_pool(
+    available: Result<u64, String>,
+    resident_bytes: u64,
+) -> WarmPoolAdvice {
+    let required_bytes = resident_bytes + WARM_POOL_RESERVE_BYTES;
+    match available {
+        Ok(available) if available >= required_bytes => WarmPoolAdvice {
+            advisable: true,
+            available_memory_bytes: Some(available),
+            required_bytes,
+            detail: format!(
+                "{} free; a warm model needs about {}.",
+                crate::format_bytes(available),
+                crate::format_bytes(required_bytes)
+            ),
+        },
+        Ok(available) => WarmPoolAdvice {
+            advisable: false,
+            available_memory_bytes: Some(available),
+            required_bytes,
+            detail: format!(
+                "Only {} free; keeping a model resident needs about {}.",
+                crate::format_bytes(available),
+                crate::format_bytes(required_bytes)
+            ),
+        },
+        // An unreadable memory figure is not evidence of pressure, so the toggle stays usable.
+        Err(error) => WarmPoolAdvice {
+            advisable: true,
+            available_memory_bytes: None,
+            required_bytes,
+            detail: format!("Could not measure free memory: {}", error),
+        },
+    }
+}
+
+/// Current advice for keeping `model_version` warm; unknown versions are budgeted like v3.
+pub(crate) fn current_warm_pool_advice(model_version: &str) -> WarmPoolAdvice {
+    let resident_bytes = model_resident_bytes(model_version).unwrap_or(V3_RESIDENT_BYTES);
+    assess_warm_pool(available_memory_bytes(), resident_bytes)
+}
+
+#[tauri::command]
+pub fn system_resources() -> Result<SystemResources, String> {
+    read_system_resources()
+}
+
+/// Reports whether there is enough free memory to keep `model_version` resident in a warm pool.
+#[tauri::command]
+pub fn warm_pool_advice(model_version: String) -> Result<WarmPoolAdvice, String> {
+    let resident_bytes = model_resident_bytes(&model_version)?;
+    Ok(assess_warm_pool(available_memory_bytes(), resident_bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_available_memory_from_vm_stat() {
+        let output = "Mach Virtual Memory Statistics: (page size of 16384 bytes)\n\
+Pages free:                               10000.\n\
+Pages active:                            400000.\n\
+Pages inactive:                           20000.\n\
+Pages speculative:                         5000.\n";
+        assert_eq!(parse_vm_stat_available(output), Some(35_000 * 16_384));
+        assert_eq!(parse_vm_stat_available("vm_stat: not supported"), None);
+    }
+
+    #[test]
+    fn advises_against_warm_pool_when_memory_is_tight() {
+        let resident = model_resident_bytes("parakeet-tdt-0.6b-v3-coreml").expect("v3 is known");
+        let required = resident + WARM_POOL_RESERVE_BYTES;
+
+        let roomy = assess_warm_pool(Ok(16 * 1024 * 1024 * 1024), resident);
+        assert!(roomy.advisable);
+        assert_eq!(roomy.required_bytes, required);
+
+        let tight = assess_warm_pool(Ok(required - 1), resident);
+        assert!(!tight.advisable);
+        assert!(tight.detail.starts_with("Only "));
+        assert!(assess_warm_pool(Ok(required), resident).advisable);
+
+        let unknown = assess_warm_pool(Err("Unexpected vm_stat output".to_string()), resident);
+        assert!(unknown.advisable);
+        assert_eq!(unknown.available_memory_bytes, None);
+
+        assert!(model_resident_bytes("v9").is_err());
+    }
+}
@@ -0,0 +1,191 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// Every bundled model is trained on 16 kHz mono audio; workers resample anything else.
+const MODEL_SAMPLE_RATE: u32 = 16_000;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SampleRateMetadata {
+    #[serde(default)]
+    pub sample_rate: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SampleRateItem {
+    pub id: String,
+    #[serde(default)]
+    pub metadata: Option<SampleRateMetadata>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SampleRateBucket {
+    pub sample_rate: Option<u32>,
+    pub label: String,
+    pub item_ids: Vec<String>,
+    pub native: bool,
+    pub recommendation: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SampleRateAnalysis {
+    pub native_sample_rate: u32,
+    pub buckets: Vec<SampleRateBucket>,
+    pub flagged_item_ids: Vec<String>,
+    pub unknown_item_ids: Vec<String>,
+}
+
+fn format_sample_rate(sample_rate: u32) -> String {
+    if sample_rate.is_multiple_of(1000) {
+        format!("{} kHz", sample_rate / 1000)
+    } else {
+        format!("{:.1} kHz", f64::from(sample_rate) / 1000.0)
+    }
+}
+
+fn resample_recommendation(sample_rate: u32, count: usize) -> String {
+    let files = if count == 1 { "file" } else { "files" };
+    let impact = if sample_rate > MODEL_SAMPLE_RATE {
+        "downsampling adds processing time; convert ahead of time to skip it"
+    } else {
+        "upsampling cannot restore missing detail, so accuracy may be lower"
+    };
+    format!(
+        "{} {} at {} will be resampled to {} by the worker: {}.",
+        count,
+        files,
+        format_sample_rate(sample_rate),
+        format_sample_rate(MODEL_SAMPLE_RATE),
+        impact
+    )
+}
+
+fn analyze(items: &[SampleRateItem]) -> SampleRateAnalysis {
+    let mut known: BTreeMap<u32, Vec<String>> = BTreeMap::new();
+    let mut unknown_item_ids = Vec::new();
+    for item in items {
+        match item
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.sample_rate)
+            .filter(|sample_rate| *sample_rate > 0)
+        {
+            Some(sample_rate) => known.entry(sample_rate).or_default().push(item.id.clone()),
+            None => unknown_item_ids.push(item.id.clone()),
+        }
+    }
+
+    let mut flagged_item_ids = Vec::new();
+    let mut buckets = known
+        .into_iter()
+        .map(|(sample_rate, item_ids)| {
+            let native = sample_rate == MODEL_SAMPLE_RATE;
+            if !native {
+                flagged_item_ids.extend(item_ids.iter().cloned());
+            }
+            SampleRateBucket {
+                sample_rate: Some(sample_rate),
+                label: format_sample_rate(sample_rate),
+                native,
+                recommendation: (!native)
+                    .then(|| resample_recommendation(sample_rate, item_ids.len())),
+                item_ids,
+            }
+        })
+        .collect::<Vec<SampleRateBucket>>();
+
+    if !unknown_item_ids.is_empty() {
+        buckets.push(SampleRateBucket {
+            sample_rate: None,
+            label: "unknown".to_string(),
+            item_ids: unknown_item_ids.clone(),
+            native: false,
+            recommendation: None,
+        });
+    }
+
+    SampleRateAnalysis {
+        native_sample_rate: MODEL_SAMPLE_RATE,
+        buckets,
+        flagged_item_ids,
+        unknown_item_ids,
+    }
+}
+
+#[tauri::command]
+pub fn analyze_sample_rates(items: Vec<SampleRateItem>) -> SampleRateAnalysis {
+    analyze(&items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: &str, sample_rate: Option<u32>) -> SampleRateItem {
+        SampleRateItem {
+            id: id.to_string(),
+            metadata: Some(SampleRateMetadata { sample_rate }),
+        }
+    }
+
+    #[test]
+    fn buckets_mixed_sample_rates_and_flags_non_native_items() {
+        let items = vec![
+            item("a", Some(44_100)),
+            item("b", Some(16_000)),
+            item("c", Some(48_000)),
+            item("d", None),
+            item("e", Some(44_100)),
+            item("f", Some(8_000)),
+            SampleRateItem {
+                id: "g".to_string(),
+                metadata: None,
+            },
+        ];
+
+        let analysis = analyze(&items);
+        assert_eq!(analysis.native_sample_rate, 16_000);
+
+        let labels = analysis
+            .buckets
+            .iter()
+            .map(|bucket| bucket.label.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(
+            labels,
+            vec!["8 kHz", "16 kHz", "44.1 kHz", "48 kHz", "unknown"]
+        );
+
+        let cd = &analysis.buckets[2];
+        assert_eq!(cd.item_ids, vec!["a".to_string(), "e".to_string()]);
+        assert!(!cd.native);
+        assert!(cd
+            .recommendation
+            .as_deref()
+            .is_some_and(|text| text.starts_with("2 files at 44.1 kHz")));
+
+        let native = &analysis.buckets[1];
+        assert!(native.native);
+        assert!(native.recommendation.is_none());
+
+        assert!(analysis.buckets[0]
+            .recommendation
+            .as_deref()
+            .is_some_and(|text| text.contains("accuracy may be lower")));
+
+        assert_eq!(analysis.flagged_item_ids, vec!["f", "a", "e", "c"]);
+        assert_eq!(analysis.unknown_item_ids, vec!["d", "g"]);
+        assert!(analysis.buckets[4].recommendation.is_none());
+    }
+
+    #[test]
+    fn empty_queue_produces_no_buckets() {
+        let analysis = analyze(&[]);
+        assert!(analysis.buckets.is_empty());
+        assert!(analysis.flagged_item_ids.is_empty());
+        assert!(analysis.unknown_item_ids.is_empty());
+    }
+}
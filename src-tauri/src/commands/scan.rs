@@ -1,20 +1,43 @@
-use serde::Serialize;
+use crate::commands::paths::expand_path;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 use std::process::Command;
-use std::sync::OnceLock;
-use std::time::{Duration, Instant};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock};
+use std::time::{Duration, Instant, UNIX_EPOCH};
 use tauri::{AppHandle, Emitter};
 use uuid::Uuid;
 use walkdir::WalkDir;
 
-const SUPPORTED_EXTENSIONS: &[&str] = &["mp3", "wav", "m4a", "flac", "ogg", "aac", "aiff", "wma"];
+pub(crate) const SUPPORTED_EXTENSIONS: &[&str] = &[
+    "mp3", "wav", "m4a", "flac", "ogg", "aac", "aiff", "aif", "caf", "wma",
+];
 const SCAN_PROGRESS_EVENT: &str = "scan-progress";
+const SCAN_CANCELLED_EVENT: &str = "scan-cancelled";
+const SCAN_SKIPPED_EVENT: &str = "scan-skipped";
+const NON_UTF8_PATH_REASON: &str =
+    "file path is not valid UTF-8 and cannot be transcribed; rename the file to include it";
 const PROGRESS_EMIT_INTERVAL: Duration = Duration::from_millis(100);
 const PROGRESS_EMIT_STEP: u32 = 50;
+const MIN_PROGRESS_EMIT_INTERVAL: Duration = Duration::from_millis(10);
+const MIN_PROGRESS_EMIT_STEP: u32 = 1;
+const MAX_SCAN_IO_DELAY_MS: u64 = 1_000;
+const SCAN_CACHE_FILE_NAME: &str = "scan-cache.json";
 
 static FFPROBE_AVAILABLE: OnceLock<bool> = OnceLock::new();
+static ACTIVE_SCANS: LazyLock<Mutex<HashMap<String, Arc<AtomicBool>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+// Concurrent scans would otherwise race on the read-modify-write of the cache file.
+static SCAN_CACHE_LOCK: Mutex<()> = Mutex::new(());
+// Cleared by the frontend while its window is hidden; scans still run and return their items.
+static SCAN_EVENTS_ENABLED: AtomicBool = AtomicBool::new(true);
 
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AudioMetadata {
     codec: Option<String>,
@@ -37,6 +60,31 @@ pub struct QueueItemData {
     metadata: Option<AudioMetadata>,
 }
 
+// Random ids stay the default; stable ones let a rescan line up with earlier manifests and history.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum ItemIdStrategy {
+    #[default]
+    Random,
+    Path,
+    PathAndMetadata,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ListEntryFailure {
+    line: usize,
+    entry: String,
+    error: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListScanResult {
+    items: Vec<QueueItemData>,
+    failures: Vec<ListEntryFailure>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct ScanProgress {
@@ -45,12 +93,243 @@ struct ScanProgress {
     current_path: String,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ScanCancelled {
+    scan_id: String,
+    found: u32,
+    scanned: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ScanSkipped {
+    scan_id: String,
+    path: String,
+    reason: String,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct DirectoryWalk {
+    discovered: Vec<String>,
+    // Queue items, manifests and history all carry paths as UTF-8 strings, so these
+    // would be corrupted by a lossy conversion and are skipped instead.
+    non_utf8: Vec<PathBuf>,
+    found: u32,
+    scanned: u32,
+    cancelled: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ProgressCadence {
+    step: u32,
+    interval: Duration,
+}
+
+impl Default for ProgressCadence {
+    fn default() -> Self {
+        Self {
+            step: PROGRESS_EMIT_STEP,
+            interval: PROGRESS_EMIT_INTERVAL,
+        }
+    }
+}
+
+impl ProgressCadence {
+    fn from_overrides(step: Option<u32>, interval_ms: Option<u64>) -> Result<Self, String> {
+        let defaults = Self::default();
+        let step = step.unwrap_or(defaults.step);
+        if step < MIN_PROGRESS_EMIT_STEP {
+            return Err(format!(
+                "Progress step must be at least {}",
+                MIN_PROGRESS_EMIT_STEP
+            ));
+        }
+
+        let interval = interval_ms
+            .map(Duration::from_millis)
+            .unwrap_or(defaults.interval);
+        if interval < MIN_PROGRESS_EMIT_INTERVAL {
+            return Err(format!(
+                "Progress interval must be at least {}ms",
+                MIN_PROGRESS_EMIT_INTERVAL.as_millis()
+            ));
+        }
+
+        Ok(Self { step, interval })
+    }
+
+    fn should_emit(&self, scanned: u32, since_last_emit: Duration) -> bool {
+        scanned.is_multiple_of(self.step) || since_last_emit >= self.interval
+    }
+}
+
+struct ScanRegistration {
+    scan_id: String,
+    cancel_flag: Arc<AtomicBool>,
+}
+
+impl ScanRegistration {
+    fn register(scan_id: &str) -> Result<Self, String> {
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let mut scans = ACTIVE_SCANS
+            .lock()
+            .map_err(|_| "Failed to lock active scans".to_string())?;
+        scans.insert(scan_id.to_string(), cancel_flag.clone());
+        Ok(Self {
+            scan_id: scan_id.to_string(),
+            cancel_flag,
+        })
+    }
+}
+
+impl Drop for ScanRegistration {
+    fn drop(&mut self) {
+        if let Ok(mut scans) = ACTIVE_SCANS.lock() {
+            // A newer scan may have reused the id; only remove our own flag.
+            if scans
+                .get(&self.scan_id)
+                .is_some_and(|flag| Arc::ptr_eq(flag, &self.cancel_flag))
+            {
+                scans.remove(&self.scan_id);
+            }
+        }
+    }
+}
+
 #[derive(Debug, Default, PartialEq)]
 struct MetadataResult {
     duration: Option<f64>,
     metadata: Option<AudioMetadata>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct CachedScanEntry {
+    size: u64,
+    modified_nanos: u64,
+    duration: Option<f64>,
+    metadata: Option<AudioMetadata>,
+}
+
+// Keyed by path; an entry is only reused while the file's size and mtime still match.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ScanCache {
+    entries: HashMap<String, CachedScanEntry>,
+    // Paths stored since load; only these are merged back into the file.
+    #[serde(skip)]
+    stored: HashSet<String>,
+}
+
+impl ScanCache {
+    fn load(path: &Path) -> Self {
+        let payload = match std::fs::read_to_string(path) {
+            Ok(payload) => payload,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Self::default(),
+            Err(error) => {
+                eprintln!("[scan] failed to read cache {}: {}", path.display(), error);
+                return Self::default();
+            }
+        };
+        serde_json::from_str(&payload).unwrap_or_else(|error| {
+            eprintln!(
+                "[scan] ignoring unreadable cache {}: {}",
+                path.display(),
+                error
+            );
+            Self::default()
+        })
+    }
+
+    fn save(&self, path: &Path) -> Result<(), String> {
+        if self.stored.is_empty() {
+            return Ok(());
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|error| {
+                format!(
+                    "Failed to create scan cache directory {}: {}",
+                    parent.display(),
+                    error
+                )
+            })?;
+        }
+        let payload = serde_json::to_string(self)
+            .map_err(|error| format!("Failed to serialize scan cache: {}", error))?;
+        std::fs::write(path, payload)
+            .map_err(|error| format!("Failed to write scan cache {}: {}", path.display(), error))
+    }
+
+    fn lookup(&self, path: &str, size: u64, modified_nanos: u64) -> Option<MetadataResult> {
+        self.entries
+            .get(path)
+            .filter(|entry| entry.size == size && entry.modified_nanos == modified_nanos)
+            .map(|entry| MetadataResult {
+                duration: entry.duration,
+                metadata: entry.metadata.clone(),
+            })
+    }
+
+    fn store(&mut self, path: &str, size: u64, modified_nanos: u64, result: &MetadataResult) {
+        self.entries.insert(
+            path.to_string(),
+            CachedScanEntry {
+                size,
+                modified_nanos,
+                duration: result.duration,
+                metadata: result.metadata.clone(),
+            },
+        );
+        self.stored.insert(path.to_string());
+    }
+
+    fn absorb(&mut self, mut scanned: ScanCache) {
+        for path in scanned.stored.drain() {
+            if let Some(entry) = scanned.entries.remove(&path) {
+                self.entries.insert(path.clone(), entry);
+                self.stored.insert(path);
+            }
+        }
+    }
+}
+
+// The lock only covers file access; extraction runs unlocked so clear_scan_cache never waits on a scan.
+fn load_scan_cache(cache_path: Option<&Path>) -> Result<ScanCache, String> {
+    let _guard = SCAN_CACHE_LOCK
+        .lock()
+        .map_err(|_| "Failed to lock scan cache".to_string())?;
+    Ok(cache_path.map(ScanCache::load).unwrap_or_default())
+}
+
+// Merges into what is on disk now, so entries dropped by a clear during the scan stay dropped.
+fn persist_scan_cache(cache_path: Option<&Path>, scanned: ScanCache) {
+    let Some(cache_path) = cache_path.filter(|_| !scanned.stored.is_empty()) else {
+        return;
+    };
+    let saved = SCAN_CACHE_LOCK
+        .lock()
+        .map_err(|_| "Failed to lock scan cache".to_string())
+        .and_then(|_guard| {
+            let mut cache = ScanCache::load(cache_path);
+            cache.absorb(scanned);
+            cache.save(cache_path)
+        });
+    // A cache that cannot be written only costs speed on the next scan.
+    if let Err(error) = saved {
+        eprintln!("[scan] {}", error);
+    }
+}
+
+fn scan_cache_path() -> Result<PathBuf, String> {
+    let home = dirs::home_dir().ok_or_else(|| "Failed to resolve home directory".to_string())?;
+    Ok(home.join(".aura").join(SCAN_CACHE_FILE_NAME))
+}
+
+fn modified_nanos(file_info: &std::fs::Metadata) -> Option<u64> {
+    let modified = file_info.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+    u64::try_from(modified.as_nanos()).ok()
+}
+
 fn normalize_extension(path: &Path) -> Option<String> {
     path.extension()
         .and_then(|ext| ext.to_str())
@@ -194,7 +473,231 @@ fn extract_ffprobe_metadata(path: &Path) -> MetadataResult {
         .unwrap_or_default()
 }
 
-fn queue_item_for_path(path: &Path) -> Result<QueueItemData, String> {
+fn read_array<const N: usize>(reader: &mut impl Read) -> Option<[u8; N]> {
+    let mut buffer = [0u8; N];
+    reader.read_exact(&mut buffer).ok()?;
+    Some(buffer)
+}
+
+fn fourcc_label(code: [u8; 4]) -> Option<String> {
+    let label = std::str::from_utf8(&code).ok()?.trim().to_ascii_lowercase();
+    (!label.is_empty()).then_some(label)
+}
+
+/// Decodes the 80-bit IEEE 754 extended float AIFF uses for its sample rate.
+fn parse_extended_f64(bytes: [u8; 10]) -> Option<f64> {
+    let sign = if bytes[0] & 0x80 != 0 { -1.0 } else { 1.0 };
+    let exponent = i32::from(u16::from_be_bytes([bytes[0] & 0x7f, bytes[1]]));
+    let mut mantissa_bytes = [0u8; 8];
+    mantissa_bytes.copy_from_slice(&bytes[2..10]);
+    let mantissa = u64::from_be_bytes(mantissa_bytes);
+
+    if exponent == 0x7fff {
+        return None;
+    }
+    if exponent == 0 && mantissa == 0 {
+        return Some(0.0);
+    }
+
+    Some(sign * (mantissa as f64) * 2f64.powi(exponent - 16383 - 63))
+}
+
+fn pcm_bitrate(sample_rate: f64, channels: u32, bits_per_sample: u32) -> Option<u32> {
+    let bitrate = sample_rate * f64::from(channels) * f64::from(bits_per_sample);
+    (bitrate.is_finite() && bitrate > 0.0 && bitrate <= f64::from(u32::MAX))
+        .then(|| bitrate.round() as u32)
+}
+
+fn native_metadata_result(
+    codec: Option<String>,
+    bitrate: Option<u32>,
+    sample_rate: f64,
+    channels: u32,
+    duration: Option<f64>,
+) -> Option<MetadataResult> {
+    if !sample_rate.is_finite() || sample_rate <= 0.0 || channels == 0 {
+        return None;
+    }
+
+    Some(MetadataResult {
+        duration: duration.filter(|seconds| seconds.is_finite() && *seconds >= 0.0),
+        metadata: Some(AudioMetadata {
+            codec,
+            bitrate,
+            sample_rate: Some(sample_rate.round() as u32),
+            channels: u8::try_from(channels).ok(),
+        }),
+    })
+}
+
+/// Reads the COMM chunk of an AIFF/AIFC container (big-endian IFF chunks).
+fn parse_aiff_header(reader: &mut (impl Read + Seek)) -> Option<MetadataResult> {
+    if &read_array::<4>(reader)? != b"FORM" {
+        return None;
+    }
+    let _form_size = read_array::<4>(reader)?;
+    let form_type = read_array::<4>(reader)?;
+    let is_compressed = match &form_type {
+        b"AIFF" => false,
+        b"AIFC" => true,
+        _ => return None,
+    };
+
+    loop {
+        let chunk_id = read_array::<4>(reader)?;
+        let chunk_size = u32::from_be_bytes(read_array::<4>(reader)?);
+        if &chunk_id != b"COMM" {
+            let padded = i64::from(chunk_size) + i64::from(chunk_size % 2);
+            reader.seek(SeekFrom::Current(padded)).ok()?;
+            continue;
+        }
+
+        if chunk_size < 18 {
+            return None;
+        }
+
+        let channels = u16::from_be_bytes(read_array::<2>(reader)?);
+        let frames = u32::from_be_bytes(read_array::<4>(reader)?);
+        let bits_per_sample = u16::from_be_bytes(read_array::<2>(reader)?);
+        let sample_rate = parse_extended_f64(read_array::<10>(reader)?)?;
+        let compression = if is_compressed && chunk_size >= 22 {
+            fourcc_label(read_array::<4>(reader)?)
+        } else {
+            None
+        };
+
+        let (codec, bitrate) = match compression.as_deref() {
+            None | Some("none") => (
+                Some(format!("pcm_s{}be", bits_per_sample)),
+                pcm_bitrate(sample_rate, u32::from(channels), u32::from(bits_per_sample)),
+            ),
+            Some(other) => (Some(other.to_string()), None),
+        };
+        let duration = (sample_rate > 0.0).then(|| f64::from(frames) / sample_rate);
+
+        return native_metadata_result(codec, bitrate, sample_rate, u32::from(channels), duration);
+    }
+}
+
+/// Reads the `desc` chunk (plus `pakt`/`data` for frame counts) of an Apple CAF file.
+fn parse_caf_header(reader: &mut (impl Read + Seek)) -> Option<MetadataResult> {
+    if &read_array::<4>(reader)? != b"caff" {
+        return None;
+    }
+    let _version_and_flags = read_array::<4>(reader)?;
+
+    let mut description: Option<(f64, [u8; 4], u32, u32, u32, u32)> = None;
+    let mut valid_frames: Option<i64> = None;
+    let mut data_bytes: Option<i64> = None;
+
+    while let Some(chunk_type) = read_array::<4>(reader) {
+        let chunk_size = i64::from_be_bytes(read_array::<8>(reader)?);
+        match &chunk_type {
+            b"desc" => {
+                if chunk_size < 32 {
+                    return None;
+                }
+                let sample_rate = f64::from_be_bytes(read_array::<8>(reader)?);
+                let format_id = read_array::<4>(reader)?;
+                let _format_flags = read_array::<4>(reader)?;
+                let bytes_per_packet = u32::from_be_bytes(read_array::<4>(reader)?);
+                let frames_per_packet = u32::from_be_bytes(read_array::<4>(reader)?);
+                let channels = u32::from_be_bytes(read_array::<4>(reader)?);
+                let bits_per_channel = u32::from_be_bytes(read_array::<4>(reader)?);
+                description = Some((
+                    sample_rate,
+                    format_id,
+                    bytes_per_packet,
+                    frames_per_packet,
+                    channels,
+                    bits_per_channel,
+                ));
+                reader.seek(SeekFrom::Current(chunk_size - 32)).ok()?;
+            }
+            b"pakt" => {
+                if chunk_size < 24 {
+                    return None;
+                }
+                let _packets = read_array::<8>(reader)?;
+                valid_frames = Some(i64::from_be_bytes(read_array::<8>(reader)?));
+                reader.seek(SeekFrom::Current(chunk_size - 16)).ok()?;
+            }
+            b"data" => {
+                // A size of -1 means the data chunk runs to the end of the file.
+                if chunk_size < 0 {
+                    break;
+                }
+                data_bytes = Some(chunk_size.saturating_sub(4));
+                reader.seek(SeekFrom::Current(chunk_size)).ok()?;
+            }
+            _ => {
+                if chunk_size < 0 {
+                    return None;
+                }
+                reader.seek(SeekFrom::Current(chunk_size)).ok()?;
+            }
+        }
+    }
+
+    let (sample_rate, format_id, bytes_per_packet, frames_per_packet, channels, bits) =
+        description?;
+    let codec = fourcc_label(format_id);
+    let is_pcm = codec.as_deref() == Some("lpcm");
+
+    let frames = valid_frames.or_else(|| {
+        let bytes = data_bytes?;
+        (bytes_per_packet > 0 && frames_per_packet > 0).then(|| {
+            (bytes / i64::from(bytes_per_packet)).saturating_mul(i64::from(frames_per_packet))
+        })
+    });
+    let duration = frames
+        .filter(|count| *count >= 0 && sample_rate > 0.0)
+        .map(|count| count as f64 / sample_rate);
+    let bitrate = if is_pcm {
+        pcm_bitrate(sample_rate, channels, bits)
+    } else {
+        None
+    };
+
+    native_metadata_result(codec, bitrate, sample_rate, channels, duration)
+}
+
+fn extract_native_metadata(path: &Path, format: &str) -> MetadataResult {
+    if !matches!(format, "aiff" | "aif" | "caf") {
+        return MetadataResult::default();
+    }
+
+    let Ok(mut file) = File::open(path) else {
+        return MetadataResult::default();
+    };
+
+    let parsed = if format == "caf" {
+        parse_caf_header(&mut file)
+    } else {
+        parse_aiff_header(&mut file)
+    };
+    parsed.unwrap_or_default()
+}
+
+fn stable_item_id(path: &Path, metadata: Option<(u64, Option<u64>)>) -> String {
+    let canonical = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let mut hasher = Sha256::new();
+    hasher.update(canonical.to_string_lossy().as_bytes());
+    if let Some((size, modified)) = metadata {
+        hasher.update(size.to_le_bytes());
+        hasher.update(modified.unwrap_or_default().to_le_bytes());
+    }
+    let digest = hasher.finalize();
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest[..16]);
+    Uuid::from_bytes(bytes).to_string()
+}
+
+fn queue_item_for_path(
+    path: &Path,
+    cache: &mut ScanCache,
+    id_strategy: ItemIdStrategy,
+) -> Result<QueueItemData, String> {
     if !path.exists() {
         return Err(format!("Path not found: {}", path.display()));
     }
@@ -212,11 +715,34 @@ fn queue_item_for_path(path: &Path) -> Result<QueueItemData, String> {
         )
     })?;
 
-    let extracted = extract_ffprobe_metadata(path);
+    let path_key = path.to_string_lossy().to_string();
+    let modified = modified_nanos(&file_info);
+    let cached = modified.and_then(|modified| cache.lookup(&path_key, file_info.len(), modified));
+    let extracted = match cached {
+        Some(cached) => cached,
+        None => {
+            let mut extracted = extract_ffprobe_metadata(path);
+            if extracted.duration.is_none() && extracted.metadata.is_none() {
+                extracted = extract_native_metadata(path, &format);
+            }
+            // An empty result usually means ffprobe is missing; caching it would hide a later fix.
+            let found_something = extracted.duration.is_some() || extracted.metadata.is_some();
+            if let Some(modified) = modified.filter(|_| found_something) {
+                cache.store(&path_key, file_info.len(), modified, &extracted);
+            }
+            extracted
+        }
+    };
+
+    let id = match id_strategy {
+        ItemIdStrategy::Random => Uuid::new_v4().to_string(),
+        ItemIdStrategy::Path => stable_item_id(path, None),
+        ItemIdStrategy::PathAndMetadata => stable_item_id(path, Some((file_info.len(), modified))),
+    };
 
     Ok(QueueItemData {
-        id: Uuid::new_v4().to_string(),
-        path: path.to_string_lossy().to_string(),
+        id,
+        path: path_key,
         name: path
             .file_name()
             .and_then(|name| name.to_str())
@@ -231,38 +757,216 @@ fn queue_item_for_path(path: &Path) -> Result<QueueItemData, String> {
     })
 }
 
+fn emit_scan_progress_gated(
+    enabled: &AtomicBool,
+    progress: impl FnOnce() -> ScanProgress,
+    emit: impl FnOnce(ScanProgress) -> Result<(), String>,
+) -> Result<(), String> {
+    if !enabled.load(Ordering::Relaxed) {
+        return Ok(());
+    }
+    emit(progress())
+}
+
 fn emit_scan_progress(
     app: &AppHandle,
     found: u32,
     scanned: u32,
     current_path: &Path,
 ) -> Result<(), String> {
-    let progress = ScanProgress {
-        found,
-        scanned,
-        current_path: current_path.to_string_lossy().to_string(),
+    emit_scan_progress_gated(
+        &SCAN_EVENTS_ENABLED,
+        || ScanProgress {
+            found,
+            scanned,
+            current_path: current_path.to_string_lossy().to_string(),
+        },
+        |progress| {
+            app.emit(SCAN_PROGRESS_EVENT, progress)
+                .map_err(|error| format!("Failed to emit scan progress: {}", error))
+        },
+    )
+}
+
+#[tauri::command]
+pub fn set_scan_events_enabled(enabled: bool) {
+    SCAN_EVENTS_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+fn scan_files_with_cache(
+    paths: Vec<String>,
+    cache_path: Option<&Path>,
+    id_strategy: ItemIdStrategy,
+) -> Result<Vec<QueueItemData>, String> {
+    let mut cache = load_scan_cache(cache_path)?;
+    let items = paths
+        .iter()
+        .map(|path| expand_path(path))
+        .map(|path| queue_item_for_path(&path, &mut cache, id_strategy))
+        .collect::<Result<Vec<QueueItemData>, String>>()?;
+
+    persist_scan_cache(cache_path, cache);
+    Ok(items)
+}
+
+fn scan_list_with_cache(
+    list_path: &Path,
+    cache_path: Option<&Path>,
+    id_strategy: ItemIdStrategy,
+) -> Result<ListScanResult, String> {
+    let content = std::fs::read_to_string(list_path).map_err(|error| {
+        format!(
+            "Failed to read file list {}: {}",
+            list_path.display(),
+            error
+        )
+    })?;
+    let base_dir = list_path.parent().unwrap_or_else(|| Path::new(""));
+
+    let mut cache = load_scan_cache(cache_path)?;
+    let mut result = ListScanResult {
+        items: Vec::new(),
+        failures: Vec::new(),
     };
+    for (index, line) in content.lines().enumerate() {
+        // Tolerate a BOM from editors that add one to the first line.
+        let entry = line.trim().trim_start_matches('\u{feff}');
+        if entry.is_empty() || entry.starts_with('#') {
+            continue;
+        }
+        let path = base_dir.join(entry);
+        match queue_item_for_path(&path, &mut cache, id_strategy) {
+            Ok(item) => result.items.push(item),
+            Err(error) => result.failures.push(ListEntryFailure {
+                line: index + 1,
+                entry: entry.to_string(),
+                error,
+            }),
+        }
+    }
 
-    app.emit(SCAN_PROGRESS_EVENT, progress)
-        .map_err(|error| format!("Failed to emit scan progress: {}", error))
+    persist_scan_cache(cache_path, cache);
+    Ok(result)
 }
 
 #[tauri::command]
-pub async fn scan_files(paths: Vec<String>) -> Result<Vec<QueueItemData>, String> {
-    paths
-        .into_iter()
-        .map(PathBuf::from)
-        .map(|path| queue_item_for_path(&path))
-        .collect()
+pub async fn scan_from_list(
+    list_path: String,
+    id_strategy: Option<ItemIdStrategy>,
+) -> Result<ListScanResult, String> {
+    let list_path = expand_path(&list_path);
+    if list_path.as_os_str().is_empty() {
+        return Err("File list path is empty".to_string());
+    }
+    let cache_path = scan_cache_path()
+        .map_err(|error| eprintln!("[scan] scan cache disabled: {}", error))
+        .ok();
+    scan_list_with_cache(
+        &list_path,
+        cache_path.as_deref(),
+        id_strategy.unwrap_or_default(),
+    )
+}
+
+#[tauri::command]
+pub async fn scan_files(
+    paths: Vec<String>,
+    id_strategy: Option<ItemIdStrategy>,
+) -> Result<Vec<QueueItemData>, String> {
+    let cache_path = scan_cache_path()
+        .map_err(|error| eprintln!("[scan] scan cache disabled: {}", error))
+        .ok();
+    scan_files_with_cache(
+        paths,
+        cache_path.as_deref(),
+        id_strategy.unwrap_or_default(),
+    )
+}
+
+#[tauri::command]
+pub fn clear_scan_cache() -> Result<bool, String> {
+    let cache_path = scan_cache_path()?;
+    let _guard = SCAN_CACHE_LOCK
+        .lock()
+        .map_err(|_| "Failed to lock scan cache".to_string())?;
+    match std::fs::remove_file(&cache_path) {
+        Ok(()) => Ok(true),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(error) => Err(format!(
+            "Failed to remove scan cache {}: {}",
+            cache_path.display(),
+            error
+        )),
+    }
+}
+
+// Anything slower than this per entry would make a large share effectively unscannable.
+fn scan_io_delay(io_delay_ms: Option<u64>) -> Result<Duration, String> {
+    let delay_ms = io_delay_ms.unwrap_or(0);
+    if delay_ms > MAX_SCAN_IO_DELAY_MS {
+        return Err(format!(
+            "Scan I/O delay must be at most {}ms",
+            MAX_SCAN_IO_DELAY_MS
+        ));
+    }
+    Ok(Duration::from_millis(delay_ms))
+}
+
+async fn walk_audio_files(
+    files: impl Iterator<Item = PathBuf>,
+    cancel_flag: &AtomicBool,
+    cadence: ProgressCadence,
+    io_delay: Duration,
+    mut on_progress: impl FnMut(u32, u32, &Path) -> Result<(), String>,
+) -> Result<DirectoryWalk, String> {
+    let mut walk = DirectoryWalk::default();
+    let mut last_emit = Instant::now();
+
+    for current_path in files {
+        if cancel_flag.load(Ordering::SeqCst) {
+            walk.cancelled = true;
+            break;
+        }
+        // Pausing between entries spreads directory reads out so a slow network volume keeps up.
+        if walk.scanned > 0 && !io_delay.is_zero() {
+            tokio::time::sleep(io_delay).await;
+        }
+
+        walk.scanned = walk.scanned.saturating_add(1);
+
+        if is_supported_extension(&current_path) {
+            match current_path.to_str() {
+                Some(path) => {
+                    walk.found = walk.found.saturating_add(1);
+                    walk.discovered.push(path.to_string());
+                }
+                None => walk.non_utf8.push(current_path.clone()),
+            }
+        }
+
+        if cadence.should_emit(walk.scanned, last_emit.elapsed()) {
+            on_progress(walk.found, walk.scanned, &current_path)?;
+            last_emit = Instant::now();
+        }
+    }
+
+    Ok(walk)
 }
 
 #[tauri::command]
 pub async fn scan_directory(
     path: String,
     recursive: bool,
+    scan_id: Option<String>,
+    progress_step: Option<u32>,
+    progress_interval_ms: Option<u64>,
+    id_strategy: Option<ItemIdStrategy>,
+    io_delay_ms: Option<u64>,
     app: AppHandle,
 ) -> Result<Vec<QueueItemData>, String> {
-    let root = PathBuf::from(&path);
+    let cadence = ProgressCadence::from_overrides(progress_step, progress_interval_ms)?;
+    let io_delay = scan_io_delay(io_delay_ms)?;
+    let root = expand_path(&path);
 
     if !root.exists() {
         return Err(format!("Directory not found: {}", root.display()));
@@ -278,53 +982,264 @@ pub async fn scan_directory(
         WalkDir::new(&root).max_depth(1)
     };
 
-    let mut found = 0u32;
-    let mut scanned = 0u32;
-    let mut discovered: Vec<String> = Vec::new();
-    let mut last_emit = Instant::now();
+    let scan_id = scan_id
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let registration = ScanRegistration::register(&scan_id)?;
 
-    for entry in walker.into_iter() {
-        let entry = match entry {
-            Ok(entry) => entry,
+    let files = walker
+        .into_iter()
+        .filter_map(|entry| match entry {
+            Ok(entry) => Some(entry),
             Err(error) => {
                 eprintln!("scan_directory warning: {}", error);
-                continue;
+                None
             }
-        };
+        })
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path());
 
-        if !entry.file_type().is_file() {
-            continue;
+    let walk = walk_audio_files(
+        files,
+        &registration.cancel_flag,
+        cadence,
+        io_delay,
+        |found, scanned, current| emit_scan_progress(&app, found, scanned, current),
+    )
+    .await?;
+    drop(registration);
+
+    emit_scan_progress(&app, walk.found, walk.scanned, &root)?;
+    for skipped in &walk.non_utf8 {
+        let payload = ScanSkipped {
+            scan_id: scan_id.clone(),
+            path: skipped.to_string_lossy().to_string(),
+            reason: NON_UTF8_PATH_REASON.to_string(),
+        };
+        eprintln!(
+            "scan_directory warning: skipping {}: {}",
+            payload.path, payload.reason
+        );
+        if let Err(error) = app.emit(SCAN_SKIPPED_EVENT, payload) {
+            eprintln!(
+                "scan_directory warning: failed to emit skipped file: {}",
+                error
+            );
+        }
+    }
+    if walk.cancelled {
+        let payload = ScanCancelled {
+            scan_id,
+            found: walk.found,
+            scanned: walk.scanned,
+        };
+        if let Err(error) = app.emit(SCAN_CANCELLED_EVENT, payload) {
+            eprintln!(
+                "scan_directory warning: failed to emit cancellation: {}",
+                error
+            );
         }
+        // A cancelled scan must not go on to ffprobe what it already found.
+        return Ok(Vec::new());
+    }
 
-        scanned = scanned.saturating_add(1);
-        let current_path = entry.path();
+    scan_files(walk.discovered, id_strategy).await
+}
 
-        if is_supported_extension(current_path) {
-            found = found.saturating_add(1);
-            discovered.push(current_path.to_string_lossy().to_string());
+#[tauri::command]
+pub fn cancel_scan(scan_id: String) -> Result<bool, String> {
+    let scans = ACTIVE_SCANS
+        .lock()
+        .map_err(|_| "Failed to lock active scans".to_string())?;
+    match scans.get(scan_id.trim()) {
+        Some(flag) => {
+            flag.store(true, Ordering::SeqCst);
+            Ok(true)
         }
+        None => Ok(false),
+    }
+}
 
-        if scanned.is_multiple_of(PROGRESS_EMIT_STEP)
-            || last_emit.elapsed() >= PROGRESS_EMIT_INTERVAL
-        {
-            emit_scan_progress(&app, found, scanned, current_path)?;
-            last_emit = Instant::now();
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QueueSortKey {
+    Name,
+    Size,
+    Duration,
+}
+
+impl QueueSortKey {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "name" => Ok(Self::Name),
+            "size" => Ok(Self::Size),
+            "duration" => Ok(Self::Duration),
+            other => Err(format!(
+                "Unsupported queue sort key: {} (expected name, size or duration)",
+                other
+            )),
         }
     }
+}
+
+/// The part of a frontend queue item that `sort_queue` orders by; every other field is ignored.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SortableQueueItem {
+    id: String,
+    path: String,
+    name: String,
+    #[serde(default)]
+    size: u64,
+    #[serde(default)]
+    duration: Option<f64>,
+}
 
-    emit_scan_progress(&app, found, scanned, &root)?;
-    scan_files(discovered).await
+fn compare_queue_items(
+    left: &SortableQueueItem,
+    right: &SortableQueueItem,
+    key: QueueSortKey,
+    descending: bool,
+) -> CmpOrdering {
+    let ordered = |ordering: CmpOrdering| {
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    };
+    let primary = match key {
+        QueueSortKey::Name => ordered(left.name.to_lowercase().cmp(&right.name.to_lowercase())),
+        QueueSortKey::Size => ordered(left.size.cmp(&right.size)),
+        // Unknown durations go last in either direction.
+        QueueSortKey::Duration => match (left.duration, right.duration) {
+            (Some(left), Some(right)) => ordered(left.total_cmp(&right)),
+            (Some(_), None) => CmpOrdering::Less,
+            (None, Some(_)) => CmpOrdering::Greater,
+            (None, None) => CmpOrdering::Equal,
+        },
+    };
+    primary.then_with(|| left.path.cmp(&right.path))
+}
+
+#[tauri::command]
+/// Returns the queue item ids in sorted order; the frontend reorders its own items by them.
+pub fn sort_queue(
+    mut items: Vec<SortableQueueItem>,
+    by: String,
+    descending: bool,
+) -> Result<Vec<String>, String> {
+    let key = QueueSortKey::parse(&by)?;
+    items.sort_by(|left, right| compare_queue_items(left, right, key, descending));
+    Ok(items.into_iter().map(|item| item.id).collect())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn sortable_item(path: &str, size: u64, duration: Option<f64>) -> SortableQueueItem {
+        SortableQueueItem {
+            id: path.to_string(),
+            path: path.to_string(),
+            name: Path::new(path)
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or_default()
+                .to_string(),
+            size,
+            duration,
+        }
+    }
+
+    // Fixture ids are the paths, so the returned order reads as paths.
+    fn sorted_paths(items: &[SortableQueueItem], by: &str, descending: bool) -> Vec<String> {
+        sort_queue(items.to_vec(), by.to_string(), descending)
+            .expect("sort key should be supported")
+    }
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .expect("runtime should build")
+            .block_on(future)
+    }
+
+    #[test]
+    fn sorts_queue_by_name_size_and_duration_with_path_tiebreak() {
+        let items = vec![
+            sortable_item("/b/Interview.wav", 300, Some(90.0)),
+            sortable_item("/a/memo.wav", 100, None),
+            sortable_item("/a/interview.wav", 300, Some(12.5)),
+            sortable_item("/c/zoom.wav", 50, Some(600.0)),
+        ];
+
+        assert_eq!(
+            sorted_paths(&items, "name", false),
+            vec![
+                "/a/interview.wav",
+                "/b/Interview.wav",
+                "/a/memo.wav",
+                "/c/zoom.wav"
+            ]
+        );
+        assert_eq!(
+            sorted_paths(&items, "size", true),
+            vec![
+                "/a/interview.wav",
+                "/b/Interview.wav",
+                "/a/memo.wav",
+                "/c/zoom.wav"
+            ]
+        );
+        assert_eq!(
+            sorted_paths(&items, "Duration", false),
+            vec![
+                "/a/interview.wav",
+                "/b/Interview.wav",
+                "/c/zoom.wav",
+                "/a/memo.wav"
+            ]
+        );
+        assert!(sort_queue(items, "bitrate".to_string(), false).is_err());
+    }
+
+    #[test]
+    fn sorts_full_frontend_items_without_a_format() {
+        let items = serde_json::from_value::<Vec<SortableQueueItem>>(serde_json::json!([
+            {"id": "b", "path": "/b.wav", "name": "b.wav", "size": 2, "status": "completed",
+             "transcriptPath": "/out/b.txt", "language": "de", "rtfx": 40.0},
+            {"id": "a", "path": "/a.wav", "name": "a.wav", "size": 1, "status": "idle"}
+        ]))
+        .expect("frontend items should deserialize");
+        assert_eq!(
+            sort_queue(items, "size".to_string(), false),
+            Ok(vec!["a".to_string(), "b".to_string()])
+        );
+    }
+
+    #[test]
+    fn missing_durations_sort_last_even_when_descending() {
+        let items = vec![
+            sortable_item("/b.wav", 1, None),
+            sortable_item("/c.wav", 1, Some(5.0)),
+            sortable_item("/a.wav", 1, None),
+            sortable_item("/d.wav", 1, Some(50.0)),
+        ];
+
+        assert_eq!(
+            sorted_paths(&items, "duration", true),
+            vec!["/d.wav", "/c.wav", "/a.wav", "/b.wav"]
+        );
+    }
+
     #[test]
     fn accepts_supported_extensions_case_insensitively() {
         assert!(is_supported_extension(Path::new("/tmp/test.WAV")));
         assert!(is_supported_extension(Path::new("/tmp/test.m4a")));
         assert!(is_supported_extension(Path::new("/tmp/test.AIFF")));
+        assert!(is_supported_extension(Path::new("/tmp/memo.caf")));
     }
 
     #[test]
@@ -370,4 +1285,476 @@ mod tests {
             })
         );
     }
+
+    // 44100 Hz encoded as an 80-bit IEEE extended float.
+    const AIFF_RATE_44100: [u8; 10] = [0x40, 0x0e, 0xac, 0x44, 0, 0, 0, 0, 0, 0];
+
+    fn aiff_fixture(form_type: &[u8; 4], comm: &[u8]) -> Vec<u8> {
+        let mut chunks = Vec::new();
+        chunks.extend_from_slice(b"FVER");
+        chunks.extend_from_slice(&4u32.to_be_bytes());
+        chunks.extend_from_slice(&[0xa2, 0x80, 0x51, 0x40]);
+        chunks.extend_from_slice(b"COMM");
+        chunks.extend_from_slice(&(comm.len() as u32).to_be_bytes());
+        chunks.extend_from_slice(comm);
+        chunks.extend_from_slice(b"SSND");
+        chunks.extend_from_slice(&8u32.to_be_bytes());
+        chunks.extend_from_slice(&[0u8; 8]);
+
+        let mut bytes = b"FORM".to_vec();
+        bytes.extend_from_slice(&((chunks.len() + 4) as u32).to_be_bytes());
+        bytes.extend_from_slice(form_type);
+        bytes.extend_from_slice(&chunks);
+        bytes
+    }
+
+    fn aiff_comm(channels: u16, frames: u32, bits: u16) -> Vec<u8> {
+        let mut comm = Vec::new();
+        comm.extend_from_slice(&channels.to_be_bytes());
+        comm.extend_from_slice(&frames.to_be_bytes());
+        comm.extend_from_slice(&bits.to_be_bytes());
+        comm.extend_from_slice(&AIFF_RATE_44100);
+        comm
+    }
+
+    fn caf_chunk(chunk_type: &[u8; 4], size: i64, payload: &[u8]) -> Vec<u8> {
+        let mut chunk = chunk_type.to_vec();
+        chunk.extend_from_slice(&size.to_be_bytes());
+        chunk.extend_from_slice(payload);
+        chunk
+    }
+
+    fn caf_desc(
+        sample_rate: f64,
+        format_id: &[u8; 4],
+        bytes_per_packet: u32,
+        frames_per_packet: u32,
+        channels: u32,
+        bits: u32,
+    ) -> Vec<u8> {
+        let mut desc = Vec::new();
+        desc.extend_from_slice(&sample_rate.to_be_bytes());
+        desc.extend_from_slice(format_id);
+        desc.extend_from_slice(&0u32.to_be_bytes());
+        desc.extend_from_slice(&bytes_per_packet.to_be_bytes());
+        desc.extend_from_slice(&frames_per_packet.to_be_bytes());
+        desc.extend_from_slice(&channels.to_be_bytes());
+        desc.extend_from_slice(&bits.to_be_bytes());
+        caf_chunk(b"desc", 32, &desc)
+    }
+
+    #[test]
+    fn parses_aiff_comm_chunk() {
+        let bytes = aiff_fixture(b"AIFF", &aiff_comm(2, 88_200, 16));
+
+        let parsed =
+            parse_aiff_header(&mut std::io::Cursor::new(bytes)).expect("AIFF header should parse");
+
+        assert_eq!(parsed.duration, Some(2.0));
+        assert_eq!(
+            parsed.metadata,
+            Some(AudioMetadata {
+                codec: Some("pcm_s16be".to_string()),
+                bitrate: Some(1_411_200),
+                sample_rate: Some(44_100),
+                channels: Some(2),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_aifc_compression_type() {
+        let mut comm = aiff_comm(1, 44_100, 16);
+        comm.extend_from_slice(b"sowt");
+        comm.extend_from_slice(&[0, 0]);
+        let bytes = aiff_fixture(b"AIFC", &comm);
+
+        let parsed =
+            parse_aiff_header(&mut std::io::Cursor::new(bytes)).expect("AIFC header should parse");
+
+        assert_eq!(parsed.duration, Some(1.0));
+        let metadata = parsed.metadata.expect("metadata should be present");
+        assert_eq!(metadata.codec.as_deref(), Some("sowt"));
+        assert_eq!(metadata.channels, Some(1));
+    }
+
+    #[test]
+    fn parses_caf_pcm_duration_from_data_chunk() {
+        let mut bytes = b"caff".to_vec();
+        bytes.extend_from_slice(&[0, 1, 0, 0]);
+        bytes.extend(caf_desc(16_000.0, b"lpcm", 2, 1, 1, 16));
+        // Edit count plus 16000 frames of 16-bit mono audio; the body is truncated.
+        bytes.extend(caf_chunk(b"data", 4 + 32_000, &[0, 0, 0, 0]));
+
+        let parsed =
+            parse_caf_header(&mut std::io::Cursor::new(bytes)).expect("CAF header should parse");
+
+        assert_eq!(parsed.duration, Some(1.0));
+        assert_eq!(
+            parsed.metadata,
+            Some(AudioMetadata {
+                codec: Some("lpcm".to_string()),
+                bitrate: Some(256_000),
+                sample_rate: Some(16_000),
+                channels: Some(1),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_caf_compressed_duration_from_packet_table() {
+        let mut pakt = Vec::new();
+        pakt.extend_from_slice(&47i64.to_be_bytes());
+        pakt.extend_from_slice(&132_300i64.to_be_bytes());
+        pakt.extend_from_slice(&2112i32.to_be_bytes());
+        pakt.extend_from_slice(&0i32.to_be_bytes());
+
+        let mut bytes = b"caff".to_vec();
+        bytes.extend_from_slice(&[0, 1, 0, 0]);
+        bytes.extend(caf_desc(44_100.0, b"aac ", 0, 1024, 2, 0));
+        bytes.extend(caf_chunk(b"pakt", pakt.len() as i64, &pakt));
+        bytes.extend(caf_chunk(b"data", -1, &[0, 0, 0, 0]));
+
+        let parsed =
+            parse_caf_header(&mut std::io::Cursor::new(bytes)).expect("CAF header should parse");
+
+        assert_eq!(parsed.duration, Some(3.0));
+        let metadata = parsed.metadata.expect("metadata should be present");
+        assert_eq!(metadata.codec.as_deref(), Some("aac"));
+        assert_eq!(metadata.bitrate, None);
+        assert_eq!(metadata.sample_rate, Some(44_100));
+        assert_eq!(metadata.channels, Some(2));
+    }
+
+    #[test]
+    fn walk_stops_between_entries_once_cancelled() {
+        let cancel_flag = AtomicBool::new(false);
+        let files = ["a.wav", "notes.txt", "b.mp3", "c.flac", "d.wav"]
+            .iter()
+            .enumerate()
+            .map(|(index, name)| {
+                if index == 3 {
+                    cancel_flag.store(true, Ordering::SeqCst);
+                }
+                PathBuf::from("/audio").join(name)
+            });
+
+        let walk = block_on(walk_audio_files(
+            files,
+            &cancel_flag,
+            ProgressCadence::default(),
+            Duration::ZERO,
+            |_, _, _| Ok(()),
+        ))
+        .expect("walk should succeed");
+        assert!(walk.cancelled);
+        assert_eq!(walk.scanned, 3);
+        assert_eq!(walk.found, 2);
+        assert_eq!(walk.discovered, vec!["/audio/a.wav", "/audio/b.mp3"]);
+
+        let untouched = AtomicBool::new(false);
+        let full = block_on(walk_audio_files(
+            ["a.wav", "b.mp3"].iter().map(PathBuf::from),
+            &untouched,
+            ProgressCadence::default(),
+            Duration::ZERO,
+            |_, _, _| Ok(()),
+        ))
+        .expect("walk should succeed");
+        assert!(!full.cancelled);
+        assert_eq!(full.found, 2);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn walk_skips_non_utf8_file_names_instead_of_mangling_them() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let bad = PathBuf::from("/audio").join(OsStr::from_bytes(b"caf\xe9.wav"));
+        let good = PathBuf::from("/audio/good.wav");
+        let walk = block_on(walk_audio_files(
+            vec![bad.clone(), good].into_iter(),
+            &AtomicBool::new(false),
+            ProgressCadence::default(),
+            Duration::ZERO,
+            |_, _, _| Ok(()),
+        ))
+        .expect("walk should succeed");
+
+        assert_eq!(walk.non_utf8, vec![bad]);
+        assert_eq!(walk.discovered, vec!["/audio/good.wav"]);
+        assert_eq!(walk.found, 1);
+        assert_eq!(walk.scanned, 2);
+    }
+
+    #[test]
+    fn walk_pauses_between_entries_when_an_io_delay_is_set() {
+        assert_eq!(scan_io_delay(None), Ok(Duration::ZERO));
+        assert_eq!(scan_io_delay(Some(25)), Ok(Duration::from_millis(25)));
+        assert!(scan_io_delay(Some(MAX_SCAN_IO_DELAY_MS + 1)).is_err());
+
+        let started = Instant::now();
+        let walk = block_on(walk_audio_files(
+            ["a.wav", "b.wav", "c.wav", "d.wav"]
+                .iter()
+                .map(PathBuf::from),
+            &AtomicBool::new(false),
+            ProgressCadence::default(),
+            Duration::from_millis(20),
+            |_, _, _| Ok(()),
+        ))
+        .expect("walk should succeed");
+
+        assert_eq!(walk.found, 4);
+        // Three pauses: none before the first entry.
+        assert!(started.elapsed() >= Duration::from_millis(60));
+    }
+
+    #[test]
+    fn scan_progress_is_only_emitted_while_events_are_enabled() {
+        let enabled = AtomicBool::new(true);
+        let mut emitted = Vec::new();
+        let progress = || ScanProgress {
+            found: 1,
+            scanned: 2,
+            current_path: "/audio/a.wav".to_string(),
+        };
+
+        emit_scan_progress_gated(&enabled, progress, |progress| {
+            emitted.push(progress.scanned);
+            Ok(())
+        })
+        .expect("emit should succeed");
+        enabled.store(false, Ordering::Relaxed);
+        emit_scan_progress_gated(&enabled, progress, |_| Err("should not emit".to_string()))
+            .expect("a disabled gate skips the emit");
+
+        assert_eq!(emitted, vec![2]);
+    }
+
+    #[test]
+    fn progress_cadence_respects_custom_step_and_interval() {
+        let defaults = ProgressCadence::from_overrides(None, None).expect("defaults are valid");
+        assert_eq!(defaults, ProgressCadence::default());
+        assert!(defaults.should_emit(50, Duration::ZERO));
+        assert!(!defaults.should_emit(49, Duration::from_millis(99)));
+        assert!(defaults.should_emit(49, Duration::from_millis(100)));
+
+        let sparse =
+            ProgressCadence::from_overrides(Some(500), Some(2_000)).expect("overrides are valid");
+        assert!(!sparse.should_emit(50, Duration::from_millis(100)));
+        assert!(sparse.should_emit(500, Duration::ZERO));
+        assert!(sparse.should_emit(7, Duration::from_secs(2)));
+
+        let chatty =
+            ProgressCadence::from_overrides(Some(1), Some(10)).expect("minimums are valid");
+        assert!(chatty.should_emit(3, Duration::ZERO));
+
+        assert!(ProgressCadence::from_overrides(Some(0), None).is_err());
+        assert!(ProgressCadence::from_overrides(None, Some(9)).is_err());
+
+        let mut emitted = Vec::new();
+        block_on(walk_audio_files(
+            (1..=6).map(|index| PathBuf::from(format!("/audio/{}.wav", index))),
+            &AtomicBool::new(false),
+            ProgressCadence::from_overrides(Some(2), Some(60_000)).expect("overrides are valid"),
+            Duration::ZERO,
+            |_, scanned, _| {
+                emitted.push(scanned);
+                Ok(())
+            },
+        ))
+        .expect("walk should succeed");
+        assert_eq!(emitted, vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn cancel_scan_flags_only_registered_scans() {
+        let scan_id = format!("scan-{}", Uuid::new_v4());
+        assert!(!cancel_scan(scan_id.clone()).expect("cancel should not error"));
+
+        let registration = ScanRegistration::register(&scan_id).expect("scan should register");
+        assert!(cancel_scan(scan_id.clone()).expect("cancel should not error"));
+        assert!(registration.cancel_flag.load(Ordering::SeqCst));
+
+        drop(registration);
+        assert!(!cancel_scan(scan_id).expect("cancel should not error"));
+    }
+
+    #[test]
+    fn scan_cache_reuses_metadata_until_mtime_changes() {
+        let root = std::env::temp_dir().join(format!("scan-cache-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&root).expect("fixture directory should be created");
+        let audio = root.join("memo.wav");
+        std::fs::write(&audio, b"not really audio").expect("fixture should be written");
+        let audio_key = audio.to_string_lossy().to_string();
+        let cache_path = root.join(SCAN_CACHE_FILE_NAME);
+
+        let file_info = std::fs::metadata(&audio).expect("fixture metadata");
+        let modified = modified_nanos(&file_info).expect("fixture mtime");
+        // Seed a value extraction could never produce, so a hit is unambiguous.
+        let mut seeded = ScanCache::default();
+        seeded.store(
+            &audio_key,
+            file_info.len(),
+            modified,
+            &MetadataResult {
+                duration: Some(42.0),
+                metadata: None,
+            },
+        );
+        seeded.save(&cache_path).expect("cache should be written");
+
+        let hit = scan_files_with_cache(
+            vec![audio_key.clone()],
+            Some(&cache_path),
+            ItemIdStrategy::Random,
+        )
+        .expect("scan should succeed");
+        assert_eq!(hit[0].duration, Some(42.0));
+
+        File::options()
+            .write(true)
+            .open(&audio)
+            .and_then(|file| file.set_modified(UNIX_EPOCH + Duration::from_secs(1_700_000_000)))
+            .expect("mtime should be updated");
+        let miss = scan_files_with_cache(
+            vec![audio_key.clone()],
+            Some(&cache_path),
+            ItemIdStrategy::Random,
+        )
+        .expect("scan should succeed");
+        assert_eq!(miss[0].duration, None);
+
+        // Without ffprobe the miss extracts nothing, and an empty result is not cached.
+        let refreshed = ScanCache::load(&cache_path);
+        let entry = refreshed.entries.get(&audio_key).expect("entry is kept");
+        assert_eq!(
+            entry.modified_nanos, modified,
+            "an empty result is not stored"
+        );
+
+        let mut merged = ScanCache::default();
+        let mut scanned = ScanCache::default();
+        scanned.store(
+            "/audio/new.wav",
+            1,
+            2,
+            &MetadataResult {
+                duration: Some(3.0),
+                metadata: None,
+            },
+        );
+        merged.absorb(scanned);
+        assert_eq!(
+            merged
+                .entries
+                .get("/audio/new.wav")
+                .map(|entry| entry.duration),
+            Some(Some(3.0))
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn scans_file_lists_relative_to_the_list_and_reports_bad_lines() {
+        let root = std::env::temp_dir().join(format!("scan-list-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(root.join("audio")).expect("fixture directory should be created");
+        let absolute = root.join("audio").join("absolute.wav");
+        std::fs::write(&absolute, b"RIFF").expect("fixture should be written");
+        std::fs::write(root.join("audio").join("relative.mp3"), b"ID3")
+            .expect("fixture should be written");
+        let list = root.join("queue.txt");
+        std::fs::write(
+            &list,
+            format!(
+                "# interviews\n\n{}\n  audio/relative.mp3  \n# audio/commented.wav\naudio/missing.wav\n",
+                absolute.display()
+            ),
+        )
+        .expect("list should be written");
+
+        let result = scan_list_with_cache(&list, None, ItemIdStrategy::Random)
+            .expect("list should be scanned");
+        assert_eq!(
+            result
+                .items
+                .iter()
+                .map(|item| item.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["absolute.wav", "relative.mp3"]
+        );
+        assert_eq!(
+            result.items[1].path,
+            root.join("audio/relative.mp3").to_string_lossy()
+        );
+        assert_eq!(result.failures.len(), 1);
+        assert_eq!(result.failures[0].line, 6);
+        assert_eq!(result.failures[0].entry, "audio/missing.wav");
+        assert!(result.failures[0].error.starts_with("Path not found"));
+        assert!(
+            scan_list_with_cache(&root.join("absent.txt"), None, ItemIdStrategy::Random).is_err()
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn stable_id_strategies_survive_rescans() {
+        let root = std::env::temp_dir().join(format!("scan-ids-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&root).expect("fixture directory should be created");
+        let audio = root.join("memo.wav");
+        std::fs::write(&audio, b"RIFF").expect("fixture should be written");
+        let scan = |path: &Path, strategy| {
+            scan_files_with_cache(vec![path.to_string_lossy().to_string()], None, strategy)
+                .expect("scan should succeed")
+                .remove(0)
+                .id
+        };
+
+        let by_path = scan(&audio, ItemIdStrategy::Path);
+        assert_eq!(by_path, scan(&audio, ItemIdStrategy::Path));
+        // Non-canonical spellings of the same file resolve to the same id.
+        assert_eq!(
+            by_path,
+            scan(&root.join(".").join("memo.wav"), ItemIdStrategy::Path)
+        );
+        assert!(Uuid::parse_str(&by_path).is_ok());
+
+        let by_metadata = scan(&audio, ItemIdStrategy::PathAndMetadata);
+        assert_eq!(by_metadata, scan(&audio, ItemIdStrategy::PathAndMetadata));
+        assert_ne!(by_metadata, by_path);
+        std::fs::write(&audio, b"RIFF and more").expect("fixture should be rewritten");
+        assert_ne!(by_metadata, scan(&audio, ItemIdStrategy::PathAndMetadata));
+        assert_eq!(by_path, scan(&audio, ItemIdStrategy::Path));
+
+        assert_ne!(
+            scan(&audio, ItemIdStrategy::Random),
+            scan(&audio, ItemIdStrategy::Random)
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn malformed_native_headers_yield_no_metadata() {
+        assert!(
+            parse_aiff_header(&mut std::io::Cursor::new(b"RIFF\0\0\0\0WAVE".to_vec())).is_none()
+        );
+        assert!(parse_caf_header(&mut std::io::Cursor::new(b"caff".to_vec())).is_none());
+
+        let truncated = aiff_fixture(b"AIFF", &[0, 2, 0, 0]);
+        assert!(parse_aiff_header(&mut std::io::Cursor::new(truncated)).is_none());
+
+        let root = std::env::temp_dir().join(format!("scan-native-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&root).expect("fixture dir should be created");
+        let garbage = root.join("broken.caf");
+        std::fs::write(&garbage, b"not a caf file").expect("fixture should be written");
+        assert_eq!(
+            extract_native_metadata(&garbage, "caf"),
+            MetadataResult::default()
+        );
+    }
 }
@@ -0,0 +1,407 @@
+use crate::commands::export::{add_zip_entry, create_zip_archive, finish_zip_archive};
+use crate::commands::scan::SUPPORTED_EXTENSIONS;
+use crate::providers::launcher::event_log_path;
+use crate::providers::registry::{probe_all, resolve_swift_binary_path};
+use crate::providers::settings::{load_settings, PersistedSettings};
+use crate::{health_check, run_startup_diagnostics, StartupDiagnosticsRequest};
+use serde::Serialize;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+const EVENT_LOG_TAIL_LINES: usize = 2000;
+const RECENT_REPORT_LIMIT: usize = 5;
+
+fn pretty_json(value: &impl Serialize) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_else(|error| {
+        json!({ "error": format!("Failed to serialize section: {}", error) }).to_string()
+    })
+}
+
+fn tail_lines(content: &str, limit: usize) -> String {
+    let lines = content.lines().collect::<Vec<&str>>();
+    let start = lines.len().saturating_sub(limit);
+    let mut tail = lines[start..].join("\n");
+    tail.push('\n');
+    tail
+}
+
+fn recent_run_reports(output_dir: &Path, limit: usize) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(output_dir.join("_reports")) else {
+        return Vec::new();
+    };
+
+    // Reports are named run-<timestamp>.json, so the newest sort last.
+    let mut reports = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "json"))
+        .collect::<Vec<PathBuf>>();
+    reports.sort();
+    reports.into_iter().rev().take(limit).collect()
+}
+
+// Replaces only the matches whose surrounding text passes `bounded(before, after)`.
+fn replace_bounded(
+    text: &str,
+    needle: &str,
+    replacement: &str,
+    bounded: impl Fn(&str, &str) -> bool,
+) -> String {
+    let mut redacted = String::with_capacity(text.len());
+    let mut copied = 0;
+    for (start, _) in text.match_indices(needle) {
+        let end = start + needle.len();
+        if bounded(&text[..start], &text[end..]) {
+            redacted.push_str(&text[copied..start]);
+            redacted.push_str(replacement);
+            copied = end;
+        }
+    }
+    redacted.push_str(&text[copied..]);
+    redacted
+}
+
+// Short usernames such as "al" also occur inside ordinary words, so only whole words count.
+fn replace_whole_word(text: &str, word: &str, replacement: &str) -> String {
+    let is_word_char = |ch: char| ch.is_alphanumeric() || ch == '_';
+    replace_bounded(text, word, replacement, |before, after| {
+        !before.chars().next_back().is_some_and(is_word_char)
+            && !after.chars().next().is_some_and(is_word_char)
+    })
+}
+
+// "/Users/al" is a prefix of "/Users/alice", so the home path must end where a path component does.
+fn replace_home_path(text: &str, home: &str) -> String {
+    replace_bounded(text, home, "~", |_, after| {
+        after
+            .chars()
+            .next()
+            .is_none_or(|ch| ch == '/' || ch == '"' || ch == '\'' || ch.is_whitespace())
+    })
+}
+
+fn redact_user_paths(text: &str, home: Option<&Path>) -> String {
+    let Some(home) = home.and_then(Path::to_str).filter(|home| !home.is_empty()) else {
+        return text.to_string();
+    };
+    let redacted = replace_home_path(text, home);
+    match Path::new(home)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .filter(|name| !name.is_empty())
+    {
+        Some(username) => replace_whole_word(&redacted, username, "<user>"),
+        None => redacted,
+    }
+}
+
+fn write_support_bundle(
+    destination: &Path,
+    sections: &[(String, String)],
+    home: Option<&Path>,
+) -> Result<(), String> {
+    let mut zip = create_zip_archive(destination)?;
+    for (name, content) in sections {
+        add_zip_entry(&mut zip, name, redact_user_paths(content, home).as_bytes())?;
+    }
+    finish_zip_archive(zip, destination)
+}
+
+fn log_sections(event_log: Option<&Path>, output_dir: &Path) -> Vec<(String, String)> {
+    let mut sections = Vec::new();
+    if let Some(path) = event_log {
+        match std::fs::read_to_string(path) {
+            Ok(content) => sections.push((
+                "logs/events.jsonl".to_string(),
+                tail_lines(&content, EVENT_LOG_TAIL_LINES),
+            )),
+            Err(error) => eprintln!("[support] skipping event log {}: {}", path.display(), error),
+        }
+    }
+
+    for report in recent_run_reports(output_dir, RECENT_REPORT_LIMIT) {
+        let Some(name) = report.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        match std::fs::read_to_string(&report) {
+            Ok(content) => sections.push((format!("logs/reports/{}", name), content)),
+            Err(error) => eprintln!("[support] skipping report {}: {}", report.display(), error),
+        }
+    }
+    sections
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectiveConfig {
+    pub data_dir: Option<String>,
+    pub sessions_dir: Option<String>,
+    pub history_db: Option<String>,
+    pub models_root: Option<String>,
+    pub swift_binary: String,
+    pub supported_extensions: Vec<String>,
+    pub default_provider: Option<String>,
+    pub default_model: Option<String>,
+    pub max_parallel_sessions: usize,
+}
+
+fn display_path(path: &Path, home: Option<&Path>) -> String {
+    match home.and_then(|home| path.strip_prefix(home).ok()) {
+        Some(relative) if relative.as_os_str().is_empty() => "~".to_string(),
+        Some(relative) => format!("~/{}", relative.display()),
+        None => path.to_string_lossy().to_string(),
+    }
+}
+
+// Mirrors the paths the history, manifest and model resolvers derive from the home directory.
+fn assemble_effective_config(
+    home: Option<&Path>,
+    swift_binary: &Path,
+    settings: &PersistedSettings,
+    redact_home: bool,
+) -> EffectiveConfig {
+    let shown = |path: &Path| display_path(path, home.filter(|_| redact_home));
+    let data_dir = home.map(|home| home.join(".aura"));
+    let sessions_dir = data_dir.as_ref().map(|dir| dir.join("sessions"));
+    let models_root =
+        crate::fluid_models_root_from(home.map(|home| home.as_os_str().to_owned())).ok();
+
+    EffectiveConfig {
+        data_dir: data_dir.as_deref().map(shown),
+        history_db: sessions_dir
+            .as_ref()
+            .map(|dir| shown(&dir.join("history.db"))),
+        sessions_dir: sessions_dir.as_deref().map(shown),
+        models_root: models_root.as_deref().map(shown),
+        swift_binary: shown(swift_binary),
+        supported_extensions: SUPPORTED_EXTENSIONS
+            .iter()
+            .map(|extension| extension.to_string())
+            .collect(),
+        default_provider: settings.default_provider.clone(),
+        default_model: settings.default_model.clone(),
+        max_parallel_sessions: settings.max_parallel_sessions,
+    }
+}
+
+#[tauri::command]
+pub fn effective_config(app: AppHandle, redact_home: Option<bool>) -> EffectiveConfig {
+    assemble_effective_config(
+        dirs::home_dir().as_deref(),
+        &resolve_swift_binary_path(&app),
+        &load_settings(),
+        redact_home.unwrap_or(false),
+    )
+}
+
+#[tauri::command]
+pub async fn create_support_bundle(
+    app: AppHandle,
+    destination: String,
+    diagnostics: StartupDiagnosticsRequest,
+) -> Result<String, String> {
+    let destination = destination.trim();
+    if destination.is_empty() {
+        return Err("Support bundle destination is empty".to_string());
+    }
+
+    let output_dir = PathBuf::from(&diagnostics.output_dir);
+    let package = app.package_info();
+    let mut sections = vec![(
+        "app-version.json".to_string(),
+        pretty_json(&json!({
+            "name": package.name,
+            "version": package.version.to_string(),
+            "os": std::env::consts::OS,
+            "arch": std::env::consts::ARCH,
+        })),
+    )];
+    sections.push((
+        "diagnostics.json".to_string(),
+        match run_startup_diagnostics(app.clone(), diagnostics) {
+            Ok(result) => pretty_json(&result),
+            Err(error) => pretty_json(&json!({ "error": error })),
+        },
+    ));
+    sections.push((
+        "health-check.json".to_string(),
+        match health_check(app.clone()).await {
+            Ok(status) => pretty_json(&status),
+            Err(error) => pretty_json(&json!({ "error": error })),
+        },
+    ));
+    sections.push(("providers.json".to_string(), pretty_json(&probe_all(&app))));
+    sections.extend(log_sections(event_log_path().as_deref(), &output_dir));
+
+    let destination_path = PathBuf::from(destination);
+    write_support_bundle(&destination_path, &sections, dirs::home_dir().as_deref())?;
+    Ok(destination_path.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::Read;
+    use zip::ZipArchive;
+
+    #[test]
+    fn effective_config_reflects_injected_home_and_settings() {
+        let home = Path::new("/Users/jdoe");
+        let swift_binary = Path::new("/Users/jdoe/dev/swift-worker/.build/release/coreml-batch");
+        let settings = PersistedSettings {
+            max_parallel_sessions: 3,
+            default_provider: Some("faster-whisper".to_string()),
+            default_model: Some("large-v3".to_string()),
+            ..PersistedSettings::default()
+        };
+
+        let config = assemble_effective_config(Some(home), swift_binary, &settings, false);
+        assert_eq!(config.data_dir.as_deref(), Some("/Users/jdoe/.aura"));
+        assert_eq!(
+            config.history_db.as_deref(),
+            Some("/Users/jdoe/.aura/sessions/history.db")
+        );
+        assert_eq!(
+            config.models_root.as_deref(),
+            Some("/Users/jdoe/Library/Application Support/FluidAudio/Models")
+        );
+        assert_eq!(config.default_provider.as_deref(), Some("faster-whisper"));
+        assert_eq!(config.max_parallel_sessions, 3);
+        assert!(config.supported_extensions.contains(&"flac".to_string()));
+
+        let redacted = assemble_effective_config(Some(home), swift_binary, &settings, true);
+        assert_eq!(redacted.sessions_dir.as_deref(), Some("~/.aura/sessions"));
+        assert_eq!(
+            redacted.swift_binary,
+            "~/dev/swift-worker/.build/release/coreml-batch"
+        );
+
+        let homeless =
+            assemble_effective_config(None, Path::new("/opt/coreml-batch"), &settings, true);
+        assert_eq!(homeless.data_dir, None);
+        assert_eq!(homeless.models_root, None);
+        assert_eq!(homeless.swift_binary, "/opt/coreml-batch");
+    }
+
+    #[test]
+    fn username_is_redacted_only_as_a_whole_word() {
+        let home = Path::new("/Users/al");
+        assert_eq!(
+            redact_user_paths("total: al, /Users/al/out, /Volumes/al/x, alpha", Some(home)),
+            "total: <user>, ~/out, /Volumes/<user>/x, alpha"
+        );
+    }
+
+    #[test]
+    fn home_path_is_not_redacted_inside_a_longer_home() {
+        let home = Path::new("/Users/al");
+        assert_eq!(
+            redact_user_paths(
+                "/Users/alice/out \"/Users/al\" /Users/al/x /Users/al",
+                Some(home)
+            ),
+            "/Users/alice/out \"~\" ~/x ~"
+        );
+    }
+
+    #[test]
+    fn support_bundle_contains_sections_with_home_paths_redacted() {
+        let root = std::env::temp_dir().join(format!("support-bundle-{}", uuid::Uuid::new_v4()));
+        let reports_dir = root.join("_reports");
+        std::fs::create_dir_all(&reports_dir).expect("reports dir should be created");
+        for index in 0..7 {
+            std::fs::write(
+                reports_dir.join(format!("run-2026021{}-000000.json", index)),
+                "{}",
+            )
+            .expect("report fixture should be written");
+        }
+
+        let event_log = root.join("events.jsonl");
+        std::fs::write(&event_log, "{\"output_dir\":\"/Users/jdoe/out\"}\n")
+            .expect("event log fixture should be written");
+
+        let home = Path::new("/Users/jdoe");
+        let mut sections = vec![
+            (
+                "app-version.json".to_string(),
+                pretty_json(&json!({ "version": "0.1.0" })),
+            ),
+            (
+                "diagnostics.json".to_string(),
+                pretty_json(&json!({ "checkedOutputPath": "/Users/jdoe/Transcripts" })),
+            ),
+            (
+                "health-check.json".to_string(),
+                pretty_json(&json!({ "swiftOk": true })),
+            ),
+            (
+                "providers.json".to_string(),
+                "[{\"owner\": \"jdoe\"}]".to_string(),
+            ),
+        ];
+        sections.extend(log_sections(Some(&event_log), &root));
+
+        let destination = root.join("support.zip");
+        write_support_bundle(&destination, &sections, Some(home))
+            .expect("support bundle should be written");
+
+        let mut archive = ZipArchive::new(File::open(&destination).expect("bundle should exist"))
+            .expect("bundle should be a readable zip");
+        let mut names = archive
+            .file_names()
+            .map(str::to_string)
+            .collect::<Vec<String>>();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                "app-version.json",
+                "diagnostics.json",
+                "health-check.json",
+                "logs/events.jsonl",
+                "logs/reports/run-20260212-000000.json",
+                "logs/reports/run-20260213-000000.json",
+                "logs/reports/run-20260214-000000.json",
+                "logs/reports/run-20260215-000000.json",
+                "logs/reports/run-20260216-000000.json",
+                "providers.json",
+            ]
+        );
+
+        let mut diagnostics = String::new();
+        archive
+            .by_name("diagnostics.json")
+            .expect("diagnostics should be bundled")
+            .read_to_string(&mut diagnostics)
+            .expect("diagnostics should be utf-8");
+        assert!(diagnostics.contains("~/Transcripts"));
+        assert!(!diagnostics.contains("/Users/jdoe"));
+
+        let mut providers = String::new();
+        archive
+            .by_name("providers.json")
+            .expect("providers should be bundled")
+            .read_to_string(&mut providers)
+            .expect("providers should be utf-8");
+        assert_eq!(providers, "[{\"owner\": \"<user>\"}]");
+
+        let mut events = String::new();
+        archive
+            .by_name("logs/events.jsonl")
+            .expect("event log should be bundled")
+            .read_to_string(&mut events)
+            .expect("event log should be utf-8");
+        assert_eq!(events, "{\"output_dir\":\"~/out\"}\n");
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn tails_event_log_to_the_most_recent_lines() {
+        assert_eq!(tail_lines("a\nb\nc\n", 2), "b\nc\n");
+        assert_eq!(tail_lines("a", 5), "a\n");
+    }
+}
@@ -0,0 +1,544 @@
+use crate::commands::convert::sidecar_cues;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+const UTF16LE_BOM: &[u8] = &[0xFF, 0xFE];
+const UTF16BE_BOM: &[u8] = &[0xFE, 0xFF];
+const UTF16_ZERO_BYTE_RATIO: f64 = 0.3;
+
+// Windows-1252 code points for bytes 0x80..=0x9F; unassigned bytes map to C1 controls.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextEncoding {
+    Utf8,
+    Utf8Bom,
+    Utf16Le,
+    Utf16Be,
+    Windows1252,
+}
+
+impl TextEncoding {
+    fn label(self) -> &'static str {
+        match self {
+            TextEncoding::Utf8 => "utf-8",
+            TextEncoding::Utf8Bom => "utf-8-bom",
+            TextEncoding::Utf16Le => "utf-16le",
+            TextEncoding::Utf16Be => "utf-16be",
+            TextEncoding::Windows1252 => "windows-1252",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptText {
+    pub content: String,
+    pub detected_encoding: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranscriptFormat {
+    Text,
+    Json,
+    Srt,
+    Vtt,
+}
+
+impl TranscriptFormat {
+    fn label(self) -> &'static str {
+        match self {
+            TranscriptFormat::Text => "text",
+            TranscriptFormat::Json => "json",
+            TranscriptFormat::Srt => "srt",
+            TranscriptFormat::Vtt => "vtt",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TypedTranscript {
+    pub format: String,
+    pub content: String,
+}
+
+fn decode_utf16(bytes: &[u8], little_endian: bool) -> String {
+    let units = bytes
+        .chunks_exact(2)
+        .map(|pair| {
+            if little_endian {
+                u16::from_le_bytes([pair[0], pair[1]])
+            } else {
+                u16::from_be_bytes([pair[0], pair[1]])
+            }
+        })
+        .collect::<Vec<u16>>();
+    String::from_utf16_lossy(&units)
+}
+
+fn decode_windows_1252(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| match byte {
+            0x80..=0x9F => WINDOWS_1252_HIGH[usize::from(byte - 0x80)],
+            _ => char::from(*byte),
+        })
+        .collect()
+}
+
+fn sniff_bomless_utf16(bytes: &[u8]) -> Option<TextEncoding> {
+    let pairs = bytes.len() / 2;
+    if pairs == 0 {
+        return None;
+    }
+
+    let zero_even = bytes.iter().step_by(2).filter(|byte| **byte == 0).count();
+    let zero_odd = bytes
+        .iter()
+        .skip(1)
+        .step_by(2)
+        .filter(|byte| **byte == 0)
+        .count();
+
+    // Mostly-ASCII UTF-16 text leaves a zero in every other byte.
+    let ratio = |zeros: usize| zeros as f64 / pairs as f64;
+    if ratio(zero_odd) >= UTF16_ZERO_BYTE_RATIO && zero_even == 0 {
+        Some(TextEncoding::Utf16Le)
+    } else if ratio(zero_even) >= UTF16_ZERO_BYTE_RATIO && zero_odd == 0 {
+        Some(TextEncoding::Utf16Be)
+    } else {
+        None
+    }
+}
+
+pub fn decode_transcript_bytes(bytes: Vec<u8>) -> (String, TextEncoding) {
+    if let Some(rest) = bytes.strip_prefix(UTF8_BOM) {
+        return (
+            String::from_utf8_lossy(rest).into_owned(),
+            TextEncoding::Utf8Bom,
+        );
+    }
+    if let Some(rest) = bytes.strip_prefix(UTF16LE_BOM) {
+        return (decode_utf16(rest, true), TextEncoding::Utf16Le);
+    }
+    if let Some(rest) = bytes.strip_prefix(UTF16BE_BOM) {
+        return (decode_utf16(rest, false), TextEncoding::Utf16Be);
+    }
+
+    let bytes = match String::from_utf8(bytes) {
+        Ok(content) => {
+            // ASCII-only UTF-16 is valid UTF-8, so NULs are the only tell.
+            if !content.contains('\0') {
+                return (content, TextEncoding::Utf8);
+            }
+            content.into_bytes()
+        }
+        Err(error) => error.into_bytes(),
+    };
+
+    match sniff_bomless_utf16(&bytes) {
+        Some(TextEncoding::Utf16Le) => (decode_utf16(&bytes, true), TextEncoding::Utf16Le),
+        Some(TextEncoding::Utf16Be) => (decode_utf16(&bytes, false), TextEncoding::Utf16Be),
+        _ => match String::from_utf8(bytes) {
+            Ok(content) => (content, TextEncoding::Utf8),
+            Err(error) => (
+                decode_windows_1252(error.as_bytes()),
+                TextEncoding::Windows1252,
+            ),
+        },
+    }
+}
+
+pub fn read_transcript_file(path: &Path) -> Result<TranscriptText, String> {
+    if !path.exists() {
+        return Err(format!("Transcript not found: {}", path.display()));
+    }
+
+    let bytes = std::fs::read(path)
+        .map_err(|error| format!("Failed to read transcript {}: {}", path.display(), error))?;
+    let (content, encoding) = decode_transcript_bytes(bytes);
+
+    Ok(TranscriptText {
+        content,
+        detected_encoding: encoding.label().to_string(),
+    })
+}
+
+fn looks_like_json(content: &str) -> bool {
+    let trimmed = content.trim_start();
+    (trimmed.starts_with('{') || trimmed.starts_with('['))
+        && serde_json::from_str::<serde_json::Value>(content).is_ok()
+}
+
+fn looks_like_vtt(content: &str) -> bool {
+    content.trim_start().starts_with("WEBVTT")
+}
+
+fn looks_like_srt(content: &str) -> bool {
+    // SRT opens with a numeric cue index followed by a "00:00:01,000 --> ..." timing line.
+    let mut lines = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty());
+    let index_ok = lines
+        .next()
+        .is_some_and(|line| line.chars().all(|ch| ch.is_ascii_digit()));
+    index_ok
+        && lines
+            .next()
+            .is_some_and(|line| line.contains("-->") && line.contains(','))
+}
+
+fn sniff_transcript_format(content: &str) -> TranscriptFormat {
+    if looks_like_vtt(content) {
+        TranscriptFormat::Vtt
+    } else if looks_like_srt(content) {
+        TranscriptFormat::Srt
+    } else if looks_like_json(content) {
+        TranscriptFormat::Json
+    } else {
+        TranscriptFormat::Text
+    }
+}
+
+pub fn detect_transcript_format(path: &Path, content: &str) -> TranscriptFormat {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase);
+
+    // A known extension wins when the content agrees; plain .txt is always shown as text.
+    let claimed = match extension.as_deref() {
+        Some("txt") => return TranscriptFormat::Text,
+        Some("json") => looks_like_json(content).then_some(TranscriptFormat::Json),
+        Some("srt") => looks_like_srt(content).then_some(TranscriptFormat::Srt),
+        Some("vtt") => looks_like_vtt(content).then_some(TranscriptFormat::Vtt),
+        _ => None,
+    };
+    claimed.unwrap_or_else(|| sniff_transcript_format(content))
+}
+
+#[tauri::command]
+pub fn read_transcript_typed(path: String) -> Result<TypedTranscript, String> {
+    let normalized = path.trim();
+    if normalized.is_empty() {
+        return Err("Transcript path is empty".to_string());
+    }
+
+    let path = PathBuf::from(normalized);
+    let transcript = read_transcript_file(&path)?;
+    Ok(TypedTranscript {
+        format: detect_transcript_format(&path, &transcript.content)
+            .label()
+            .to_string(),
+        content: transcript.content,
+    })
+}
+
+// Only the text fields are kept; serde skips words, probabilities and other bulky fields.
+#[derive(Debug, Deserialize)]
+struct SidecarSegmentText {
+    #[serde(default)]
+    text: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SidecarText {
+    #[serde(default)]
+    text: Option<String>,
+    #[serde(default)]
+    segments: Vec<SidecarSegmentText>,
+}
+
+impl SidecarText {
+    fn into_text(self) -> Option<String> {
+        if let Some(text) = self.text.filter(|text| !text.trim().is_empty()) {
+            return Some(text.trim().to_string());
+        }
+
+        let joined = self
+            .segments
+            .iter()
+            .filter_map(|segment| segment.text.as_deref())
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<&str>>()
+            .join(" ");
+        (!joined.is_empty()).then_some(joined)
+    }
+}
+
+#[tauri::command]
+pub fn extract_text_from_json(path: String) -> Result<String, String> {
+    let normalized = path.trim();
+    if normalized.is_empty() {
+        return Err("Transcript path is empty".to_string());
+    }
+
+    let path = PathBuf::from(normalized);
+    let file = File::open(&path)
+        .map_err(|error| format!("Failed to open transcript {}: {}", path.display(), error))?;
+    let sidecar: SidecarText = serde_json::from_reader(BufReader::new(file)).map_err(|error| {
+        format!(
+            "Failed to parse transcript JSON {}: {}",
+            path.display(),
+            error
+        )
+    })?;
+    sidecar
+        .into_text()
+        .ok_or_else(|| format!("Transcript JSON has no text: {}", path.display()))
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct TranscriptSegment {
+    pub start: f64,
+    pub end: f64,
+    pub text: String,
+}
+
+/// Returns the timed segments of a JSON sidecar so the UI can seek the audio to each one.
+#[tauri::command]
+pub fn transcript_segments(json_path: String) -> Result<Vec<TranscriptSegment>, String> {
+    let normalized = json_path.trim();
+    if normalized.is_empty() {
+        return Err("Transcript path is empty".to_string());
+    }
+
+    let path = PathBuf::from(normalized);
+    let transcript = read_transcript_file(&path)?;
+    if detect_transcript_format(&path, &transcript.content) != TranscriptFormat::Json {
+        return Err(format!(
+            "Transcript is not a JSON sidecar: {}",
+            path.display()
+        ));
+    }
+    let segments = sidecar_cues(&transcript.content)
+        .map_err(|error| format!("{}: {}", error, path.display()))?
+        .into_iter()
+        .filter(|cue| !cue.text.is_empty())
+        .map(|cue| TranscriptSegment {
+            start: cue.start_ms as f64 / 1000.0,
+            end: cue.end_ms as f64 / 1000.0,
+            text: cue.text,
+        })
+        .collect::<Vec<TranscriptSegment>>();
+    if segments.is_empty() {
+        return Err(format!(
+            "Transcript JSON has no segments: {}",
+            path.display()
+        ));
+    }
+    Ok(segments)
+}
+
+#[tauri::command]
+pub fn read_transcript_with_encoding(path: String) -> Result<TranscriptText, String> {
+    let normalized = path.trim();
+    if normalized.is_empty() {
+        return Err("Transcript path is empty".to_string());
+    }
+
+    read_transcript_file(&PathBuf::from(normalized))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn write_fixture(name: &str, bytes: &[u8]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("transcript-encoding-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&root).expect("fixture directory should be created");
+        let path = root.join(name);
+        std::fs::write(&path, bytes).expect("fixture should be written");
+        path
+    }
+
+    #[test]
+    fn reads_utf16le_transcript_with_bom() {
+        let mut bytes = UTF16LE_BOM.to_vec();
+        for unit in "Héllo wörld".encode_utf16() {
+            bytes.extend_from_slice(&unit.to_le_bytes());
+        }
+        let path = write_fixture("utf16.txt", &bytes);
+
+        let transcript = read_transcript_file(&path).expect("transcript should decode");
+        assert_eq!(transcript.content, "Héllo wörld");
+        assert_eq!(transcript.detected_encoding, "utf-16le");
+    }
+
+    #[test]
+    fn reads_windows_1252_transcript() {
+        // "Café – “quoted” €5" in Windows-1252.
+        let bytes = b"Caf\xE9 \x96 \x93quoted\x94 \x805";
+        let path = write_fixture("legacy.txt", bytes);
+
+        let transcript = read_transcript_file(&path).expect("transcript should decode");
+        assert_eq!(transcript.content, "Café – “quoted” €5");
+        assert_eq!(transcript.detected_encoding, "windows-1252");
+    }
+
+    #[test]
+    fn keeps_utf8_and_detects_bomless_utf16() {
+        let (content, encoding) = decode_transcript_bytes("plain ütf-8".as_bytes().to_vec());
+        assert_eq!(content, "plain ütf-8");
+        assert_eq!(encoding, TextEncoding::Utf8);
+
+        let (content, encoding) = decode_transcript_bytes(b"\xEF\xBB\xBFbom".to_vec());
+        assert_eq!(content, "bom");
+        assert_eq!(encoding, TextEncoding::Utf8Bom);
+
+        let bytes = "ascii text"
+            .encode_utf16()
+            .flat_map(|unit| unit.to_be_bytes())
+            .collect::<Vec<u8>>();
+        let (content, encoding) = decode_transcript_bytes(bytes);
+        assert_eq!(content, "ascii text");
+        assert_eq!(encoding, TextEncoding::Utf16Be);
+    }
+
+    #[test]
+    fn detects_transcript_format_by_extension_and_content() {
+        let fixtures = [
+            ("plain.txt", "Just a spoken sentence.", "text"),
+            ("looks-like.txt", r#"{"text": "hello"}"#, "text"),
+            (
+                "sidecar.json",
+                r#"{"segments": [{"text": "hello"}]}"#,
+                "json",
+            ),
+            (
+                "captions.srt",
+                "1\n00:00:00,000 --> 00:00:01,500\nhello\n",
+                "srt",
+            ),
+            (
+                "captions.vtt",
+                "WEBVTT\n\n00:00.000 --> 00:01.500\nhello\n",
+                "vtt",
+            ),
+            ("broken.json", "not json after all", "text"),
+            ("untyped", "WEBVTT\n\n00:00.000 --> 00:01.500\nhi\n", "vtt"),
+            ("untyped.out", r#"[{"start": 0.0}]"#, "json"),
+        ];
+
+        for (name, content, expected) in fixtures {
+            let path = write_fixture(name, content.as_bytes());
+            let typed = read_transcript_typed(path.to_string_lossy().to_string())
+                .expect("fixture should be readable");
+            assert_eq!(typed.format, expected, "format for {}", name);
+            assert_eq!(typed.content, content);
+        }
+    }
+
+    #[test]
+    fn extracts_text_from_flat_and_segmented_sidecars() {
+        let flat = write_fixture(
+            "flat.json",
+            br#"{"text": " Hello world. ", "language": "en", "segments": [{"text": "ignored"}]}"#,
+        );
+        assert_eq!(
+            extract_text_from_json(flat.to_string_lossy().to_string()),
+            Ok("Hello world.".to_string())
+        );
+
+        let segmented = write_fixture(
+            "segmented.json",
+            br#"{"segments": [
+                {"start": 0.0, "end": 1.2, "text": " Hello", "words": [{"word": "Hello"}]},
+                {"start": 1.2, "end": 1.4, "text": "  "},
+                {"start": 1.4, "end": 2.0, "text": "world. "}
+            ]}"#,
+        );
+        assert_eq!(
+            extract_text_from_json(segmented.to_string_lossy().to_string()),
+            Ok("Hello world.".to_string())
+        );
+
+        let empty = write_fixture("empty.json", br#"{"text": "", "segments": []}"#);
+        assert!(extract_text_from_json(empty.to_string_lossy().to_string()).is_err());
+    }
+
+    #[test]
+    fn reads_segments_from_segmented_and_token_timing_sidecars() {
+        let segmented = write_fixture(
+            "segmented.json",
+            br#"{"text": "Hello world.", "segments": [
+                {"id": 0, "start": 0.0, "end": 1.2, "text": " Hello", "words": [{"word": "Hello"}]},
+                {"id": 1, "start": 1.2, "end": 1.4, "text": "  "},
+                {"id": 2, "start": 1.4, "end": 2.0, "text": "world. "}
+            ]}"#,
+        );
+        assert_eq!(
+            transcript_segments(segmented.to_string_lossy().to_string()),
+            Ok(vec![
+                TranscriptSegment {
+                    start: 0.0,
+                    end: 1.2,
+                    text: "Hello".to_string(),
+                },
+                TranscriptSegment {
+                    start: 1.4,
+                    end: 2.0,
+                    text: "world.".to_string(),
+                },
+            ])
+        );
+
+        // Shape of the CoreML worker's TranscriptJson: flat text plus per-token timings.
+        let coreml = write_fixture(
+            "coreml.json",
+            "{\"text\": \"Hello world. Next one\", \"confidence\": 0.9, \"tokenTimings\": [
+                {\"token\": \"\u{2581}Hel\", \"tokenId\": 1, \"startTime\": 0.0, \"endTime\": 0.3, \"confidence\": 0.9},
+                {\"token\": \"lo\", \"tokenId\": 2, \"startTime\": 0.3, \"endTime\": 0.5, \"confidence\": 0.9},
+                {\"token\": \"\u{2581}world.\", \"tokenId\": 3, \"startTime\": 0.6, \"endTime\": 1.0, \"confidence\": 0.9},
+                {\"token\": \"\u{2581}Next\", \"tokenId\": 4, \"startTime\": 1.2, \"endTime\": 1.5, \"confidence\": 0.9},
+                {\"token\": \"\u{2581}one\", \"tokenId\": 5, \"startTime\": 3.0, \"endTime\": 3.4, \"confidence\": 0.9}
+            ]}"
+            .as_bytes(),
+        );
+        assert_eq!(
+            transcript_segments(coreml.to_string_lossy().to_string()),
+            Ok(vec![
+                TranscriptSegment {
+                    start: 0.0,
+                    end: 1.0,
+                    text: "Hello world.".to_string(),
+                },
+                TranscriptSegment {
+                    start: 1.2,
+                    end: 1.5,
+                    text: "Next".to_string(),
+                },
+                TranscriptSegment {
+                    start: 3.0,
+                    end: 3.4,
+                    text: "one".to_string(),
+                },
+            ])
+        );
+
+        let untimed = write_fixture(
+            "untimed.json",
+            br#"{"segments": [{"start": 0.0, "end": 1.0, "text": "Hi"}, {"text": "there"}]}"#,
+        );
+        let error = transcript_segments(untimed.to_string_lossy().to_string())
+            .expect_err("second segment has no timing");
+        assert!(error.contains("no timestamps"), "{error}");
+
+        let text_only = write_fixture("text_only.json", br#"{"text": "Hello world."}"#);
+        assert!(transcript_segments(text_only.to_string_lossy().to_string()).is_err());
+        let plain = write_fixture("notes.txt", b"Hello world.");
+        assert!(transcript_segments(plain.to_string_lossy().to_string()).is_err());
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{disk_space_for, format_bytes, DiskSpace};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct VolumeInfo {
+    pub name: String,
+    pub mount_path: String,
+    pub total_bytes: u64,
+    pub free_bytes: u64,
+    pub total_label: String,
+    pub free_label: String,
+}
+
+#[cfg(target_os = "macos")]
+fn mounted_volumes() -> Vec<(String, PathBuf)> {
+    let Ok(entries) = std::fs::read_dir("/Volumes") else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| {
+            (
+                entry.file_name().to_string_lossy().to_string(),
+                entry.path(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn mounted_volumes() -> Vec<(String, PathBuf)> {
+    let Ok(mounts) = std::fs::read_to_string("/proc/mounts") else {
+        return vec![("/".to_string(), PathBuf::from("/"))];
+    };
+    // Only block-device mounts are real volumes; proc, tmpfs, cgroup and friends are skipped.
+    mounts
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let device = fields.next()?;
+            let mount_path = fields.next()?.replace("\\040", " ");
+            device.starts_with("/dev/").then(|| {
+                let name = Path::new(&mount_path)
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_else(|| mount_path.clone());
+                (name, PathBuf::from(mount_path))
+            })
+        })
+        .collect()
+}
+
+#[cfg(windows)]
+fn mounted_volumes() -> Vec<(String, PathBuf)> {
+    (b'A'..=b'Z')
+        .map(|letter| format!("{}:\\", char::from(letter)))
+        .filter(|root| Path::new(root).exists())
+        .map(|root| (root.trim_end_matches('\\').to_string(), PathBuf::from(root)))
+        .collect()
+}
+
+fn assemble_volumes(
+    mounts: Vec<(String, PathBuf)>,
+    space_for: impl Fn(&Path) -> Result<DiskSpace, String>,
+) -> Vec<VolumeInfo> {
+    let mut seen = HashSet::new();
+    let mut volumes = mounts
+        .into_iter()
+        .filter(|(_, mount_path)| seen.insert(mount_path.clone()))
+        .filter_map(|(name, mount_path)| {
+            let space = match space_for(&mount_path) {
+                Ok(space) => space,
+                Err(error) => {
+                    eprintln!("[volumes] skipping {}: {}", mount_path.display(), error);
+                    return None;
+                }
+            };
+            (space.total_bytes > 0).then(|| VolumeInfo {
+                name,
+                mount_path: mount_path.to_string_lossy().to_string(),
+                total_bytes: space.total_bytes,
+                free_bytes: space.available_bytes,
+                total_label: format_bytes(space.total_bytes),
+                free_label: format_bytes(space.available_bytes),
+            })
+        })
+        .collect::<Vec<VolumeInfo>>();
+    volumes.sort_by_key(|volume| volume.name.to_lowercase());
+    volumes
+}
+
+#[tauri::command]
+pub async fn available_volumes() -> Result<Vec<VolumeInfo>, String> {
+    tokio::task::spawn_blocking(|| assemble_volumes(mounted_volumes(), disk_space_for))
+        .await
+        .map_err(|error| format!("Failed to list volumes: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assembles_volumes_with_formatted_sizes_and_skips_unusable_mounts() {
+        let mounts = vec![
+            ("Backup".to_string(), PathBuf::from("/Volumes/Backup")),
+            ("Macintosh HD".to_string(), PathBuf::from("/")),
+            ("Offline".to_string(), PathBuf::from("/Volumes/Offline")),
+            ("Empty".to_string(), PathBuf::from("/Volumes/Empty")),
+            ("Macintosh HD".to_string(), PathBuf::from("/")),
+        ];
+        let volumes = assemble_volumes(mounts, |path| match path.to_str() {
+            Some("/") => Ok(DiskSpace {
+                total_bytes: 500 * 1024 * 1024 * 1024,
+                available_bytes: 120 * 1024 * 1024 * 1024,
+            }),
+            Some("/Volumes/Backup") => Ok(DiskSpace {
+                total_bytes: 2 * 1024 * 1024 * 1024 * 1024,
+                available_bytes: 1536,
+            }),
+            Some("/Volumes/Empty") => Ok(DiskSpace {
+                total_bytes: 0,
+                available_bytes: 0,
+            }),
+            _ => Err("df failed".to_string()),
+        });
+
+        assert_eq!(
+            volumes,
+            vec![
+                VolumeInfo {
+                    name: "Backup".to_string(),
+                    mount_path: "/Volumes/Backup".to_string(),
+                    total_bytes: 2 * 1024 * 1024 * 1024 * 1024,
+                    free_bytes: 1536,
+                    total_label: "2.0 TB".to_string(),
+                    free_label: "1.5 KB".to_string(),
+                },
+                VolumeInfo {
+                    name: "Macintosh HD".to_string(),
+                    mount_path: "/".to_string(),
+                    total_bytes: 500 * 1024 * 1024 * 1024,
+                    free_bytes: 120 * 1024 * 1024 * 1024,
+                    total_label: "500.0 GB".to_string(),
+                    free_label: "120.0 GB".to_string(),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use crate::commands::convert::transcript_plain_text;
+use serde::Serialize;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WordErrorRate {
+    pub wer: f64,
+    pub reference_words: usize,
+    pub hypothesis_words: usize,
+    pub substitutions: usize,
+    pub deletions: usize,
+    pub insertions: usize,
+    pub normalized: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct EditCounts {
+    substitutions: usize,
+    deletions: usize,
+    insertions: usize,
+}
+
+impl EditCounts {
+    fn cost(self) -> usize {
+        self.substitutions + self.deletions + self.insertions
+    }
+}
+
+// Keeps in-word apostrophes so "don't" stays one token after stripping punctuation.
+fn tokenize(text: &str, normalize: bool) -> Vec<String> {
+    text.split_whitespace()
+        .map(|word| {
+            if !normalize {
+                return word.to_string();
+            }
+            word.chars()
+                .filter(|ch| ch.is_alphanumeric() || *ch == '\'')
+                .collect::<String>()
+                .trim_matches('\'')
+                .to_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+// Levenshtein over words with two rolling rows, so hour-long transcripts don't need an n*m table.
+fn align(reference: &[String], hypothesis: &[String]) -> EditCounts {
+    let mut previous = (0..=hypothesis.len())
+        .map(|insertions| EditCounts {
+            insertions,
+            ..EditCounts::default()
+        })
+        .collect::<Vec<EditCounts>>();
+
+    for (row, reference_word) in reference.iter().enumerate() {
+        let mut current = Vec::with_capacity(hypothesis.len() + 1);
+        current.push(EditCounts {
+            deletions: row + 1,
+            ..EditCounts::default()
+        });
+        for (column, hypothesis_word) in hypothesis.iter().enumerate() {
+            let mut diagonal = previous[column];
+            if reference_word != hypothesis_word {
+                diagonal.substitutions += 1;
+            }
+            let mut deletion = previous[column + 1];
+            deletion.deletions += 1;
+            let mut insertion = current[column];
+            insertion.insertions += 1;
+
+            // Ties prefer substitutions, then deletions, matching the usual alignment reports.
+            let best = [diagonal, deletion, insertion]
+                .into_iter()
+                .min_by_key(|counts| counts.cost())
+                .unwrap_or(diagonal);
+            current.push(best);
+        }
+        previous = current;
+    }
+
+    previous[hypothesis.len()]
+}
+
+fn word_error_rate(hypothesis: &str, reference: &str, normalize: bool) -> WordErrorRate {
+    let reference_words = tokenize(reference, normalize);
+    let hypothesis_words = tokenize(hypothesis, normalize);
+    let counts = align(&reference_words, &hypothesis_words);
+
+    // WER is undefined for an empty reference; count any hypothesis words as a full miss.
+    let wer = if reference_words.is_empty() {
+        if hypothesis_words.is_empty() {
+            0.0
+        } else {
+            1.0
+        }
+    } else {
+        counts.cost() as f64 / reference_words.len() as f64
+    };
+
+    WordErrorRate {
+        wer,
+        reference_words: reference_words.len(),
+        hypothesis_words: hypothesis_words.len(),
+        substitutions: counts.substitutions,
+        deletions: counts.deletions,
+        insertions: counts.insertions,
+        normalized: normalize,
+    }
+}
+
+#[tauri::command]
+pub fn compare_to_reference(
+    hypothesis_path: String,
+    reference_path: String,
+    normalize: Option<bool>,
+) -> Result<WordErrorRate, String> {
+    let hypothesis_path = hypothesis_path.trim();
+    let reference_path = reference_path.trim();
+    if hypothesis_path.is_empty() || reference_path.is_empty() {
+        return Err("Hypothesis and reference transcript paths are required".to_string());
+    }
+
+    let hypothesis = transcript_plain_text(Path::new(hypothesis_path))?;
+    let reference = transcript_plain_text(Path::new(reference_path))?;
+    Ok(word_error_rate(
+        &hypothesis,
+        &reference,
+        normalize.unwrap_or(true),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(report: &WordErrorRate) -> (usize, usize, usize) {
+        (report.substitutions, report.deletions, report.insertions)
+    }
+
+    #[test]
+    fn counts_substitutions_deletions_and_insertions() {
+        let report = word_error_rate("the big cat sit on mat", "the cat sat on the mat", false);
+        assert_eq!(counts(&report), (1, 1, 1));
+        assert_eq!(report.wer, 0.5);
+        assert_eq!((report.reference_words, report.hypothesis_words), (6, 6));
+
+        let raw = word_error_rate("hello world", "Hello, World!", false);
+        assert_eq!(counts(&raw), (2, 0, 0));
+        assert_eq!(raw.wer, 1.0);
+        let normalized = word_error_rate("hello world", "Hello, World!", true);
+        assert_eq!(counts(&normalized), (0, 0, 0));
+        assert_eq!(normalized.wer, 0.0);
+        assert_eq!(
+            tokenize("Don't -- STOP 'now'", true),
+            vec!["don't", "stop", "now"]
+        );
+
+        assert_eq!(word_error_rate("", "", true).wer, 0.0);
+        let missing = word_error_rate("", "one two three four", true);
+        assert_eq!(counts(&missing), (0, 4, 0));
+        assert_eq!(missing.wer, 1.0);
+        let no_reference = word_error_rate("stray words", "", true);
+        assert_eq!(counts(&no_reference), (0, 0, 2));
+        assert_eq!(no_reference.wer, 1.0);
+    }
+
+    #[test]
+    fn compares_transcript_files_including_empty_ones() {
+        let root = std::env::temp_dir().join(format!("wer-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&root).expect("fixture directory should be created");
+        let hypothesis = root.join("hypothesis.srt");
+        let reference = root.join("reference.txt");
+        let empty = root.join("empty.txt");
+        std::fs::write(
+            &hypothesis,
+            "1\n00:00:00,000 --> 00:00:01,000\nWelcome back\n\n2\n00:00:01,000 --> 00:00:02,000\nto a show.\n",
+        )
+        .expect("hypothesis should be written");
+        std::fs::write(&reference, "Welcome back to the show.\n")
+            .expect("reference should be written");
+        std::fs::write(&empty, "").expect("empty file should be written");
+
+        let report = compare_to_reference(
+            hypothesis.to_string_lossy().to_string(),
+            reference.to_string_lossy().to_string(),
+            None,
+        )
+        .expect("comparison should succeed");
+        assert_eq!(counts(&report), (1, 0, 0));
+        assert_eq!(report.wer, 0.2);
+
+        let against_empty = compare_to_reference(
+            empty.to_string_lossy().to_string(),
+            reference.to_string_lossy().to_string(),
+            Some(true),
+        )
+        .expect("empty hypothesis should compare");
+        assert_eq!(counts(&against_empty), (0, 5, 0));
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
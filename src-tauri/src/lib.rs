@@ -1,3 +1,4 @@
+use providers::resolver::MODEL_REVISION_MARKER;
 use serde::{Deserialize, Serialize};
 use std::ffi::OsStr;
 use std::io::{BufRead, BufReader};
@@ -19,6 +20,7 @@ const BATCH_EVENT: &str = "batch-event";
 const MODEL_EVENT: &str = "model-event";
 const MENU_EVENT_FILES_SELECTED: &str = "files-selected";
 const FILES_OPENED_EVENT: &str = "files-opened";
+const PENDING_OPEN_PATHS_FILE_NAME: &str = "pending-open-paths.json";
 const MENU_EVENT_FOLDER_SELECTED: &str = "folder-selected";
 const MENU_EVENT_START_TRANSCRIPTION: &str = "menu-start-transcription";
 const MENU_EVENT_STOP_TRANSCRIPTION: &str = "menu-stop-transcription";
@@ -34,8 +36,9 @@ const MENU_ID_DOCS: &str = "docs";
 const MENU_ID_MODEL_MANAGER: &str = "model-manager";
 const MENU_ID_DIAGNOSTICS: &str = "diagnostics";
 const DOCUMENTATION_URL: &str = "https://github.com/neno-is-ooo/batch-transcriber";
-const SUPPORTED_AUDIO_EXTENSIONS: &[&str] =
-    &["mp3", "wav", "m4a", "flac", "ogg", "aac", "aiff", "wma"];
+const SUPPORTED_AUDIO_EXTENSIONS: &[&str] = &[
+    "mp3", "wav", "m4a", "flac", "ogg", "aac", "aiff", "aif", "caf", "wma",
+];
 
 #[derive(Debug, Clone, Copy, Default)]
 struct MenuState {
@@ -70,6 +73,24 @@ struct RunBatchRequest {
     ffmpeg_fallback: bool,
 }
 
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct BatchRequestProblem {
+    field: String,
+    message: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct BatchRequestValidation {
+    input_dir: String,
+    output_dir: String,
+    model_dir: String,
+    model_version: String,
+    extensions: Vec<String>,
+    problems: Vec<BatchRequestProblem>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct InstallModelRequest {
@@ -102,6 +123,12 @@ struct FailureItem {
     error: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct FailureReport {
+    #[serde(default)]
+    failures: Vec<FailureItem>,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct ModelCatalogEntry {
@@ -134,6 +161,40 @@ struct InstallModelResult {
     exit_code: i32,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RepairModelResult {
+    id: String,
+    model_version: String,
+    model_dir: String,
+    repaired: Vec<String>,
+    full_reinstall: bool,
+    installed: bool,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct ModelUpdateStatus {
+    id: String,
+    model_version: String,
+    installed_revision: Option<String>,
+    latest_revision: String,
+    status: String,
+    update_available: bool,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct InstalledModelUsage {
+    id: String,
+    model_version: String,
+    display_name: String,
+    model_dir: String,
+    bytes: u64,
+    size: String,
+    installed: bool,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct StartupDiagnosticsRequest {
@@ -179,6 +240,8 @@ struct ManagedModelDef {
     description: &'static str,
     size_hint: &'static str,
     recommended_for: &'static str,
+    // Bumped whenever the published model files change; stamped into the install marker.
+    revision: &'static str,
 }
 
 const MANAGED_MODELS: [ManagedModelDef; 2] = [
@@ -190,6 +253,7 @@ const MANAGED_MODELS: [ManagedModelDef; 2] = [
         description: "Multilingual model (English + 25 European languages).",
         size_hint: "Large (~0.6B params)",
         recommended_for: "Best overall accuracy",
+        revision: "1",
     },
     ManagedModelDef {
         id: "parakeet-tdt-0.6b-v2-coreml",
@@ -199,20 +263,28 @@ const MANAGED_MODELS: [ManagedModelDef; 2] = [
         description: "English-focused model with strong recall.",
         size_hint: "Large (~0.6B params)",
         recommended_for: "English-heavy workflows",
+        revision: "1",
     },
 ];
 
 fn model_by_version(model_version: &str) -> Result<ManagedModelDef, String> {
+    let canonical = providers::resolver::normalize_model_version(model_version)
+        .ok_or_else(|| format!("Unsupported model version: {}", model_version))?;
     MANAGED_MODELS
         .iter()
-        .find(|def| def.model_version.eq_ignore_ascii_case(model_version))
+        .find(|def| def.model_version == canonical)
         .copied()
         .ok_or_else(|| format!("Unsupported model version: {}", model_version))
 }
 
 fn fluid_models_root() -> Result<PathBuf, String> {
-    let home =
-        std::env::var("HOME").map_err(|_| "HOME environment variable is not set".to_string())?;
+    fluid_models_root_from(std::env::var_os("HOME"))
+}
+
+fn fluid_models_root_from(home: Option<std::ffi::OsString>) -> Result<PathBuf, String> {
+    let home = home
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| "HOME environment variable is not set".to_string())?;
     Ok(PathBuf::from(home)
         .join("Library")
         .join("Application Support")
@@ -220,20 +292,110 @@ fn fluid_models_root() -> Result<PathBuf, String> {
         .join("Models"))
 }
 
+fn ensure_models_root(root: &Path) -> Result<(), String> {
+    std::fs::create_dir_all(root).map_err(|error| {
+        format!(
+            "Failed to create models directory {}: {}",
+            root.display(),
+            error
+        )
+    })
+}
+
 fn model_dir_for(def: ManagedModelDef) -> Result<PathBuf, String> {
     Ok(fluid_models_root()?.join(def.folder_name))
 }
 
+const REQUIRED_MODEL_FILES: [&str; 5] = [
+    "Preprocessor.mlmodelc",
+    "Encoder.mlmodelc",
+    "Decoder.mlmodelc",
+    "JointDecision.mlmodelc",
+    "parakeet_vocab.json",
+];
+
 fn is_model_installed(model_dir: &Path) -> bool {
-    let required = [
-        "Preprocessor.mlmodelc",
-        "Encoder.mlmodelc",
-        "Decoder.mlmodelc",
-        "JointDecision.mlmodelc",
-        "parakeet_vocab.json",
-    ];
+    REQUIRED_MODEL_FILES
+        .iter()
+        .all(|name| model_dir.join(name).exists())
+}
+
+// The version line lets the launcher identify the model even if the folder is later renamed.
+fn write_model_revision_marker(model_dir: &Path, def: ManagedModelDef) -> Result<(), String> {
+    let marker = model_dir.join(MODEL_REVISION_MARKER);
+    let contents = format!("{}\n{}\n", def.revision, def.model_version);
+    std::fs::write(&marker, contents).map_err(|error| {
+        format!(
+            "Failed to write model revision marker {}: {}",
+            marker.display(),
+            error
+        )
+    })
+}
+
+fn installed_model_revision(model_dir: &Path) -> Option<String> {
+    std::fs::read_to_string(model_dir.join(MODEL_REVISION_MARKER))
+        .ok()
+        .and_then(|contents| contents.lines().next().map(|line| line.trim().to_string()))
+        .filter(|revision| !revision.is_empty())
+}
+
+fn model_update_status(def: ManagedModelDef, model_dir: &Path) -> ModelUpdateStatus {
+    let installed_revision = installed_model_revision(model_dir);
+    let status = match installed_revision.as_deref() {
+        None => "unknown",
+        Some(revision) if revision == def.revision => "up_to_date",
+        Some(_) => "outdated",
+    };
+    ModelUpdateStatus {
+        id: def.id.to_string(),
+        model_version: def.model_version.to_string(),
+        installed_revision,
+        latest_revision: def.revision.to_string(),
+        status: status.to_string(),
+        update_available: status == "outdated",
+    }
+}
+
+fn model_file_is_intact(path: &Path) -> bool {
+    if path
+        .extension()
+        .is_some_and(|extension| extension == "mlmodelc")
+    {
+        // Compiled CoreML bundles are directories anchored by coremldata.bin.
+        return path.is_dir()
+            && std::fs::metadata(path.join("coremldata.bin"))
+                .map(|metadata| metadata.is_file() && metadata.len() > 0)
+                .unwrap_or(false);
+    }
+
+    std::fs::read(path)
+        .ok()
+        .is_some_and(|bytes| serde_json::from_slice::<serde_json::Value>(&bytes).is_ok())
+}
+
+fn model_files_needing_repair(model_dir: &Path) -> Vec<String> {
+    REQUIRED_MODEL_FILES
+        .iter()
+        .filter(|name| !model_file_is_intact(&model_dir.join(name)))
+        .map(|name| name.to_string())
+        .collect()
+}
 
-    required.iter().all(|name| model_dir.join(name).exists())
+fn remove_model_file(path: &Path) -> Result<(), String> {
+    let result = if path.is_dir() {
+        std::fs::remove_dir_all(path)
+    } else {
+        std::fs::remove_file(path)
+    };
+    match result {
+        Err(error) if error.kind() != std::io::ErrorKind::NotFound => Err(format!(
+            "Failed to remove damaged model file {}: {}",
+            path.display(),
+            error
+        )),
+        _ => Ok(()),
+    }
 }
 
 fn model_catalog_entry(def: ManagedModelDef) -> Result<ModelCatalogEntry, String> {
@@ -363,7 +525,17 @@ fn format_bytes(value: u64) -> String {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DiskSpace {
+    total_bytes: u64,
+    available_bytes: u64,
+}
+
 fn available_disk_bytes_for(path: &Path) -> Result<u64, String> {
+    disk_space_for(path).map(|space| space.available_bytes)
+}
+
+fn disk_space_for(path: &Path) -> Result<DiskSpace, String> {
     let target = nearest_existing_path(path);
     let output = Command::new("df")
         .arg("-k")
@@ -396,13 +568,19 @@ fn available_disk_bytes_for(path: &Path) -> Result<u64, String> {
         ));
     }
 
+    let total_kb = columns[1]
+        .parse::<u64>()
+        .map_err(|_| format!("Unable to parse total disk blocks from df output: {}", line))?;
     let available_kb = columns[3].parse::<u64>().map_err(|_| {
         format!(
             "Unable to parse available disk blocks from df output: {}",
             line
         )
     })?;
-    Ok(available_kb.saturating_mul(1024))
+    Ok(DiskSpace {
+        total_bytes: total_kb.saturating_mul(1024),
+        available_bytes: available_kb.saturating_mul(1024),
+    })
 }
 
 fn ensure_local_tool_built(
@@ -629,6 +807,82 @@ fn focus_main_window<R: Runtime>(app: &AppHandle<R>) {
     }
 }
 
+fn pending_open_paths_file() -> Result<PathBuf, String> {
+    let home = dirs::home_dir().ok_or_else(|| "Failed to resolve home directory".to_string())?;
+    Ok(home.join(".aura").join(PENDING_OPEN_PATHS_FILE_NAME))
+}
+
+fn read_pending_open_paths(file: &Path) -> Vec<String> {
+    let payload = match std::fs::read_to_string(file) {
+        Ok(payload) => payload,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Vec::new(),
+        Err(error) => {
+            eprintln!(
+                "[file-open] failed to read pending paths {}: {}",
+                file.display(),
+                error
+            );
+            return Vec::new();
+        }
+    };
+
+    serde_json::from_str::<Vec<String>>(&payload).unwrap_or_else(|error| {
+        eprintln!(
+            "[file-open] ignoring unreadable pending paths {}: {}",
+            file.display(),
+            error
+        );
+        Vec::new()
+    })
+}
+
+fn merge_pending_open_paths(existing: Vec<String>, incoming: Vec<String>) -> Vec<String> {
+    let mut merged = Vec::with_capacity(existing.len() + incoming.len());
+    for path in existing.into_iter().chain(incoming) {
+        if !merged.contains(&path) {
+            merged.push(path);
+        }
+    }
+    merged
+}
+
+fn persist_pending_open_paths(file: &Path, audio_paths: &[String]) -> Result<(), String> {
+    let merged = merge_pending_open_paths(read_pending_open_paths(file), audio_paths.to_vec());
+    if let Some(parent) = file.parent() {
+        std::fs::create_dir_all(parent).map_err(|error| {
+            format!(
+                "Failed to create pending paths directory {}: {}",
+                parent.display(),
+                error
+            )
+        })?;
+    }
+
+    let payload = serde_json::to_string(&merged)
+        .map_err(|error| format!("Failed to serialize pending paths: {}", error))?;
+    std::fs::write(file, payload).map_err(|error| {
+        format!(
+            "Failed to write pending paths {}: {}",
+            file.display(),
+            error
+        )
+    })
+}
+
+fn drain_pending_open_paths(file: &Path, in_memory: Vec<String>) -> Vec<String> {
+    let persisted = read_pending_open_paths(file);
+    if let Err(error) = std::fs::remove_file(file) {
+        if error.kind() != std::io::ErrorKind::NotFound {
+            eprintln!(
+                "[file-open] failed to clear pending paths {}: {}",
+                file.display(),
+                error
+            );
+        }
+    }
+    merge_pending_open_paths(persisted, in_memory)
+}
+
 fn handle_opened_audio_paths<R: Runtime>(app: &AppHandle<R>, audio_paths: Vec<String>) {
     if audio_paths.is_empty() {
         return;
@@ -642,6 +896,12 @@ fn handle_opened_audio_paths<R: Runtime>(app: &AppHandle<R>, audio_paths: Vec<St
                     true
                 } else {
                     locked.pending_paths.extend(audio_paths.iter().cloned());
+                    // Survives a frontend that never becomes ready in this run.
+                    if let Err(error) = pending_open_paths_file()
+                        .and_then(|file| persist_pending_open_paths(&file, &audio_paths))
+                    {
+                        eprintln!("[file-open] failed to persist pending paths: {}", error);
+                    }
                     false
                 }
             }
@@ -666,10 +926,7 @@ fn pick_and_add_files<R: Runtime>(app: &AppHandle<R>) {
     let handle = app.clone();
     app.dialog()
         .file()
-        .add_filter(
-            "Audio",
-            &["mp3", "wav", "m4a", "flac", "ogg", "aac", "aiff", "wma"],
-        )
+        .add_filter("Audio", SUPPORTED_AUDIO_EXTENSIONS)
         .pick_files(move |paths| {
             let Some(paths) = paths else {
                 return;
@@ -841,6 +1098,7 @@ fn build_menu<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<Menu<R>> {
 
 #[tauri::command]
 fn run_startup_diagnostics(
+    app: AppHandle,
     request: StartupDiagnosticsRequest,
 ) -> Result<StartupDiagnosticsResult, String> {
     let managed = model_by_version(&request.model_version)?;
@@ -861,6 +1119,21 @@ fn run_startup_diagnostics(
                 .to_string(),
             action: "No action needed.".to_string(),
         });
+
+        let unsupported = commands::ffmpeg::probe_ffmpeg_capabilities().unsupported_extensions();
+        if !unsupported.is_empty() {
+            checks.push(DiagnosticCheck {
+                id: "ffmpeg_codecs".to_string(),
+                status: "warn".to_string(),
+                title: "ffmpeg is missing some audio decoders".to_string(),
+                detail: format!(
+                    "ffmpeg cannot decode these supported formats: {}.",
+                    unsupported.join(", ")
+                ),
+                action: "Install a full ffmpeg build (`brew install ffmpeg`) to enable fallback conversion for every format."
+                    .to_string(),
+            });
+        }
     } else {
         checks.push(DiagnosticCheck {
             id: "ffmpeg".to_string(),
@@ -922,6 +1195,31 @@ fn run_startup_diagnostics(
         });
     }
 
+    let architecture = commands::architecture::check_worker_architecture(
+        &providers::registry::resolve_swift_binary_path(&app),
+    );
+    if !architecture.worker_archs.is_empty() {
+        checks.push(DiagnosticCheck {
+            id: "worker_arch".to_string(),
+            status: if architecture.mismatch { "warn" } else { "ok" }.to_string(),
+            title: if architecture.mismatch {
+                "Worker architecture does not match this Mac"
+            } else {
+                "Worker runs natively"
+            }
+            .to_string(),
+            detail: architecture.detail,
+            action: if architecture.mismatch {
+                format!(
+                    "Rebuild the Swift worker for {} (`cd swift-worker && swift build -c release`) or install the matching app build.",
+                    architecture.host_arch
+                )
+            } else {
+                "No action needed.".to_string()
+            },
+        });
+    }
+
     let recommended_disk_bytes = if is_model_installed(&requested_model_dir) {
         3 * 1024_u64 * 1024_u64 * 1024_u64
     } else {
@@ -983,6 +1281,32 @@ fn get_model_catalog() -> Result<Vec<ModelCatalogEntry>, String> {
         .collect()
 }
 
+#[tauri::command]
+fn reveal_models_root(app: AppHandle) -> Result<String, String> {
+    let root = fluid_models_root()?;
+    ensure_models_root(&root)?;
+    open_directory(&app, &root, "models directory")
+}
+
+fn open_directory(app: &AppHandle, directory: &Path, description: &str) -> Result<String, String> {
+    let display = directory.to_string_lossy().to_string();
+    app.opener()
+        .open_path(display.clone(), None::<&str>)
+        .map_err(|error| format!("Failed to open {} {}: {}", description, display, error))?;
+    Ok(display)
+}
+
+#[tauri::command]
+fn open_session_output(app: AppHandle, session_id: String) -> Result<String, String> {
+    let session_id = session_id.trim();
+    if session_id.is_empty() {
+        return Err("Session id is empty".to_string());
+    }
+
+    let output_dir = commands::history::session_output_dir(session_id)?;
+    open_directory(&app, &output_dir, "session output directory")
+}
+
 #[tauri::command]
 fn resolve_model_path(request: ResolveModelPathRequest) -> Result<ResolveModelPathResult, String> {
     let model = model_by_version(&request.model_version)?;
@@ -1110,6 +1434,9 @@ async fn install_model(
     .map_err(|e| format!("Failed to emit model install finished event: {}", e))?;
 
     if status.success() {
+        if result.installed {
+            write_model_revision_marker(&model_dir, model)?;
+        }
         return Ok(result);
     }
 
@@ -1119,6 +1446,213 @@ async fn install_model(
     ))
 }
 
+fn directory_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+// Only known model folders are reported; anything else under the root belongs to other FluidAudio apps.
+fn installed_models_usage_in(root: &Path) -> Vec<InstalledModelUsage> {
+    MANAGED_MODELS
+        .iter()
+        .map(|def| (def, root.join(def.folder_name)))
+        .filter(|(_, model_dir)| model_dir.is_dir())
+        .map(|(def, model_dir)| {
+            let bytes = directory_size(&model_dir);
+            InstalledModelUsage {
+                id: def.id.to_string(),
+                model_version: def.model_version.to_string(),
+                display_name: def.display_name.to_string(),
+                model_dir: model_dir.to_string_lossy().to_string(),
+                bytes,
+                size: format_bytes(bytes),
+                installed: is_model_installed(&model_dir),
+            }
+        })
+        .collect()
+}
+
+#[tauri::command]
+fn installed_models_usage() -> Result<Vec<InstalledModelUsage>, String> {
+    Ok(installed_models_usage_in(&fluid_models_root()?))
+}
+
+#[tauri::command]
+fn check_model_updates() -> Result<Vec<ModelUpdateStatus>, String> {
+    let mut updates = Vec::new();
+    for def in MANAGED_MODELS {
+        let model_dir = model_dir_for(def)?;
+        if is_model_installed(&model_dir) {
+            updates.push(model_update_status(def, &model_dir));
+        }
+    }
+    Ok(updates)
+}
+
+#[tauri::command]
+async fn repair_model(app: AppHandle, model_version: String) -> Result<RepairModelResult, String> {
+    let model = model_by_version(&model_version)?;
+    let model_dir = model_dir_for(model)?;
+    let damaged = model_files_needing_repair(&model_dir);
+
+    let mut result = RepairModelResult {
+        id: model.id.to_string(),
+        model_version: model.model_version.to_string(),
+        model_dir: model_dir.to_string_lossy().to_string(),
+        repaired: Vec::new(),
+        full_reinstall: false,
+        installed: damaged.is_empty(),
+    };
+    if damaged.is_empty() {
+        return Ok(result);
+    }
+
+    // modelctl can only download whole models, so damaged files are removed and the
+    // model reinstalled; intact files are left in place for the downloader to reuse.
+    eprintln!(
+        "model repair note: {} does not support per-file downloads; reinstalling {} to repair {}",
+        providers::registry::SWIFT_MODELCTL_TOOL_NAME,
+        model.model_version,
+        damaged.join(", ")
+    );
+    for name in &damaged {
+        remove_model_file(&model_dir.join(name))?;
+    }
+
+    install_model(
+        app,
+        InstallModelRequest {
+            model_version: model.model_version.to_string(),
+        },
+    )
+    .await?;
+
+    let remaining = model_files_needing_repair(&model_dir);
+    if !remaining.is_empty() {
+        return Err(format!(
+            "Model repair finished but files are still damaged: {}",
+            remaining.join(", ")
+        ));
+    }
+
+    result.repaired = damaged;
+    result.full_reinstall = true;
+    result.installed = true;
+    Ok(result)
+}
+
+fn canonical_path_string(path: &Path) -> String {
+    std::fs::canonicalize(path)
+        .unwrap_or_else(|_| path.to_path_buf())
+        .to_string_lossy()
+        .to_string()
+}
+
+// The output directory may not exist yet, so probe the closest ancestor the worker would create it under.
+fn output_dir_write_problem(output_dir: &Path) -> Option<String> {
+    let existing = nearest_existing_path(output_dir);
+    if !existing.is_dir() {
+        return Some(format!(
+            "{} is not a directory, so the output directory cannot be created",
+            existing.display()
+        ));
+    }
+
+    let probe = existing.join(format!(".aura-write-check-{}", uuid::Uuid::new_v4()));
+    match std::fs::write(&probe, b"") {
+        Ok(()) => {
+            let _ = std::fs::remove_file(&probe);
+            None
+        }
+        Err(error) => Some(format!("Cannot write to {}: {}", existing.display(), error)),
+    }
+}
+
+fn validate_batch_request_fields(request: &RunBatchRequest) -> BatchRequestValidation {
+    let mut problems = Vec::new();
+    let mut problem = |field: &str, message: String| {
+        problems.push(BatchRequestProblem {
+            field: field.to_string(),
+            message,
+        })
+    };
+
+    let input_dir = PathBuf::from(request.input_dir.trim());
+    if request.input_dir.trim().is_empty() {
+        problem("inputDir", "Input directory is empty".to_string());
+    } else if !input_dir.exists() {
+        problem(
+            "inputDir",
+            format!("Input directory not found: {}", input_dir.display()),
+        );
+    } else if !input_dir.is_dir() {
+        problem(
+            "inputDir",
+            format!("Input path is not a directory: {}", input_dir.display()),
+        );
+    }
+
+    let output_dir = PathBuf::from(request.output_dir.trim());
+    if request.output_dir.trim().is_empty() {
+        problem("outputDir", "Output directory is empty".to_string());
+    } else if let Some(message) = output_dir_write_problem(&output_dir) {
+        problem("outputDir", message);
+    }
+
+    let model_version = match model_by_version(request.model_version.trim()) {
+        Ok(def) => def.model_version.to_string(),
+        Err(error) => {
+            problem("modelVersion", error);
+            request.model_version.trim().to_string()
+        }
+    };
+
+    let model_dir = PathBuf::from(request.model_dir.trim());
+    if request.model_dir.trim().is_empty() {
+        problem("modelDir", "Model directory is empty".to_string());
+    } else if !model_dir.is_dir() {
+        problem(
+            "modelDir",
+            format!("Model directory not found: {}", model_dir.display()),
+        );
+    } else if !is_model_installed(&model_dir) {
+        problem(
+            "modelDir",
+            format!(
+                "Required model files are missing from {}",
+                model_dir.display()
+            ),
+        );
+    }
+
+    let extensions = match providers::manifest::normalize_extensions(&request.extensions) {
+        Ok(extensions) => extensions,
+        Err(error) => {
+            problem("extensions", error);
+            request.extensions.clone()
+        }
+    };
+
+    BatchRequestValidation {
+        input_dir: canonical_path_string(&input_dir),
+        output_dir: canonical_path_string(&output_dir),
+        model_dir: canonical_path_string(&model_dir),
+        model_version,
+        extensions,
+        problems,
+    }
+}
+
+#[tauri::command]
+fn validate_batch_request(request: RunBatchRequest) -> BatchRequestValidation {
+    validate_batch_request_fields(&request)
+}
+
 #[tauri::command]
 async fn run_batch_transcription(
     app: AppHandle,
@@ -1134,6 +1668,7 @@ async fn run_batch_transcription(
     if !Path::new(&request.input_dir).exists() {
         return Err(format!("Input directory not found: {}", request.input_dir));
     }
+    let extensions = providers::manifest::normalize_extensions(&request.extensions)?;
 
     let mut args: Vec<String> = vec![
         "--input-dir".into(),
@@ -1147,7 +1682,7 @@ async fn run_batch_transcription(
         "--output-format".into(),
         request.output_format.clone(),
         "--extensions".into(),
-        request.extensions.join(","),
+        extensions.join(","),
         "--max-retries".into(),
         request.max_retries.to_string(),
     ];
@@ -1289,6 +1824,40 @@ async fn get_providers(app: AppHandle) -> Result<Vec<providers::registry::Provid
     Ok(providers::registry::probe_all(&app))
 }
 
+#[tauri::command]
+fn get_cached_capabilities() -> Result<Vec<providers::registry::CachedCapabilities>, String> {
+    providers::registry::cached_capabilities()
+}
+
+#[tauri::command]
+fn clear_capability_cache() -> Result<usize, String> {
+    providers::registry::clear_capability_cache()
+}
+
+#[tauri::command]
+async fn probe_provider(
+    app: AppHandle,
+    provider_id: String,
+) -> Result<providers::registry::Provider, String> {
+    providers::registry::probe_provider(&app, &provider_id)
+}
+
+#[tauri::command]
+async fn resolve_best_provider(
+    app: AppHandle,
+    model: String,
+) -> Result<providers::registry::ProviderSelection, String> {
+    providers::registry::resolve_best_provider(&app, &model)
+}
+
+#[tauri::command]
+fn validate_provider_model(
+    provider_id: String,
+    model: String,
+) -> providers::resolver::ProviderModelValidation {
+    providers::resolver::validate_provider_model(&provider_id, &model)
+}
+
 #[tauri::command]
 async fn resolve_provider_runtime(
     app: AppHandle,
@@ -1315,13 +1884,26 @@ async fn start_transcription(
     provider: String,
     model: String,
     output_dir: String,
-    settings: providers::manifest::TranscriptionSettings,
+    mut settings: providers::manifest::TranscriptionSettings,
 ) -> Result<String, String> {
     if items.is_empty() {
         return Err("No queue items provided".to_string());
     }
+    let persisted = providers::settings::load_settings();
+    let (provider, model) =
+        providers::settings::provider_and_model_or_defaults(&provider, &model, &persisted)?;
+    settings.extensions = providers::manifest::normalize_extensions(&settings.extensions)?;
 
-    let output_dir = PathBuf::from(output_dir);
+    let (items, previously_transcribed) = if settings.skip_previously_transcribed {
+        commands::history::split_previously_transcribed(items)?
+    } else {
+        (items, Vec::new())
+    };
+    if items.is_empty() {
+        return Err("All queued files were already transcribed in a previous session".to_string());
+    }
+
+    let output_dir = commands::paths::expand_path(&output_dir);
     std::fs::create_dir_all(&output_dir).map_err(|error| {
         format!(
             "Failed to create output directory {}: {}",
@@ -1330,6 +1912,12 @@ async fn start_transcription(
         )
     })?;
 
+    let provider = if provider == providers::registry::AUTO_PROVIDER_ID {
+        providers::registry::resolve_best_provider(&app, &model)?.provider_id
+    } else {
+        provider
+    };
+
     let runtime_settings = providers::resolver::ProviderSettings {
         swift_binary_override: Some(providers::registry::resolve_swift_binary_path(&app)),
         models_root_override: Some(providers::registry::default_models_root()),
@@ -1338,30 +1926,221 @@ async fn start_transcription(
 
     let runtime = providers::resolver::resolve_provider(&provider, &model, &runtime_settings)
         .map_err(|error| error.to_string())?;
+    providers::launcher::validate_extra_args(&settings.extra_args)?;
+    // Refused before the event log is swapped, so a running session keeps logging to its own file.
+    providers::launcher::ensure_session_capacity()?;
+    providers::launcher::set_event_log(settings.event_log_path.as_deref())?;
+
+    let launch_options = providers::launcher::LaunchOptions {
+        notification_preferences: providers::launcher::NotificationPreferences {
+            notifications_enabled: settings.notifications_enabled,
+            notify_on_complete: settings.notify_on_complete,
+            notify_on_error: settings.notify_on_error,
+            completion_sound: persisted
+                .completion_sound
+                .as_deref()
+                .and_then(notifications::allowed_sound),
+            failure_sound: persisted
+                .failure_sound
+                .as_deref()
+                .and_then(notifications::allowed_sound),
+        },
+        write_index: settings.write_index,
+        append_index: false,
+        parent_session_id: None,
+        retranscribe: None,
+        extra_args: settings.extra_args.clone(),
+        warm_pool: settings.warm_pool,
+    };
 
-    let queued_item_ids = items
+    let launcher = providers::launcher::WorkerLauncher::new(app.clone());
+    let chunks = providers::manifest::partition_queue(&items, settings.chunk_size);
+    let session_id = if chunks.len() > 1 {
+        let parent_session_id = uuid::Uuid::new_v4().to_string();
+        launcher
+            .launch_chunked(
+                &runtime,
+                providers::launcher::ChunkedRun {
+                    parent_session_id: parent_session_id.clone(),
+                    provider: provider.clone(),
+                    model: model.clone(),
+                    output_dir: output_dir.clone(),
+                    settings: settings.clone(),
+                    chunks,
+                    options: launch_options,
+                },
+            )
+            .await?;
+        parent_session_id
+    } else {
+        let queued_item_ids = items
+            .iter()
+            .map(|item| item.id.clone())
+            .collect::<Vec<String>>();
+
+        let (session_id, manifest_path) = providers::manifest::generate_manifest(
+            &provider,
+            &model,
+            &output_dir,
+            &items,
+            &settings,
+        )?;
+
+        if let Err(error) = launcher
+            .launch(
+                &runtime,
+                &session_id,
+                &manifest_path,
+                &output_dir,
+                queued_item_ids,
+                launch_options,
+            )
+            .await
+        {
+            let _ = providers::manifest::cleanup_manifest(&manifest_path);
+            return Err(error);
+        }
+        session_id
+    };
+
+    providers::launcher::emit_session_event(
+        &app,
+        serde_json::json!({
+            "event": "start",
+            "session_id": session_id.clone(),
+            "provider": provider,
+            "model": model,
+            "previously_transcribed": previously_transcribed
+                .iter()
+                .map(|item| item.id.clone())
+                .collect::<Vec<String>>()
+        }),
+    )
+    .map_err(|error| format!("Failed to emit start event: {}", error))?;
+
+    Ok(session_id)
+}
+
+fn relaunch_check_for_manifest(
+    manifest_path: &Path,
+    check: impl FnOnce(&str, &str) -> providers::registry::RelaunchCheck,
+) -> Result<providers::registry::RelaunchCheck, String> {
+    let manifest = commands::history::parse_manifest(manifest_path)?;
+    Ok(check(&manifest.provider, &manifest.model))
+}
+
+#[tauri::command]
+async fn can_relaunch(
+    app: AppHandle,
+    manifest_path: String,
+) -> Result<providers::registry::RelaunchCheck, String> {
+    relaunch_check_for_manifest(Path::new(manifest_path.trim()), |provider, model| {
+        providers::registry::check_relaunch(&app, provider, model)
+    })
+}
+
+fn relaunch_error(check: &providers::registry::RelaunchCheck) -> String {
+    let reason = check
+        .reason
+        .clone()
+        .unwrap_or_else(|| format!("Provider {} cannot run", check.provider_id));
+    match &check.suggestion {
+        Some(suggestion) => format!(
+            "Cannot relaunch with {}: {}. {} is available instead.",
+            check.provider_id, reason, suggestion.name
+        ),
+        None => format!("Cannot relaunch with {}: {}", check.provider_id, reason),
+    }
+}
+
+#[tauri::command]
+async fn retranscribe_file(
+    app: AppHandle,
+    session_id: String,
+    file_id: String,
+    overwrite: bool,
+) -> Result<String, String> {
+    let session = commands::history::load_session(&session_id)?;
+    let file = session
+        .files
         .iter()
-        .map(|item| item.id.clone())
-        .collect::<Vec<String>>();
+        .find(|file| file.id == file_id)
+        .ok_or_else(|| format!("File {} not found in session {}", file_id, session_id))?;
+    let source = PathBuf::from(&file.path);
+    if !source.is_file() {
+        return Err(format!(
+            "Source audio no longer exists: {}",
+            source.display()
+        ));
+    }
+
+    let output_dir = PathBuf::from(&session.output_dir);
+    std::fs::create_dir_all(&output_dir).map_err(|error| {
+        format!(
+            "Failed to create output directory {}: {}",
+            output_dir.display(),
+            error
+        )
+    })?;
 
-    let (session_id, manifest_path) =
-        providers::manifest::generate_manifest(&provider, &model, &output_dir, &items, &settings)?;
+    providers::launcher::ensure_session_capacity()?;
+    let relaunch = providers::registry::check_relaunch(&app, &session.provider, &session.model);
+    if !relaunch.can_relaunch {
+        return Err(relaunch_error(&relaunch));
+    }
 
-    let notification_preferences = providers::launcher::NotificationPreferences {
-        notifications_enabled: settings.notifications_enabled,
-        notify_on_complete: settings.notify_on_complete,
-        notify_on_error: settings.notify_on_error,
+    let runtime_settings = providers::resolver::ProviderSettings {
+        swift_binary_override: Some(providers::registry::resolve_swift_binary_path(&app)),
+        models_root_override: Some(providers::registry::default_models_root()),
+        check_availability: true,
     };
+    let runtime =
+        providers::resolver::resolve_provider(&session.provider, &session.model, &runtime_settings)
+            .map_err(|error| error.to_string())?;
+
+    let original = providers::manifest::read_manifest(&session.id).map_err(|error| {
+        format!(
+            "Failed to load the original settings of session {}: {}",
+            session.id, error
+        )
+    })?;
+    let settings = original.settings.for_retranscribe(overwrite);
+    let items = vec![providers::manifest::QueueItem {
+        id: file.id.clone(),
+        path: source,
+        status: "queued".to_string(),
+    }];
+    let (run_session_id, manifest_path) = providers::manifest::generate_manifest(
+        &session.provider,
+        &session.model,
+        &output_dir,
+        &items,
+        &settings,
+    )?;
 
     let launcher = providers::launcher::WorkerLauncher::new(app.clone());
     if let Err(error) = launcher
         .launch(
             &runtime,
-            &session_id,
+            &run_session_id,
             &manifest_path,
             &output_dir,
-            queued_item_ids,
-            notification_preferences,
+            vec![file.id.clone()],
+            providers::launcher::LaunchOptions {
+                notification_preferences: providers::launcher::NotificationPreferences {
+                    notifications_enabled: false,
+                    ..Default::default()
+                },
+                write_index: false,
+                append_index: false,
+                parent_session_id: None,
+                retranscribe: Some(providers::launcher::RetranscribeTarget {
+                    session_id: session.id.clone(),
+                    file_id: file.id.clone(),
+                }),
+                extra_args: settings.extra_args.clone(),
+                warm_pool: false,
+            },
         )
         .await
     {
@@ -1369,18 +2148,19 @@ async fn start_transcription(
         return Err(error);
     }
 
-    app.emit(
-        providers::launcher::SESSION_EVENT,
+    providers::launcher::emit_session_event(
+        &app,
         serde_json::json!({
-            "event": "start",
-            "session_id": session_id.clone(),
-            "provider": provider,
-            "model": model
+            "event": "retranscribe_started",
+            "session_id": run_session_id.clone(),
+            "original_session_id": session.id,
+            "original_file_id": file.id,
+            "overwrite": overwrite,
         }),
     )
-    .map_err(|error| format!("Failed to emit start event: {}", error))?;
+    .map_err(|error| format!("Failed to emit retranscribe start event: {}", error))?;
 
-    Ok(session_id)
+    Ok(run_session_id)
 }
 
 #[tauri::command]
@@ -1389,6 +2169,36 @@ async fn stop_transcription(app: AppHandle, session_id: String) -> Result<(), St
     launcher.stop(&session_id).await
 }
 
+fn stop_active_session_for_shutdown(app: &AppHandle) {
+    if providers::launcher::active_session_id().is_none() {
+        return;
+    }
+    let launcher = providers::launcher::WorkerLauncher::new(app.clone());
+    if let Some(session_id) = tauri::async_runtime::block_on(launcher.stop_for_shutdown()) {
+        eprintln!("stopped session {} for shutdown", session_id);
+    }
+}
+
+#[tauri::command]
+fn read_session_manifest(
+    session_id: String,
+) -> Result<providers::manifest::SessionManifest, String> {
+    providers::manifest::read_manifest(session_id.trim())
+}
+
+/// Lets the frontend stop and archive a running session before it asks the app to quit.
+#[tauri::command]
+async fn prepare_shutdown(app: AppHandle) -> Result<Option<String>, String> {
+    let launcher = providers::launcher::WorkerLauncher::new(app);
+    Ok(launcher.stop_for_shutdown().await)
+}
+
+#[tauri::command]
+fn force_clear_active_session(app: AppHandle) -> Result<Option<String>, String> {
+    let launcher = providers::launcher::WorkerLauncher::new(app);
+    launcher.force_clear_active_session()
+}
+
 #[tauri::command]
 fn update_menu_state(app: AppHandle, has_items: bool, is_processing: bool) -> Result<(), String> {
     update_menu_state_internal(
@@ -1409,7 +2219,17 @@ fn register_file_open_listener(
         .lock()
         .map_err(|_| "Failed to lock file-open state".to_string())?;
     locked.frontend_ready = true;
-    Ok(std::mem::take(&mut locked.pending_paths))
+    let in_memory = std::mem::take(&mut locked.pending_paths);
+    match pending_open_paths_file() {
+        Ok(file) => Ok(drain_pending_open_paths(&file, in_memory)),
+        Err(error) => {
+            eprintln!(
+                "[file-open] failed to resolve pending paths file: {}",
+                error
+            );
+            Ok(in_memory)
+        }
+    }
 }
 
 #[tauri::command]
@@ -1419,21 +2239,41 @@ fn read_transcript(path: String) -> Result<String, String> {
         return Err("Transcript path is empty".to_string());
     }
 
-    let transcript_path = PathBuf::from(normalized);
-    if !transcript_path.exists() {
+    commands::transcript::read_transcript_file(&PathBuf::from(normalized))
+        .map(|transcript| transcript.content)
+}
+
+#[tauri::command]
+fn read_failure_report(path: String) -> Result<Vec<FailureItem>, String> {
+    let normalized = path.trim();
+    if normalized.is_empty() {
+        return Err("Failure report path is empty".to_string());
+    }
+
+    let report_path = PathBuf::from(normalized);
+    if !report_path.is_file() {
         return Err(format!(
-            "Transcript not found: {}",
-            transcript_path.display()
+            "Failure report not found: {}",
+            report_path.display()
         ));
     }
 
-    std::fs::read_to_string(&transcript_path).map_err(|error| {
+    let content = std::fs::read_to_string(&report_path).map_err(|error| {
         format!(
-            "Failed to read transcript {}: {}",
-            transcript_path.display(),
+            "Failed to read failure report {}: {}",
+            report_path.display(),
             error
         )
-    })
+    })?;
+    serde_json::from_str::<FailureReport>(&content)
+        .map(|report| report.failures)
+        .map_err(|error| {
+            format!(
+                "Failed to parse failure report {}: {}",
+                report_path.display(),
+                error
+            )
+        })
 }
 
 #[tauri::command]
@@ -1488,6 +2328,47 @@ fn request_notification_permission() -> bool {
     notifications::request_permission()
 }
 
+#[tauri::command]
+async fn test_notification() -> notifications::NotificationTestStatus {
+    notifications::test_notification()
+}
+
+#[tauri::command]
+fn get_max_parallel_sessions() -> usize {
+    providers::settings::load_settings().max_parallel_sessions
+}
+
+#[tauri::command]
+fn set_max_parallel_sessions(value: usize) -> Result<usize, String> {
+    providers::settings::set_max_parallel_sessions(value)
+}
+
+#[tauri::command]
+fn get_provider_defaults() -> providers::settings::ProviderDefaults {
+    providers::settings::ProviderDefaults::from(&providers::settings::load_settings())
+}
+
+#[tauri::command]
+fn set_provider_defaults(
+    provider: String,
+    model: String,
+) -> Result<providers::settings::ProviderDefaults, String> {
+    providers::settings::set_provider_defaults(&provider, &model)
+}
+
+#[tauri::command]
+fn get_notification_sounds() -> providers::settings::NotificationSounds {
+    providers::settings::NotificationSounds::from(&providers::settings::load_settings())
+}
+
+#[tauri::command]
+fn set_notification_sounds(
+    completion: Option<String>,
+    failure: Option<String>,
+) -> Result<providers::settings::NotificationSounds, String> {
+    providers::settings::set_notification_sounds(completion.as_deref(), failure.as_deref())
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     let app = tauri::Builder::default()
@@ -1504,32 +2385,119 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_opener::init())
         .invoke_handler(tauri::generate_handler![
+            validate_batch_request,
             run_batch_transcription,
             get_model_catalog,
             resolve_model_path,
+            reveal_models_root,
+            open_session_output,
             install_model,
+            repair_model,
+            check_model_updates,
+            installed_models_usage,
             run_startup_diagnostics,
             health_check,
+            commands::architecture::architecture_check,
+            commands::support::create_support_bundle,
+            commands::support::effective_config,
             get_providers,
+            probe_provider,
+            get_cached_capabilities,
+            clear_capability_cache,
+            providers::watcher::start_providers_watch,
+            providers::watcher::stop_providers_watch,
+            commands::languages::detect_languages,
+            commands::inline_audio::transcribe_bytes,
+            resolve_best_provider,
+            validate_provider_model,
             resolve_provider_runtime,
             start_transcription,
+            retranscribe_file,
+            can_relaunch,
+            read_session_manifest,
             stop_transcription,
+            force_clear_active_session,
+            prepare_shutdown,
             update_menu_state,
             register_file_open_listener,
             read_transcript,
+            read_failure_report,
+            commands::transcript::read_transcript_with_encoding,
+            commands::transcript::read_transcript_typed,
+            commands::transcript::extract_text_from_json,
+            commands::transcript::transcript_segments,
+            commands::merge::merge_chunk_transcripts,
             export_transcript,
+            commands::convert::convert_transcript,
+            commands::convert::export_speaker_vtt,
+            commands::wer::compare_to_reference,
+            commands::export::check_export_destination,
             commands::export::export_transcripts,
+            commands::export::export_metadata_only,
+            commands::export::export_transcripts_with_progress,
+            commands::export::cancel_export,
+            commands::export::derive_relative_paths,
+            commands::paths::resolve_path,
+            commands::resources::system_resources,
+            commands::resources::warm_pool_advice,
+            commands::estimate::estimate_output_size,
+            commands::volumes::available_volumes,
+            commands::duplicates::find_duplicate_audio,
+            commands::sample_rate::analyze_sample_rates,
+            commands::long_files::flag_long_files,
+            commands::ffmpeg::ffmpeg_capabilities,
+            commands::ffmpeg::fallback_plan,
+            commands::ffmpeg::split_audio,
             commands::history::get_session_history,
+            commands::history::get_session_history_page,
+            commands::history::set_session_tags,
+            commands::history::sessions_for_output_dir,
+            commands::history::flag_empty_transcripts,
+            commands::history::provider_performance,
+            commands::history::verify_history_integrity,
+            commands::history::slowest_files,
+            commands::history::recent_output_dirs,
+            commands::history::last_session_summary,
+            commands::history::delete_sessions,
+            commands::history::migrate_provider_ids,
+            commands::history::find_stale_sessions,
+            commands::history::resolve_stale_session,
+            commands::history::history_stats,
             commands::history::delete_session,
+            commands::history::rename_transcripts,
+            commands::history::checkpoint_history,
             check_notification_permission,
             request_notification_permission,
+            test_notification,
+            get_notification_sounds,
+            set_notification_sounds,
+            get_max_parallel_sessions,
+            set_max_parallel_sessions,
+            get_provider_defaults,
+            set_provider_defaults,
             commands::scan::scan_files,
-            commands::scan::scan_directory
+            commands::scan::scan_from_list,
+            commands::scan::scan_directory,
+            commands::scan::set_scan_events_enabled,
+            commands::scan::cancel_scan,
+            commands::scan::clear_scan_cache,
+            commands::scan::sort_queue
         ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application");
 
     app.run(|app_handle, event| {
+        // Stopping on ExitRequested leaves the window alive to receive the cancellation events.
+        if matches!(
+            event,
+            tauri::RunEvent::ExitRequested { .. } | tauri::RunEvent::Exit
+        ) {
+            stop_active_session_for_shutdown(app_handle);
+        }
+        if matches!(event, tauri::RunEvent::Exit) {
+            providers::pool::shutdown_warm_pool();
+        }
+
         #[cfg(any(target_os = "macos", target_os = "ios"))]
         if let tauri::RunEvent::Opened { urls } = event {
             let file_paths = urls
@@ -1545,8 +2513,15 @@ pub fn run() {
 #[cfg(test)]
 mod tests {
     use super::{
-        filter_audio_file_paths, local_venv_path, menu_enabled_flags, venv_exists, MenuState,
+        drain_pending_open_paths, ensure_models_root, filter_audio_file_paths,
+        fluid_models_root_from, installed_models_usage_in, is_model_installed, local_venv_path,
+        menu_enabled_flags, model_files_needing_repair, model_update_status,
+        persist_pending_open_paths, read_failure_report, relaunch_check_for_manifest,
+        relaunch_error, remove_model_file, validate_batch_request_fields, venv_exists,
+        write_model_revision_marker, MenuState, RunBatchRequest, MANAGED_MODELS,
+        MODEL_REVISION_MARKER, REQUIRED_MODEL_FILES,
     };
+    use crate::providers;
     use std::fs;
     use std::path::Path;
     use std::path::PathBuf;
@@ -1620,4 +2595,363 @@ mod tests {
         fs::create_dir_all(with_bin.join("bin")).expect("venv bin directory should be created");
         assert!(venv_exists(&with_bin));
     }
+
+    #[test]
+    fn model_repair_targets_missing_and_corrupt_files() {
+        let model_dir = unique_test_dir("model_repair_targets_missing_and_corrupt_files");
+        fs::create_dir_all(&model_dir).expect("model directory should be created");
+        assert_eq!(
+            model_files_needing_repair(&model_dir).len(),
+            REQUIRED_MODEL_FILES.len()
+        );
+
+        for name in [
+            "Preprocessor.mlmodelc",
+            "Encoder.mlmodelc",
+            "Decoder.mlmodelc",
+        ] {
+            let bundle = model_dir.join(name);
+            fs::create_dir_all(&bundle).expect("bundle should be created");
+            fs::write(bundle.join("coremldata.bin"), b"data").expect("bundle should be written");
+        }
+        // An interrupted download leaves an empty bundle behind.
+        fs::create_dir_all(model_dir.join("JointDecision.mlmodelc"))
+            .expect("empty bundle should be created");
+        fs::write(model_dir.join("parakeet_vocab.json"), b"{\"0\": \"<unk>\"")
+            .expect("truncated vocab should be written");
+
+        assert!(
+            is_model_installed(&model_dir),
+            "presence check alone passes"
+        );
+        assert_eq!(
+            model_files_needing_repair(&model_dir),
+            vec![
+                "JointDecision.mlmodelc".to_string(),
+                "parakeet_vocab.json".to_string()
+            ]
+        );
+
+        fs::write(
+            model_dir.join("Encoder.mlmodelc").join("coremldata.bin"),
+            b"",
+        )
+        .expect("bundle should be truncated");
+        fs::write(model_dir.join("parakeet_vocab.json"), b"{\"0\": \"<unk>\"}")
+            .expect("vocab should be written");
+        assert_eq!(
+            model_files_needing_repair(&model_dir),
+            vec![
+                "Encoder.mlmodelc".to_string(),
+                "JointDecision.mlmodelc".to_string()
+            ]
+        );
+
+        remove_model_file(&model_dir.join("Encoder.mlmodelc")).expect("bundle should be removed");
+        remove_model_file(&model_dir.join("missing.mlmodelc")).expect("missing files are ignored");
+        assert!(!model_dir.join("Encoder.mlmodelc").exists());
+    }
+
+    #[test]
+    fn model_update_status_compares_revision_marker_with_catalog() {
+        let model_dir = unique_test_dir("model_updates");
+        fs::create_dir_all(&model_dir).expect("model dir should be created");
+        let def = MANAGED_MODELS[0];
+
+        let unknown = model_update_status(def, &model_dir);
+        assert_eq!(unknown.status, "unknown");
+        assert!(unknown.installed_revision.is_none());
+        assert!(!unknown.update_available);
+
+        write_model_revision_marker(&model_dir, def).expect("marker should be written");
+        let current = model_update_status(def, &model_dir);
+        assert_eq!(current.status, "up_to_date");
+        assert_eq!(current.installed_revision.as_deref(), Some(def.revision));
+        assert!(!current.update_available);
+        assert_eq!(
+            providers::resolver::read_model_version(&model_dir),
+            Some(def.model_version)
+        );
+
+        fs::write(model_dir.join(MODEL_REVISION_MARKER), "0\n").expect("marker should be written");
+        let outdated = model_update_status(def, &model_dir);
+        assert_eq!(outdated.status, "outdated");
+        assert_eq!(outdated.installed_revision.as_deref(), Some("0"));
+        assert_eq!(outdated.latest_revision, def.revision);
+        assert!(outdated.update_available);
+
+        let _ = fs::remove_dir_all(&model_dir);
+    }
+
+    #[test]
+    fn models_root_resolves_from_home_and_is_created() {
+        assert_eq!(
+            fluid_models_root_from(None).expect_err("missing HOME should error"),
+            "HOME environment variable is not set"
+        );
+        assert!(fluid_models_root_from(Some("".into())).is_err());
+
+        let home = unique_test_dir("models_root_resolves_from_home");
+        let root = fluid_models_root_from(Some(home.clone().into_os_string()))
+            .expect("models root should resolve");
+        assert_eq!(
+            root,
+            home.join("Library/Application Support/FluidAudio/Models")
+        );
+
+        assert!(!root.exists());
+        ensure_models_root(&root).expect("models root should be created");
+        assert!(root.is_dir());
+        ensure_models_root(&root).expect("existing models root is fine");
+    }
+
+    #[test]
+    fn pending_open_paths_persist_and_drain_once() {
+        let root = unique_test_dir("pending_open_paths_persist_and_drain_once");
+        let file = root.join("pending-open-paths.json");
+
+        persist_pending_open_paths(&file, &["/tmp/a.wav".to_string()])
+            .expect("first batch should persist");
+        persist_pending_open_paths(&file, &["/tmp/a.wav".to_string(), "/tmp/b.mp3".to_string()])
+            .expect("second batch should persist");
+
+        let drained = drain_pending_open_paths(
+            &file,
+            vec!["/tmp/b.mp3".to_string(), "/tmp/c.m4a".to_string()],
+        );
+        assert_eq!(
+            drained,
+            vec![
+                "/tmp/a.wav".to_string(),
+                "/tmp/b.mp3".to_string(),
+                "/tmp/c.m4a".to_string()
+            ]
+        );
+        assert!(!file.exists(), "drain should clear the on-disk queue");
+        assert!(drain_pending_open_paths(&file, Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn stale_pending_open_paths_from_previous_run_are_drained() {
+        let root = unique_test_dir("stale_pending_open_paths_from_previous_run");
+        fs::create_dir_all(&root).expect("test directory should be created");
+        let file = root.join("pending-open-paths.json");
+        fs::write(&file, r#"["/tmp/left-over.wav"]"#).expect("stale queue should be written");
+
+        assert_eq!(
+            drain_pending_open_paths(&file, Vec::new()),
+            vec!["/tmp/left-over.wav".to_string()]
+        );
+        assert!(!file.exists());
+
+        fs::write(&file, "not json").expect("corrupt queue should be written");
+        assert_eq!(
+            drain_pending_open_paths(&file, vec!["/tmp/new.wav".to_string()]),
+            vec!["/tmp/new.wav".to_string()]
+        );
+        assert!(!file.exists(), "corrupt queues are discarded");
+    }
+
+    #[test]
+    fn read_failure_report_parses_worker_run_report() {
+        let root = unique_test_dir("read_failure_report_parses_worker_run_report");
+        fs::create_dir_all(&root).expect("test directory should be created");
+        let report = root.join("run-20260212-000000.json");
+        fs::write(
+            &report,
+            r#"{
+  "durationSeconds" : 12.5,
+  "failed" : 2,
+  "failures" : [
+    {
+      "attempts" : 2,
+      "error" : "decode failed",
+      "file" : "/audio/b.wav",
+      "relativePath" : "b.wav"
+    },
+    {
+      "attempts" : 1,
+      "error" : "ffmpeg fallback failed",
+      "file" : "/audio/nested/c.mp3",
+      "relativePath" : "nested/c.mp3"
+    }
+  ],
+  "processed" : 3,
+  "total" : 5
+}"#,
+        )
+        .expect("fixture report should be written");
+
+        let failures = read_failure_report(report.to_string_lossy().to_string())
+            .expect("fixture report should parse");
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[0].file, "/audio/b.wav");
+        assert_eq!(failures[0].error, "decode failed");
+        assert_eq!(failures[1].file, "/audio/nested/c.mp3");
+
+        assert!(
+            read_failure_report(root.join("missing.json").to_string_lossy().to_string()).is_err()
+        );
+        fs::write(&report, "not json").expect("corrupt report should be written");
+        assert!(read_failure_report(report.to_string_lossy().to_string()).is_err());
+    }
+
+    fn batch_request(input_dir: &Path, output_dir: &Path, model_dir: &Path) -> RunBatchRequest {
+        RunBatchRequest {
+            input_dir: input_dir.to_string_lossy().to_string(),
+            output_dir: output_dir.to_string_lossy().to_string(),
+            model_dir: model_dir.to_string_lossy().to_string(),
+            model_version: "V3".to_string(),
+            output_format: "both".to_string(),
+            recursive: true,
+            overwrite: false,
+            dry_run: false,
+            extensions: vec![".WAV".to_string(), "mp3".to_string()],
+            max_retries: 1,
+            ffmpeg_fallback: true,
+        }
+    }
+
+    fn problem_fields(request: &RunBatchRequest) -> Vec<String> {
+        validate_batch_request_fields(request)
+            .problems
+            .into_iter()
+            .map(|problem| problem.field)
+            .collect()
+    }
+
+    #[test]
+    fn reports_disk_usage_for_managed_model_folders_only() {
+        let root = unique_test_dir("reports_disk_usage_for_managed_model_folders_only");
+        assert!(installed_models_usage_in(&root).is_empty());
+
+        let complete = root.join(MANAGED_MODELS[0].folder_name);
+        for name in REQUIRED_MODEL_FILES {
+            fs::create_dir_all(complete.join(name)).expect("model bundle should be created");
+            fs::write(complete.join(name).join("coremldata.bin"), vec![0u8; 1024])
+                .expect("model file should be written");
+        }
+        let partial = root.join(MANAGED_MODELS[1].folder_name);
+        fs::create_dir_all(&partial).expect("partial model dir should be created");
+        fs::write(partial.join(REQUIRED_MODEL_FILES[4]), b"{}").expect("vocab should be written");
+        fs::create_dir_all(root.join("some-other-model")).expect("foreign dir should be created");
+
+        let usage = installed_models_usage_in(&root);
+        assert_eq!(usage.len(), 2);
+        assert_eq!(usage[0].model_version, "v3");
+        assert!(usage[0].installed);
+        assert_eq!(usage[0].bytes, 5 * 1024);
+        assert_eq!(usage[0].size, "5.0 KB");
+        assert_eq!(usage[1].model_version, "v2");
+        assert!(!usage[1].installed);
+        assert_eq!(usage[1].bytes, 2);
+        assert_eq!(usage[1].size, "2 B");
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn validate_batch_request_reports_each_missing_piece() {
+        let root = unique_test_dir("validate_batch_request_reports_each_missing_piece");
+        let input_dir = root.join("audio");
+        let model_dir = root.join("model");
+        fs::create_dir_all(&input_dir).expect("input dir should be created");
+        fs::create_dir_all(&model_dir).expect("model dir should be created");
+        for name in REQUIRED_MODEL_FILES {
+            fs::write(model_dir.join(name), b"").expect("model file should be written");
+        }
+
+        let valid = batch_request(&input_dir, &root.join("out").join("nested"), &model_dir);
+        let validation = validate_batch_request_fields(&valid);
+        assert!(validation.problems.is_empty(), "{:?}", validation.problems);
+        assert_eq!(validation.model_version, "v3");
+        assert_eq!(validation.extensions, vec!["wav", "mp3"]);
+        assert!(
+            !root.join("out").exists(),
+            "validation must not create outputs"
+        );
+
+        let mut unknown_version = batch_request(&input_dir, &root, &model_dir);
+        unknown_version.model_version = "v9".to_string();
+        unknown_version.extensions = vec![" . ".to_string()];
+        assert_eq!(
+            problem_fields(&unknown_version),
+            vec!["modelVersion", "extensions"]
+        );
+
+        fs::remove_file(model_dir.join(REQUIRED_MODEL_FILES[1]))
+            .expect("model file should be removed");
+        let blocker = root.join("blocker");
+        fs::write(&blocker, b"").expect("blocking file should be written");
+        let doomed = batch_request(&root.join("missing"), &blocker.join("out"), &model_dir);
+        assert_eq!(
+            problem_fields(&doomed),
+            vec!["inputDir", "outputDir", "modelDir"]
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn relaunch_check_suggests_an_available_provider_for_old_manifests() {
+        let root = unique_test_dir("relaunch_check_suggests_an_available_provider");
+        fs::create_dir_all(&root).expect("test directory should be created");
+        let manifest_path = root.join("session.json");
+        fs::write(
+            &manifest_path,
+            r#"{
+  "sessionId": "session-old",
+  "createdAt": "2026-02-12T00:00:00.000Z",
+  "provider": "parakeet-coreml",
+  "model": "v3",
+  "outputDir": "/tmp/out",
+  "settings": {},
+  "files": []
+}"#,
+        )
+        .expect("manifest should be written");
+
+        let provider = |id: &str, name: &str, available: bool| providers::registry::Provider {
+            id: id.to_string(),
+            name: name.to_string(),
+            runtime: providers::registry::ProviderRuntime::PythonUv {
+                package: id.to_string(),
+                entry_point: id.to_string(),
+            },
+            available,
+            capabilities: None,
+            install_instructions: None,
+        };
+        let probed = vec![
+            provider("whisper-openai", "Whisper (OpenAI)", true),
+            provider("coreml-local", "CoreML Local", false),
+            provider("faster-whisper", "Faster Whisper", true),
+        ];
+
+        let check = relaunch_check_for_manifest(&manifest_path, |provider_id, model| {
+            providers::registry::assess_relaunch(&probed, provider_id, model)
+        })
+        .expect("manifest should be readable");
+        assert!(!check.can_relaunch);
+        assert_eq!(check.provider_id, "coreml-local");
+        assert_eq!(check.reason.as_deref(), Some("CoreML Local is unavailable"));
+        assert_eq!(
+            check
+                .suggestion
+                .as_ref()
+                .map(|suggestion| suggestion.provider_id.as_str()),
+            Some("faster-whisper")
+        );
+        assert_eq!(
+            relaunch_error(&check),
+            "Cannot relaunch with coreml-local: CoreML Local is unavailable. Faster Whisper is available instead."
+        );
+
+        let healthy = providers::registry::assess_relaunch(&probed, "whisper-openai", "base");
+        assert!(healthy.can_relaunch);
+        assert_eq!(healthy.suggestion, None);
+        assert!(relaunch_check_for_manifest(&root.join("missing.json"), |_, _| healthy).is_err());
+
+        let _ = fs::remove_dir_all(&root);
+    }
 }
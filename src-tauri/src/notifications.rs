@@ -1,3 +1,4 @@
+use serde::Serialize;
 use std::process::Command;
 
 const CHECK_PERMISSION_SCRIPT: &str = r#"
@@ -79,7 +80,33 @@ pub fn request_permission() -> bool {
     }
 }
 
-#[cfg(target_os = "macos")]
+// The sounds macOS ships in /System/Library/Sounds; anything else would silently play nothing.
+pub const NOTIFICATION_SOUNDS: &[&str] = &[
+    "Basso",
+    "Blow",
+    "Bottle",
+    "Frog",
+    "Funk",
+    "Glass",
+    "Hero",
+    "Morse",
+    "Ping",
+    "Pop",
+    "Purr",
+    "Sosumi",
+    "Submarine",
+    "Tink",
+];
+
+pub fn allowed_sound(name: &str) -> Option<&'static str> {
+    let name = name.trim();
+    NOTIFICATION_SOUNDS
+        .iter()
+        .find(|sound| sound.eq_ignore_ascii_case(name))
+        .copied()
+}
+
+#[cfg(any(target_os = "macos", test))]
 fn escape_applescript(input: &str) -> String {
     input
         .replace('\\', "\\\\")
@@ -87,18 +114,29 @@ fn escape_applescript(input: &str) -> String {
         .replace('\n', " ")
 }
 
+#[cfg(any(target_os = "macos", test))]
+fn notification_script(title: &str, body: &str, sound_name: Option<&str>) -> String {
+    let mut script = format!(
+        "display notification \"{}\" with title \"{}\"",
+        escape_applescript(body),
+        escape_applescript(title)
+    );
+    if let Some(sound) = sound_name.and_then(allowed_sound) {
+        script.push_str(&format!(" sound name \"{}\"", sound));
+    }
+    script
+}
+
 pub fn send(title: &str, body: &str) -> bool {
+    send_with_sound(title, body, None)
+}
+
+pub fn send_with_sound(title: &str, body: &str, sound_name: Option<&str>) -> bool {
     #[cfg(target_os = "macos")]
     {
-        let script = format!(
-            "display notification \"{}\" with title \"{}\"",
-            escape_applescript(body),
-            escape_applescript(title)
-        );
-
         return Command::new("/usr/bin/osascript")
             .arg("-e")
-            .arg(script)
+            .arg(notification_script(title, body, sound_name))
             .status()
             .map(|status| status.success())
             .unwrap_or(false);
@@ -106,14 +144,83 @@ pub fn send(title: &str, body: &str) -> bool {
 
     #[cfg(not(target_os = "macos"))]
     {
-        let _ = (title, body);
+        let _ = (title, body, sound_name);
         false
     }
 }
 
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationTestStatus {
+    pub permission: String,
+    pub requested: bool,
+    pub sent: bool,
+    pub detail: String,
+}
+
+fn assemble_test_status(
+    supported: bool,
+    check: impl FnOnce() -> bool,
+    request: impl FnOnce() -> bool,
+    send: impl FnOnce() -> bool,
+) -> NotificationTestStatus {
+    // Off macOS the permission calls are stubs that always succeed, so don't consult them.
+    if !supported {
+        return NotificationTestStatus {
+            permission: "unsupported".to_string(),
+            requested: false,
+            sent: false,
+            detail: "Desktop notifications are only delivered on macOS.".to_string(),
+        };
+    }
+
+    let mut requested = false;
+    let mut granted = check();
+    if !granted {
+        requested = true;
+        granted = request();
+    }
+    if !granted {
+        return NotificationTestStatus {
+            permission: "denied".to_string(),
+            requested,
+            sent: false,
+            detail: "Notifications are blocked. Allow them for Batch Transcriber in System Settings > Notifications.".to_string(),
+        };
+    }
+
+    let sent = send();
+    NotificationTestStatus {
+        permission: "granted".to_string(),
+        requested,
+        sent,
+        detail: if sent {
+            "A sample notification was sent.".to_string()
+        } else {
+            "Permission is granted but osascript failed to display the notification.".to_string()
+        },
+    }
+}
+
+pub fn test_notification() -> NotificationTestStatus {
+    assemble_test_status(
+        cfg!(target_os = "macos"),
+        check_permission,
+        request_permission,
+        || {
+            send(
+                "Batch Transcriber",
+                "Notifications are working. You'll be told when sessions finish.",
+            )
+        },
+    )
+}
+
 #[cfg(test)]
 mod tests {
-    use super::parse_permission_output;
+    use super::{
+        allowed_sound, assemble_test_status, notification_script, parse_permission_output,
+    };
 
     #[test]
     fn parses_granted_permission_output() {
@@ -126,4 +233,59 @@ mod tests {
         assert!(!parse_permission_output(b"denied"));
         assert!(!parse_permission_output(b""));
     }
+
+    #[test]
+    fn builds_notification_script_with_allowlisted_sound() {
+        assert_eq!(
+            notification_script("Done", "3 \"files\" ok", None),
+            "display notification \"3 \\\"files\\\" ok\" with title \"Done\""
+        );
+        assert_eq!(
+            notification_script("Done", "ok", Some("glass")),
+            "display notification \"ok\" with title \"Done\" sound name \"Glass\""
+        );
+        assert_eq!(
+            notification_script("Done", "ok", Some("Glass\" & do shell script \"rm")),
+            "display notification \"ok\" with title \"Done\""
+        );
+    }
+
+    #[test]
+    fn allowlists_only_builtin_macos_sounds() {
+        assert_eq!(allowed_sound(" basso "), Some("Basso"));
+        assert_eq!(allowed_sound("Submarine"), Some("Submarine"));
+        assert_eq!(allowed_sound("Klaxon"), None);
+        assert_eq!(allowed_sound("../Glass"), None);
+        assert_eq!(allowed_sound(""), None);
+    }
+
+    #[test]
+    fn test_status_requests_permission_only_when_missing() {
+        let granted = assemble_test_status(true, || true, || panic!("not requested"), || true);
+        assert_eq!(granted.permission, "granted");
+        assert!(!granted.requested);
+        assert!(granted.sent);
+
+        let requested = assemble_test_status(true, || false, || true, || false);
+        assert_eq!(requested.permission, "granted");
+        assert!(requested.requested);
+        assert!(!requested.sent);
+
+        let denied = assemble_test_status(true, || false, || false, || panic!("not sent"));
+        assert_eq!(denied.permission, "denied");
+        assert!(denied.requested);
+        assert!(!denied.sent);
+    }
+
+    #[test]
+    fn test_status_reports_unsupported_platforms_without_probing() {
+        let status = assemble_test_status(
+            false,
+            || panic!("not checked"),
+            || panic!("not requested"),
+            || panic!("not sent"),
+        );
+        assert_eq!(status.permission, "unsupported");
+        assert!(!status.sent);
+    }
 }
@@ -1,20 +1,42 @@
-use super::registry::{python_uv_command_args, ProviderRuntime};
+use super::manifest::{
+    cleanup_manifest, generate_manifest_with_parent, QueueItem, TranscriptionSettings,
+};
+use super::pool::{
+    checkout_warm_worker, discard_warm_worker, release_warm_worker, ServeWorker, SERVE_FLAG,
+};
+use super::registry::{python_uv_command_args, query_capabilities, ProviderRuntime};
+use super::resolver::{normalize_model_version, read_model_version};
+use super::settings::load_settings;
 use crate::commands::history::{
-    archive_session_from_manifest, FileOutcome, SessionSummarySnapshot,
+    archive_cancelled_session, archive_session_from_manifest, parse_manifest, update_session_file,
+    write_session_index, FileOutcome, SessionRecord, SessionSummarySnapshot,
 };
+use crate::commands::resources::{current_warm_pool_advice, WarmPoolAdvice};
 use crate::notifications;
 use serde_json::{json, Value};
-use std::collections::HashMap;
-use std::io::{BufRead, BufReader};
+use std::collections::{HashMap, HashSet};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
-use std::process::{Child, Command, Stdio};
+use std::process::{Child, ChildStderr, ChildStdout, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, LazyLock, Mutex};
 use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter};
 use tokio::task::JoinHandle;
 
 pub const SESSION_EVENT: &str = "transcription-event";
+// Far beyond any legitimate event, but small enough that a runaway blob cannot balloon memory.
+const MAX_WORKER_LINE_BYTES: usize = 8 * 1024 * 1024;
+const TRUNCATED_LINE_PREVIEW_BYTES: usize = 256;
 const STOP_TIMEOUT: Duration = Duration::from_secs(5);
+// Quitting waits on this, so a worker that ignores SIGTERM gets less grace than a user stop.
+const SHUTDOWN_STOP_TIMEOUT: Duration = Duration::from_secs(2);
+// Upper bound on the whole shutdown stop, including archiving, before falling back to a force clear.
+pub const SHUTDOWN_DEADLINE: Duration = Duration::from_secs(4);
+// Highest worker event protocol this build understands; workers announce theirs in a `protocol` event.
+pub const WORKER_PROTOCOL_VERSION: u64 = 1;
+const PROTOCOL_VERSION_FLAG: &str = "--protocol-version";
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LaunchCommand {
@@ -31,6 +53,8 @@ pub struct NotificationPreferences {
     pub notifications_enabled: bool,
     pub notify_on_complete: bool,
     pub notify_on_error: bool,
+    pub completion_sound: Option<&'static str>,
+    pub failure_sound: Option<&'static str>,
 }
 
 impl Default for NotificationPreferences {
@@ -39,10 +63,82 @@ impl Default for NotificationPreferences {
             notifications_enabled: true,
             notify_on_complete: true,
             notify_on_error: true,
+            completion_sound: None,
+            failure_sound: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    User,
+    Timeout,
+    Forced,
+    Shutdown,
+}
+
+impl StopReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StopReason::User => "user",
+            StopReason::Timeout => "timeout",
+            StopReason::Forced => "forced",
+            StopReason::Shutdown => "shutdown",
+        }
+    }
+
+    fn grace_period(self) -> Duration {
+        match self {
+            StopReason::Shutdown => SHUTDOWN_STOP_TIMEOUT,
+            _ => STOP_TIMEOUT,
+        }
+    }
+
+    // A user stop that had to fall back to SIGKILL is reported as a timeout.
+    fn after_stop(self, graceful: bool) -> Self {
+        if self == StopReason::User && !graceful {
+            StopReason::Timeout
+        } else {
+            self
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetranscribeTarget {
+    pub session_id: String,
+    pub file_id: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LaunchOptions {
+    pub notification_preferences: NotificationPreferences,
+    pub write_index: bool,
+    pub append_index: bool,
+    pub parent_session_id: Option<String>,
+    pub retranscribe: Option<RetranscribeTarget>,
+    pub extra_args: Vec<String>,
+    pub warm_pool: bool,
+}
+
+pub struct ChunkedRun {
+    pub parent_session_id: String,
+    pub provider: String,
+    pub model: String,
+    pub output_dir: PathBuf,
+    pub settings: TranscriptionSettings,
+    pub chunks: Vec<Vec<QueueItem>>,
+    pub options: LaunchOptions,
+}
+
+enum WorkerOutput {
+    Spawned {
+        stdout: ChildStdout,
+        stderr: ChildStderr,
+    },
+    Warm(ServeWorker),
+}
+
 #[allow(dead_code)]
 pub struct WorkerProcess {
     pub child: Arc<Mutex<Child>>,
@@ -51,12 +147,92 @@ pub struct WorkerProcess {
 
 struct ActiveProcess {
     session_id: String,
+    parent_session_id: Option<String>,
     manifest_path: PathBuf,
+    output_dir: PathBuf,
     queued_item_ids: Vec<String>,
+    retranscribe: Option<RetranscribeTarget>,
+    pid: u32,
     child: Arc<Mutex<Child>>,
+    progress: Arc<SessionProgress>,
+}
+
+// Shared between the stream task and stop so a cancelled run keeps what already finished.
+#[derive(Debug, Default)]
+struct SessionProgress {
+    outcomes: Mutex<HashMap<String, FileOutcome>>,
+    stopping: AtomicBool,
+}
+
+impl SessionProgress {
+    fn record(&self, file_path: &str, outcome: &FileOutcome) {
+        if let Ok(mut outcomes) = self.outcomes.lock() {
+            outcomes.insert(file_path.to_string(), outcome.clone());
+        }
+    }
+
+    fn snapshot(&self) -> HashMap<String, FileOutcome> {
+        self.outcomes
+            .lock()
+            .map(|outcomes| outcomes.clone())
+            .unwrap_or_default()
+    }
 }
 
 static ACTIVE_PROCESS: LazyLock<Mutex<Option<ActiveProcess>>> = LazyLock::new(|| Mutex::new(None));
+static CHUNKED_RUNS: LazyLock<Mutex<HashMap<String, Arc<AtomicBool>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+static EVENT_LOG: LazyLock<Mutex<Option<EventLog>>> = LazyLock::new(|| Mutex::new(None));
+
+struct EventLog {
+    path: PathBuf,
+    file: File,
+}
+
+impl EventLog {
+    fn open(path: &Path) -> Result<Self, String> {
+        if path.is_dir() {
+            return Err(format!("Event log path is a directory: {}", path.display()));
+        }
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent).map_err(|error| {
+                    format!(
+                        "Failed to create event log directory {}: {}",
+                        parent.display(),
+                        error
+                    )
+                })?;
+            }
+        }
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|error| format!("Failed to open event log {}: {}", path.display(), error))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+        })
+    }
+
+    fn append(&mut self, payload: &Value) -> Result<(), String> {
+        let mut line = serde_json::to_vec(payload)
+            .map_err(|error| format!("Failed to serialize event log entry: {}", error))?;
+        line.push(b'\n');
+        self.file
+            .write_all(&line)
+            .and_then(|_| self.file.flush())
+            .map_err(|error| {
+                format!(
+                    "Failed to write event log {}: {}",
+                    self.path.display(),
+                    error
+                )
+            })
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 struct SessionSummary {
@@ -67,6 +243,42 @@ struct SessionSummary {
     duration_seconds: f64,
 }
 
+/// Routes subsequent session events to `path` as JSON lines, or stops logging when `None`.
+pub fn set_event_log(path: Option<&str>) -> Result<(), String> {
+    let log = match path.map(str::trim).filter(|path| !path.is_empty()) {
+        Some(path) => Some(EventLog::open(Path::new(path))?),
+        None => None,
+    };
+    let mut current = EVENT_LOG
+        .lock()
+        .map_err(|_| "Failed to configure event log".to_string())?;
+    *current = log;
+    Ok(())
+}
+
+pub fn event_log_path() -> Option<PathBuf> {
+    EVENT_LOG
+        .lock()
+        .ok()
+        .and_then(|current| current.as_ref().map(|log| log.path.clone()))
+}
+
+fn record_session_event(payload: &Value) {
+    let Ok(mut current) = EVENT_LOG.lock() else {
+        return;
+    };
+    if let Some(log) = current.as_mut() {
+        if let Err(error) = log.append(payload) {
+            eprintln!("[launcher] {}", error);
+        }
+    }
+}
+
+pub fn emit_session_event(app: &AppHandle, payload: Value) -> tauri::Result<()> {
+    record_session_event(&payload);
+    app.emit(SESSION_EVENT, payload)
+}
+
 pub fn launch_command_for_runtime(runtime: &ProviderRuntime) -> Option<LaunchCommand> {
     match runtime {
         ProviderRuntime::SwiftNative { binary_path, .. } => Some(LaunchCommand {
@@ -85,6 +297,19 @@ pub fn launch_command_for_runtime(runtime: &ProviderRuntime) -> Option<LaunchCom
 }
 
 fn infer_model_version_from_model_dir(model_dir: &Path) -> String {
+    if let Some(version) = read_model_version(model_dir) {
+        return version.to_string();
+    }
+
+    let folder = model_dir
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    if let Some(version) = normalize_model_version(&folder) {
+        return version.to_string();
+    }
+
+    // Unmarked custom folders fall back to sniffing the path for a version hint.
     let lower = model_dir.to_string_lossy().to_ascii_lowercase();
     if lower.contains("v2") {
         "v2".to_string()
@@ -93,33 +318,223 @@ fn infer_model_version_from_model_dir(model_dir: &Path) -> String {
     }
 }
 
-fn command_args_for_runtime(
-    runtime: &ProviderRuntime,
-    manifest_path: &Path,
-    output_dir: &Path,
-) -> Result<LaunchCommand, String> {
-    let mut launch = launch_command_for_runtime(runtime)
-        .ok_or_else(|| "Cloud API providers do not support local worker launching".to_string())?;
+// Whisper runtimes carry no model here, so they are budgeted like the larger CoreML model.
+fn warm_pool_model_version(provider: &ProviderRuntime) -> String {
+    match provider {
+        ProviderRuntime::SwiftNative { model_dir, .. } => {
+            infer_model_version_from_model_dir(model_dir)
+        }
+        _ => "v3".to_string(),
+    }
+}
+
+// A warm worker stays resident after the run, so tight memory means a cold launch instead.
+fn use_warm_worker(
+    requested: bool,
+    replaying: bool,
+    advice: impl FnOnce() -> WarmPoolAdvice,
+) -> bool {
+    if !requested || replaying {
+        return false;
+    }
+    let advice = advice();
+    if !advice.advisable {
+        eprintln!("[launcher] not keeping a warm worker: {}", advice.detail);
+    }
+    advice.advisable
+}
+
+// Flags the launcher sets itself; user-supplied extra args may not override them.
+const RESERVED_WORKER_FLAGS: &[&str] = &[
+    "--manifest",
+    "--output-dir",
+    "--model-dir",
+    "--model-version",
+    PROTOCOL_VERSION_FLAG,
+    SERVE_FLAG,
+];
+
+pub fn validate_extra_args(extra_args: &[String]) -> Result<(), String> {
+    for arg in extra_args {
+        let flag = arg.split('=').next().unwrap_or_default();
+        if RESERVED_WORKER_FLAGS.contains(&flag) {
+            return Err(format!(
+                "Extra worker argument {} overrides a flag managed by the app",
+                arg
+            ));
+        }
+    }
+    Ok(())
+}
 
-    if let ProviderRuntime::SwiftNative { model_dir, .. } = runtime {
-        launch.args.extend([
+pub(crate) fn model_args_for_runtime(runtime: &ProviderRuntime) -> Vec<String> {
+    match runtime {
+        ProviderRuntime::SwiftNative { model_dir, .. } => vec![
             "--model-dir".to_string(),
             model_dir.to_string_lossy().to_string(),
             "--model-version".to_string(),
             infer_model_version_from_model_dir(model_dir),
-        ]);
+        ],
+        _ => Vec::new(),
+    }
+}
+
+// Only the CoreML worker pays a model warmup worth keeping a process alive for.
+fn serve_command_for_runtime(
+    runtime: &ProviderRuntime,
+    extra_args: &[String],
+    worker_protocol: Option<u64>,
+) -> Result<Option<LaunchCommand>, String> {
+    validate_extra_args(extra_args)?;
+    if !matches!(runtime, ProviderRuntime::SwiftNative { .. }) {
+        return Ok(None);
     }
+    let Some(mut launch) = launch_command_for_runtime(runtime) else {
+        return Ok(None);
+    };
+
+    launch.args.extend(model_args_for_runtime(runtime));
+    launch.args.extend(protocol_args(worker_protocol));
+    launch.args.push(SERVE_FLAG.to_string());
+    launch.args.extend(extra_args.iter().cloned());
+    Ok(Some(launch))
+}
+
+pub(crate) fn command_args_for_runtime(
+    runtime: &ProviderRuntime,
+    manifest_path: &Path,
+    output_dir: &Path,
+    extra_args: &[String],
+    worker_protocol: Option<u64>,
+) -> Result<LaunchCommand, String> {
+    validate_extra_args(extra_args)?;
+    let mut launch = launch_command_for_runtime(runtime)
+        .ok_or_else(|| "Cloud API providers do not support local worker launching".to_string())?;
 
+    launch.args.extend(model_args_for_runtime(runtime));
     launch.args.extend([
         "--manifest".to_string(),
         manifest_path.to_string_lossy().to_string(),
         "--output-dir".to_string(),
         output_dir.to_string_lossy().to_string(),
     ]);
+    launch.args.extend(protocol_args(worker_protocol));
+    launch.args.extend(extra_args.iter().cloned());
 
     Ok(launch)
 }
 
+/// Protocol version the worker advertises in `--capabilities`, if it speaks the handshake at all.
+pub(crate) fn advertised_worker_protocol(runtime: &ProviderRuntime) -> Option<u64> {
+    query_capabilities(runtime)
+        .ok()
+        .and_then(|capabilities| capabilities.protocol_version)
+}
+
+// Workers built before the handshake exit on unknown flags, so only ask those that advertise it.
+fn protocol_args(worker_protocol: Option<u64>) -> Vec<String> {
+    match worker_protocol {
+        Some(_) => vec![
+            PROTOCOL_VERSION_FLAG.to_string(),
+            WORKER_PROTOCOL_VERSION.to_string(),
+        ],
+        None => Vec::new(),
+    }
+}
+
+// Only the first event can be a handshake; workers that skip it keep the pre-handshake behavior.
+fn check_protocol_event(value: &Value) -> Option<Result<u64, String>> {
+    if value.get("event").and_then(Value::as_str) != Some("protocol") {
+        return None;
+    }
+
+    Some(match value.get("version").and_then(Value::as_u64) {
+        Some(version) if (1..=WORKER_PROTOCOL_VERSION).contains(&version) => Ok(version),
+        Some(version) => Err(format!(
+            "Worker speaks event protocol version {} but this app supports up to version {}; update the app or install a matching worker",
+            version, WORKER_PROTOCOL_VERSION
+        )),
+        None => Err("Worker sent a protocol event without a valid version".to_string()),
+    })
+}
+
+#[derive(Debug, PartialEq)]
+enum WorkerLine {
+    Complete(String),
+    Truncated { preview: String, bytes: usize },
+}
+
+// Reads one line without ever holding more than `max_bytes` of it; the rest is skipped up to the newline.
+fn read_capped_line(
+    reader: &mut impl BufRead,
+    max_bytes: usize,
+) -> std::io::Result<Option<WorkerLine>> {
+    let mut line = Vec::new();
+    let mut bytes = 0usize;
+    let mut read_any = false;
+    loop {
+        let (consumed, found_newline) = {
+            let available = reader.fill_buf()?;
+            if available.is_empty() {
+                break;
+            }
+            read_any = true;
+            let newline = available.iter().position(|byte| *byte == b'\n');
+            let chunk = &available[..newline.unwrap_or(available.len())];
+            let keep = max_bytes.saturating_sub(line.len()).min(chunk.len());
+            line.extend_from_slice(&chunk[..keep]);
+            bytes += chunk.len();
+            (
+                chunk.len() + usize::from(newline.is_some()),
+                newline.is_some(),
+            )
+        };
+        reader.consume(consumed);
+        if found_newline {
+            break;
+        }
+    }
+
+    if !read_any {
+        return Ok(None);
+    }
+    if bytes > max_bytes {
+        line.truncate(TRUNCATED_LINE_PREVIEW_BYTES);
+        return Ok(Some(WorkerLine::Truncated {
+            preview: String::from_utf8_lossy(&line).into_owned(),
+            bytes,
+        }));
+    }
+    if line.last() == Some(&b'\r') {
+        line.pop();
+    }
+    Ok(Some(WorkerLine::Complete(
+        String::from_utf8_lossy(&line).into_owned(),
+    )))
+}
+
+fn truncated_line_event(stream: &str, preview: String, bytes: usize) -> Value {
+    json!({
+        "event": "worker_line_truncated",
+        "stream": stream,
+        "bytes": bytes,
+        "limit": MAX_WORKER_LINE_BYTES,
+        "preview": preview,
+    })
+}
+
+// Oversized stdout lines come through as a `worker_line_truncated` event in place of the line.
+pub(crate) fn capped_lines(mut reader: impl BufRead) -> impl Iterator<Item = String> {
+    std::iter::from_fn(move || {
+        match read_capped_line(&mut reader, MAX_WORKER_LINE_BYTES).ok()?? {
+            WorkerLine::Complete(line) => Some(line),
+            WorkerLine::Truncated { preview, bytes } => {
+                Some(truncated_line_event("stdout", preview, bytes).to_string())
+            }
+        }
+    })
+}
+
 fn parse_worker_line(line: &str) -> Result<Option<Value>, serde_json::Error> {
     if line.trim().is_empty() {
         return Ok(None);
@@ -145,7 +560,7 @@ fn parse_summary_event(value: &Value) -> Option<SessionSummary> {
     })
 }
 
-fn parse_fatal_error(value: &Value) -> Option<String> {
+pub(crate) fn parse_fatal_error(value: &Value) -> Option<String> {
     if value.get("event").and_then(Value::as_str) != Some("fatal_error") {
         return None;
     }
@@ -156,7 +571,27 @@ fn parse_fatal_error(value: &Value) -> Option<String> {
         .map(str::to_string)
 }
 
-fn parse_file_outcome(value: &Value) -> Option<(String, FileOutcome)> {
+// Workers that report `attempts` on the final event know better than our own retry count.
+fn reported_retries(value: &Value) -> u32 {
+    value
+        .get("attempts")
+        .and_then(Value::as_u64)
+        .map(|attempts| u32::try_from(attempts.saturating_sub(1)).unwrap_or(u32::MAX))
+        .unwrap_or(0)
+}
+
+fn parse_file_retry(value: &Value) -> Option<String> {
+    if value.get("event").and_then(Value::as_str) != Some("file_retry") {
+        return None;
+    }
+
+    value
+        .get("file")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+pub(crate) fn parse_file_outcome(value: &Value) -> Option<(String, FileOutcome)> {
     let event_name = value.get("event").and_then(Value::as_str)?;
     let file_path = value.get("file").and_then(Value::as_str)?.to_string();
 
@@ -176,6 +611,9 @@ fn parse_file_outcome(value: &Value) -> Option<(String, FileOutcome)> {
                         .and_then(Value::as_str)
                         .map(str::to_string),
                     error: None,
+                    audio_seconds: value.get("duration_seconds").and_then(Value::as_f64),
+                    processing_seconds: value.get("processing_seconds").and_then(Value::as_f64),
+                    retries: reported_retries(value),
                 },
             ))
         }
@@ -197,6 +635,9 @@ fn parse_file_outcome(value: &Value) -> Option<(String, FileOutcome)> {
                     .get("reason")
                     .and_then(Value::as_str)
                     .map(str::to_string),
+                audio_seconds: None,
+                processing_seconds: None,
+                retries: 0,
             },
         )),
         "file_failed" => Some((
@@ -209,12 +650,387 @@ fn parse_file_outcome(value: &Value) -> Option<(String, FileOutcome)> {
                     .get("error")
                     .and_then(Value::as_str)
                     .map(str::to_string),
+                audio_seconds: None,
+                processing_seconds: None,
+                retries: reported_retries(value),
             },
         )),
         _ => None,
     }
 }
 
+const PARTIAL_TRANSCRIPT_DIR: &str = ".aura-partials";
+const PARTIAL_TRANSCRIPT_SUFFIX: &str = ".partial.txt";
+
+// Partials live in a directory only the app writes to, so cleanup can never touch user files.
+fn partial_transcript_dir(output_dir: &Path) -> PathBuf {
+    output_dir.join(PARTIAL_TRANSCRIPT_DIR)
+}
+
+fn partial_transcript_path(output_dir: &Path, file_id: &str) -> Option<PathBuf> {
+    let safe_id = !file_id.is_empty()
+        && file_id
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_');
+    safe_id.then(|| {
+        partial_transcript_dir(output_dir).join(format!("{}{}", file_id, PARTIAL_TRANSCRIPT_SUFFIX))
+    })
+}
+
+// Worker events name files by path; partials are keyed by the manifest's file id instead.
+fn manifest_file_ids(manifest_path: &Path) -> HashMap<String, String> {
+    parse_manifest(manifest_path)
+        .map(|manifest| {
+            manifest
+                .files
+                .into_iter()
+                .map(|file| (file.path.to_string_lossy().to_string(), file.id))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn append_partial_transcript(
+    output_dir: &Path,
+    file_ids: &HashMap<String, String>,
+    value: &Value,
+) -> Result<(), String> {
+    if value.get("event").and_then(Value::as_str) != Some("file_partial") {
+        return Ok(());
+    }
+    let (Some(file_path), Some(text)) = (
+        value.get("file").and_then(Value::as_str),
+        value.get("text").and_then(Value::as_str),
+    ) else {
+        return Ok(());
+    };
+    let Some(partial_path) = file_ids
+        .get(file_path)
+        .and_then(|file_id| partial_transcript_path(output_dir, file_id))
+    else {
+        return Ok(());
+    };
+
+    std::fs::create_dir_all(partial_transcript_dir(output_dir)).map_err(|error| {
+        format!(
+            "Failed to create partial transcript directory {}: {}",
+            partial_transcript_dir(output_dir).display(),
+            error
+        )
+    })?;
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&partial_path)
+        .and_then(|mut file| file.write_all(text.as_bytes()))
+        .map_err(|error| {
+            format!(
+                "Failed to append partial transcript {}: {}",
+                partial_path.display(),
+                error
+            )
+        })
+}
+
+fn finalize_partial_transcript(
+    output_dir: &Path,
+    file_ids: &HashMap<String, String>,
+    value: &Value,
+) -> Result<(), String> {
+    if value.get("event").and_then(Value::as_str) != Some("file_done") {
+        return Ok(());
+    }
+    let Some(file_path) = value.get("file").and_then(Value::as_str) else {
+        return Ok(());
+    };
+    let Some(partial_path) = file_ids
+        .get(file_path)
+        .and_then(|file_id| partial_transcript_path(output_dir, file_id))
+    else {
+        return Ok(());
+    };
+    if !partial_path.is_file() {
+        return Ok(());
+    }
+
+    let final_path = value
+        .get("output")
+        .and_then(|entry| entry.get("txt"))
+        .and_then(Value::as_str)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            let stem = Path::new(file_path).file_stem().unwrap_or_default();
+            output_dir.join(format!("{}.txt", stem.to_string_lossy()))
+        });
+
+    // A transcript the worker wrote itself is authoritative; the partial copy is only a fallback.
+    if final_path.exists() {
+        return std::fs::remove_file(&partial_path).map_err(|error| {
+            format!(
+                "Failed to remove partial transcript {}: {}",
+                partial_path.display(),
+                error
+            )
+        });
+    }
+
+    std::fs::rename(&partial_path, &final_path).map_err(|error| {
+        format!(
+            "Failed to finalize partial transcript {} -> {}: {}",
+            partial_path.display(),
+            final_path.display(),
+            error
+        )
+    })
+}
+
+fn cleanup_orphaned_partial_transcripts(output_dir: &Path) -> Vec<PathBuf> {
+    let partial_dir = partial_transcript_dir(output_dir);
+    let Ok(entries) = std::fs::read_dir(&partial_dir) else {
+        return Vec::new();
+    };
+
+    let mut removed = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let is_partial = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.ends_with(PARTIAL_TRANSCRIPT_SUFFIX));
+        if !is_partial || !path.is_file() {
+            continue;
+        }
+        match std::fs::remove_file(&path) {
+            Ok(()) => removed.push(path),
+            Err(error) => eprintln!(
+                "[launcher] failed to remove orphaned partial transcript {}: {}",
+                path.display(),
+                error
+            ),
+        }
+    }
+    // Only succeeds once the directory is empty, which is all that should be removed.
+    let _ = std::fs::remove_dir(&partial_dir);
+    removed
+}
+
+// Re-transcription events carry the session and file they redo so the UI can route them.
+fn scope_event(mut value: Value, retranscribe: Option<&RetranscribeTarget>) -> Value {
+    if let (Some(target), Some(object)) = (retranscribe, value.as_object_mut()) {
+        object.insert("original_session_id".to_string(), json!(target.session_id));
+        object.insert("original_file_id".to_string(), json!(target.file_id));
+    }
+    value
+}
+
+const HISTORY_CHECKPOINT_INTERVAL: usize = 5;
+
+// Once a stop has begun the cancelled archive owns the row; a late checkpoint would flip it back.
+fn history_checkpoint_due(outcomes_recorded: usize, stopping: bool) -> bool {
+    !stopping
+        && outcomes_recorded > 0
+        && outcomes_recorded.is_multiple_of(HISTORY_CHECKPOINT_INTERVAL)
+}
+
+// Persists partial outcomes with a `running` status so a crash mid-session keeps them.
+fn checkpoint_session_progress(
+    manifest_path: &Path,
+    session_id: &str,
+    file_outcomes: &HashMap<String, FileOutcome>,
+) {
+    if let Err(error) = archive_session_from_manifest(
+        manifest_path,
+        session_id,
+        None,
+        -1,
+        "running",
+        file_outcomes,
+    ) {
+        eprintln!(
+            "[history] failed to checkpoint session {}: {}",
+            session_id, error
+        );
+    }
+}
+
+#[derive(Debug, Default)]
+struct WorkerStream {
+    latest_summary: Option<SessionSummary>,
+    fatal_error: Option<String>,
+    file_outcomes: HashMap<String, FileOutcome>,
+    outcomes_recorded: usize,
+    protocol_version: Option<u64>,
+    protocol_mismatch: bool,
+}
+
+// Everything a session does with worker stdout, minus the process, so recorded output can be replayed.
+fn consume_worker_stream(
+    lines: impl Iterator<Item = String>,
+    session_id: &str,
+    manifest_path: &Path,
+    output_dir: &Path,
+    retranscribe: Option<&RetranscribeTarget>,
+    progress: &SessionProgress,
+    emit: &mut dyn FnMut(Value),
+) -> WorkerStream {
+    let mut stream = WorkerStream::default();
+    let mut retry_counts: HashMap<String, u32> = HashMap::new();
+    let file_ids = manifest_file_ids(manifest_path);
+    let mut first_event = true;
+    for line in lines {
+        match parse_worker_line(&line) {
+            Ok(Some(mut value)) => {
+                if std::mem::take(&mut first_event) {
+                    match check_protocol_event(&value) {
+                        Some(Ok(version)) => stream.protocol_version = Some(version),
+                        Some(Err(error)) => {
+                            emit(scope_event(
+                                json!({
+                                    "event": "protocol_mismatch",
+                                    "error": error,
+                                    "workerVersion": value.get("version").cloned(),
+                                    "supportedVersion": WORKER_PROTOCOL_VERSION,
+                                }),
+                                retranscribe,
+                            ));
+                            stream.fatal_error = Some(error);
+                            stream.protocol_mismatch = true;
+                            // Nothing after this can be trusted; the caller kills the worker.
+                            return stream;
+                        }
+                        None => {}
+                    }
+                }
+                if let Some(summary) = parse_summary_event(&value) {
+                    stream.latest_summary = Some(summary);
+                }
+                if let Some(error) = parse_fatal_error(&value) {
+                    stream.fatal_error = Some(error);
+                }
+                if let Some(file_path) = parse_file_retry(&value) {
+                    *retry_counts.entry(file_path).or_default() += 1;
+                }
+                if let Some((file_path, mut outcome)) = parse_file_outcome(&value) {
+                    outcome.retries = outcome
+                        .retries
+                        .max(retry_counts.remove(&file_path).unwrap_or(0));
+                    if let Some(object) = value.as_object_mut() {
+                        object.insert("retries".to_string(), json!(outcome.retries));
+                    }
+                    progress.record(&file_path, &outcome);
+                    stream.file_outcomes.insert(file_path, outcome);
+                    stream.outcomes_recorded += 1;
+                    if retranscribe.is_none()
+                        && history_checkpoint_due(
+                            stream.outcomes_recorded,
+                            progress.stopping.load(Ordering::SeqCst),
+                        )
+                    {
+                        checkpoint_session_progress(
+                            manifest_path,
+                            session_id,
+                            &stream.file_outcomes,
+                        );
+                    }
+                }
+                if let Err(error) = append_partial_transcript(output_dir, &file_ids, &value)
+                    .and_then(|_| finalize_partial_transcript(output_dir, &file_ids, &value))
+                {
+                    eprintln!("[launcher] {}", error);
+                }
+                emit(scope_event(value, retranscribe));
+            }
+            Ok(None) => {}
+            Err(_) => emit(json!({
+                "event": "worker_stdout",
+                "line": line,
+            })),
+        }
+    }
+    stream
+}
+
+const REPLAY_FIXTURE_ENV: &str = "AURA_REPLAY_WORKER_STDOUT";
+
+// Debug builds can stand in a recorded stdout capture for the worker to exercise the stream loop.
+#[cfg(debug_assertions)]
+fn replay_fixture_command() -> Option<LaunchCommand> {
+    let fixture = std::env::var_os(REPLAY_FIXTURE_ENV).filter(|value| !value.is_empty())?;
+    Some(LaunchCommand {
+        program: "cat".to_string(),
+        args: vec![PathBuf::from(fixture).to_string_lossy().to_string()],
+    })
+}
+
+#[cfg(not(debug_assertions))]
+fn replay_fixture_command() -> Option<LaunchCommand> {
+    None
+}
+
+fn retranscribe_outcome(
+    file_outcomes: HashMap<String, FileOutcome>,
+    exit_code: i32,
+) -> FileOutcome {
+    file_outcomes
+        .into_values()
+        .next()
+        .unwrap_or_else(|| FileOutcome {
+            status: "failed".to_string(),
+            transcript_path: None,
+            json_path: None,
+            error: Some(format!(
+                "Worker exited with code {} before finishing the file",
+                exit_code
+            )),
+            audio_seconds: None,
+            processing_seconds: None,
+            retries: 0,
+        })
+}
+
+fn record_retranscribe_outcome(
+    target: &RetranscribeTarget,
+    manifest_path: &Path,
+    exit_code: i32,
+    file_outcomes: HashMap<String, FileOutcome>,
+) -> Result<SessionRecord, String> {
+    // The one-off manifest never becomes a session of its own.
+    let _ = cleanup_manifest(manifest_path);
+    update_session_file(
+        &target.session_id,
+        &target.file_id,
+        &retranscribe_outcome(file_outcomes, exit_code),
+    )
+}
+
+fn archive_cancelled_run(
+    manifest_path: &Path,
+    session_id: &str,
+    exit_code: i32,
+    reason: StopReason,
+    retranscribe: Option<&RetranscribeTarget>,
+    outcomes: &HashMap<String, FileOutcome>,
+) {
+    // A cancelled re-transcription leaves the original file record as it was.
+    if retranscribe.is_some() {
+        let _ = cleanup_manifest(manifest_path);
+        return;
+    }
+
+    if let Err(error) = archive_cancelled_session(
+        manifest_path,
+        session_id,
+        exit_code,
+        reason.as_str(),
+        outcomes,
+    ) {
+        eprintln!(
+            "[history] failed to archive cancelled session {}: {}",
+            session_id, error
+        );
+    }
+}
+
 fn format_duration(seconds: f64) -> String {
     if !seconds.is_finite() || seconds <= 0.0 {
         return "0s".to_string();
@@ -230,7 +1046,11 @@ fn format_duration(seconds: f64) -> String {
     }
 }
 
-fn show_completion_notification(summary: Option<SessionSummary>, output_dir: &Path) {
+fn show_completion_notification(
+    summary: Option<SessionSummary>,
+    output_dir: &Path,
+    sound: Option<&str>,
+) {
     let failed = summary.map(|entry| entry.failed).unwrap_or(0);
     let processed = summary.map(|entry| entry.processed).unwrap_or(0);
     let title = if failed > 0 {
@@ -260,15 +1080,15 @@ fn show_completion_notification(summary: Option<SessionSummary>, output_dir: &Pa
     };
 
     let body = format!("{} Output: {}", details, output_dir.display());
-    let _ = notifications::send(title, &body);
+    let _ = notifications::send_with_sound(title, &body, sound);
 }
 
-fn show_failure_notification(exit_code: i32, fatal_error: Option<&str>) {
+fn show_failure_notification(exit_code: i32, fatal_error: Option<&str>, sound: Option<&str>) {
     let detail = match fatal_error {
         Some(error) if !error.trim().is_empty() => error.to_string(),
         _ => format!("Worker exited with code {}.", exit_code),
     };
-    let _ = notifications::send("Transcription Failed", &detail);
+    let _ = notifications::send_with_sound("Transcription Failed", &detail, sound);
 }
 
 fn maybe_show_session_notification(
@@ -289,27 +1109,90 @@ fn maybe_show_session_notification(
     let completed = exit_code == 0 || exit_code == 2;
     if completed {
         if notification_preferences.notify_on_complete {
-            show_completion_notification(summary, output_dir);
+            show_completion_notification(
+                summary,
+                output_dir,
+                notification_preferences.completion_sound,
+            );
         }
         return;
     }
 
     if notification_preferences.notify_on_error {
-        show_failure_notification(exit_code, fatal_error);
+        show_failure_notification(
+            exit_code,
+            fatal_error,
+            notification_preferences.failure_sound,
+        );
     }
 }
 
 fn stream_stderr(app: AppHandle, stderr: impl std::io::Read) {
-    let reader = BufReader::new(stderr);
-    for line in reader.lines().map_while(Result::ok) {
-        let _ = app.emit(
-            SESSION_EVENT,
-            json!({
+    let mut reader = BufReader::new(stderr);
+    while let Ok(Some(line)) = read_capped_line(&mut reader, MAX_WORKER_LINE_BYTES) {
+        let payload = match line {
+            WorkerLine::Complete(line) => json!({
                 "event": "worker_stderr",
                 "line": line,
             }),
-        );
-    }
+            WorkerLine::Truncated { preview, bytes } => {
+                truncated_line_event("stderr", preview, bytes)
+            }
+        };
+        let _ = emit_session_event(&app, payload);
+    }
+}
+
+fn ends_warm_session(line: &str) -> bool {
+    matches!(
+        parse_worker_line(line)
+            .ok()
+            .flatten()
+            .as_ref()
+            .and_then(|value| value.get("event"))
+            .and_then(Value::as_str),
+        Some("summary" | "fatal_error")
+    )
+}
+
+// A warm worker keeps running after a session, so its output ends at the summary instead of EOF.
+fn warm_session_lines(worker: ServeWorker) -> impl Iterator<Item = String> {
+    let mut finished = false;
+    std::iter::from_fn(move || {
+        if finished {
+            return None;
+        }
+        let line = worker.next_line()?;
+        finished = ends_warm_session(&line);
+        Some(line)
+    })
+}
+
+fn finish_warm_session(
+    worker: &ServeWorker,
+    session_id: &str,
+    summary: Option<SessionSummary>,
+    fatal: bool,
+) -> i32 {
+    match summary {
+        Some(summary) if !fatal => {
+            release_warm_worker(session_id);
+            if summary.failed > 0 {
+                2
+            } else {
+                0
+            }
+        }
+        _ => {
+            let exit_code = if fatal {
+                1
+            } else {
+                wait_for_exit_code(&worker.child)
+            };
+            discard_warm_worker(session_id, worker);
+            exit_code
+        }
+    }
 }
 
 fn wait_for_exit_code(child: &Arc<Mutex<Child>>) -> i32 {
@@ -337,6 +1220,184 @@ fn clear_active_session_if_matches(session_id: &str) {
     }
 }
 
+struct ClearedSession {
+    session_id: String,
+    manifest_path: PathBuf,
+    queued_item_ids: Vec<String>,
+    retranscribe: Option<RetranscribeTarget>,
+    exit_code: i32,
+    outcomes: HashMap<String, FileOutcome>,
+}
+
+// Files the worker already reported keep their result in the queue; only the rest are requeued.
+fn unfinished_item_ids(
+    manifest_path: &Path,
+    queued_item_ids: &[String],
+    outcomes: &HashMap<String, FileOutcome>,
+) -> Vec<String> {
+    let Ok(manifest) = parse_manifest(manifest_path) else {
+        return queued_item_ids.to_vec();
+    };
+    let finished = manifest
+        .files
+        .iter()
+        .filter(|file| outcomes.contains_key(file.path.to_string_lossy().as_ref()))
+        .map(|file| file.id.as_str())
+        .collect::<HashSet<&str>>();
+    queued_item_ids
+        .iter()
+        .filter(|id| !finished.contains(id.as_str()))
+        .cloned()
+        .collect()
+}
+
+fn force_clear_active_process() -> Option<ClearedSession> {
+    // Recover from a poisoned lock too: this is the escape hatch when normal stop failed.
+    let active = ACTIVE_PROCESS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .take()?;
+
+    active.progress.stopping.store(true, Ordering::SeqCst);
+    cancel_chunked_run(&active.session_id);
+    if let Some(parent_session_id) = active.parent_session_id.as_deref() {
+        cancel_chunked_run(parent_session_id);
+    }
+
+    // The stream task may be parked in wait() holding the child lock, so fall back to the pid.
+    let exit_code = match active.child.try_lock() {
+        Ok(mut child) => {
+            let _ = child.kill();
+            child
+                .try_wait()
+                .ok()
+                .flatten()
+                .and_then(|status| status.code())
+                .unwrap_or(-1)
+        }
+        Err(_) => {
+            #[cfg(unix)]
+            let _ = Command::new("kill")
+                .arg("-KILL")
+                .arg(active.pid.to_string())
+                .status();
+            #[cfg(not(unix))]
+            let _ = active.pid;
+            -1
+        }
+    };
+
+    Some(ClearedSession {
+        session_id: active.session_id,
+        manifest_path: active.manifest_path,
+        queued_item_ids: active.queued_item_ids,
+        retranscribe: active.retranscribe,
+        exit_code,
+        outcomes: active.progress.snapshot(),
+    })
+}
+
+// Launches past the cap are refused outright; nothing queues behind a running session.
+fn check_parallel_capacity(
+    active_sessions: usize,
+    max_parallel_sessions: usize,
+) -> Result<(), String> {
+    if active_sessions >= max_parallel_sessions {
+        return Err(format!(
+            "Cannot start another transcription session: {} of {} allowed parallel sessions already running",
+            active_sessions, max_parallel_sessions
+        ));
+    }
+    Ok(())
+}
+
+// A chunked run counts once, whether its current chunk holds the worker or it sits between chunks.
+pub(crate) fn running_session_count() -> Result<usize, String> {
+    let active_parent = {
+        let active = ACTIVE_PROCESS
+            .lock()
+            .map_err(|_| "Failed to inspect active worker process".to_string())?;
+        active
+            .as_ref()
+            .map(|active| active.parent_session_id.clone())
+    };
+    let chunked_runs = CHUNKED_RUNS
+        .lock()
+        .map_err(|_| "Failed to inspect chunked sessions".to_string())?;
+    let idle_chunked_runs = chunked_runs
+        .keys()
+        .filter(|parent| active_parent.as_ref().and_then(Option::as_deref) != Some(parent.as_str()))
+        .count();
+    // An inline transcription runs the same models outside the session slot, so it counts too.
+    Ok(usize::from(active_parent.is_some())
+        + idle_chunked_runs
+        + usize::from(crate::commands::inline_audio::inline_transcription_running()))
+}
+
+/// Refuses a new session once the persisted parallel-session cap is reached.
+pub fn ensure_session_capacity() -> Result<(), String> {
+    check_parallel_capacity(
+        running_session_count()?,
+        load_settings().max_parallel_sessions,
+    )
+}
+
+pub fn active_session_id() -> Option<String> {
+    ACTIVE_PROCESS
+        .lock()
+        .ok()?
+        .as_ref()
+        .map(|active| active.session_id.clone())
+}
+
+pub fn active_session_output_dir() -> Option<PathBuf> {
+    ACTIVE_PROCESS
+        .lock()
+        .ok()?
+        .as_ref()
+        .map(|active| active.output_dir.clone())
+}
+
+fn chunk_launch_options(
+    options: &LaunchOptions,
+    parent_session_id: &str,
+    chunk_index: usize,
+    chunk_count: usize,
+) -> LaunchOptions {
+    let mut chunk_options = options.clone();
+    chunk_options.parent_session_id = Some(parent_session_id.to_string());
+    // Later chunks add their files to the index the first chunk started.
+    chunk_options.append_index = chunk_index > 0;
+    // One notification for the whole run rather than one per chunk.
+    if chunk_index + 1 < chunk_count {
+        chunk_options.notification_preferences.notifications_enabled = false;
+    }
+    chunk_options
+}
+
+fn register_chunked_run(parent_session_id: &str) -> Result<Arc<AtomicBool>, String> {
+    let cancel_flag = Arc::new(AtomicBool::new(false));
+    CHUNKED_RUNS
+        .lock()
+        .map_err(|_| "Failed to register chunked session".to_string())?
+        .insert(parent_session_id.to_string(), cancel_flag.clone());
+    Ok(cancel_flag)
+}
+
+fn unregister_chunked_run(parent_session_id: &str) {
+    if let Ok(mut runs) = CHUNKED_RUNS.lock() {
+        runs.remove(parent_session_id);
+    }
+}
+
+fn cancel_chunked_run(parent_session_id: &str) {
+    if let Ok(runs) = CHUNKED_RUNS.lock() {
+        if let Some(flag) = runs.get(parent_session_id) {
+            flag.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
 fn send_sigterm(child: &Arc<Mutex<Child>>) -> Result<(), String> {
     #[cfg(unix)]
     {
@@ -380,23 +1441,83 @@ fn force_kill(child: &Arc<Mutex<Child>>) -> Result<(), String> {
     }
 }
 
+// SIGTERM first, then SIGKILL once the grace period runs out; returns whether the worker exited on its own.
+async fn terminate_within(child: &Arc<Mutex<Child>>, grace: Duration) -> Result<bool, String> {
+    send_sigterm(child)?;
+
+    let deadline = Instant::now() + grace;
+    loop {
+        let finished = {
+            let mut guard = child
+                .lock()
+                .map_err(|_| "Failed to poll active worker process".to_string())?;
+            match guard.try_wait() {
+                Ok(Some(_status)) => true,
+                Ok(None) => false,
+                Err(error) => {
+                    return Err(format!(
+                        "Failed while waiting for worker shutdown: {}",
+                        error
+                    ));
+                }
+            }
+        };
+
+        if finished {
+            return Ok(true);
+        }
+
+        if Instant::now() >= deadline {
+            force_kill(child)?;
+            return Ok(false);
+        }
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+}
+
 impl WorkerLauncher {
     pub fn new(app_handle: AppHandle) -> Self {
         Self { app_handle }
     }
 
-    fn build_command(
-        &self,
-        provider: &ProviderRuntime,
-        manifest_path: &Path,
-        output_dir: &Path,
-    ) -> Result<Command, String> {
-        let launch = command_args_for_runtime(provider, manifest_path, output_dir)?;
+    fn build_command(&self, launch: &LaunchCommand) -> Command {
         let mut command = Command::new(&launch.program);
         command.args(&launch.args);
         command.stdout(Stdio::piped());
         command.stderr(Stdio::piped());
-        Ok(command)
+        command
+    }
+
+    fn checkout_warm_worker(
+        &self,
+        provider: &ProviderRuntime,
+        session_id: &str,
+        extra_args: &[String],
+    ) -> Option<ServeWorker> {
+        let command = match serve_command_for_runtime(
+            provider,
+            extra_args,
+            advertised_worker_protocol(provider),
+        ) {
+            Ok(Some(command)) => command,
+            Ok(None) => return None,
+            Err(error) => {
+                eprintln!("[launcher] {}", error);
+                return None;
+            }
+        };
+
+        let stderr_app = self.app_handle.clone();
+        match checkout_warm_worker(&command, session_id, move |stderr| {
+            stream_stderr(stderr_app, stderr)
+        }) {
+            Ok(worker) => worker,
+            Err(error) => {
+                eprintln!("[launcher] {}; spawning a dedicated worker", error);
+                None
+            }
+        }
     }
 
     pub async fn launch(
@@ -406,9 +1527,10 @@ impl WorkerLauncher {
         manifest_path: &Path,
         output_dir: &Path,
         queued_item_ids: Vec<String>,
-        notification_preferences: NotificationPreferences,
+        options: LaunchOptions,
     ) -> Result<WorkerProcess, String> {
         {
+            // The cap is checked where sessions start; this guards the single worker slot.
             let active = ACTIVE_PROCESS
                 .lock()
                 .map_err(|_| "Failed to inspect active worker process".to_string())?;
@@ -417,43 +1539,107 @@ impl WorkerLauncher {
             }
         }
 
-        self.app_handle
-            .emit(
-                SESSION_EVENT,
+        let replay_command = replay_fixture_command();
+        let launch_command = match replay_command.clone() {
+            Some(command) => command,
+            None => command_args_for_runtime(
+                provider,
+                manifest_path,
+                output_dir,
+                &options.extra_args,
+                advertised_worker_protocol(provider),
+            )?,
+        };
+
+        emit_session_event(
+            &self.app_handle,
+            scope_event(
                 json!({
                     "event": "worker_started",
                     "session_id": session_id,
+                    "parent_session_id": options.parent_session_id,
                     "manifest_path": manifest_path.to_string_lossy(),
                     "output_dir": output_dir.to_string_lossy(),
+                    "command": std::iter::once(&launch_command.program)
+                        .chain(&launch_command.args)
+                        .collect::<Vec<&String>>(),
                 }),
-            )
-            .map_err(|error| format!("Failed to emit worker_started: {}", error))?;
+                options.retranscribe.as_ref(),
+            ),
+        )
+        .map_err(|error| format!("Failed to emit worker_started: {}", error))?;
 
-        let mut child = self
-            .build_command(provider, manifest_path, output_dir)?
-            .spawn()
-            .map_err(|error| format!("Failed to launch worker: {}", error))?;
+        // No worker is running, so any partial transcript left here belongs to a crashed session.
+        for path in cleanup_orphaned_partial_transcripts(output_dir) {
+            eprintln!(
+                "[launcher] removed orphaned partial transcript {}",
+                path.display()
+            );
+        }
 
-        let stdout = child
-            .stdout
-            .take()
-            .ok_or_else(|| "Failed to capture worker stdout".to_string())?;
-        let stderr = child
-            .stderr
-            .take()
-            .ok_or_else(|| "Failed to capture worker stderr".to_string())?;
+        let warm_worker = if use_warm_worker(options.warm_pool, replay_command.is_some(), || {
+            current_warm_pool_advice(&warm_pool_model_version(provider))
+        }) {
+            self.checkout_warm_worker(provider, session_id, &options.extra_args)
+        } else {
+            None
+        };
+        let warm_worker = warm_worker.and_then(|worker| {
+            let request = json!({
+                "manifest": manifest_path.to_string_lossy(),
+                "output_dir": output_dir.to_string_lossy(),
+            });
+            match worker.submit(&request) {
+                Ok(()) => Some(worker),
+                Err(error) => {
+                    eprintln!("[launcher] {}; spawning a dedicated worker", error);
+                    discard_warm_worker(session_id, &worker);
+                    None
+                }
+            }
+        });
 
-        let child = Arc::new(Mutex::new(child));
+        let (child, pid, output) = match warm_worker {
+            Some(worker) => (worker.child.clone(), worker.pid, WorkerOutput::Warm(worker)),
+            None => {
+                let mut child = self
+                    .build_command(&launch_command)
+                    .spawn()
+                    .map_err(|error| format!("Failed to launch worker: {}", error))?;
+
+                let stdout = child
+                    .stdout
+                    .take()
+                    .ok_or_else(|| "Failed to capture worker stdout".to_string())?;
+                let stderr = child
+                    .stderr
+                    .take()
+                    .ok_or_else(|| "Failed to capture worker stderr".to_string())?;
+
+                let pid = child.id();
+                (
+                    Arc::new(Mutex::new(child)),
+                    pid,
+                    WorkerOutput::Spawned { stdout, stderr },
+                )
+            }
+        };
 
+        let progress = Arc::new(SessionProgress::default());
         {
             let mut active = ACTIVE_PROCESS
                 .lock()
                 .map_err(|_| "Failed to register active worker process".to_string())?;
             *active = Some(ActiveProcess {
                 session_id: session_id.to_string(),
+                parent_session_id: options.parent_session_id.clone(),
                 manifest_path: manifest_path.to_path_buf(),
+                output_dir: output_dir.to_path_buf(),
                 queued_item_ids,
+                retranscribe: options.retranscribe.clone(),
+                pid,
                 child: child.clone(),
+                progress: progress.clone(),
             });
         }
 
@@ -464,43 +1650,62 @@ impl WorkerLauncher {
         let child_for_stream = child.clone();
 
         let stream_task = tokio::task::spawn_blocking(move || {
-            let stderr_app = app_for_stream.clone();
-            let stderr_handle = std::thread::spawn(move || stream_stderr(stderr_app, stderr));
-
-            let reader = BufReader::new(stdout);
-            let mut latest_summary: Option<SessionSummary> = None;
-            let mut fatal_error: Option<String> = None;
-            let mut file_outcomes: HashMap<String, FileOutcome> = HashMap::new();
-            for line in reader.lines().map_while(Result::ok) {
-                match parse_worker_line(&line) {
-                    Ok(Some(value)) => {
-                        if let Some(summary) = parse_summary_event(&value) {
-                            latest_summary = Some(summary);
-                        }
-                        if let Some(error) = parse_fatal_error(&value) {
-                            fatal_error = Some(error);
-                        }
-                        if let Some((file_path, outcome)) = parse_file_outcome(&value) {
-                            file_outcomes.insert(file_path, outcome);
-                        }
-                        let _ = app_for_stream.emit(SESSION_EVENT, value);
-                    }
-                    Ok(None) => {}
-                    Err(_) => {
-                        let _ = app_for_stream.emit(
-                            SESSION_EVENT,
-                            json!({
-                                "event": "worker_stdout",
-                                "line": line,
-                            }),
-                        );
+            let (lines, stderr_handle, warm_worker): (Box<dyn Iterator<Item = String>>, _, _) =
+                match output {
+                    WorkerOutput::Spawned { stdout, stderr } => {
+                        let stderr_app = app_for_stream.clone();
+                        (
+                            Box::new(capped_lines(BufReader::new(stdout))),
+                            Some(std::thread::spawn(move || {
+                                stream_stderr(stderr_app, stderr)
+                            })),
+                            None,
+                        )
                     }
+                    WorkerOutput::Warm(worker) => (
+                        Box::new(warm_session_lines(worker.clone())),
+                        None,
+                        Some(worker),
+                    ),
+                };
+
+            let WorkerStream {
+                latest_summary,
+                fatal_error,
+                file_outcomes,
+                protocol_mismatch,
+                ..
+            } = consume_worker_stream(
+                lines,
+                &session_id_owned,
+                &manifest_path_owned,
+                &output_dir_owned,
+                options.retranscribe.as_ref(),
+                &progress,
+                &mut |value| {
+                    let _ = emit_session_event(&app_for_stream, value);
+                },
+            );
+
+            // A warm worker is discarded below; a spawned one would otherwise run to completion.
+            if protocol_mismatch && warm_worker.is_none() {
+                if let Err(error) = force_kill(&child_for_stream) {
+                    eprintln!("[launcher] {}", error);
                 }
             }
+            if let Some(handle) = stderr_handle {
+                let _ = handle.join();
+            }
 
-            let _ = stderr_handle.join();
-
-            let exit_code = wait_for_exit_code(&child_for_stream);
+            let exit_code = match warm_worker.as_ref() {
+                Some(worker) => finish_warm_session(
+                    worker,
+                    &session_id_owned,
+                    latest_summary,
+                    fatal_error.is_some(),
+                ),
+                None => wait_for_exit_code(&child_for_stream),
+            };
             let status = if exit_code == 0 || exit_code == 2 {
                 "completed"
             } else {
@@ -513,41 +1718,73 @@ impl WorkerLauncher {
                 failed: summary.failed,
                 duration_seconds: summary.duration_seconds,
             });
-            if let Err(error) = archive_session_from_manifest(
-                &manifest_path_owned,
-                &session_id_owned,
-                summary_snapshot,
-                exit_code,
-                status,
-                &file_outcomes,
-            ) {
-                eprintln!(
-                    "[history] failed to archive session {}: {}",
-                    session_id_owned, error
-                );
+            // A stop owns the archive; recording here too would overwrite it with "failed".
+            let archived = match options.retranscribe.as_ref() {
+                _ if progress.stopping.load(Ordering::SeqCst) => None,
+                Some(target) => Some(record_retranscribe_outcome(
+                    target,
+                    &manifest_path_owned,
+                    exit_code,
+                    file_outcomes,
+                )),
+                None => Some(archive_session_from_manifest(
+                    &manifest_path_owned,
+                    &session_id_owned,
+                    summary_snapshot,
+                    exit_code,
+                    status,
+                    &file_outcomes,
+                )),
+            };
+            match archived {
+                None => {}
+                Some(Ok(record)) => {
+                    if options.write_index {
+                        if let Err(error) =
+                            write_session_index(&output_dir_owned, &record, options.append_index)
+                        {
+                            eprintln!(
+                                "[history] failed to write index for session {}: {}",
+                                session_id_owned, error
+                            );
+                        }
+                    }
+                }
+                Some(Err(error)) => {
+                    eprintln!(
+                        "[history] failed to archive session {}: {}",
+                        session_id_owned, error
+                    );
+                }
             }
 
-            let _ = app_for_stream.emit(
-                SESSION_EVENT,
-                json!({
-                    "event": "worker_finished",
-                    "session_id": session_id_owned.clone(),
-                    "exit_code": exit_code,
-                    "success": exit_code == 0 || exit_code == 2,
-                }),
+            let _ = emit_session_event(
+                &app_for_stream,
+                scope_event(
+                    json!({
+                        "event": "worker_finished",
+                        "session_id": session_id_owned.clone(),
+                        "exit_code": exit_code,
+                        "success": exit_code == 0 || exit_code == 2,
+                    }),
+                    options.retranscribe.as_ref(),
+                ),
             );
-            let _ = app_for_stream.emit(
-                SESSION_EVENT,
-                json!({
-                    "event": "session_summary",
-                    "session_id": session_id_owned.clone(),
-                    "exit_code": exit_code,
-                    "status": status,
-                }),
+            let _ = emit_session_event(
+                &app_for_stream,
+                scope_event(
+                    json!({
+                        "event": "session_summary",
+                        "session_id": session_id_owned.clone(),
+                        "exit_code": exit_code,
+                        "status": status,
+                    }),
+                    options.retranscribe.as_ref(),
+                ),
             );
 
             maybe_show_session_notification(
-                notification_preferences,
+                options.notification_preferences,
                 exit_code,
                 latest_summary,
                 fatal_error.as_deref(),
@@ -561,7 +1798,17 @@ impl WorkerLauncher {
     }
 
     pub async fn stop(&self, session_id: &str) -> Result<(), String> {
-        let (child, manifest_path, queued_item_ids) = {
+        self.stop_with_reason(session_id, StopReason::User).await
+    }
+
+    pub async fn stop_with_reason(
+        &self,
+        session_id: &str,
+        requested_reason: StopReason,
+    ) -> Result<(), String> {
+        cancel_chunked_run(session_id);
+
+        let (child, manifest_path, queued_item_ids, active_session_id, retranscribe, progress) = {
             let active = ACTIVE_PROCESS
                 .lock()
                 .map_err(|_| "Failed to access active worker process".to_string())?;
@@ -570,7 +1817,9 @@ impl WorkerLauncher {
                 return Ok(());
             };
 
-            if active.session_id != session_id {
+            if active.session_id != session_id
+                && active.parent_session_id.as_deref() != Some(session_id)
+            {
                 return Err(format!(
                     "Session mismatch: active={}, requested={}",
                     active.session_id, session_id
@@ -581,87 +1830,277 @@ impl WorkerLauncher {
                 active.child.clone(),
                 active.manifest_path.clone(),
                 active.queued_item_ids.clone(),
+                active.session_id.clone(),
+                active.retranscribe.clone(),
+                active.progress.clone(),
             )
         };
 
-        send_sigterm(&child)?;
+        progress.stopping.store(true, Ordering::SeqCst);
+        let graceful = terminate_within(&child, requested_reason.grace_period()).await?;
 
-        let deadline = Instant::now() + STOP_TIMEOUT;
-        let mut graceful = false;
+        clear_active_session_if_matches(&active_session_id);
+        let exit_code = wait_for_exit_code(&child);
+        let reason = requested_reason.after_stop(graceful);
+        let outcomes = progress.snapshot();
+        let reset_item_ids = unfinished_item_ids(&manifest_path, &queued_item_ids, &outcomes);
+        archive_cancelled_run(
+            &manifest_path,
+            &active_session_id,
+            exit_code,
+            reason,
+            retranscribe.as_ref(),
+            &outcomes,
+        );
 
-        loop {
-            let finished = {
-                let mut guard = child
-                    .lock()
-                    .map_err(|_| "Failed to poll active worker process".to_string())?;
-                match guard.try_wait() {
-                    Ok(Some(_status)) => true,
-                    Ok(None) => false,
-                    Err(error) => {
-                        return Err(format!(
-                            "Failed while waiting for worker shutdown: {}",
-                            error
-                        ));
-                    }
-                }
-            };
+        emit_session_event(
+            &self.app_handle,
+            scope_event(
+                json!({
+                    "event": "worker_stopped",
+                    "session_id": session_id,
+                    "reason": reason.as_str(),
+                    "graceful": graceful,
+                    "reset_item_ids": reset_item_ids,
+                }),
+                retranscribe.as_ref(),
+            ),
+        )
+        .map_err(|error| format!("Failed to emit worker_stopped: {}", error))?;
 
-            if finished {
-                graceful = true;
-                break;
-            }
+        emit_session_event(
+            &self.app_handle,
+            scope_event(
+                json!({
+                    "event": "session_summary",
+                    "session_id": session_id,
+                    "exit_code": exit_code,
+                    "status": "cancelled",
+                    "reset_item_ids": reset_item_ids,
+                }),
+                retranscribe.as_ref(),
+            ),
+        )
+        .map_err(|error| format!("Failed to emit cancellation summary: {}", error))?;
 
-            if Instant::now() >= deadline {
-                break;
-            }
+        Ok(())
+    }
 
-            tokio::time::sleep(Duration::from_millis(100)).await;
+    /// Stops the running session for app quit, archiving it as cancelled with reason `shutdown`.
+    pub async fn stop_for_shutdown(&self) -> Option<String> {
+        let session_id = active_session_id()?;
+        let stopped = tokio::time::timeout(
+            SHUTDOWN_DEADLINE,
+            self.stop_with_reason(&session_id, StopReason::Shutdown),
+        )
+        .await;
+        match stopped {
+            Ok(Ok(())) => Some(session_id),
+            outcome => {
+                if let Ok(Err(error)) = outcome {
+                    eprintln!(
+                        "[launcher] shutdown stop of {} failed: {}",
+                        session_id, error
+                    );
+                } else {
+                    eprintln!("[launcher] shutdown stop of {} timed out", session_id);
+                }
+                self.force_clear_with_reason(StopReason::Shutdown)
+                    .unwrap_or_else(|error| {
+                        eprintln!("[launcher] {}", error);
+                        Some(session_id)
+                    })
+            }
         }
+    }
 
-        if !graceful {
-            force_kill(&child)?;
-        }
+    /// Kills any registered worker and frees the active slot without waiting for it to exit.
+    pub fn force_clear_active_session(&self) -> Result<Option<String>, String> {
+        self.force_clear_with_reason(StopReason::Forced)
+    }
 
-        clear_active_session_if_matches(session_id);
-        let exit_code = wait_for_exit_code(&child);
-        if let Err(error) = archive_session_from_manifest(
-            &manifest_path,
-            session_id,
-            None,
-            exit_code,
-            "cancelled",
-            &HashMap::new(),
-        ) {
-            eprintln!(
-                "[history] failed to archive cancelled session {}: {}",
-                session_id, error
-            );
-        }
+    fn force_clear_with_reason(&self, reason: StopReason) -> Result<Option<String>, String> {
+        let Some(cleared) = force_clear_active_process() else {
+            return Ok(None);
+        };
+        let reset_item_ids = unfinished_item_ids(
+            &cleared.manifest_path,
+            &cleared.queued_item_ids,
+            &cleared.outcomes,
+        );
+
+        archive_cancelled_run(
+            &cleared.manifest_path,
+            &cleared.session_id,
+            cleared.exit_code,
+            reason,
+            cleared.retranscribe.as_ref(),
+            &cleared.outcomes,
+        );
 
-        self.app_handle
-            .emit(
-                SESSION_EVENT,
+        emit_session_event(
+            &self.app_handle,
+            scope_event(
                 json!({
                     "event": "worker_stopped",
-                    "session_id": session_id,
-                    "reason": if graceful { "graceful" } else { "forced" },
-                    "reset_item_ids": queued_item_ids,
+                    "session_id": cleared.session_id,
+                    "reason": reason.as_str(),
+                    "reset_item_ids": reset_item_ids,
                 }),
-            )
-            .map_err(|error| format!("Failed to emit worker_stopped: {}", error))?;
+                cleared.retranscribe.as_ref(),
+            ),
+        )
+        .map_err(|error| format!("Failed to emit worker_stopped: {}", error))?;
 
-        self.app_handle
-            .emit(
-                SESSION_EVENT,
+        emit_session_event(
+            &self.app_handle,
+            scope_event(
                 json!({
                     "event": "session_summary",
-                    "session_id": session_id,
-                    "exit_code": exit_code,
+                    "session_id": cleared.session_id,
+                    "exit_code": cleared.exit_code,
                     "status": "cancelled",
-                    "reset_item_ids": queued_item_ids,
+                    "reset_item_ids": reset_item_ids,
                 }),
-            )
-            .map_err(|error| format!("Failed to emit cancellation summary: {}", error))?;
+                cleared.retranscribe.as_ref(),
+            ),
+        )
+        .map_err(|error| format!("Failed to emit cancellation summary: {}", error))?;
+
+        Ok(Some(cleared.session_id))
+    }
+
+    async fn launch_chunk(
+        &self,
+        runtime: &ProviderRuntime,
+        run: &ChunkedRun,
+        chunk_index: usize,
+    ) -> Result<WorkerProcess, String> {
+        let chunk = &run.chunks[chunk_index];
+        let (session_id, manifest_path) = generate_manifest_with_parent(
+            &run.provider,
+            &run.model,
+            &run.output_dir,
+            chunk,
+            &run.settings,
+            Some(&run.parent_session_id),
+        )?;
+
+        let completed_items = run.chunks[..chunk_index]
+            .iter()
+            .map(Vec::len)
+            .sum::<usize>();
+        let total_items = run.chunks.iter().map(Vec::len).sum::<usize>();
+        let _ = emit_session_event(
+            &self.app_handle,
+            json!({
+                "event": "chunk_started",
+                "session_id": session_id,
+                "parent_session_id": run.parent_session_id,
+                "chunk_index": chunk_index,
+                "chunk_count": run.chunks.len(),
+                "completed_items": completed_items,
+                "total_items": total_items,
+            }),
+        );
+
+        let options = chunk_launch_options(
+            &run.options,
+            &run.parent_session_id,
+            chunk_index,
+            run.chunks.len(),
+        );
+        let queued_item_ids = chunk.iter().map(|item| item.id.clone()).collect();
+        self.launch(
+            runtime,
+            &session_id,
+            &manifest_path,
+            &run.output_dir,
+            queued_item_ids,
+            options,
+        )
+        .await
+        .inspect_err(|_| {
+            let _ = cleanup_manifest(&manifest_path);
+        })
+    }
+
+    /// Runs each chunk as its own archived sub-session, one after another.
+    /// The first chunk is launched before returning so startup errors reach the caller.
+    pub async fn launch_chunked(
+        &self,
+        runtime: &ProviderRuntime,
+        run: ChunkedRun,
+    ) -> Result<(), String> {
+        let cancel_flag = register_chunked_run(&run.parent_session_id)?;
+        let first = match self.launch_chunk(runtime, &run, 0).await {
+            Ok(process) => process,
+            Err(error) => {
+                unregister_chunked_run(&run.parent_session_id);
+                return Err(error);
+            }
+        };
+
+        let launcher = WorkerLauncher::new(self.app_handle.clone());
+        let runtime = runtime.clone();
+        tokio::spawn(async move {
+            let mut current = Some(first);
+            let mut chunk_index = 0;
+            let mut launch_error = None;
+            while let Some(process) = current.take() {
+                let _ = process.stream_task.await;
+                let _ = emit_session_event(
+                    &launcher.app_handle,
+                    json!({
+                        "event": "chunk_finished",
+                        "parent_session_id": run.parent_session_id,
+                        "chunk_index": chunk_index,
+                        "chunk_count": run.chunks.len(),
+                    }),
+                );
+
+                chunk_index += 1;
+                if chunk_index >= run.chunks.len() || cancel_flag.load(Ordering::SeqCst) {
+                    break;
+                }
+
+                match launcher.launch_chunk(&runtime, &run, chunk_index).await {
+                    Ok(process) => current = Some(process),
+                    Err(error) => {
+                        eprintln!(
+                            "[launcher] failed to launch chunk {} of session {}: {}",
+                            chunk_index + 1,
+                            run.parent_session_id,
+                            error
+                        );
+                        let _ = emit_session_event(
+                            &launcher.app_handle,
+                            json!({
+                                "event": "chunk_launch_failed",
+                                "parent_session_id": run.parent_session_id,
+                                "chunk_index": chunk_index,
+                                "chunk_count": run.chunks.len(),
+                                "error": error,
+                            }),
+                        );
+                        launch_error = Some(error);
+                    }
+                }
+            }
+
+            unregister_chunked_run(&run.parent_session_id);
+            let _ = emit_session_event(
+                &launcher.app_handle,
+                json!({
+                    "event": "chunked_session_finished",
+                    "session_id": run.parent_session_id,
+                    "chunks_run": chunk_index,
+                    "chunk_count": run.chunks.len(),
+                    "cancelled": cancel_flag.load(Ordering::SeqCst),
+                    "error": launch_error,
+                }),
+            );
+        });
 
         Ok(())
     }
@@ -670,6 +2109,7 @@ impl WorkerLauncher {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::providers::manifest::{FileEntry, SessionManifest};
     use serde_json::json;
     use std::path::PathBuf;
     use std::time::{Duration, Instant};
@@ -754,6 +2194,8 @@ mod tests {
             &runtime,
             Path::new("/tmp/sessions/session-a.json"),
             Path::new("/tmp/out"),
+            &[],
+            None,
         )
         .expect("swift runtime should produce args");
 
@@ -766,6 +2208,191 @@ mod tests {
         assert!(launch.args.contains(&"/tmp/out".to_string()));
         assert!(launch.args.contains(&"--model-version".to_string()));
         assert!(launch.args.contains(&"v2".to_string()));
+        // Without an advertised protocol the worker gets exactly the pre-handshake flags.
+        assert!(!launch.args.contains(&PROTOCOL_VERSION_FLAG.to_string()));
+    }
+
+    #[test]
+    fn model_version_normalization_agrees_across_call_sites() {
+        use crate::providers::registry::COREML_PROVIDER_ID;
+        use crate::providers::resolver::{resolve_provider, ProviderSettings};
+
+        let settings = ProviderSettings {
+            swift_binary_override: Some(PathBuf::from("/tmp/coreml-batch")),
+            models_root_override: Some(PathBuf::from("/tmp/models")),
+            check_availability: false,
+        };
+        for (input, expected) in [
+            ("V3", "v3"),
+            (" v2 ", "v2"),
+            ("Parakeet-TDT-0.6b-V3-CoreML", "v3"),
+            ("PARAKEET-TDT-0.6B-V2-COREML", "v2"),
+        ] {
+            assert_eq!(normalize_model_version(input), Some(expected), "{input}");
+            let managed = crate::model_by_version(input).expect("managed model should match");
+            assert_eq!(managed.model_version, expected, "{input}");
+            let Ok(ProviderRuntime::SwiftNative { model_dir, .. }) =
+                resolve_provider(COREML_PROVIDER_ID, input, &settings)
+            else {
+                panic!("{input} should resolve to the CoreML runtime");
+            };
+            assert_eq!(model_dir.file_name(), Some(managed.folder_name.as_ref()));
+            assert_eq!(infer_model_version_from_model_dir(&model_dir), expected);
+        }
+
+        assert_eq!(normalize_model_version("v4"), None);
+        assert!(crate::model_by_version("v4").is_err());
+    }
+
+    #[test]
+    fn tight_memory_falls_back_to_a_cold_launch() {
+        use crate::commands::resources::assess_warm_pool;
+
+        let resident = 1024 * 1024 * 1024;
+        let tight = || assess_warm_pool(Ok(resident), resident);
+        let roomy = || assess_warm_pool(Ok(64 * resident), resident);
+        assert!(!use_warm_worker(true, false, tight));
+        assert!(use_warm_worker(true, false, roomy));
+        // Unmeasurable memory does not block the pool.
+        assert!(use_warm_worker(true, false, || assess_warm_pool(
+            Err("vm_stat missing".to_string()),
+            resident
+        )));
+
+        let unused =
+            || -> WarmPoolAdvice { panic!("advice is only read when a warm worker is wanted") };
+        assert!(!use_warm_worker(false, false, unused));
+        assert!(!use_warm_worker(true, true, unused));
+    }
+
+    #[test]
+    fn installed_model_files_override_the_model_folder_name() {
+        use crate::providers::resolver::MODEL_REVISION_MARKER;
+
+        let model_dir = std::env::temp_dir()
+            .join(format!("launcher-model-{}", uuid::Uuid::new_v4()))
+            .join("my-finetune-v2-export");
+        std::fs::create_dir_all(&model_dir).expect("model dir should be created");
+        assert_eq!(infer_model_version_from_model_dir(&model_dir), "v2");
+
+        // An unmarked folder is identified by the size of its vocabulary.
+        let vocab = (0..8192)
+            .map(|id| (id.to_string(), json!(format!("tok{}", id))))
+            .collect::<serde_json::Map<String, serde_json::Value>>();
+        std::fs::write(
+            model_dir.join("parakeet_vocab.json"),
+            serde_json::to_vec(&vocab).expect("vocab should serialize"),
+        )
+        .expect("vocab should be written");
+        assert_eq!(infer_model_version_from_model_dir(&model_dir), "v3");
+
+        // A pre-version marker only holds the revision, so the vocabulary still decides.
+        std::fs::write(model_dir.join(MODEL_REVISION_MARKER), "2\n")
+            .expect("marker should be written");
+        assert_eq!(infer_model_version_from_model_dir(&model_dir), "v3");
+
+        std::fs::write(
+            model_dir.join(MODEL_REVISION_MARKER),
+            "2\nparakeet-tdt-0.6b-v2-coreml\n",
+        )
+        .expect("marker should be written");
+        assert_eq!(infer_model_version_from_model_dir(&model_dir), "v2");
+
+        // An unreadable version line is ignored rather than trusted.
+        std::fs::write(model_dir.join(MODEL_REVISION_MARKER), "2\nlatest\n")
+            .expect("marker should be written");
+        assert_eq!(infer_model_version_from_model_dir(&model_dir), "v3");
+
+        let _ = std::fs::remove_dir_all(model_dir.parent().expect("temp root"));
+    }
+
+    #[test]
+    fn serve_command_keeps_model_flags_and_leaves_manifest_to_stdin() {
+        let swift = ProviderRuntime::SwiftNative {
+            binary_path: PathBuf::from("/tmp/coreml-batch"),
+            model_dir: PathBuf::from("/tmp/models/v3"),
+        };
+        let launch = serve_command_for_runtime(&swift, &["--beam-size=5".to_string()], Some(1))
+            .expect("serve command should build")
+            .expect("swift runtime should support a warm worker");
+        assert_eq!(launch.program, "/tmp/coreml-batch");
+        assert_eq!(
+            launch.args,
+            vec![
+                "--model-dir",
+                "/tmp/models/v3",
+                "--model-version",
+                "v3",
+                "--protocol-version",
+                "1",
+                "--serve",
+                "--beam-size=5",
+            ]
+        );
+
+        let python = ProviderRuntime::PythonUv {
+            package: "whisper-batch".to_string(),
+            entry_point: "whisper_batch".to_string(),
+        };
+        assert_eq!(serve_command_for_runtime(&python, &[], None), Ok(None));
+        assert!(validate_extra_args(&["--serve".to_string()]).is_err());
+    }
+
+    #[test]
+    fn appends_extra_args_after_managed_flags() {
+        let runtime = ProviderRuntime::SwiftNative {
+            binary_path: PathBuf::from("/tmp/coreml-batch"),
+            model_dir: PathBuf::from("/tmp/models/v3"),
+        };
+        let extra_args = vec![
+            "--beam-size".to_string(),
+            "5".to_string(),
+            "--temperature=0.2".to_string(),
+        ];
+
+        let launch = command_args_for_runtime(
+            &runtime,
+            Path::new("/tmp/sessions/session-a.json"),
+            Path::new("/tmp/out"),
+            &extra_args,
+            Some(WORKER_PROTOCOL_VERSION),
+        )
+        .expect("extra args should be accepted");
+        assert_eq!(launch.args[launch.args.len() - 3..], extra_args[..]);
+        let output_dir_flag = launch
+            .args
+            .iter()
+            .position(|arg| arg == "--output-dir")
+            .expect("managed flags should be present");
+        assert_eq!(output_dir_flag, launch.args.len() - 7);
+        assert_eq!(
+            launch.args[launch.args.len() - 5..launch.args.len() - 3],
+            ["--protocol-version", "1"]
+        );
+    }
+
+    #[test]
+    fn rejects_extra_args_that_override_reserved_flags() {
+        for arg in ["--manifest", "--output-dir=/elsewhere", "--model-version"] {
+            let error = validate_extra_args(&["--beam-size".to_string(), arg.to_string()])
+                .expect_err("reserved flags should be rejected");
+            assert!(error.contains(arg), "{error}");
+        }
+        validate_extra_args(&["--manifest-cache".to_string(), "5".to_string()])
+            .expect("unrelated flags sharing a prefix are allowed");
+
+        let runtime = ProviderRuntime::SwiftNative {
+            binary_path: PathBuf::from("/tmp/coreml-batch"),
+            model_dir: PathBuf::from("/tmp/models/v3"),
+        };
+        assert!(command_args_for_runtime(
+            &runtime,
+            Path::new("/tmp/sessions/session-a.json"),
+            Path::new("/tmp/out"),
+            &["--output-dir".to_string(), "/elsewhere".to_string()],
+            None,
+        )
+        .is_err());
     }
 
     #[test]
@@ -782,6 +2409,54 @@ mod tests {
         assert!(error.is_syntax() || error.is_data() || error.is_eof());
     }
 
+    #[test]
+    fn oversized_worker_lines_are_truncated_into_an_event() {
+        let oversized = format!(
+            "{{\"event\":\"file_done\",\"audio\":\"{}\"}}",
+            "A".repeat(300)
+        );
+        let input = format!(
+            "{{\"event\":\"start\"}}\r\n{}\n{{\"event\":\"summary\"}}",
+            oversized
+        );
+        let mut reader = std::io::Cursor::new(input.into_bytes());
+
+        assert_eq!(
+            read_capped_line(&mut reader, 64).expect("line should read"),
+            Some(WorkerLine::Complete("{\"event\":\"start\"}".to_string()))
+        );
+        match read_capped_line(&mut reader, 64).expect("line should read") {
+            Some(WorkerLine::Truncated { preview, bytes }) => {
+                assert_eq!(bytes, oversized.len());
+                assert_eq!(preview, oversized[..64]);
+            }
+            other => panic!("expected a truncated line, got {:?}", other),
+        }
+        // The remainder of the oversized line is skipped, not returned as a line of its own.
+        assert_eq!(
+            read_capped_line(&mut reader, 64).expect("line should read"),
+            Some(WorkerLine::Complete("{\"event\":\"summary\"}".to_string()))
+        );
+        assert_eq!(
+            read_capped_line(&mut reader, 64).expect("eof should read"),
+            None
+        );
+
+        let huge = format!("{}\nnext\n", "x".repeat(MAX_WORKER_LINE_BYTES + 1));
+        let lines = capped_lines(std::io::Cursor::new(huge.into_bytes())).collect::<Vec<String>>();
+        assert_eq!(lines.len(), 2);
+        let event = parse_worker_line(&lines[0])
+            .expect("truncation event should be valid json")
+            .expect("truncation event should not be blank");
+        assert_eq!(event["event"], "worker_line_truncated");
+        assert_eq!(event["bytes"], MAX_WORKER_LINE_BYTES + 1);
+        assert_eq!(
+            event["preview"].as_str().map(str::len),
+            Some(TRUNCATED_LINE_PREVIEW_BYTES)
+        );
+        assert_eq!(lines[1], "next");
+    }
+
     #[test]
     fn ignores_blank_worker_lines() {
         let parsed = parse_worker_line("   ").expect("blank lines should not fail");
@@ -816,6 +2491,236 @@ mod tests {
         );
     }
 
+    #[test]
+    fn chunk_options_carry_parent_and_notify_only_on_last_chunk() {
+        let options = LaunchOptions {
+            write_index: true,
+            ..LaunchOptions::default()
+        };
+
+        let first = chunk_launch_options(&options, "parent-1", 0, 3);
+        assert_eq!(first.parent_session_id.as_deref(), Some("parent-1"));
+        assert!(first.write_index);
+        assert!(!first.append_index);
+        assert!(!first.notification_preferences.notifications_enabled);
+
+        let last = chunk_launch_options(&options, "parent-1", 2, 3);
+        assert!(last.append_index);
+        assert!(last.notification_preferences.notifications_enabled);
+    }
+
+    #[test]
+    fn cancelling_a_chunked_run_sets_its_flag() {
+        let flag = register_chunked_run("parent-cancel").expect("run should register");
+        cancel_chunked_run("parent-other");
+        assert!(!flag.load(Ordering::SeqCst));
+
+        cancel_chunked_run("parent-cancel");
+        assert!(flag.load(Ordering::SeqCst));
+        assert!(running_session_count().expect("count should be readable") >= 1);
+        assert!(check_parallel_capacity(
+            running_session_count().expect("count should be readable"),
+            1
+        )
+        .is_err());
+        unregister_chunked_run("parent-cancel");
+    }
+
+    #[test]
+    fn event_log_appends_session_events_as_json_lines_in_order() {
+        let root =
+            std::env::temp_dir().join(format!("launcher-event-log-{}", uuid::Uuid::new_v4()));
+        let path = root.join("nested").join("events.jsonl");
+        let events = [
+            json!({"event": "worker_started", "session_id": "session-a"}),
+            json!({"event": "file_done", "file": "/audio/a.wav", "output": {"txt": "/out/a.txt"}}),
+            json!({"event": "session_summary", "session_id": "session-a", "status": "completed"}),
+        ];
+
+        set_event_log(Some(path.to_str().expect("temp path should be utf-8")))
+            .expect("event log should open and create its parent directory");
+        for event in &events {
+            record_session_event(event);
+        }
+        set_event_log(None).expect("event log should close");
+        record_session_event(&json!({"event": "after_close"}));
+
+        let contents = std::fs::read_to_string(&path).expect("event log should be readable");
+        let logged = contents
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).expect("each line should be JSON"))
+            .collect::<Vec<Value>>();
+        assert_eq!(logged, events.to_vec());
+
+        assert!(set_event_log(Some(root.to_str().expect("temp path should be utf-8"))).is_err());
+        set_event_log(Some("   ")).expect("blank paths disable logging");
+    }
+
+    #[test]
+    fn partial_transcripts_append_then_rename_on_file_done() {
+        let output_dir =
+            std::env::temp_dir().join(format!("launcher-partials-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&output_dir).expect("temp output dir should be created");
+        let file_ids = HashMap::from([
+            ("/audio/long.wav".to_string(), "file-long".to_string()),
+            ("/audio/short.wav".to_string(), "file-short".to_string()),
+        ]);
+        let partial_path = partial_transcript_dir(&output_dir).join("file-long.partial.txt");
+
+        for text in ["Hello ", "there, ", "world."] {
+            append_partial_transcript(
+                &output_dir,
+                &file_ids,
+                &json!({"event": "file_partial", "file": "/audio/long.wav", "text": text}),
+            )
+            .expect("partial text should append");
+        }
+        assert_eq!(
+            std::fs::read_to_string(&partial_path).expect("partial should exist"),
+            "Hello there, world."
+        );
+
+        finalize_partial_transcript(
+            &output_dir,
+            &file_ids,
+            &json!({"event": "file_done", "file": "/audio/long.wav"}),
+        )
+        .expect("partial should be finalized");
+        assert!(!partial_path.exists());
+        assert_eq!(
+            std::fs::read_to_string(output_dir.join("long.txt")).expect("final should exist"),
+            "Hello there, world."
+        );
+
+        let worker_output = output_dir.join("short.txt");
+        std::fs::write(&worker_output, "worker transcript").expect("worker output should write");
+        append_partial_transcript(
+            &output_dir,
+            &file_ids,
+            &json!({"event": "file_partial", "file": "/audio/short.wav", "text": "partial"}),
+        )
+        .expect("partial text should append");
+        finalize_partial_transcript(
+            &output_dir,
+            &file_ids,
+            &json!({"event": "file_done", "file": "/audio/short.wav", "output": {"txt": worker_output}}),
+        )
+        .expect("partial should be discarded");
+        assert!(!partial_transcript_dir(&output_dir)
+            .join("file-short.partial.txt")
+            .exists());
+        assert_eq!(
+            std::fs::read_to_string(&worker_output).expect("worker output should remain"),
+            "worker transcript"
+        );
+
+        let _ = std::fs::remove_dir_all(&output_dir);
+    }
+
+    #[test]
+    fn cleans_up_only_app_owned_partial_transcripts() {
+        let output_dir =
+            std::env::temp_dir().join(format!("launcher-orphans-{}", uuid::Uuid::new_v4()));
+        let partial_dir = partial_transcript_dir(&output_dir);
+        std::fs::create_dir_all(&partial_dir).expect("partial dir should be created");
+        std::fs::write(partial_dir.join("file-a.partial.txt"), "half").expect("write partial");
+        std::fs::write(output_dir.join("notes.partial.txt"), "user file").expect("write user file");
+
+        let removed = cleanup_orphaned_partial_transcripts(&output_dir);
+        assert_eq!(removed, vec![partial_dir.join("file-a.partial.txt")]);
+        assert!(!partial_dir.exists());
+        assert!(output_dir.join("notes.partial.txt").exists());
+        assert!(cleanup_orphaned_partial_transcripts(&output_dir.join("missing")).is_empty());
+
+        let _ = std::fs::remove_dir_all(&output_dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn force_clear_kills_registered_worker_and_empties_slot() {
+        assert!(
+            force_clear_active_process().is_none(),
+            "idle clear is a no-op"
+        );
+
+        let child = spawn_long_running_child();
+        let pid = child.lock().expect("child lock should succeed").id();
+        *ACTIVE_PROCESS.lock().expect("active lock should succeed") = Some(ActiveProcess {
+            session_id: "stuck-session".to_string(),
+            parent_session_id: None,
+            manifest_path: PathBuf::from("/tmp/stuck-manifest.json"),
+            output_dir: PathBuf::from("/tmp"),
+            queued_item_ids: vec!["item-1".to_string()],
+            retranscribe: None,
+            pid,
+            child: child.clone(),
+            progress: Arc::default(),
+        });
+
+        let cleared = force_clear_active_process().expect("registered session should be cleared");
+        assert_eq!(cleared.session_id, "stuck-session");
+        assert_eq!(cleared.queued_item_ids, vec!["item-1".to_string()]);
+        assert!(ACTIVE_PROCESS
+            .lock()
+            .expect("active lock should succeed")
+            .is_none());
+        assert!(wait_until_exited(&child, Duration::from_secs(2)));
+        assert!(force_clear_active_process().is_none());
+    }
+
+    #[test]
+    fn retranscribe_events_reference_the_original_session() {
+        let target = RetranscribeTarget {
+            session_id: "session-original".to_string(),
+            file_id: "file-b".to_string(),
+        };
+        let scoped = scope_event(
+            json!({"event": "file_done", "file": "/audio/b.wav"}),
+            Some(&target),
+        );
+        assert_eq!(scoped["original_session_id"], "session-original");
+        assert_eq!(scoped["original_file_id"], "file-b");
+        assert_eq!(scoped["event"], "file_done");
+
+        let unscoped = scope_event(json!({"event": "summary"}), None);
+        assert!(unscoped.get("original_session_id").is_none());
+
+        let missing = retranscribe_outcome(HashMap::new(), 1);
+        assert_eq!(missing.status, "failed");
+        assert_eq!(
+            missing.error.as_deref(),
+            Some("Worker exited with code 1 before finishing the file")
+        );
+    }
+
+    #[test]
+    fn stop_reasons_reflect_the_stop_path() {
+        assert_eq!(StopReason::User.after_stop(true).as_str(), "user");
+        assert_eq!(StopReason::User.after_stop(false).as_str(), "timeout");
+        assert_eq!(StopReason::Shutdown.after_stop(true).as_str(), "shutdown");
+        assert_eq!(StopReason::Shutdown.after_stop(false).as_str(), "shutdown");
+        assert_eq!(StopReason::Forced.after_stop(false).as_str(), "forced");
+    }
+
+    #[test]
+    fn history_checkpoints_every_interval_of_outcomes() {
+        assert!(!history_checkpoint_due(0, false));
+        assert!(!history_checkpoint_due(
+            HISTORY_CHECKPOINT_INTERVAL - 1,
+            false
+        ));
+        assert!(history_checkpoint_due(HISTORY_CHECKPOINT_INTERVAL, false));
+        assert!(!history_checkpoint_due(
+            HISTORY_CHECKPOINT_INTERVAL + 1,
+            false
+        ));
+        assert!(history_checkpoint_due(
+            HISTORY_CHECKPOINT_INTERVAL * 3,
+            false
+        ));
+        assert!(!history_checkpoint_due(HISTORY_CHECKPOINT_INTERVAL, true));
+    }
+
     #[test]
     fn formats_duration_for_notification_body() {
         assert_eq!(format_duration(0.2), "0s");
@@ -874,4 +2779,274 @@ mod tests {
 
         assert!(exited, "process should exit after force kill");
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn shutdown_stop_kills_a_worker_that_ignores_sigterm_within_its_grace() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .expect("runtime should build");
+        let stubborn = Command::new("sh")
+            .arg("-c")
+            .arg("trap '' TERM; exec sleep 30")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("should spawn stubborn process");
+        let stubborn = Arc::new(Mutex::new(stubborn));
+        // Give the shell time to install the trap before signalling it.
+        std::thread::sleep(Duration::from_millis(100));
+
+        let started = Instant::now();
+        let graceful = runtime
+            .block_on(terminate_within(&stubborn, Duration::from_millis(300)))
+            .expect("stop should succeed");
+        assert!(!graceful, "an ignored SIGTERM falls back to SIGKILL");
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert!(wait_until_exited(&stubborn, Duration::from_secs(2)));
+
+        let polite = spawn_long_running_child();
+        let graceful = runtime
+            .block_on(terminate_within(
+                &polite,
+                StopReason::Shutdown.grace_period(),
+            ))
+            .expect("stop should succeed");
+        assert!(graceful);
+        assert!(StopReason::Shutdown.grace_period() < STOP_TIMEOUT);
+        assert!(StopReason::Shutdown.grace_period() < SHUTDOWN_DEADLINE);
+    }
+
+    #[test]
+    fn rejects_launches_beyond_the_parallel_session_cap() {
+        assert!(check_parallel_capacity(0, 1).is_ok());
+        let error = check_parallel_capacity(1, 1).expect_err("second launch should be rejected");
+        assert!(error.contains("1 of 1"));
+
+        for active in 0..3 {
+            assert!(check_parallel_capacity(active, 3).is_ok());
+        }
+        assert!(check_parallel_capacity(3, 3).is_err());
+    }
+
+    #[test]
+    fn parses_protocol_handshake_versions() {
+        assert_eq!(check_protocol_event(&json!({"event": "start"})), None);
+        assert_eq!(
+            check_protocol_event(&json!({"event": "protocol", "version": 1, "requested": 1})),
+            Some(Ok(1))
+        );
+        assert!(check_protocol_event(
+            &json!({"event": "protocol", "version": WORKER_PROTOCOL_VERSION + 1})
+        )
+        .is_some_and(|result| result.is_err()));
+        assert!(
+            check_protocol_event(&json!({"event": "protocol", "version": "2"}))
+                .is_some_and(|result| result.is_err())
+        );
+    }
+
+    #[test]
+    fn newer_worker_protocol_stops_event_parsing() {
+        let root = std::env::temp_dir().join(format!("launcher-protocol-{}", uuid::Uuid::new_v4()));
+        let lines = [
+            r#"{"event":"protocol","version":99}"#,
+            r#"{"event":"file_done","file":"/audio/a.wav","transcript_path":"/out/a.txt"}"#,
+            r#"{"event":"summary","total":1,"processed":1}"#,
+        ]
+        .map(str::to_string);
+        let mut lines = lines.into_iter();
+
+        let mut emitted = Vec::new();
+        let stream = consume_worker_stream(
+            lines.by_ref(),
+            "session-protocol",
+            &root.join("session-protocol.json"),
+            &root,
+            None,
+            &SessionProgress::default(),
+            &mut |value| emitted.push(value),
+        );
+
+        assert_eq!(emitted.len(), 1);
+        assert_eq!(lines.len(), 2, "stdout is not drained after a mismatch");
+        assert_eq!(emitted[0]["event"], "protocol_mismatch");
+        assert_eq!(emitted[0]["workerVersion"], 99);
+        assert!(stream.protocol_mismatch);
+        assert!(stream
+            .fatal_error
+            .as_deref()
+            .is_some_and(|error| error.contains("version 99")));
+        assert!(stream.file_outcomes.is_empty());
+        assert!(stream.latest_summary.is_none());
+
+        // A protocol event after the first line is not a handshake and is forwarded as-is.
+        let late = [
+            r#"{"event":"start","total":0}"#,
+            r#"{"event":"protocol","version":99}"#,
+        ]
+        .map(str::to_string);
+        let stream = consume_worker_stream(
+            late.into_iter(),
+            "session-protocol",
+            &root.join("session-protocol.json"),
+            &root,
+            None,
+            &SessionProgress::default(),
+            &mut |_| {},
+        );
+        assert!(!stream.protocol_mismatch);
+        assert_eq!(stream.protocol_version, None);
+    }
+
+    #[test]
+    fn stream_shares_finished_outcomes_with_a_pending_stop() {
+        let root = std::env::temp_dir().join(format!("launcher-stop-{}", uuid::Uuid::new_v4()));
+        let lines = [
+            r#"{"event":"file_done","file":"/audio/a.wav","transcript_path":"/out/a.txt"}"#,
+            r#"{"event":"file_started","file":"/audio/b.wav"}"#,
+            r#"{"event":"file_done","file":"/audio/b.wav","transcript_path":"/out/b.txt"}"#,
+            r#"{"event":"file_started","file":"/audio/c.wav"}"#,
+        ]
+        .map(str::to_string);
+
+        let progress = SessionProgress::default();
+        consume_worker_stream(
+            lines.into_iter(),
+            "session-stop",
+            &root.join("session-stop.json"),
+            &root,
+            None,
+            &progress,
+            &mut |_| {},
+        );
+        progress.stopping.store(true, Ordering::SeqCst);
+
+        let outcomes = progress.snapshot();
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes.values().all(|outcome| outcome.status == "success"));
+        assert!(!outcomes.contains_key("/audio/c.wav"));
+    }
+
+    #[test]
+    fn replays_recorded_worker_stdout_through_the_stream_loop() {
+        let root = std::env::temp_dir().join(format!("launcher-replay-{}", uuid::Uuid::new_v4()));
+        let output_dir = root.join("out");
+        std::fs::create_dir_all(&output_dir).expect("temp output dir should be created");
+        let fixture = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("fixtures")
+            .join("worker-stdout")
+            .join("mixed-session.jsonl");
+        let lines = BufReader::new(File::open(&fixture).expect("fixture should open"))
+            .lines()
+            .map_while(Result::ok);
+
+        let mut emitted = Vec::new();
+        let stream = consume_worker_stream(
+            lines,
+            "session-replay",
+            &root.join("session-replay.json"),
+            &output_dir,
+            None,
+            &SessionProgress::default(),
+            &mut |value| emitted.push(value),
+        );
+
+        assert_eq!(
+            emitted
+                .iter()
+                .map(|value| value["event"].as_str().unwrap_or_default())
+                .collect::<Vec<&str>>(),
+            vec![
+                "start",
+                "models_loaded",
+                "file_started",
+                "file_progress",
+                "file_retry",
+                "file_done",
+                "worker_stdout",
+                "file_started",
+                "file_failed",
+                "file_skipped",
+                "summary",
+            ]
+        );
+        assert_eq!(
+            emitted[6]["line"],
+            "warning: CoreML compute units fell back to CPU"
+        );
+        assert_eq!(emitted[5]["retries"], 1);
+        assert_eq!(stream.outcomes_recorded, 3);
+        assert_eq!(stream.fatal_error, None);
+        assert_eq!(
+            stream.latest_summary.map(|summary| (
+                summary.processed,
+                summary.skipped,
+                summary.failed
+            )),
+            Some((1, 1, 1))
+        );
+
+        let manifest_path = root.join("session-replay.json");
+        let manifest = SessionManifest {
+            session_id: "session-replay".to_string(),
+            created_at: "2026-02-12T00:00:00.000Z".to_string(),
+            provider: "coreml-local".to_string(),
+            model: "v3".to_string(),
+            output_dir: output_dir.clone(),
+            settings: TranscriptionSettings::default(),
+            files: ["a", "b", "c"]
+                .iter()
+                .map(|name| FileEntry {
+                    id: format!("file-{}", name),
+                    path: PathBuf::from(format!("/audio/{}.wav", name)),
+                    status: "queued".to_string(),
+                })
+                .collect(),
+            parent_session_id: None,
+        };
+        std::fs::write(
+            &manifest_path,
+            serde_json::to_vec(&manifest).expect("manifest should serialize"),
+        )
+        .expect("manifest should be written");
+
+        let queued = ["file-a", "file-b", "file-c", "file-d"].map(str::to_string);
+        let mut partial = stream.file_outcomes.clone();
+        partial.remove("/audio/b.wav");
+        assert_eq!(
+            unfinished_item_ids(&manifest_path, &queued, &partial),
+            vec!["file-b".to_string(), "file-d".to_string()]
+        );
+
+        let record = crate::commands::history::archive_session_with_path(
+            Some(&root.join("history.db")),
+            &manifest_path,
+            "session-replay",
+            None,
+            0,
+            "completed",
+            &stream.file_outcomes,
+        )
+        .expect("replayed session should archive");
+        assert_eq!(
+            record
+                .files
+                .iter()
+                .map(|file| (file.status.as_str(), file.error.as_deref(), file.retries))
+                .collect::<Vec<_>>(),
+            vec![
+                ("success", None, 1),
+                ("failed", Some("decode failed"), 0),
+                ("skipped", Some("already transcribed"), 0),
+            ]
+        );
+        assert_eq!(
+            record.files[0].transcript_path.as_deref(),
+            Some("/tmp/replay-out/a.txt")
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
 }
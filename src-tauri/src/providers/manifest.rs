@@ -64,6 +64,18 @@ pub struct TranscriptionSettings {
     pub notify_on_complete: bool,
     #[serde(default = "default_notify_on_error")]
     pub notify_on_error: bool,
+    #[serde(default)]
+    pub write_index: bool,
+    #[serde(default)]
+    pub chunk_size: Option<usize>,
+    #[serde(default)]
+    pub skip_previously_transcribed: bool,
+    #[serde(default)]
+    pub event_log_path: Option<String>,
+    #[serde(default)]
+    pub extra_args: Vec<String>,
+    #[serde(default)]
+    pub warm_pool: bool,
 }
 
 impl Default for TranscriptionSettings {
@@ -79,6 +91,28 @@ impl Default for TranscriptionSettings {
             notifications_enabled: default_notifications_enabled(),
             notify_on_complete: default_notify_on_complete(),
             notify_on_error: default_notify_on_error(),
+            write_index: false,
+            chunk_size: None,
+            skip_previously_transcribed: false,
+            event_log_path: None,
+            extra_args: Vec::new(),
+            warm_pool: false,
+        }
+    }
+}
+
+impl TranscriptionSettings {
+    /// Settings for re-running one file with the formats and worker options of its original run.
+    pub fn for_retranscribe(&self, overwrite: bool) -> Self {
+        Self {
+            overwrite,
+            dry_run: false,
+            notifications_enabled: false,
+            write_index: false,
+            chunk_size: None,
+            skip_previously_transcribed: false,
+            event_log_path: None,
+            ..self.clone()
         }
     }
 }
@@ -101,6 +135,8 @@ pub struct SessionManifest {
     pub output_dir: PathBuf,
     pub settings: TranscriptionSettings,
     pub files: Vec<FileEntry>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_session_id: Option<String>,
 }
 
 pub fn get_sessions_dir() -> Result<PathBuf, String> {
@@ -108,7 +144,50 @@ pub fn get_sessions_dir() -> Result<PathBuf, String> {
     Ok(home.join(".aura").join("sessions"))
 }
 
-fn write_manifest_atomic(
+// Session ids are generated UUIDs; anything else could point outside the sessions dir.
+pub fn validate_session_id(session_id: &str) -> Result<(), String> {
+    let valid = !session_id.is_empty()
+        && session_id.len() <= 64
+        && session_id
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("Invalid session id: {}", session_id))
+    }
+}
+
+fn read_manifest_in(sessions_dir: &Path, session_id: &str) -> Result<SessionManifest, String> {
+    validate_session_id(session_id)?;
+    let path = sessions_dir.join(format!("{}.json", session_id));
+    let payload = match std::fs::read_to_string(&path) {
+        Ok(payload) => payload,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            return Err(format!("Session manifest not found: {}", session_id))
+        }
+        Err(error) => {
+            return Err(format!(
+                "Failed to read session manifest {}: {}",
+                path.display(),
+                error
+            ))
+        }
+    };
+    serde_json::from_str(&payload).map_err(|error| {
+        format!(
+            "Failed to parse session manifest {}: {}",
+            path.display(),
+            error
+        )
+    })
+}
+
+pub fn read_manifest(session_id: &str) -> Result<SessionManifest, String> {
+    read_manifest_in(&get_sessions_dir()?, session_id)
+}
+
+pub(crate) fn write_manifest_atomic(
     manifest: &SessionManifest,
     sessions_dir: &Path,
 ) -> Result<PathBuf, String> {
@@ -169,12 +248,47 @@ fn write_manifest_atomic(
     Ok(manifest_path)
 }
 
+pub fn partition_queue(items: &[QueueItem], chunk_size: Option<usize>) -> Vec<Vec<QueueItem>> {
+    match chunk_size {
+        Some(size) if size > 0 && items.len() > size => {
+            items.chunks(size).map(<[QueueItem]>::to_vec).collect()
+        }
+        _ => vec![items.to_vec()],
+    }
+}
+
+pub fn normalize_extensions(extensions: &[String]) -> Result<Vec<String>, String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for extension in extensions {
+        let extension = extension.trim().trim_start_matches('.').to_lowercase();
+        if !extension.is_empty() && !normalized.contains(&extension) {
+            normalized.push(extension);
+        }
+    }
+
+    if normalized.is_empty() {
+        return Err("No valid file extensions provided".to_string());
+    }
+    Ok(normalized)
+}
+
 pub fn generate_manifest(
     provider: &str,
     model: &str,
     output_dir: &Path,
     items: &[QueueItem],
     settings: &TranscriptionSettings,
+) -> Result<(String, PathBuf), String> {
+    generate_manifest_with_parent(provider, model, output_dir, items, settings, None)
+}
+
+pub fn generate_manifest_with_parent(
+    provider: &str,
+    model: &str,
+    output_dir: &Path,
+    items: &[QueueItem],
+    settings: &TranscriptionSettings,
+    parent_session_id: Option<&str>,
 ) -> Result<(String, PathBuf), String> {
     let session_id = Uuid::new_v4().to_string();
     let created_at = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
@@ -199,6 +313,7 @@ pub fn generate_manifest(
         output_dir: output_dir.to_path_buf(),
         settings: settings.clone(),
         files,
+        parent_session_id: parent_session_id.map(str::to_string),
     };
 
     let sessions_dir = get_sessions_dir()?;
@@ -223,6 +338,28 @@ pub fn cleanup_manifest(path: &Path) -> Result<(), String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn retranscribe_settings_keep_the_original_formats_and_worker_args() {
+        let original = TranscriptionSettings {
+            output_format: "srt".to_string(),
+            max_retries: 3,
+            extra_args: vec!["--language".to_string(), "de".to_string()],
+            chunk_size: Some(10),
+            skip_previously_transcribed: true,
+            write_index: true,
+            ..TranscriptionSettings::default()
+        };
+
+        let settings = original.for_retranscribe(true);
+        assert_eq!(settings.output_format, "srt");
+        assert_eq!(settings.max_retries, 3);
+        assert_eq!(settings.extra_args, original.extra_args);
+        assert!(settings.overwrite);
+        assert_eq!(settings.chunk_size, None);
+        assert!(!settings.skip_previously_transcribed);
+        assert!(!settings.write_index);
+    }
+
     fn test_sessions_dir() -> PathBuf {
         std::env::temp_dir().join(format!("coreml-manifest-tests-{}", Uuid::new_v4()))
     }
@@ -245,15 +382,32 @@ mod tests {
                 notifications_enabled: true,
                 notify_on_complete: true,
                 notify_on_error: true,
+                write_index: false,
+                chunk_size: None,
+                skip_previously_transcribed: false,
+                event_log_path: None,
+                extra_args: Vec::new(),
+                warm_pool: false,
             },
             files: vec![FileEntry {
                 id: "file-1".to_string(),
                 path: PathBuf::from("/tmp/audio/a.wav"),
                 status: "queued".to_string(),
             }],
+            parent_session_id: None,
         }
     }
 
+    fn queue_items(count: usize) -> Vec<QueueItem> {
+        (0..count)
+            .map(|index| QueueItem {
+                id: format!("item-{}", index),
+                path: PathBuf::from(format!("/tmp/audio/{}.wav", index)),
+                status: "queued".to_string(),
+            })
+            .collect()
+    }
+
     #[test]
     fn writes_valid_manifest_json() {
         let sessions_dir = test_sessions_dir();
@@ -274,6 +428,28 @@ mod tests {
         assert_eq!(decoded.files[0].id, "file-1");
     }
 
+    #[test]
+    fn reads_manifest_by_id_and_rejects_traversal() {
+        let sessions_dir = test_sessions_dir();
+        let session_id = Uuid::new_v4().to_string();
+        write_manifest_atomic(&fixture_manifest(&session_id), &sessions_dir)
+            .expect("manifest should be written successfully");
+        std::fs::write(sessions_dir.join("secret.json"), "{}").expect("decoy should be written");
+
+        let manifest =
+            read_manifest_in(&sessions_dir, &session_id).expect("manifest should be readable");
+        assert_eq!(manifest, fixture_manifest(&session_id));
+
+        for attempt in ["../secret", "..", "a/b", "", "sub\\secret", "/etc/passwd"] {
+            let error = read_manifest_in(&sessions_dir.join("nested"), attempt)
+                .expect_err("traversal should be rejected");
+            assert!(error.starts_with("Invalid session id"), "{}", error);
+        }
+        assert!(read_manifest_in(&sessions_dir, "missing-session")
+            .expect_err("missing manifest")
+            .contains("not found"));
+    }
+
     #[test]
     fn atomic_write_renames_tmp_to_final() {
         let sessions_dir = test_sessions_dir();
@@ -287,6 +463,45 @@ mod tests {
         assert!(!tmp.exists());
     }
 
+    #[test]
+    fn partitions_queue_into_ordered_chunks() {
+        let items = queue_items(5);
+        let chunks = partition_queue(&items, Some(2));
+        assert_eq!(
+            chunks.iter().map(Vec::len).collect::<Vec<usize>>(),
+            vec![2, 2, 1]
+        );
+        assert_eq!(chunks[1][0].id, "item-2");
+        assert_eq!(chunks.concat(), items);
+
+        assert_eq!(partition_queue(&items, None), vec![items.clone()]);
+        assert_eq!(partition_queue(&items, Some(0)), vec![items.clone()]);
+        assert_eq!(partition_queue(&items, Some(5)), vec![items.clone()]);
+    }
+
+    #[test]
+    fn normalizes_messy_extension_lists() {
+        let messy = [" WAV ", "", ".mp3", "wav"].map(str::to_string);
+        assert_eq!(
+            normalize_extensions(&messy).expect("extensions should normalize"),
+            vec!["wav".to_string(), "mp3".to_string()]
+        );
+
+        let empty = [" ", ".", ""].map(str::to_string);
+        assert!(normalize_extensions(&empty).is_err());
+    }
+
+    #[test]
+    fn parent_session_id_is_only_serialized_for_chunks() {
+        let mut manifest = fixture_manifest("session-chunk");
+        let payload = serde_json::to_value(&manifest).expect("manifest should serialize");
+        assert!(payload.get("parentSessionId").is_none());
+
+        manifest.parent_session_id = Some("parent-1".to_string());
+        let payload = serde_json::to_value(&manifest).expect("manifest should serialize");
+        assert_eq!(payload["parentSessionId"], "parent-1");
+    }
+
     #[test]
     fn cleanup_manifest_removes_existing_manifest() {
         let sessions_dir = test_sessions_dir();
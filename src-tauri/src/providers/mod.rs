@@ -1,4 +1,7 @@
 pub mod launcher;
 pub mod manifest;
+pub mod pool;
 pub mod registry;
 pub mod resolver;
+pub mod settings;
+pub mod watcher;
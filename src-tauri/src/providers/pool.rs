@@ -0,0 +1,379 @@
+use super::launcher::{capped_lines, LaunchCommand};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStderr, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+pub const SERVE_FLAG: &str = "--serve";
+pub const WARM_POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PoolState {
+    Empty,
+    Idle { since: Instant },
+    Busy { session_id: String },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Acquire<W> {
+    Reuse(W),
+    Spawn { evicted: Option<W> },
+    Busy,
+}
+
+struct PooledWorker<W> {
+    key: String,
+    state: PoolState,
+    worker: W,
+}
+
+// Holds at most one warm worker; `key` identifies the command it was started with.
+pub struct WarmPool<W> {
+    slot: Option<PooledWorker<W>>,
+    idle_timeout: Duration,
+}
+
+impl<W: Clone> WarmPool<W> {
+    pub fn new(idle_timeout: Duration) -> Self {
+        Self {
+            slot: None,
+            idle_timeout,
+        }
+    }
+
+    pub fn state(&self) -> PoolState {
+        self.slot
+            .as_ref()
+            .map(|slot| slot.state.clone())
+            .unwrap_or(PoolState::Empty)
+    }
+
+    pub fn acquire(&mut self, key: &str, session_id: &str) -> Acquire<W> {
+        match (self.state(), self.slot.as_mut()) {
+            (PoolState::Busy { .. }, _) => Acquire::Busy,
+            (PoolState::Idle { .. }, Some(slot)) if slot.key == key => {
+                slot.state = PoolState::Busy {
+                    session_id: session_id.to_string(),
+                };
+                Acquire::Reuse(slot.worker.clone())
+            }
+            _ => Acquire::Spawn {
+                evicted: self.evict(),
+            },
+        }
+    }
+
+    pub fn insert_busy(&mut self, key: &str, session_id: &str, worker: W) -> Option<W> {
+        let evicted = self.evict();
+        self.slot = Some(PooledWorker {
+            key: key.to_string(),
+            state: PoolState::Busy {
+                session_id: session_id.to_string(),
+            },
+            worker,
+        });
+        evicted
+    }
+
+    pub fn release(&mut self, session_id: &str, now: Instant) -> bool {
+        match self.slot.as_mut() {
+            Some(slot) if matches!(&slot.state, PoolState::Busy { session_id: busy } if busy == session_id) =>
+            {
+                slot.state = PoolState::Idle { since: now };
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn evict_session(&mut self, session_id: &str) -> Option<W> {
+        let owned = matches!(
+            self.slot.as_ref().map(|slot| &slot.state),
+            Some(PoolState::Busy { session_id: busy }) if busy == session_id
+        );
+        if owned {
+            self.evict()
+        } else {
+            None
+        }
+    }
+
+    pub fn evict_expired(&mut self, now: Instant) -> Option<W> {
+        let expired = matches!(
+            self.slot.as_ref().map(|slot| &slot.state),
+            Some(PoolState::Idle { since }) if now.duration_since(*since) >= self.idle_timeout
+        );
+        if expired {
+            self.evict()
+        } else {
+            None
+        }
+    }
+
+    pub fn evict(&mut self) -> Option<W> {
+        self.slot.take().map(|slot| slot.worker)
+    }
+}
+
+pub fn help_advertises_serve(help: &str) -> bool {
+    help.split_whitespace()
+        .any(|token| token.trim_matches(['[', ']', ',', ';']) == SERVE_FLAG)
+}
+
+#[derive(Clone)]
+pub struct ServeWorker {
+    pub child: Arc<Mutex<Child>>,
+    pub pid: u32,
+    stdin: Arc<Mutex<ChildStdin>>,
+    lines: Arc<Mutex<Receiver<String>>>,
+}
+
+impl ServeWorker {
+    pub fn spawn(mut command: Command) -> Result<(Self, ChildStderr), String> {
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|error| format!("Failed to launch warm worker: {}", error))?;
+        let stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| "Failed to capture warm worker stdin".to_string())?;
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| "Failed to capture warm worker stdout".to_string())?;
+        let stderr = child
+            .stderr
+            .take()
+            .ok_or_else(|| "Failed to capture warm worker stderr".to_string())?;
+
+        // The reader outlives individual sessions; the channel closes when the worker exits.
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            for line in capped_lines(BufReader::new(stdout)) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok((
+            Self {
+                pid: child.id(),
+                child: Arc::new(Mutex::new(child)),
+                stdin: Arc::new(Mutex::new(stdin)),
+                lines: Arc::new(Mutex::new(receiver)),
+            },
+            stderr,
+        ))
+    }
+
+    pub fn submit(&self, request: &serde_json::Value) -> Result<(), String> {
+        let mut line = serde_json::to_vec(request)
+            .map_err(|error| format!("Failed to serialize warm worker request: {}", error))?;
+        line.push(b'\n');
+        let mut stdin = self
+            .stdin
+            .lock()
+            .map_err(|_| "Failed to lock warm worker stdin".to_string())?;
+        stdin
+            .write_all(&line)
+            .and_then(|_| stdin.flush())
+            .map_err(|error| format!("Failed to send manifest to warm worker: {}", error))
+    }
+
+    pub fn next_line(&self) -> Option<String> {
+        self.lines.lock().ok()?.recv().ok()
+    }
+
+    pub fn shutdown(&self) {
+        if let Ok(mut child) = self.child.lock() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+static WARM_POOL: LazyLock<Mutex<WarmPool<ServeWorker>>> =
+    LazyLock::new(|| Mutex::new(WarmPool::new(WARM_POOL_IDLE_TIMEOUT)));
+static SERVE_SUPPORT: LazyLock<Mutex<HashMap<String, bool>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn worker_supports_serve(program: &str) -> bool {
+    if let Some(cached) = SERVE_SUPPORT
+        .lock()
+        .ok()
+        .and_then(|cache| cache.get(program).copied())
+    {
+        return cached;
+    }
+
+    let supported = Command::new(program)
+        .arg("--help")
+        .stdin(Stdio::null())
+        .output()
+        .map(|output| {
+            help_advertises_serve(&String::from_utf8_lossy(&output.stdout))
+                || help_advertises_serve(&String::from_utf8_lossy(&output.stderr))
+        })
+        .unwrap_or(false);
+    if let Ok(mut cache) = SERVE_SUPPORT.lock() {
+        cache.insert(program.to_string(), supported);
+    }
+    supported
+}
+
+/// Hands out the warm worker for `command`, spawning it if needed, or `None` when the
+/// worker has no serve mode and the session should spawn its own process.
+pub fn checkout_warm_worker(
+    command: &LaunchCommand,
+    session_id: &str,
+    stream_stderr: impl FnOnce(ChildStderr) + Send + 'static,
+) -> Result<Option<ServeWorker>, String> {
+    if !worker_supports_serve(&command.program) {
+        return Ok(None);
+    }
+
+    let key = std::iter::once(command.program.as_str())
+        .chain(command.args.iter().map(String::as_str))
+        .collect::<Vec<&str>>()
+        .join(" ");
+    let mut pool = WARM_POOL
+        .lock()
+        .map_err(|_| "Failed to access warm worker pool".to_string())?;
+    match pool.acquire(&key, session_id) {
+        Acquire::Reuse(worker) => Ok(Some(worker)),
+        Acquire::Busy => Ok(None),
+        Acquire::Spawn { evicted } => {
+            if let Some(stale) = evicted {
+                stale.shutdown();
+            }
+            let mut process = Command::new(&command.program);
+            process.args(&command.args);
+            let (worker, stderr) = ServeWorker::spawn(process)?;
+            std::thread::spawn(move || stream_stderr(stderr));
+            if let Some(replaced) = pool.insert_busy(&key, session_id, worker.clone()) {
+                replaced.shutdown();
+            }
+            Ok(Some(worker))
+        }
+    }
+}
+
+pub fn release_warm_worker(session_id: &str) {
+    let released = WARM_POOL
+        .lock()
+        .map(|mut pool| pool.release(session_id, Instant::now()))
+        .unwrap_or(false);
+    if !released {
+        return;
+    }
+
+    std::thread::spawn(|| {
+        std::thread::sleep(WARM_POOL_IDLE_TIMEOUT);
+        let expired = WARM_POOL
+            .lock()
+            .ok()
+            .and_then(|mut pool| pool.evict_expired(Instant::now()));
+        if let Some(worker) = expired {
+            worker.shutdown();
+        }
+    });
+}
+
+// A worker that crashed, was stopped or hit a fatal error is never handed out again.
+pub fn discard_warm_worker(session_id: &str, worker: &ServeWorker) {
+    if let Ok(mut pool) = WARM_POOL.lock() {
+        pool.evict_session(session_id);
+    }
+    worker.shutdown();
+}
+
+pub fn shutdown_warm_pool() {
+    let evicted = WARM_POOL.lock().ok().and_then(|mut pool| pool.evict());
+    if let Some(worker) = evicted {
+        worker.shutdown();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn worker_cycles_between_busy_and_idle_and_is_reused_for_the_same_command() {
+        let mut pool = WarmPool::new(Duration::from_secs(60));
+        assert_eq!(pool.state(), PoolState::Empty);
+        assert_eq!(
+            pool.acquire("coreml v3", "session-1"),
+            Acquire::Spawn { evicted: None }
+        );
+
+        assert_eq!(pool.insert_busy("coreml v3", "session-1", 7), None);
+        assert_eq!(
+            pool.state(),
+            PoolState::Busy {
+                session_id: "session-1".to_string()
+            }
+        );
+        assert_eq!(pool.acquire("coreml v3", "session-2"), Acquire::Busy);
+        assert!(!pool.release("session-2", Instant::now()));
+
+        let released_at = Instant::now();
+        assert!(pool.release("session-1", released_at));
+        assert_eq!(pool.state(), PoolState::Idle { since: released_at });
+
+        assert_eq!(pool.acquire("coreml v3", "session-2"), Acquire::Reuse(7));
+        assert_eq!(
+            pool.state(),
+            PoolState::Busy {
+                session_id: "session-2".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn idle_workers_are_evicted_after_timeout_or_command_change() {
+        let timeout = Duration::from_secs(60);
+        let mut pool = WarmPool::new(timeout);
+        pool.insert_busy("coreml v3", "session-1", 7);
+        let released_at = Instant::now();
+        pool.release("session-1", released_at);
+
+        assert_eq!(pool.evict_expired(released_at + timeout / 2), None);
+        assert_eq!(pool.state(), PoolState::Idle { since: released_at });
+        assert_eq!(pool.evict_expired(released_at + timeout), Some(7));
+        assert_eq!(pool.state(), PoolState::Empty);
+
+        pool.insert_busy("coreml v3", "session-2", 8);
+        assert_eq!(pool.evict_session("session-1"), None);
+        assert_eq!(pool.evict_expired(released_at + timeout * 2), None);
+        pool.release("session-2", released_at);
+        assert_eq!(
+            pool.acquire("coreml v2", "session-3"),
+            Acquire::Spawn { evicted: Some(8) }
+        );
+        assert_eq!(pool.state(), PoolState::Empty);
+
+        pool.insert_busy("coreml v3", "session-4", 9);
+        assert_eq!(pool.evict_session("session-4"), Some(9));
+        assert_eq!(pool.state(), PoolState::Empty);
+    }
+
+    #[test]
+    fn detects_serve_flag_in_worker_help() {
+        assert!(help_advertises_serve(
+            "USAGE: coreml-batch [--manifest <path>] [--serve]\n  --serve, keep running"
+        ));
+        assert!(help_advertises_serve(
+            "  --serve    Read manifests from stdin"
+        ));
+        assert!(!help_advertises_serve(
+            "  --server-url <url>\n  --preserve-paths"
+        ));
+    }
+}
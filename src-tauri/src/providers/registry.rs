@@ -1,8 +1,10 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use std::sync::{LazyLock, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 use tauri::AppHandle;
@@ -11,16 +13,27 @@ pub const COREML_PROVIDER_ID: &str = "coreml-local";
 pub const LEGACY_COREML_PROVIDER_ID: &str = "parakeet-coreml";
 pub const WHISPER_OPENAI_PROVIDER_ID: &str = "whisper-openai";
 pub const FASTER_WHISPER_PROVIDER_ID: &str = "faster-whisper";
+pub const AUTO_PROVIDER_ID: &str = "auto";
 pub const SWIFT_TOOL_NAME: &str = "coreml-batch";
 pub const LEGACY_SWIFT_TOOL_NAME: &str = "parakeet-batch";
 pub const SWIFT_MODELCTL_TOOL_NAME: &str = "coreml-modelctl";
 pub const LEGACY_SWIFT_MODELCTL_TOOL_NAME: &str = "parakeet-modelctl";
 
 const CAPABILITY_TIMEOUT: Duration = Duration::from_secs(5);
+const CAPABILITY_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
 const UV_INSTALL_URL: &str = "https://docs.astral.sh/uv/getting-started/installation/";
+// Auto selection order; cloud runtimes rank after every local provider.
+const PROVIDER_PREFERENCE: &[&str] = &[
+    COREML_PROVIDER_ID,
+    FASTER_WHISPER_PROVIDER_ID,
+    WHISPER_OPENAI_PROVIDER_ID,
+];
 type AvailabilityRunner = dyn Fn(&str, &[String]) -> bool;
 type CapabilityRunner = dyn Fn(&str, &[String], Duration) -> Option<Vec<u8>>;
 
+static CAPABILITY_CACHE: LazyLock<Mutex<CapabilityCache>> =
+    LazyLock::new(|| Mutex::new(CapabilityCache::new(CAPABILITY_CACHE_TTL)));
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Provider {
@@ -32,6 +45,24 @@ pub struct Provider {
     pub install_instructions: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderSelection {
+    pub provider_id: String,
+    pub name: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RelaunchCheck {
+    pub provider_id: String,
+    pub model: String,
+    pub can_relaunch: bool,
+    pub reason: Option<String>,
+    pub suggestion: Option<ProviderSelection>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type")]
 pub enum ProviderRuntime {
@@ -73,6 +104,9 @@ pub struct Capabilities {
     pub language_detection: Option<bool>,
     #[serde(alias = "translation")]
     pub translation: Option<bool>,
+    // Workers that accept `--protocol-version` say so here; older builds reject the flag.
+    #[serde(default, alias = "protocol_version")]
+    pub protocol_version: Option<u64>,
 }
 
 fn cloud_capabilities() -> Capabilities {
@@ -85,6 +119,7 @@ fn cloud_capabilities() -> Capabilities {
         speaker_diarization: Some(false),
         language_detection: Some(true),
         translation: Some(false),
+        protocol_version: None,
     }
 }
 
@@ -151,7 +186,44 @@ fn is_executable(path: &Path) -> bool {
 }
 
 fn parse_capabilities_output(output: &[u8]) -> Option<Capabilities> {
-    serde_json::from_slice::<Capabilities>(output).ok()
+    parse_capabilities_detailed(output).ok()
+}
+
+fn parse_capabilities_detailed(output: &[u8]) -> Result<Capabilities, String> {
+    let value = serde_json::from_slice::<serde_json::Value>(output)
+        .map_err(|error| format!("capabilities output is not valid JSON: {}", error))?;
+    let Some(fields) = value.as_object() else {
+        return Err(format!(
+            "capabilities output must be a JSON object, got {}",
+            json_type_name(&value)
+        ));
+    };
+
+    serde_json::from_value::<Capabilities>(value.clone()).map_err(|error| {
+        // Re-check fields one at a time so the message can name the offending key.
+        fields
+            .iter()
+            .find_map(|(key, field)| {
+                let single = serde_json::json!({ key.as_str(): field });
+                serde_json::from_value::<Capabilities>(single)
+                    .err()
+                    .map(|field_error| {
+                        format!("invalid capabilities field `{}`: {}", key, field_error)
+                    })
+            })
+            .unwrap_or_else(|| format!("invalid capabilities payload: {}", error))
+    })
+}
+
+fn json_type_name(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "a boolean",
+        serde_json::Value::Number(_) => "a number",
+        serde_json::Value::String(_) => "a string",
+        serde_json::Value::Array(_) => "an array",
+        serde_json::Value::Object(_) => "an object",
+    }
 }
 
 fn binary_supports_capabilities_with(path: &Path, command_runner: &CapabilityRunner) -> bool {
@@ -235,30 +307,36 @@ fn check_available_with(
     }
 }
 
-fn query_capabilities_with(
-    runtime: &ProviderRuntime,
-    command_runner: &CapabilityRunner,
-) -> Option<Capabilities> {
+fn capability_command(runtime: &ProviderRuntime) -> Option<(String, Vec<String>)> {
     match runtime {
-        ProviderRuntime::SwiftNative { binary_path, .. } => {
-            let program = binary_path.to_string_lossy().to_string();
-            let args = vec!["--capabilities".to_string()];
-            command_runner(&program, &args, CAPABILITY_TIMEOUT)
-                .and_then(|output| parse_capabilities_output(&output))
-        }
+        ProviderRuntime::SwiftNative { binary_path, .. } => Some((
+            binary_path.to_string_lossy().to_string(),
+            vec!["--capabilities".to_string()],
+        )),
         ProviderRuntime::PythonUv {
             package,
             entry_point,
-        } => {
-            let args =
-                python_uv_command_args(package, entry_point, &[String::from("--capabilities")]);
-            command_runner("uv", &args, CAPABILITY_TIMEOUT)
-                .and_then(|output| parse_capabilities_output(&output))
-        }
-        ProviderRuntime::CloudAPI { .. } => Some(cloud_capabilities()),
+        } => Some((
+            "uv".to_string(),
+            python_uv_command_args(package, entry_point, &[String::from("--capabilities")]),
+        )),
+        ProviderRuntime::CloudAPI { .. } => None,
     }
 }
 
+fn query_capabilities_with(
+    runtime: &ProviderRuntime,
+    command_runner: &CapabilityRunner,
+) -> Result<Capabilities, String> {
+    let Some((program, args)) = capability_command(runtime) else {
+        return Ok(cloud_capabilities());
+    };
+
+    let output = command_runner(&program, &args, CAPABILITY_TIMEOUT)
+        .ok_or_else(|| format!("`{} --capabilities` failed or timed out", program))?;
+    parse_capabilities_detailed(&output)
+}
+
 pub(crate) fn default_models_root() -> PathBuf {
     crate::fluid_models_root().unwrap_or_else(|_| {
         if let Ok(home) = std::env::var("HOME") {
@@ -346,17 +424,16 @@ fn install_instructions(runtime: &ProviderRuntime, uv_available: bool) -> String
         ProviderRuntime::PythonUv { package, .. } if !uv_available => {
             format!("Install uv ({UV_INSTALL_URL}) and then run provider setup for `{package}`.")
         }
-        ProviderRuntime::PythonUv { package, .. } => {
-            let probe_cmd = match worker_project_dir(package) {
-                Some(project_dir) => format!(
-                    "uv --directory {} run {} --capabilities",
-                    project_dir.display(),
-                    package.replace('-', "_")
-                ),
-                None => format!("uv run --package {package} {package} --capabilities"),
-            };
-            format!("Install or fix the `{package}` runtime so `{probe_cmd}` succeeds.")
-        }
+        // uv works, so the failing probe means the worker's environment was never synced.
+        ProviderRuntime::PythonUv { package, .. } => match worker_project_dir(package) {
+            Some(project_dir) => format!(
+                "uv is installed but the `{package}` worker is not set up. Run `cd {} && uv sync`, then retry.",
+                project_dir.display()
+            ),
+            None => format!(
+                "uv is installed but the `{package}` worker is not set up. Run `uv sync --package {package}` from the workspace root, then retry."
+            ),
+        },
         ProviderRuntime::CloudAPI { .. } => {
             "Set the API base URL and credentials in settings before use.".to_string()
         }
@@ -405,7 +482,7 @@ fn probe_with(
     mut providers: Vec<Provider>,
     uv_available: bool,
     availability_checker: &dyn Fn(&ProviderRuntime) -> bool,
-    capabilities_query: &dyn Fn(&ProviderRuntime) -> Option<Capabilities>,
+    capabilities_query: &dyn Fn(&ProviderRuntime) -> Result<Capabilities, String>,
 ) -> Vec<Provider> {
     for provider in &mut providers {
         if let ProviderRuntime::SwiftNative { binary_path, .. } = &provider.runtime {
@@ -423,13 +500,16 @@ fn probe_with(
 
         if available {
             provider.install_instructions = None;
-            provider.capabilities = capabilities_query(&provider.runtime);
-            if provider.capabilities.is_none() {
-                eprintln!(
-                    "provider probe warning: failed to query capabilities for {}",
-                    provider.id
-                );
-            }
+            provider.capabilities = match capabilities_query(&provider.runtime) {
+                Ok(capabilities) => Some(capabilities),
+                Err(error) => {
+                    eprintln!(
+                        "provider probe warning: failed to query capabilities for {}: {}",
+                        provider.id, error
+                    );
+                    None
+                }
+            };
         } else {
             provider.capabilities = None;
             provider.install_instructions =
@@ -444,8 +524,146 @@ pub fn check_available(runtime: &ProviderRuntime) -> bool {
     check_available_with(runtime, &command_status_success, &command_output_with_timeout)
 }
 
-pub fn query_capabilities(runtime: &ProviderRuntime) -> Option<Capabilities> {
-    query_capabilities_with(runtime, &command_output_with_timeout)
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedCapabilities {
+    pub command: String,
+    pub capabilities: Capabilities,
+    pub age_seconds: u64,
+}
+
+struct CapabilityCacheEntry {
+    capabilities: Capabilities,
+    cached_at: Instant,
+}
+
+// Only successful probes are kept, so a worker the user just fixed is re-probed on the next query.
+struct CapabilityCache {
+    entries: BTreeMap<String, CapabilityCacheEntry>,
+    ttl: Duration,
+}
+
+impl CapabilityCache {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            entries: BTreeMap::new(),
+            ttl,
+        }
+    }
+
+    fn get(&self, command: &str, now: Instant) -> Option<Capabilities> {
+        self.entries
+            .get(command)
+            .filter(|entry| now.saturating_duration_since(entry.cached_at) < self.ttl)
+            .map(|entry| entry.capabilities.clone())
+    }
+
+    fn insert(&mut self, command: String, capabilities: Capabilities, now: Instant) {
+        self.entries.insert(
+            command,
+            CapabilityCacheEntry {
+                capabilities,
+                cached_at: now,
+            },
+        );
+    }
+
+    fn snapshot(&self, now: Instant) -> Vec<CachedCapabilities> {
+        self.entries
+            .iter()
+            .filter(|(_, entry)| now.saturating_duration_since(entry.cached_at) < self.ttl)
+            .map(|(command, entry)| CachedCapabilities {
+                command: command.clone(),
+                capabilities: entry.capabilities.clone(),
+                age_seconds: now.saturating_duration_since(entry.cached_at).as_secs(),
+            })
+            .collect()
+    }
+
+    fn remove(&mut self, command: &str) {
+        self.entries.remove(command);
+    }
+
+    fn clear(&mut self) -> usize {
+        let cleared = self.entries.len();
+        self.entries.clear();
+        cleared
+    }
+}
+
+fn capability_cache_key(runtime: &ProviderRuntime) -> Option<String> {
+    let (program, args) = capability_command(runtime)?;
+    Some(
+        std::iter::once(program)
+            .chain(args)
+            .collect::<Vec<_>>()
+            .join(" "),
+    )
+}
+
+fn query_capabilities_cached(
+    runtime: &ProviderRuntime,
+    cache: &Mutex<CapabilityCache>,
+    command_runner: &CapabilityRunner,
+    now: Instant,
+) -> Result<Capabilities, String> {
+    let Some(command) = capability_cache_key(runtime) else {
+        return Ok(cloud_capabilities());
+    };
+    if let Some(cached) = cache.lock().ok().and_then(|cache| cache.get(&command, now)) {
+        return Ok(cached);
+    }
+
+    let result = query_capabilities_with(runtime, command_runner);
+    if let (Ok(capabilities), Ok(mut cache)) = (&result, cache.lock()) {
+        cache.insert(command, capabilities.clone(), now);
+    }
+    result
+}
+
+fn refresh_capabilities_cached(
+    runtime: &ProviderRuntime,
+    cache: &Mutex<CapabilityCache>,
+    command_runner: &CapabilityRunner,
+    now: Instant,
+) -> Result<Capabilities, String> {
+    if let (Some(command), Ok(mut cache)) = (capability_cache_key(runtime), cache.lock()) {
+        cache.remove(&command);
+    }
+    query_capabilities_cached(runtime, cache, command_runner, now)
+}
+
+pub fn query_capabilities(runtime: &ProviderRuntime) -> Result<Capabilities, String> {
+    query_capabilities_cached(
+        runtime,
+        &CAPABILITY_CACHE,
+        &command_output_with_timeout,
+        Instant::now(),
+    )
+}
+
+/// Probes the worker again even when a cached answer is still fresh.
+pub fn refresh_capabilities(runtime: &ProviderRuntime) -> Result<Capabilities, String> {
+    refresh_capabilities_cached(
+        runtime,
+        &CAPABILITY_CACHE,
+        &command_output_with_timeout,
+        Instant::now(),
+    )
+}
+
+pub fn cached_capabilities() -> Result<Vec<CachedCapabilities>, String> {
+    CAPABILITY_CACHE
+        .lock()
+        .map(|cache| cache.snapshot(Instant::now()))
+        .map_err(|_| "Failed to lock capability cache".to_string())
+}
+
+pub fn clear_capability_cache() -> Result<usize, String> {
+    CAPABILITY_CACHE
+        .lock()
+        .map(|mut cache| cache.clear())
+        .map_err(|_| "Failed to lock capability cache".to_string())
 }
 
 pub fn probe_all(app: &AppHandle) -> Vec<Provider> {
@@ -461,6 +679,159 @@ pub fn probe_all(app: &AppHandle) -> Vec<Provider> {
     )
 }
 
+fn probe_single_with(
+    providers: Vec<Provider>,
+    provider_id: &str,
+    uv_available: &dyn Fn() -> bool,
+    availability_checker: &dyn Fn(&ProviderRuntime) -> bool,
+    capabilities_query: &dyn Fn(&ProviderRuntime) -> Result<Capabilities, String>,
+) -> Result<Provider, String> {
+    let requested = normalize_provider_id(provider_id.trim());
+    let provider = providers
+        .into_iter()
+        .find(|provider| provider.id == requested)
+        .ok_or_else(|| format!("Unknown provider: {}", provider_id))?;
+    // Only Python runtimes depend on uv, so skip spawning it for the others.
+    let uv_available =
+        matches!(provider.runtime, ProviderRuntime::PythonUv { .. }) && uv_available();
+
+    probe_with(
+        vec![provider],
+        uv_available,
+        availability_checker,
+        capabilities_query,
+    )
+    .pop()
+    .ok_or_else(|| format!("Failed to probe provider: {}", provider_id))
+}
+
+pub fn probe_provider(app: &AppHandle, provider_id: &str) -> Result<Provider, String> {
+    let swift_binary = resolve_swift_binary_path(app);
+    probe_single_with(
+        known_providers(swift_binary, default_models_root()),
+        provider_id,
+        &|| crate::command_succeeds("uv", &["--version"]),
+        &check_available,
+        &refresh_capabilities,
+    )
+}
+
+fn preference_rank(provider: &Provider) -> usize {
+    if matches!(provider.runtime, ProviderRuntime::CloudAPI { .. }) {
+        return PROVIDER_PREFERENCE.len();
+    }
+
+    PROVIDER_PREFERENCE
+        .iter()
+        .position(|id| *id == normalize_provider_id(&provider.id))
+        .unwrap_or(PROVIDER_PREFERENCE.len() + 1)
+}
+
+fn supports_model(provider: &Provider, model: &str) -> bool {
+    let model = model.trim();
+    match &provider.capabilities {
+        Some(capabilities) if !model.is_empty() && !capabilities.supported_models.is_empty() => {
+            capabilities
+                .supported_models
+                .iter()
+                .any(|supported| supported.eq_ignore_ascii_case(model))
+        }
+        _ => true,
+    }
+}
+
+pub fn select_best_provider(providers: &[Provider], model: &str) -> Option<ProviderSelection> {
+    let mut ranked = providers.iter().collect::<Vec<&Provider>>();
+    ranked.sort_by_key(|provider| preference_rank(provider));
+
+    let mut skipped = Vec::new();
+    for provider in ranked {
+        if !provider.available {
+            skipped.push(format!("{} is unavailable", provider.name));
+            continue;
+        }
+        if !supports_model(provider, model) {
+            skipped.push(format!(
+                "{} does not support model {}",
+                provider.name,
+                model.trim()
+            ));
+            continue;
+        }
+
+        let reason = if skipped.is_empty() {
+            format!(
+                "{} is available and is the preferred provider",
+                provider.name
+            )
+        } else {
+            format!(
+                "{} is the first available provider ({})",
+                provider.name,
+                skipped.join("; ")
+            )
+        };
+        return Some(ProviderSelection {
+            provider_id: normalize_provider_id(&provider.id).to_string(),
+            name: provider.name.clone(),
+            reason,
+        });
+    }
+
+    None
+}
+
+pub fn assess_relaunch(providers: &[Provider], provider_id: &str, model: &str) -> RelaunchCheck {
+    let recorded = normalize_provider_id(provider_id.trim());
+    let provider = providers
+        .iter()
+        .find(|provider| normalize_provider_id(&provider.id) == recorded);
+    let reason = match provider {
+        None => Some(format!("Provider {} is no longer known", provider_id)),
+        Some(provider) if !provider.available => Some(format!("{} is unavailable", provider.name)),
+        Some(provider) if !supports_model(provider, model) => Some(format!(
+            "{} does not support model {}",
+            provider.name,
+            model.trim()
+        )),
+        Some(_) => None,
+    };
+
+    // Another provider will not know the recorded model name, so suggest by availability alone.
+    let suggestion = reason.as_ref().and_then(|_| {
+        let alternatives = providers
+            .iter()
+            .filter(|provider| normalize_provider_id(&provider.id) != recorded)
+            .cloned()
+            .collect::<Vec<Provider>>();
+        select_best_provider(&alternatives, "")
+    });
+
+    RelaunchCheck {
+        provider_id: recorded.to_string(),
+        model: model.trim().to_string(),
+        can_relaunch: reason.is_none(),
+        reason,
+        suggestion,
+    }
+}
+
+/// Probes only the recorded provider unless it cannot run, then everything for a suggestion.
+pub fn check_relaunch(app: &AppHandle, provider_id: &str, model: &str) -> RelaunchCheck {
+    if let Ok(provider) = probe_provider(app, provider_id) {
+        let check = assess_relaunch(std::slice::from_ref(&provider), provider_id, model);
+        if check.can_relaunch {
+            return check;
+        }
+    }
+    assess_relaunch(&probe_all(app), provider_id, model)
+}
+
+pub fn resolve_best_provider(app: &AppHandle, model: &str) -> Result<ProviderSelection, String> {
+    select_best_provider(&probe_all(app), model)
+        .ok_or_else(|| "No transcription provider is available".to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -600,6 +971,98 @@ mod tests {
         assert_eq!(capabilities.word_timestamps, Some(true));
     }
 
+    #[test]
+    fn reports_which_capability_field_failed_to_parse() {
+        let wrong_type = br#"{"supported_models": ["v3"], "word_timestamps": "yes"}"#;
+        let error = parse_capabilities_detailed(wrong_type).expect_err("string bool should fail");
+        assert!(error.contains("`word_timestamps`"), "{error}");
+        assert!(error.contains("expected a boolean"), "{error}");
+        assert!(parse_capabilities_output(wrong_type).is_none());
+
+        let negative = br#"{"concurrentFiles": -1}"#;
+        let error = parse_capabilities_detailed(negative).expect_err("negative u32 should fail");
+        assert!(error.contains("`concurrentFiles`"), "{error}");
+
+        let error =
+            parse_capabilities_detailed(b"{\"supported_models\": [").expect_err("truncated");
+        assert!(error.contains("not valid JSON"), "{error}");
+
+        let error = parse_capabilities_detailed(b"[\"v3\"]").expect_err("array payload");
+        assert!(
+            error.contains("must be a JSON object, got an array"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn capability_cache_reports_entry_ages_and_clears() {
+        let runtime = ProviderRuntime::SwiftNative {
+            binary_path: PathBuf::from("/tmp/coreml-batch"),
+            model_dir: PathBuf::from("/tmp/models"),
+        };
+        let cache = Mutex::new(CapabilityCache::new(Duration::from_secs(60)));
+        let probes = std::rc::Rc::new(std::cell::Cell::new(0));
+        let counter = probes.clone();
+        // The first probe times out, as a broken worker would; later ones succeed.
+        let runner = move |_: &str, _: &[String], _: Duration| {
+            counter.set(counter.get() + 1);
+            (counter.get() > 1).then(|| br#"{"supported_models":["v3"]}"#.to_vec())
+        };
+        let start = Instant::now();
+
+        let failed = query_capabilities_cached(&runtime, &cache, &runner, start);
+        assert!(failed.is_err_and(|error| error.contains("timed out")));
+        assert!(cache.lock().expect("cache lock").snapshot(start).is_empty());
+
+        let first = query_capabilities_cached(&runtime, &cache, &runner, start);
+        let second =
+            query_capabilities_cached(&runtime, &cache, &runner, start + Duration::from_secs(30));
+        assert!(first.is_ok());
+        assert_eq!(first, second);
+        assert_eq!(probes.get(), 2, "a fresh entry answers without probing");
+
+        let cached = cache
+            .lock()
+            .expect("cache lock")
+            .snapshot(start + Duration::from_secs(42));
+        assert_eq!(cached.len(), 1);
+        assert_eq!(cached[0].command, "/tmp/coreml-batch --capabilities");
+        assert_eq!(cached[0].age_seconds, 42);
+        assert_eq!(Ok(cached[0].capabilities.clone()), first);
+        assert!(cache
+            .lock()
+            .expect("cache lock")
+            .snapshot(start + Duration::from_secs(61))
+            .is_empty());
+
+        let _ = refresh_capabilities_cached(&runtime, &cache, &runner, start);
+        assert_eq!(probes.get(), 3, "a refresh bypasses a fresh entry");
+
+        assert_eq!(cache.lock().expect("cache lock").clear(), 1);
+        assert!(cache.lock().expect("cache lock").snapshot(start).is_empty());
+        let _ = query_capabilities_cached(&runtime, &cache, &runner, start);
+        assert_eq!(probes.get(), 4, "a cleared cache probes again");
+    }
+
+    #[test]
+    fn query_capabilities_with_reports_runner_failure() {
+        let runtime = ProviderRuntime::SwiftNative {
+            binary_path: PathBuf::from("/tmp/coreml-batch"),
+            model_dir: PathBuf::from("/tmp/models"),
+        };
+
+        let error = query_capabilities_with(&runtime, &|_, _, _| None).expect_err("no output");
+        assert!(
+            error.contains("/tmp/coreml-batch --capabilities"),
+            "{error}"
+        );
+
+        let error =
+            query_capabilities_with(&runtime, &|_, _, _| Some(br#"{"translation": 1}"#.to_vec()))
+                .expect_err("numeric bool should fail");
+        assert!(error.contains("`translation`"), "{error}");
+    }
+
     #[test]
     fn generates_install_instructions_for_missing_python_runtime() {
         let runtime = ProviderRuntime::PythonUv {
@@ -611,6 +1074,34 @@ mod tests {
         assert!(instructions.contains("Install uv"));
     }
 
+    #[test]
+    fn distinguishes_missing_uv_from_unsynced_worker_package() {
+        let runtime = ProviderRuntime::PythonUv {
+            package: "whisper-batch".to_string(),
+            entry_point: "whisper_batch".to_string(),
+        };
+
+        assert_eq!(
+            install_instructions(&runtime, false),
+            format!(
+                "Install uv ({UV_INSTALL_URL}) and then run provider setup for `whisper-batch`."
+            )
+        );
+        assert_eq!(
+            install_instructions(&runtime, true),
+            "uv is installed but the `whisper-batch` worker is not set up. Run `cd workers/whisper-batch && uv sync`, then retry."
+        );
+
+        let unknown = ProviderRuntime::PythonUv {
+            package: "custom-batch".to_string(),
+            entry_point: "custom_batch".to_string(),
+        };
+        assert_eq!(
+            install_instructions(&unknown, true),
+            "uv is installed but the `custom-batch` worker is not set up. Run `uv sync --package custom-batch` from the workspace root, then retry."
+        );
+    }
+
     #[test]
     fn probe_with_marks_unavailable_and_sets_install_instructions() {
         let missing_swift = Provider {
@@ -626,7 +1117,7 @@ mod tests {
         };
 
         let probed = probe_with(vec![missing_swift], true, &check_available, &|_| {
-            Some(Capabilities::default())
+            Ok(Capabilities::default())
         });
 
         assert!(!probed[0].available);
@@ -663,10 +1154,11 @@ mod tests {
             speaker_diarization: Some(false),
             language_detection: Some(true),
             translation: Some(false),
+            protocol_version: None,
         };
 
         let probed = probe_with(vec![provider], true, &|_| true, &|_| {
-            Some(expected_caps.clone())
+            Ok(expected_caps.clone())
         });
 
         assert!(probed[0].available);
@@ -674,9 +1166,157 @@ mod tests {
         assert!(probed[0].install_instructions.is_none());
     }
 
+    #[test]
+    fn probe_single_returns_requested_provider_and_rejects_unknown_ids() {
+        let providers = || {
+            known_providers(
+                PathBuf::from("/tmp/missing/coreml-batch"),
+                PathBuf::from("/tmp/models"),
+            )
+        };
+        let uv_probed = std::cell::Cell::new(false);
+        let uv_available = || {
+            uv_probed.set(true);
+            true
+        };
+
+        let whisper = probe_single_with(
+            providers(),
+            WHISPER_OPENAI_PROVIDER_ID,
+            &uv_available,
+            &|_| true,
+            &|_| Ok(Capabilities::default()),
+        )
+        .expect("known provider should be probed");
+        assert_eq!(whisper.id, WHISPER_OPENAI_PROVIDER_ID);
+        assert!(whisper.available);
+        assert_eq!(whisper.capabilities, Some(Capabilities::default()));
+        assert!(uv_probed.get());
+
+        uv_probed.set(false);
+        let coreml = probe_single_with(
+            providers(),
+            LEGACY_COREML_PROVIDER_ID,
+            &uv_available,
+            &|_| false,
+            &|_| Ok(Capabilities::default()),
+        )
+        .expect("legacy ids resolve to the current provider");
+        assert_eq!(coreml.id, COREML_PROVIDER_ID);
+        assert!(!coreml.available);
+        assert!(coreml.install_instructions.is_some());
+        assert!(!uv_probed.get(), "uv is only checked for python runtimes");
+
+        let unknown = probe_single_with(providers(), "nope", &uv_available, &|_| true, &|_| {
+            Ok(Capabilities::default())
+        })
+        .expect_err("unknown ids should error");
+        assert_eq!(unknown, "Unknown provider: nope");
+    }
+
     #[test]
     fn normalize_provider_id_maps_legacy_value() {
         assert_eq!(normalize_provider_id(LEGACY_COREML_PROVIDER_ID), COREML_PROVIDER_ID);
         assert_eq!(normalize_provider_id(WHISPER_OPENAI_PROVIDER_ID), WHISPER_OPENAI_PROVIDER_ID);
     }
+
+    fn provider_with(id: &str, name: &str, runtime: ProviderRuntime, available: bool) -> Provider {
+        Provider {
+            id: id.to_string(),
+            name: name.to_string(),
+            runtime,
+            available,
+            capabilities: None,
+            install_instructions: None,
+        }
+    }
+
+    fn providers_with_availability(available: [bool; 4]) -> Vec<Provider> {
+        let mut providers = known_providers(
+            PathBuf::from("/tmp/missing/coreml-batch"),
+            PathBuf::from("/tmp/models"),
+        );
+        providers.push(provider_with(
+            "cloud-api",
+            "Cloud API",
+            ProviderRuntime::CloudAPI {
+                base_url: "https://api.example.com".to_string(),
+                requires_key: true,
+            },
+            false,
+        ));
+        // Listing order differs from preference order on purpose.
+        providers.reverse();
+        for provider in &mut providers {
+            provider.available = match provider.id.as_str() {
+                COREML_PROVIDER_ID => available[0],
+                FASTER_WHISPER_PROVIDER_ID => available[1],
+                WHISPER_OPENAI_PROVIDER_ID => available[2],
+                _ => available[3],
+            };
+        }
+        providers
+    }
+
+    #[test]
+    fn selects_best_provider_by_preference_order() {
+        let cases = [
+            ([true, true, true, true], Some(COREML_PROVIDER_ID)),
+            ([false, true, true, true], Some(FASTER_WHISPER_PROVIDER_ID)),
+            ([false, false, true, true], Some(WHISPER_OPENAI_PROVIDER_ID)),
+            ([false, false, false, true], Some("cloud-api")),
+            (
+                [false, true, false, false],
+                Some(FASTER_WHISPER_PROVIDER_ID),
+            ),
+            ([false, false, false, false], None),
+        ];
+
+        for (availability, expected) in cases {
+            let selection = select_best_provider(&providers_with_availability(availability), "");
+            assert_eq!(
+                selection
+                    .as_ref()
+                    .map(|selection| selection.provider_id.as_str()),
+                expected,
+                "availability {availability:?}"
+            );
+        }
+
+        let preferred = select_best_provider(&providers_with_availability([true; 4]), "")
+            .expect("a provider should be selected");
+        assert!(preferred.reason.contains("preferred provider"));
+
+        let fallback = select_best_provider(
+            &providers_with_availability([false, false, true, false]),
+            "",
+        )
+        .expect("a provider should be selected");
+        assert!(fallback.reason.contains("CoreML Local is unavailable"));
+        assert!(fallback.reason.contains("Faster Whisper is unavailable"));
+    }
+
+    #[test]
+    fn skips_available_providers_that_do_not_support_model() {
+        let mut providers = providers_with_availability([true, true, false, false]);
+        for provider in &mut providers {
+            if provider.id == COREML_PROVIDER_ID {
+                provider.capabilities = Some(Capabilities {
+                    supported_models: vec!["v3".to_string()],
+                    ..Capabilities::default()
+                });
+            }
+        }
+
+        let selection =
+            select_best_provider(&providers, "large-v3").expect("a provider should be selected");
+        assert_eq!(selection.provider_id, FASTER_WHISPER_PROVIDER_ID);
+        assert!(selection
+            .reason
+            .contains("CoreML Local does not support model large-v3"));
+
+        let selection =
+            select_best_provider(&providers, "V3").expect("a provider should be selected");
+        assert_eq!(selection.provider_id, COREML_PROVIDER_ID);
+    }
 }
@@ -1,10 +1,11 @@
 use super::registry::{
-    check_available, normalize_provider_id, ProviderRuntime, FASTER_WHISPER_PROVIDER_ID,
-    COREML_PROVIDER_ID, SWIFT_TOOL_NAME, WHISPER_OPENAI_PROVIDER_ID,
+    check_available, normalize_provider_id, ProviderRuntime, AUTO_PROVIDER_ID, COREML_PROVIDER_ID,
+    FASTER_WHISPER_PROVIDER_ID, SWIFT_TOOL_NAME, WHISPER_OPENAI_PROVIDER_ID,
 };
+use serde::Serialize;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone)]
 pub struct ProviderSettings {
@@ -42,6 +43,13 @@ impl Display for ProviderError {
 
 impl Error for ProviderError {}
 
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderModelValidation {
+    pub valid: bool,
+    pub reason: Option<String>,
+}
+
 const COREML_V3_FOLDER: &str = "parakeet-tdt-0.6b-v3-coreml";
 const COREML_V2_FOLDER: &str = "parakeet-tdt-0.6b-v2-coreml";
 
@@ -77,17 +85,85 @@ fn validate_model(model: &str) -> Result<&str, ProviderError> {
     Ok(trimmed)
 }
 
-fn resolve_coreml_model_dir(models_root: &std::path::Path, model: &str) -> PathBuf {
+/// Maps `v2`/`v3` or a managed CoreML folder name, in any casing, to its canonical model version.
+pub(crate) fn normalize_model_version(model: &str) -> Option<&'static str> {
     let normalized = model.trim().to_ascii_lowercase();
-    let folder = match normalized.as_str() {
-        "v3" | COREML_V3_FOLDER => COREML_V3_FOLDER,
-        "v2" | COREML_V2_FOLDER => COREML_V2_FOLDER,
+    match normalized.as_str() {
+        "v3" | COREML_V3_FOLDER => Some("v3"),
+        "v2" | COREML_V2_FOLDER => Some("v2"),
+        _ => None,
+    }
+}
+
+// Line one holds the catalog revision, line two the model version; older installs only have line one.
+pub(crate) const MODEL_REVISION_MARKER: &str = ".aura-model-revision";
+// The English-only v2 vocabulary has 1,024 tokens; v3's multilingual one is far larger.
+const V2_VOCAB_SIZE: usize = 1024;
+
+fn vocab_model_version(model_dir: &Path) -> Option<&'static str> {
+    let payload = std::fs::read_to_string(model_dir.join("parakeet_vocab.json")).ok()?;
+    let size = match serde_json::from_str::<serde_json::Value>(&payload).ok()? {
+        serde_json::Value::Object(tokens) => tokens.len(),
+        serde_json::Value::Array(tokens) => tokens.len(),
+        _ => return None,
+    };
+    Some(if size > V2_VOCAB_SIZE { "v3" } else { "v2" })
+}
+
+/// Reads a model folder's version from its install marker, else from the size of its vocabulary.
+pub(crate) fn read_model_version(model_dir: &Path) -> Option<&'static str> {
+    std::fs::read_to_string(model_dir.join(MODEL_REVISION_MARKER))
+        .ok()
+        .and_then(|contents| contents.lines().nth(1).and_then(normalize_model_version))
+        .or_else(|| vocab_model_version(model_dir))
+}
+
+fn resolve_coreml_model_dir(models_root: &std::path::Path, model: &str) -> PathBuf {
+    let folder = match normalize_model_version(model) {
+        Some("v3") => COREML_V3_FOLDER,
+        Some("v2") => COREML_V2_FOLDER,
         _ => model,
     };
 
     models_root.join(folder)
 }
 
+/// The model each provider starts with in the UI; `auto` has none because it is not resolved yet.
+pub fn default_model_for(id: &str) -> Option<&'static str> {
+    match normalize_provider_id(id.trim()) {
+        COREML_PROVIDER_ID => Some("v3"),
+        WHISPER_OPENAI_PROVIDER_ID | FASTER_WHISPER_PROVIDER_ID => Some("large-v3"),
+        _ => None,
+    }
+}
+
+/// Checks the provider id and model string without probing runtimes or touching the filesystem.
+pub fn validate_provider_model(id: &str, model: &str) -> ProviderModelValidation {
+    let known = matches!(
+        normalize_provider_id(id.trim()),
+        AUTO_PROVIDER_ID
+            | COREML_PROVIDER_ID
+            | WHISPER_OPENAI_PROVIDER_ID
+            | FASTER_WHISPER_PROVIDER_ID
+    );
+    let result = if known {
+        validate_model(model).map(|_| ())
+    } else {
+        Err(ProviderError::NotFound(id.to_string()))
+    };
+
+    match result {
+        Ok(()) => ProviderModelValidation {
+            valid: true,
+            reason: None,
+        },
+        Err(error) => ProviderModelValidation {
+            valid: false,
+            reason: Some(error.to_string()),
+        },
+    }
+}
+
 pub fn resolve_provider(
     id: &str,
     model: &str,
@@ -141,8 +217,8 @@ mod tests {
             check_availability: false,
         };
 
-        let runtime = resolve_provider(COREML_PROVIDER_ID, "v3", &settings)
-            .expect("provider should resolve");
+        let runtime =
+            resolve_provider(COREML_PROVIDER_ID, "v3", &settings).expect("provider should resolve");
 
         assert_eq!(
             runtime,
@@ -161,10 +237,10 @@ mod tests {
             check_availability: false,
         };
 
-        let v2_runtime = resolve_provider(COREML_PROVIDER_ID, "v2", &settings)
-            .expect("v2 alias should resolve");
-        let v3_runtime = resolve_provider(COREML_PROVIDER_ID, "v3", &settings)
-            .expect("v3 alias should resolve");
+        let v2_runtime =
+            resolve_provider(COREML_PROVIDER_ID, "v2", &settings).expect("v2 alias should resolve");
+        let v3_runtime =
+            resolve_provider(COREML_PROVIDER_ID, "v3", &settings).expect("v3 alias should resolve");
 
         assert_eq!(
             v2_runtime,
@@ -259,6 +335,31 @@ mod tests {
         assert_eq!(error, ProviderError::InvalidModel("../escape".to_string()));
     }
 
+    #[test]
+    fn validates_provider_and_model_without_probing() {
+        let valid = validate_provider_model(COREML_PROVIDER_ID, "v3");
+        assert!(valid.valid);
+        assert!(valid.reason.is_none());
+        assert!(validate_provider_model("parakeet-coreml", "v2").valid);
+        assert!(validate_provider_model(FASTER_WHISPER_PROVIDER_ID, "large-v3").valid);
+        assert!(validate_provider_model(AUTO_PROVIDER_ID, "v3").valid);
+
+        let unknown = validate_provider_model("unknown-provider", "v3");
+        assert!(!unknown.valid);
+        assert_eq!(
+            unknown.reason.as_deref(),
+            Some("Provider not found: unknown-provider")
+        );
+
+        let traversal = validate_provider_model(WHISPER_OPENAI_PROVIDER_ID, "../escape");
+        assert!(!traversal.valid);
+        assert_eq!(
+            traversal.reason.as_deref(),
+            Some("Invalid model value: ../escape")
+        );
+        assert!(!validate_provider_model(COREML_PROVIDER_ID, "   ").valid);
+    }
+
     #[test]
     fn returns_unavailable_when_runtime_is_not_available() {
         let settings = ProviderSettings {
@@ -0,0 +1,351 @@
+use super::resolver::{default_model_for, validate_provider_model};
+use crate::notifications::{allowed_sound, NOTIFICATION_SOUNDS};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+const SETTINGS_FILE_NAME: &str = "settings.json";
+pub const MAX_PARALLEL_SESSIONS_LIMIT: usize = 8;
+
+fn default_max_parallel_sessions() -> usize {
+    1
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedSettings {
+    #[serde(default = "default_max_parallel_sessions")]
+    pub max_parallel_sessions: usize,
+    #[serde(default)]
+    pub default_provider: Option<String>,
+    #[serde(default)]
+    pub default_model: Option<String>,
+    #[serde(default)]
+    pub completion_sound: Option<String>,
+    #[serde(default)]
+    pub failure_sound: Option<String>,
+}
+
+impl Default for PersistedSettings {
+    fn default() -> Self {
+        Self {
+            max_parallel_sessions: default_max_parallel_sessions(),
+            default_provider: None,
+            default_model: None,
+            completion_sound: None,
+            failure_sound: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderDefaults {
+    pub provider: Option<String>,
+    pub model: Option<String>,
+}
+
+impl From<&PersistedSettings> for ProviderDefaults {
+    fn from(settings: &PersistedSettings) -> Self {
+        Self {
+            provider: settings.default_provider.clone(),
+            model: settings.default_model.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationSounds {
+    pub completion: Option<String>,
+    pub failure: Option<String>,
+}
+
+impl From<&PersistedSettings> for NotificationSounds {
+    fn from(settings: &PersistedSettings) -> Self {
+        Self {
+            completion: settings.completion_sound.clone(),
+            failure: settings.failure_sound.clone(),
+        }
+    }
+}
+
+fn settings_path() -> Result<PathBuf, String> {
+    let home = dirs::home_dir().ok_or_else(|| "Failed to resolve home directory".to_string())?;
+    Ok(home.join(".aura").join(SETTINGS_FILE_NAME))
+}
+
+fn load_settings_from(path: &Path) -> PersistedSettings {
+    let payload = match std::fs::read_to_string(path) {
+        Ok(payload) => payload,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            return PersistedSettings::default()
+        }
+        Err(error) => {
+            eprintln!("[settings] failed to read {}: {}", path.display(), error);
+            return PersistedSettings::default();
+        }
+    };
+    serde_json::from_str(&payload).unwrap_or_else(|error| {
+        eprintln!(
+            "[settings] ignoring unreadable settings {}: {}",
+            path.display(),
+            error
+        );
+        PersistedSettings::default()
+    })
+}
+
+fn save_settings_to(path: &Path, settings: &PersistedSettings) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|error| {
+            format!(
+                "Failed to create settings directory {}: {}",
+                parent.display(),
+                error
+            )
+        })?;
+    }
+
+    let payload = serde_json::to_string_pretty(settings)
+        .map_err(|error| format!("Failed to serialize settings: {}", error))?;
+    std::fs::write(path, payload)
+        .map_err(|error| format!("Failed to write settings {}: {}", path.display(), error))
+}
+
+pub fn load_settings() -> PersistedSettings {
+    match settings_path() {
+        Ok(path) => load_settings_from(&path),
+        Err(_) => PersistedSettings::default(),
+    }
+}
+
+pub fn validate_max_parallel_sessions(value: usize) -> Result<(), String> {
+    if value == 0 || value > MAX_PARALLEL_SESSIONS_LIMIT {
+        return Err(format!(
+            "Max parallel sessions must be between 1 and {}",
+            MAX_PARALLEL_SESSIONS_LIMIT
+        ));
+    }
+    Ok(())
+}
+
+fn set_max_parallel_sessions_with_path(path: &Path, value: usize) -> Result<usize, String> {
+    validate_max_parallel_sessions(value)?;
+    let mut settings = load_settings_from(path);
+    settings.max_parallel_sessions = value;
+    save_settings_to(path, &settings)?;
+    Ok(value)
+}
+
+pub fn set_max_parallel_sessions(value: usize) -> Result<usize, String> {
+    set_max_parallel_sessions_with_path(&settings_path()?, value)
+}
+
+fn set_provider_defaults_with_path(
+    path: &Path,
+    provider: &str,
+    model: &str,
+) -> Result<ProviderDefaults, String> {
+    let (provider, model) = (provider.trim(), model.trim());
+    let validation = validate_provider_model(provider, model);
+    if !validation.valid {
+        return Err(validation
+            .reason
+            .unwrap_or_else(|| format!("Invalid provider/model: {} / {}", provider, model)));
+    }
+
+    let mut settings = load_settings_from(path);
+    settings.default_provider = Some(provider.to_string());
+    settings.default_model = Some(model.to_string());
+    save_settings_to(path, &settings)?;
+    Ok(ProviderDefaults::from(&settings))
+}
+
+pub fn set_provider_defaults(provider: &str, model: &str) -> Result<ProviderDefaults, String> {
+    set_provider_defaults_with_path(&settings_path()?, provider, model)
+}
+
+// An empty name clears the sound; anything else must be one of the built-in macOS sounds.
+fn validate_sound(name: Option<&str>) -> Result<Option<String>, String> {
+    match name.map(str::trim).filter(|name| !name.is_empty()) {
+        None => Ok(None),
+        Some(name) => allowed_sound(name)
+            .map(|sound| Some(sound.to_string()))
+            .ok_or_else(|| {
+                format!(
+                    "Unknown notification sound: {}; choose one of {}",
+                    name,
+                    NOTIFICATION_SOUNDS.join(", ")
+                )
+            }),
+    }
+}
+
+fn set_notification_sounds_with_path(
+    path: &Path,
+    completion: Option<&str>,
+    failure: Option<&str>,
+) -> Result<NotificationSounds, String> {
+    let completion = validate_sound(completion)?;
+    let failure = validate_sound(failure)?;
+
+    let mut settings = load_settings_from(path);
+    settings.completion_sound = completion;
+    settings.failure_sound = failure;
+    save_settings_to(path, &settings)?;
+    Ok(NotificationSounds::from(&settings))
+}
+
+pub fn set_notification_sounds(
+    completion: Option<&str>,
+    failure: Option<&str>,
+) -> Result<NotificationSounds, String> {
+    set_notification_sounds_with_path(&settings_path()?, completion, failure)
+}
+
+/// Fills an empty provider or model from the persisted defaults.
+pub fn provider_and_model_or_defaults(
+    provider: &str,
+    model: &str,
+    settings: &PersistedSettings,
+) -> Result<(String, String), String> {
+    let provider_defaulted = provider.trim().is_empty();
+    let provider = match provider.trim() {
+        "" => settings
+            .default_provider
+            .clone()
+            .ok_or_else(|| "No provider given and no default provider is set".to_string())?,
+        provider => provider.to_string(),
+    };
+    // The saved default model belongs to the saved default provider; another provider gets its own.
+    let default_model =
+        if provider_defaulted || settings.default_provider.as_deref() == Some(provider.as_str()) {
+            settings.default_model.clone()
+        } else {
+            None
+        };
+    let model = match model.trim() {
+        "" => default_model
+            .or_else(|| default_model_for(&provider).map(str::to_string))
+            .ok_or_else(|| "No model given and no default model is set".to_string())?,
+        model => model.to_string(),
+    };
+    Ok((provider, model))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn persists_validated_max_parallel_sessions() {
+        let path = std::env::temp_dir()
+            .join(format!("aura-settings-{}", uuid::Uuid::new_v4()))
+            .join(SETTINGS_FILE_NAME);
+        assert_eq!(load_settings_from(&path).max_parallel_sessions, 1);
+
+        assert_eq!(set_max_parallel_sessions_with_path(&path, 3), Ok(3));
+        assert_eq!(load_settings_from(&path).max_parallel_sessions, 3);
+
+        assert!(set_max_parallel_sessions_with_path(&path, 0).is_err());
+        assert!(
+            set_max_parallel_sessions_with_path(&path, MAX_PARALLEL_SESSIONS_LIMIT + 1).is_err()
+        );
+        assert_eq!(load_settings_from(&path).max_parallel_sessions, 3);
+    }
+
+    #[test]
+    fn persists_validated_provider_defaults() {
+        let path = std::env::temp_dir()
+            .join(format!("aura-settings-{}", uuid::Uuid::new_v4()))
+            .join(SETTINGS_FILE_NAME);
+        set_max_parallel_sessions_with_path(&path, 2).expect("cap should persist");
+
+        assert!(set_provider_defaults_with_path(&path, "mystery", "v3").is_err());
+        assert!(set_provider_defaults_with_path(&path, "coreml-local", "../v3").is_err());
+        assert_eq!(load_settings_from(&path).default_provider, None);
+
+        assert_eq!(
+            set_provider_defaults_with_path(&path, " faster-whisper ", "large-v3"),
+            Ok(ProviderDefaults {
+                provider: Some("faster-whisper".to_string()),
+                model: Some("large-v3".to_string()),
+            })
+        );
+        let settings = load_settings_from(&path);
+        assert_eq!(settings.max_parallel_sessions, 2);
+        assert_eq!(settings.default_model.as_deref(), Some("large-v3"));
+    }
+
+    #[test]
+    fn persists_allowlisted_notification_sounds() {
+        let path = std::env::temp_dir()
+            .join(format!("aura-settings-{}", uuid::Uuid::new_v4()))
+            .join(SETTINGS_FILE_NAME);
+
+        assert_eq!(
+            set_notification_sounds_with_path(&path, Some("glass"), Some(" Basso ")),
+            Ok(NotificationSounds {
+                completion: Some("Glass".to_string()),
+                failure: Some("Basso".to_string()),
+            })
+        );
+        assert!(
+            set_notification_sounds_with_path(&path, Some("Klaxon"), None)
+                .expect_err("unknown sounds are rejected")
+                .contains("Unknown notification sound: Klaxon")
+        );
+        assert_eq!(
+            load_settings_from(&path).completion_sound.as_deref(),
+            Some("Glass")
+        );
+
+        let cleared = set_notification_sounds_with_path(&path, Some(""), Some("Hero"))
+            .expect("sounds should persist");
+        assert_eq!(cleared.completion, None);
+        assert_eq!(
+            load_settings_from(&path).failure_sound.as_deref(),
+            Some("Hero")
+        );
+    }
+
+    #[test]
+    fn empty_provider_and_model_fall_back_to_defaults() {
+        let mut settings = PersistedSettings::default();
+        assert_eq!(
+            provider_and_model_or_defaults("coreml-local", "v3", &settings),
+            Ok(("coreml-local".to_string(), "v3".to_string()))
+        );
+        assert_eq!(
+            provider_and_model_or_defaults(" ", "v3", &settings),
+            Err("No provider given and no default provider is set".to_string())
+        );
+        assert_eq!(
+            provider_and_model_or_defaults("coreml-local", "", &settings),
+            Ok(("coreml-local".to_string(), "v3".to_string()))
+        );
+        assert!(provider_and_model_or_defaults("auto", "", &settings).is_err());
+
+        settings.default_provider = Some("faster-whisper".to_string());
+        settings.default_model = Some("large-v3".to_string());
+        assert_eq!(
+            provider_and_model_or_defaults("", "", &settings),
+            Ok(("faster-whisper".to_string(), "large-v3".to_string()))
+        );
+        assert_eq!(
+            provider_and_model_or_defaults("", "small", &settings),
+            Ok(("faster-whisper".to_string(), "small".to_string()))
+        );
+        assert_eq!(
+            provider_and_model_or_defaults("faster-whisper", "", &settings),
+            Ok(("faster-whisper".to_string(), "large-v3".to_string()))
+        );
+
+        // A Whisper model name means nothing to the CoreML provider.
+        settings.default_model = Some("small".to_string());
+        assert_eq!(
+            provider_and_model_or_defaults("coreml-local", "", &settings),
+            Ok(("coreml-local".to_string(), "v3".to_string()))
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use super::registry::{
+    clear_capability_cache, LEGACY_SWIFT_MODELCTL_TOOL_NAME, LEGACY_SWIFT_TOOL_NAME,
+    SWIFT_MODELCTL_TOOL_NAME, SWIFT_TOOL_NAME,
+};
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+use tauri::{AppHandle, Emitter};
+use walkdir::WalkDir;
+
+pub const PROVIDERS_CHANGED_EVENT: &str = "providers-changed";
+const WATCH_POLL_INTERVAL: Duration = Duration::from_secs(1);
+// A `swift build` or `uv sync` rewrites many files in a burst; wait for it to settle.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(1500);
+const PYTHON_WORKER_DIRS: &[&str] = &["workers/whisper-batch", "workers/faster-whisper-batch"];
+
+static ACTIVE_WATCH: Mutex<Option<Arc<AtomicBool>>> = Mutex::new(None);
+
+type Snapshot = HashMap<PathBuf, (u64, Option<SystemTime>)>;
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvidersChanged {
+    pub changed: Vec<String>,
+}
+
+#[derive(Debug)]
+struct ChangeDebouncer {
+    window: Duration,
+    last_change: Option<Instant>,
+    pending: BTreeSet<PathBuf>,
+}
+
+impl ChangeDebouncer {
+    fn new(window: Duration) -> Self {
+        Self {
+            window,
+            last_change: None,
+            pending: BTreeSet::new(),
+        }
+    }
+
+    fn record(&mut self, changed: impl IntoIterator<Item = PathBuf>, now: Instant) {
+        // A path that is already pending but changed again is still part of the burst.
+        let mut changed = changed.into_iter().peekable();
+        if changed.peek().is_some() {
+            self.last_change = Some(now);
+        }
+        self.pending.extend(changed);
+    }
+
+    // Fires once the burst has been quiet for the whole window, then starts over.
+    fn flush(&mut self, now: Instant) -> Option<Vec<PathBuf>> {
+        let last_change = self.last_change?;
+        if now.duration_since(last_change) < self.window {
+            return None;
+        }
+        self.last_change = None;
+        Some(std::mem::take(&mut self.pending).into_iter().collect())
+    }
+}
+
+// Build outputs are full of object files and caches; only the tools and venv executables matter.
+fn is_relevant_change(path: &Path) -> bool {
+    let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    if [
+        SWIFT_TOOL_NAME,
+        LEGACY_SWIFT_TOOL_NAME,
+        SWIFT_MODELCTL_TOOL_NAME,
+        LEGACY_SWIFT_MODELCTL_TOOL_NAME,
+        "pyvenv.cfg",
+    ]
+    .contains(&file_name)
+    {
+        return true;
+    }
+
+    let in_venv_bin = path
+        .parent()
+        .and_then(|parent| parent.file_name())
+        .is_some_and(|name| name == "bin")
+        && path
+            .ancestors()
+            .any(|ancestor| ancestor.file_name().is_some_and(|name| name == ".venv"));
+    in_venv_bin && !file_name.starts_with('.') && !file_name.ends_with(".tmp")
+}
+
+fn watched_roots() -> Vec<PathBuf> {
+    let mut roots = Vec::new();
+    if let Ok(swift_binary) = crate::local_tool_binary_path(SWIFT_TOOL_NAME) {
+        roots.extend(swift_binary.parent().map(Path::to_path_buf));
+    }
+    if let Ok(project_root) = crate::project_root() {
+        roots.extend(
+            PYTHON_WORKER_DIRS
+                .iter()
+                .map(|dir| project_root.join(dir).join(".venv")),
+        );
+    }
+    roots
+}
+
+fn snapshot(roots: &[PathBuf]) -> Snapshot {
+    let mut files = HashMap::new();
+    for root in roots.iter().filter(|root| root.exists()) {
+        for entry in WalkDir::new(root)
+            .max_depth(2)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|entry| is_relevant_change(entry.path()))
+        {
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            files.insert(
+                entry.into_path(),
+                (metadata.len(), metadata.modified().ok()),
+            );
+        }
+    }
+    files
+}
+
+fn changed_paths(previous: &Snapshot, current: &Snapshot) -> Vec<PathBuf> {
+    let mut changed = current
+        .iter()
+        .filter(|(path, state)| previous.get(*path) != Some(state))
+        .map(|(path, _)| path.clone())
+        .collect::<Vec<PathBuf>>();
+    changed.extend(
+        previous
+            .keys()
+            .filter(|path| !current.contains_key(*path))
+            .cloned(),
+    );
+    changed
+}
+
+fn watch_loop(app: AppHandle, roots: Vec<PathBuf>, stop_flag: Arc<AtomicBool>) {
+    let mut previous = snapshot(&roots);
+    let mut debouncer = ChangeDebouncer::new(WATCH_DEBOUNCE);
+
+    while !stop_flag.load(Ordering::SeqCst) {
+        std::thread::sleep(WATCH_POLL_INTERVAL);
+        let current = snapshot(&roots);
+        debouncer.record(changed_paths(&previous, &current), Instant::now());
+        previous = current;
+
+        if let Some(changed) = debouncer.flush(Instant::now()) {
+            // A rebuilt or reinstalled worker may answer `--capabilities` differently now.
+            if let Err(error) = clear_capability_cache() {
+                eprintln!("[watcher] {}", error);
+            }
+            let payload = ProvidersChanged {
+                changed: changed
+                    .iter()
+                    .map(|path| path.to_string_lossy().to_string())
+                    .collect(),
+            };
+            if let Err(error) = app.emit(PROVIDERS_CHANGED_EVENT, payload) {
+                eprintln!("[watcher] failed to emit providers change: {}", error);
+            }
+        }
+    }
+}
+
+#[tauri::command]
+pub fn start_providers_watch(app: AppHandle) -> Result<bool, String> {
+    let mut active = ACTIVE_WATCH
+        .lock()
+        .map_err(|_| "Failed to lock providers watch".to_string())?;
+    if active.is_some() {
+        return Ok(false);
+    }
+
+    let stop_flag = Arc::new(AtomicBool::new(false));
+    let thread_flag = Arc::clone(&stop_flag);
+    let roots = watched_roots();
+    std::thread::Builder::new()
+        .name("providers-watch".to_string())
+        .spawn(move || watch_loop(app, roots, thread_flag))
+        .map_err(|error| format!("Failed to start providers watch: {}", error))?;
+    *active = Some(stop_flag);
+    Ok(true)
+}
+
+#[tauri::command]
+pub fn stop_providers_watch() -> Result<bool, String> {
+    let mut active = ACTIVE_WATCH
+        .lock()
+        .map_err(|_| "Failed to lock providers watch".to_string())?;
+    match active.take() {
+        Some(stop_flag) => {
+            stop_flag.store(true, Ordering::SeqCst);
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_tool_binaries_and_venv_executables_are_relevant() {
+        assert!(is_relevant_change(Path::new(
+            "/repo/swift-worker/.build/release/coreml-batch"
+        )));
+        assert!(is_relevant_change(Path::new(
+            "/repo/workers/whisper-batch/.venv/bin/whisper-batch"
+        )));
+        assert!(is_relevant_change(Path::new(
+            "/repo/workers/whisper-batch/.venv/pyvenv.cfg"
+        )));
+        assert!(!is_relevant_change(Path::new(
+            "/repo/swift-worker/.build/release/coreml-batch.build/main.swift.o"
+        )));
+        assert!(!is_relevant_change(Path::new(
+            "/repo/workers/whisper-batch/.venv/bin/.python.tmp"
+        )));
+        assert!(!is_relevant_change(Path::new("/usr/local/bin/python3")));
+    }
+
+    #[test]
+    fn debouncer_waits_for_a_quiet_window_and_merges_bursts() {
+        let start = Instant::now();
+        let window = Duration::from_millis(500);
+        let mut debouncer = ChangeDebouncer::new(window);
+        assert_eq!(debouncer.flush(start), None);
+
+        debouncer.record([PathBuf::from("/bin/a")], start);
+        debouncer.record(
+            [PathBuf::from("/bin/b"), PathBuf::from("/bin/a")],
+            start + Duration::from_millis(300),
+        );
+        assert_eq!(debouncer.flush(start + Duration::from_millis(600)), None);
+        assert_eq!(
+            debouncer.flush(start + Duration::from_millis(800)),
+            Some(vec![PathBuf::from("/bin/a"), PathBuf::from("/bin/b")])
+        );
+        assert_eq!(debouncer.flush(start + Duration::from_secs(5)), None);
+
+        // An empty poll does not push the deadline back.
+        debouncer.record([PathBuf::from("/bin/c")], start + Duration::from_secs(6));
+        debouncer.record(Vec::new(), start + Duration::from_millis(6400));
+        assert!(debouncer
+            .flush(start + Duration::from_millis(6500))
+            .is_some());
+
+        debouncer.record([PathBuf::from("/bin/c")], start + Duration::from_secs(7));
+        debouncer.record(
+            [PathBuf::from("/bin/c")],
+            start + Duration::from_millis(7400),
+        );
+        assert_eq!(debouncer.flush(start + Duration::from_millis(7600)), None);
+        assert_eq!(
+            debouncer.flush(start + Duration::from_millis(7900)),
+            Some(vec![PathBuf::from("/bin/c")])
+        );
+    }
+}
@@ -15,6 +15,7 @@ import { getProviderById, useProviders } from "./hooks/useProviders";
 import { useTauriEvents } from "./hooks/useTauriEvents";
 import { useQueue } from "./hooks/useQueue";
 import {
+  checkExportDestination,
   checkNotificationPermission,
   deleteSession,
   exportTranscripts,
@@ -214,7 +215,11 @@ function normalizeDialogPath(value: string | string[] | null): string | null {
 }
 
 function queueItemFromSessionFile(file: SessionFileRecord): QueueItem {
-  const status = file.status === "success" || file.status === "skipped" ? "completed" : "idle";
+  // "empty" files ran fine but only produced silence markers; they are done, not pending.
+  const status =
+    file.status === "success" || file.status === "skipped" || file.status === "empty"
+      ? "completed"
+      : "idle";
   const progress = status === "completed" ? 100 : 0;
 
   return {
@@ -951,7 +956,26 @@ export default function App() {
           return;
         }
 
-        await exportTranscripts(exportItems, destination, options);
+        let exportOptions = options;
+        if (options.format === "folder" && !options.overwrite) {
+          const existing = await checkExportDestination(destination);
+          if (existing.length > 0) {
+            const preview = existing.slice(0, 5).join(", ");
+            const more = existing.length > 5 ? ` and ${existing.length - 5} more` : "";
+            const confirmed =
+              typeof globalThis.confirm === "function"
+                ? globalThis.confirm(
+                    `${destination} already contains ${preview}${more}. Export into it and overwrite matching files?`
+                  )
+                : true;
+            if (!confirmed) {
+              return;
+            }
+            exportOptions = { ...options, overwrite: true };
+          }
+        }
+
+        await exportTranscripts(exportItems, destination, exportOptions);
         setIsExportDialogOpen(false);
         showToast("Export complete.", "success");
       } catch (error) {
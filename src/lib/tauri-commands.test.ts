@@ -1,6 +1,7 @@
 import { beforeEach, describe, expect, it, vi } from "vitest";
 import type { ScanProgress } from "./tauri-commands";
 import {
+  checkExportDestination,
   checkNotificationPermission,
   deleteSession,
   exportTranscripts,
@@ -270,7 +271,7 @@ describe("tauri-commands", () => {
   });
 
   it("invokes export_transcripts with options", async () => {
-    invokeMock.mockResolvedValueOnce("/exports/bundle.zip");
+    invokeMock.mockResolvedValueOnce({ destination: "/exports/bundle.zip", cancelled: false });
 
     await expect(
       exportTranscripts(
@@ -293,7 +294,7 @@ describe("tauri-commands", () => {
           preserveStructure: false,
         }
       )
-    ).resolves.toBe("/exports/bundle.zip");
+    ).resolves.toEqual({ destination: "/exports/bundle.zip", cancelled: false });
 
     expect(invokeMock).toHaveBeenCalledWith("export_transcripts", {
       items: [
@@ -317,6 +318,22 @@ describe("tauri-commands", () => {
     });
   });
 
+  it("invokes check_export_destination and wraps its errors", async () => {
+    invokeMock.mockResolvedValueOnce(["a.txt", "notes.md"]);
+    await expect(checkExportDestination("/exports/folder")).resolves.toEqual([
+      "a.txt",
+      "notes.md",
+    ]);
+    expect(invokeMock).toHaveBeenCalledWith("check_export_destination", {
+      destination: "/exports/folder",
+    });
+
+    invokeMock.mockRejectedValueOnce(new Error("is a file"));
+    await expect(checkExportDestination("/exports/file.txt")).rejects.toThrow(
+      "check_export_destination failed: is a file"
+    );
+  });
+
   it("invokes get_session_history and delete_session", async () => {
     const expected = [{ id: "session-a", createdAt: 1, files: [] }];
     invokeMock.mockResolvedValueOnce(expected);
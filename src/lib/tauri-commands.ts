@@ -20,6 +20,12 @@ export interface TranscriptionSettings {
   notificationsEnabled: boolean;
   notifyOnComplete: boolean;
   notifyOnError: boolean;
+  writeIndex?: boolean;
+  chunkSize?: number | null;
+  skipPreviouslyTranscribed?: boolean;
+  eventLogPath?: string | null;
+  extraArgs?: string[];
+  warmPool?: boolean;
 }
 
 export interface ExportOptions {
@@ -27,6 +33,33 @@ export interface ExportOptions {
   naming: "preserve" | "timestamp" | "numbered";
   includeMetadata: boolean;
   preserveStructure: boolean;
+  moveFiles?: boolean;
+  utf8Bom?: boolean;
+  groupByLanguage?: boolean;
+  targetFilesystem?: "native" | "exfat" | "ntfs";
+  overwrite?: boolean;
+  skipSpaceCheck?: boolean;
+}
+
+export interface RenamedExportFile {
+  sourcePath: string;
+  requestedPath: string;
+  exportedPath: string;
+}
+
+export interface ExportResult {
+  destination: string;
+  cancelled: boolean;
+  renamed?: RenamedExportFile[];
+}
+
+export interface ExportProgress {
+  exportId: string;
+  written: number;
+  total: number;
+  currentPath: string | null;
+  done: boolean;
+  cancelled: boolean;
 }
 
 export interface SessionFileRecord {
@@ -37,6 +70,8 @@ export interface SessionFileRecord {
   transcriptPath?: string;
   jsonPath?: string;
   error?: string;
+  retries?: number;
+  processingSeconds?: number;
 }
 
 export interface SessionRecord {
@@ -51,8 +86,11 @@ export interface SessionRecord {
   skipped: number;
   failed: number;
   durationSeconds: number;
+  audioSeconds?: number;
   exitCode: number;
   status: string;
+  stopReason?: string | null;
+  tags?: string[];
   files: SessionFileRecord[];
 }
 
@@ -83,9 +121,21 @@ export async function scanFiles(paths: string[]): Promise<QueueItem[]> {
 }
 
 /** Scans a directory path and returns discovered audio queue items. */
-export async function scanDirectory(path: string, recursive: boolean): Promise<QueueItem[]> {
+export async function scanDirectory(
+  path: string,
+  recursive: boolean,
+  scanId?: string,
+  progressStep?: number,
+  progressIntervalMs?: number
+): Promise<QueueItem[]> {
   try {
-    return await invoke<QueueItem[]>("scan_directory", { path, recursive });
+    return await invoke<QueueItem[]>("scan_directory", {
+      path,
+      recursive,
+      scanId,
+      progressStep,
+      progressIntervalMs,
+    });
   } catch (error) {
     throw formatInvokeError("scan_directory", error);
   }
@@ -190,23 +240,62 @@ export async function exportTranscript(
   }
 }
 
+/** Lists the visible entries a folder export would land beside; empty when the folder is new or empty. */
+export async function checkExportDestination(destination: string): Promise<string[]> {
+  try {
+    return await invoke<string[]>("check_export_destination", { destination });
+  } catch (error) {
+    throw formatInvokeError("check_export_destination", error);
+  }
+}
+
 /** Exports multiple transcript artifacts using ZIP or folder output. */
 export async function exportTranscripts(
   items: QueueItem[],
   destination: string,
-  options: ExportOptions
-): Promise<string> {
+  options: ExportOptions,
+  exportId?: string
+): Promise<ExportResult> {
   try {
-    return await invoke<string>("export_transcripts", {
+    return await invoke<ExportResult>("export_transcripts", {
       items,
       destination,
       options,
+      exportId,
     });
   } catch (error) {
     throw formatInvokeError("export_transcripts", error);
   }
 }
 
+/** Exports like `exportTranscripts`, emitting `export-progress` events as each file is written. */
+export async function exportTranscriptsWithProgress(
+  items: QueueItem[],
+  destination: string,
+  options: ExportOptions,
+  exportId?: string
+): Promise<ExportResult> {
+  try {
+    return await invoke<ExportResult>("export_transcripts_with_progress", {
+      items,
+      destination,
+      options,
+      exportId,
+    });
+  } catch (error) {
+    throw formatInvokeError("export_transcripts_with_progress", error);
+  }
+}
+
+/** Requests cancellation of a running export; resolves false when no such export is active. */
+export async function cancelExport(exportId: string): Promise<boolean> {
+  try {
+    return await invoke<boolean>("cancel_export", { exportId });
+  } catch (error) {
+    throw formatInvokeError("cancel_export", error);
+  }
+}
+
 /** Returns persisted session history records in reverse chronological order. */
 export async function getSessionHistory(): Promise<SessionRecord[]> {
   try {
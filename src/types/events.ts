@@ -49,6 +49,7 @@ export interface FileDoneEvent {
   rtfx: number;
   confidence: number;
   output: OutputPaths;
+  retries?: number;
 }
 
 export interface FileSkippedEvent {
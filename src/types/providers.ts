@@ -33,6 +33,7 @@ export interface Capabilities {
   languages?: string[];
   speedEstimate?: number;
   maxFileSizeMb?: number;
+  protocolVersion?: number;
 }
 
 export interface Provider {
@@ -31,6 +31,7 @@ export interface QueueItem {
   rtfx?: number;
   transcriptPath?: string;
   jsonPath?: string;
+  language?: string;
   error?: string;
   metadata?: AudioMetadata;
   settings?: ItemSettings;
@@ -44,6 +44,9 @@ private enum ModelVersion: String {
     }
 }
 
+// Reported in the protocol handshake; the app refuses versions newer than it understands.
+private let workerProtocolVersion = 1
+
 private struct Config {
     let inputDir: URL?
     let manifestPath: URL?
@@ -58,6 +61,7 @@ private struct Config {
     let extensionFilter: Set<String>?
     let ffmpegFallback: Bool
     let maxRetries: Int
+    let protocolVersion: Int?
 }
 
 private struct ManifestSettings {
@@ -179,6 +183,7 @@ private struct WorkerCapabilities: Codable {
     let translation: Bool
     let languages: [String]
     let speedEstimate: Double
+    let protocolVersion: Int
 
     enum CodingKeys: String, CodingKey {
         case supportedModels = "supported_models"
@@ -190,6 +195,7 @@ private struct WorkerCapabilities: Codable {
         case translation
         case languages
         case speedEstimate = "speed_estimate"
+        case protocolVersion = "protocol_version"
     }
 }
 
@@ -421,6 +427,8 @@ private func usage() -> String {
       --max-retries <n>          Retry count per file after first failure. Default: 1
       --no-ffmpeg-fallback       Disable ffmpeg conversion fallback.
       --capabilities             Print runtime capabilities JSON and exit.
+      --protocol-version <n>     Event protocol version the caller speaks. The worker answers
+                                 with a protocol event before any other event.
       --help                     Show this message.
     """
 }
@@ -461,7 +469,8 @@ private func emitCapabilitiesAndExit() -> Never {
             languageDetection: false,
             translation: false,
             languages: ["en"],
-            speedEstimate: 0.3
+            speedEstimate: 0.3,
+            protocolVersion: workerProtocolVersion
         )
         let encoder = JSONEncoder()
         encoder.outputFormatting = [.sortedKeys]
@@ -506,6 +515,7 @@ private func parseArgs(_ args: ArraySlice<String>) throws -> Config {
     var ffmpegFallback = true
     var maxRetries = 1
     var capabilitiesMode = false
+    var protocolVersion: Int? = nil
 
     var outputDirFromCli = false
     var modelDirFromCli = false
@@ -593,6 +603,14 @@ private func parseArgs(_ args: ArraySlice<String>) throws -> Config {
             ffmpegFallbackFromCli = true
         case "--capabilities":
             capabilitiesMode = true
+        case "--protocol-version":
+            let next = args.index(after: i)
+            guard next < args.endIndex else { throw CliError.missingValue(arg) }
+            guard let parsed = Int(args[next]), parsed > 0 else {
+                throw CliError.invalidValue("Invalid protocol version: \(args[next]). Use a positive integer.")
+            }
+            protocolVersion = parsed
+            i = next
         default:
             throw CliError.invalidValue("Unknown argument: \(arg)")
         }
@@ -679,7 +697,8 @@ private func parseArgs(_ args: ArraySlice<String>) throws -> Config {
         dryRun: dryRun,
         extensionFilter: extensionFilter,
         ffmpegFallback: ffmpegFallback,
-        maxRetries: maxRetries
+        maxRetries: maxRetries,
+        protocolVersion: protocolVersion
     )
 }
 
@@ -1113,6 +1132,13 @@ private struct CoreMLBatchWorker {
             let config = try parseArgs(Array(CommandLine.arguments.dropFirst())[...])
             try validateConfig(config)
 
+            if let requested = config.protocolVersion {
+                Events.emit("protocol", fields: [
+                    "version": workerProtocolVersion,
+                    "requested": requested
+                ])
+            }
+
             var startFields: [String: Any] = [
                 "input_dir": config.inputDir?.path ?? "",
                 "output_dir": config.outputDir.path,
@@ -5,7 +5,8 @@ from __future__ import annotations
 import argparse
 from collections.abc import Sequence
 
-from .worker import print_capabilities, process_manifest
+from .events import PROTOCOL_VERSION, EventEmitter
+from .worker import print_capabilities, detect_languages, process_manifest
 
 
 def build_parser() -> argparse.ArgumentParser:
@@ -14,6 +15,8 @@ def build_parser() -> argparse.ArgumentParser:
     parser.add_argument("--output-dir", dest="output_dir")
     parser.add_argument("--model", default="large-v3")
     parser.add_argument("--capabilities", action="store_true")
+    parser.add_argument("--protocol-version", dest="protocol_version", type=int)
+    parser.add_argument("--detect-language", dest="detect_language", nargs="+", metavar="AUDIO")
     return parser
 
 
@@ -25,9 +28,17 @@ def main(argv: Sequence[str] | None = None) -> int:
         print_capabilities()
         return 0
 
+    if args.detect_language:
+        return detect_languages(args.detect_language, args.model)
+
     if not args.manifest or not args.output_dir:
         parser.error("--manifest and --output-dir are required unless --capabilities is set")
 
+    if args.protocol_version is not None:
+        EventEmitter().emit(
+            "protocol", version=PROTOCOL_VERSION, requested=args.protocol_version
+        )
+
     process_manifest(args.manifest, args.output_dir, args.model)
     return 0
 
@@ -10,7 +10,7 @@ import time
 from typing import Any
 from uuid import uuid4
 
-from .events import EventEmitter, FailureRecord, OutputPaths
+from .events import PROTOCOL_VERSION, EventEmitter, FailureRecord, OutputPaths
 from .gpu_utils import get_device, get_fallback_chain, get_next_fallback, get_optimal_compute_type
 
 AVAILABLE_MODELS = (
@@ -458,6 +458,7 @@ def get_capabilities() -> dict[str, Any]:
         "device": device,
         "compute_type": compute_type,
         "fallback_chain": fallback_chain,
+        "protocol_version": PROTOCOL_VERSION,
     }
 
 
@@ -638,3 +639,34 @@ def process_manifest(
         "manifest": manifest_data,
         "output_dir": str(output_root),
     }
+
+
+def detect_languages(paths: list[str], model_name: str) -> int:
+    """Emit one `language_detected` event per file without writing transcripts."""
+
+    emitter = EventEmitter()
+    device = get_device()
+    compute_type = get_optimal_compute_type()
+    try:
+        model = ensure_model(model_name, device=device, compute_type=compute_type)
+    except Exception as error:
+        emitter.emit_fatal_error(f"Failed to load Faster-Whisper model '{model_name}': {error}")
+        return 1
+
+    for index, source_path in enumerate(paths):
+        try:
+            # Detection only looks at the first 30 seconds; the segment generator is never consumed,
+            # so nothing past that window is decoded.
+            _segments, info = model.transcribe(source_path, vad_filter=True)
+        except Exception as error:
+            emitter.emit_file_failed(index=index, file=source_path, error=str(error), attempts=1)
+            continue
+        info_data = _info_payload(info)
+        emitter.emit(
+            "language_detected",
+            index=index,
+            file=source_path,
+            language=info_data.get("language"),
+            language_probability=info_data.get("language_probability"),
+        )
+    return 0
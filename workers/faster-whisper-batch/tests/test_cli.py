@@ -18,6 +18,17 @@ class CLITests(unittest.TestCase):
         print_capabilities.assert_called_once_with()
         process_manifest.assert_not_called()
 
+    def test_detect_language_skips_manifest_processing(self) -> None:
+        with (
+            patch("faster_whisper_batch.__main__.detect_languages", return_value=0) as detect,
+            patch("faster_whisper_batch.__main__.process_manifest") as process_manifest,
+        ):
+            exit_code = main(["--model", "small", "--detect-language", "a.wav", "b.wav"])
+
+        self.assertEqual(exit_code, 0)
+        detect.assert_called_once_with(["a.wav", "b.wav"], "small")
+        process_manifest.assert_not_called()
+
     def test_requires_manifest_and_output_without_capabilities(self) -> None:
         with self.assertRaises(SystemExit) as exc:
             main([])
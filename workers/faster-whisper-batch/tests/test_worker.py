@@ -9,7 +9,7 @@ from pathlib import Path
 from typing import Any
 from unittest.mock import patch
 
-from faster_whisper_batch.worker import print_capabilities, process_manifest
+from faster_whisper_batch.worker import detect_languages, print_capabilities, process_manifest
 
 
 def parse_events(payload: str) -> list[dict[str, Any]]:
@@ -342,6 +342,7 @@ class WorkerTests(unittest.TestCase):
         self.assertEqual(payload["fallback_chain"][1], {"device": "cuda", "compute_type": "int8_float16"})
         self.assertTrue(payload["word_timestamps"])
         self.assertIn("large-v3", payload["supported_models"])
+        self.assertEqual(payload["protocol_version"], 1)
 
     def test_process_manifest_emits_fatal_error_when_model_loading_fails(self) -> None:
         with tempfile.TemporaryDirectory() as tmp_dir:
@@ -373,6 +374,30 @@ class WorkerTests(unittest.TestCase):
             for event in events:
                 assert_protocol_event(self, event)
 
+    def test_detect_languages_reports_language_without_writing_outputs(self) -> None:
+        fake_model = FakeModel(
+            {
+                "/audio/a.wav": ([FakeSegment(0, 0.0, 1.0, " hallo")], FakeInfo("de", 0.91, 12.0)),
+                "/audio/b.wav": RuntimeError("decode failed"),
+            }
+        )
+        stream = io.StringIO()
+        with (
+            patch("faster_whisper_batch.worker.get_device", return_value="cpu"),
+            patch("faster_whisper_batch.worker.get_optimal_compute_type", return_value="int8"),
+            patch("faster_whisper_batch.worker.ensure_model", return_value=fake_model),
+            redirect_stdout(stream),
+        ):
+            exit_code = detect_languages(["/audio/a.wav", "/audio/b.wav"], "small")
+
+        self.assertEqual(exit_code, 0)
+        events = parse_events(stream.getvalue())
+        self.assertEqual([event["event"] for event in events], ["language_detected", "file_failed"])
+        self.assertEqual(events[0]["file"], "/audio/a.wav")
+        self.assertEqual(events[0]["language"], "de")
+        self.assertEqual(events[0]["language_probability"], 0.91)
+        self.assertEqual(events[1]["error"], "decode failed")
+
 
 if __name__ == "__main__":
     unittest.main()
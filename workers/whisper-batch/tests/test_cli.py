@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import json
 import unittest
 from unittest.mock import patch
 
@@ -47,6 +48,29 @@ class CLITests(unittest.TestCase):
         self.assertEqual(exit_code, 0)
         process_manifest.assert_called_once_with("manifest.json", "out", "small")
 
+    def test_answers_protocol_handshake_before_processing(self) -> None:
+        with (
+            patch("whisper_batch.__main__.process_manifest") as process_manifest,
+            patch("builtins.print") as printed,
+        ):
+            exit_code = main(
+                [
+                    "--manifest",
+                    "manifest.json",
+                    "--output-dir",
+                    "out",
+                    "--protocol-version",
+                    "1",
+                ]
+            )
+
+        self.assertEqual(exit_code, 0)
+        event = json.loads(printed.call_args_list[0].args[0])
+        self.assertEqual(event["event"], "protocol")
+        self.assertEqual(event["version"], 1)
+        self.assertEqual(event["requested"], 1)
+        process_manifest.assert_called_once_with("manifest.json", "out", "base")
+
 
 if __name__ == "__main__":
     unittest.main()
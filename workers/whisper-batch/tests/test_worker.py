@@ -9,7 +9,7 @@ from pathlib import Path
 from typing import Any
 from unittest.mock import patch
 
-from whisper_batch.worker import process_manifest
+from whisper_batch.worker import detect_languages, process_manifest
 
 
 def parse_events(payload: str) -> list[dict[str, Any]]:
@@ -270,6 +270,27 @@ class WorkerTests(unittest.TestCase):
                 assert_protocol_event(self, event)
             self.assertIn("download failed", str(events[2]["error"]))
 
+    def test_detect_languages_reports_language_without_writing_outputs(self) -> None:
+        def fake_detect(_model: Any, source_path: str) -> tuple[str, float]:
+            if source_path == "/audio/b.wav":
+                raise RuntimeError("decode failed")
+            return "fr", 0.77
+
+        stream = io.StringIO()
+        with (
+            patch("whisper_batch.worker.ensure_model", return_value=object()),
+            patch("whisper_batch.worker._detect_file_language", side_effect=fake_detect),
+            redirect_stdout(stream),
+        ):
+            exit_code = detect_languages(["/audio/a.wav", "/audio/b.wav"], "base")
+
+        self.assertEqual(exit_code, 0)
+        events = parse_events(stream.getvalue())
+        self.assertEqual([event["event"] for event in events], ["language_detected", "file_failed"])
+        self.assertEqual(events[0]["language"], "fr")
+        self.assertEqual(events[0]["language_probability"], 0.77)
+        self.assertEqual(events[1]["file"], "/audio/b.wav")
+
 
 if __name__ == "__main__":
     unittest.main()
@@ -6,6 +6,9 @@ import json
 from datetime import datetime, timezone
 from typing import TypedDict
 
+# Bump when event names or fields change in a way older apps would misread.
+PROTOCOL_VERSION = 1
+
 
 class OutputPaths(TypedDict, total=False):
     txt: str
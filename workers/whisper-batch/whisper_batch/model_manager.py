@@ -5,6 +5,8 @@ from __future__ import annotations
 import json
 from typing import Any
 
+from .events import PROTOCOL_VERSION
+
 AVAILABLE_MODELS = (
     "tiny",
     "base",
@@ -161,6 +163,7 @@ def get_capabilities() -> dict[str, Any]:
         "translation": True,
         "languages": _available_languages(),
         "speed_estimate": 0.5,
+        "protocol_version": PROTOCOL_VERSION,
     }
 
 
@@ -221,3 +221,40 @@ def process_manifest(manifest_path: str, output_dir: str, model_name: str) -> di
         "manifest": manifest_data,
         "output_dir": str(output_root),
     }
+
+
+def _detect_file_language(model: Any, source_path: str) -> tuple[str, float]:
+    import whisper
+
+    # Whisper decides the language from a single 30-second window, so trim before the mel pass.
+    audio = whisper.pad_or_trim(whisper.load_audio(source_path))
+    mel = whisper.log_mel_spectrogram(audio, n_mels=model.dims.n_mels).to(model.device)
+    _, probabilities = model.detect_language(mel)
+    language = max(probabilities, key=probabilities.get)
+    return language, float(probabilities[language])
+
+
+def detect_languages(paths: list[str], model_name: str) -> int:
+    """Emit one `language_detected` event per file without writing transcripts."""
+
+    emitter = EventEmitter()
+    try:
+        model = ensure_model(model_name)
+    except Exception as error:
+        emitter.emit_fatal_error(f"Failed to load Whisper model '{model_name}': {error}")
+        return 1
+
+    for index, source_path in enumerate(paths):
+        try:
+            language, probability = _detect_file_language(model, source_path)
+        except Exception as error:
+            emitter.emit_file_failed(index=index, file=source_path, error=str(error), attempts=1)
+            continue
+        emitter.emit(
+            "language_detected",
+            index=index,
+            file=source_path,
+            language=language,
+            language_probability=probability,
+        )
+    return 0